@@ -0,0 +1,5 @@
+-- Add down migration script here
+
+DROP FUNCTION snapshot_scores(BOOLEAN);
+DROP TABLE nation_score_history;
+DROP TABLE player_score_history;
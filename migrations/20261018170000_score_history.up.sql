@@ -0,0 +1,57 @@
+-- Add up migration script here
+
+CREATE TABLE player_score_history (
+    player INTEGER NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    time TIMESTAMP WITHOUT TIME ZONE DEFAULT (NOW() AT TIME ZONE 'utc') NOT NULL,
+    score DOUBLE PRECISION NOT NULL,
+    -- NULL if the player was not ranked at this point in time (e.g. because they had no score)
+    rank BIGINT,
+    PRIMARY KEY (player, time)
+);
+
+CREATE TABLE nation_score_history (
+    nation VARCHAR(2) NOT NULL REFERENCES nationalities(iso_country_code) ON DELETE CASCADE,
+    time TIMESTAMP WITHOUT TIME ZONE DEFAULT (NOW() AT TIME ZONE 'utc') NOT NULL,
+    score DOUBLE PRECISION NOT NULL,
+    rank BIGINT,
+    PRIMARY KEY (nation, time)
+);
+
+-- Takes a snapshot of the score and rank of every player and nation whose score changed since their last snapshot.
+-- Players and nations without any snapshot only get one once they have a non-zero score.
+--
+-- A single score change can shift the rank of every player below the affected one, so snapshotting all rank changes
+-- whenever some score changes would produce a lot of snapshots. Instead, rank changes that are not caused by a
+-- change in score are only captured when explicitly requested (e.g. by a periodic job).
+--
+-- Needs to be called after `player_ranks` was refreshed. Calling it multiple times within the same transaction only keeps
+-- the last snapshot.
+CREATE FUNCTION snapshot_scores(include_rank_changes BOOLEAN) RETURNS VOID AS
+$$
+    INSERT INTO player_score_history (player, score, rank)
+    SELECT players.id, players.score, player_ranks.rank
+    FROM players
+    LEFT OUTER JOIN player_ranks ON player_ranks.id = players.id
+    LEFT JOIN LATERAL (
+        SELECT score, rank FROM player_score_history WHERE player = players.id ORDER BY time DESC LIMIT 1
+    ) AS latest ON TRUE
+    WHERE (latest.score IS NULL AND players.score != 0)
+       OR latest.score != players.score
+       OR (include_rank_changes AND latest.rank IS DISTINCT FROM player_ranks.rank)
+    ON CONFLICT (player, time) DO UPDATE SET score = EXCLUDED.score, rank = EXCLUDED.rank;
+
+    INSERT INTO nation_score_history (nation, score, rank)
+    SELECT nationalities.iso_country_code, nationalities.score, ranked_nations.rank
+    FROM nationalities
+    LEFT OUTER JOIN ranked_nations ON ranked_nations.iso_country_code = nationalities.iso_country_code
+    LEFT JOIN LATERAL (
+        SELECT score, rank FROM nation_score_history WHERE nation = nationalities.iso_country_code ORDER BY time DESC LIMIT 1
+    ) AS latest ON TRUE
+    WHERE (latest.score IS NULL AND nationalities.score != 0)
+       OR latest.score != nationalities.score
+       OR (include_rank_changes AND latest.rank IS DISTINCT FROM ranked_nations.rank)
+    ON CONFLICT (nation, time) DO UPDATE SET score = EXCLUDED.score, rank = EXCLUDED.rank;
+$$ LANGUAGE SQL;
+
+-- Baseline snapshot
+SELECT snapshot_scores(TRUE);
@@ -0,0 +1,232 @@
+-- Add down migration script here
+
+-- Everything that is not part of the main list would be lost, so refuse to migrate in that case
+DO $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM lists WHERE id != 'main') THEN
+        RAISE EXCEPTION 'Cannot revert to a single list while lists other than "main" exist';
+    END IF;
+END
+$$;
+
+DROP FUNCTION list_at(VARCHAR(32), TIMESTAMP WITHOUT TIME ZONE);
+DROP VIEW ranked_players;
+DROP VIEW ranked_nations;
+DROP MATERIALIZED VIEW player_ranks;
+DROP FUNCTION update_player_scores(INTEGER);
+DROP FUNCTION update_nation_scores(VARCHAR(2));
+DROP FUNCTION update_subdivision_scores(VARCHAR(2), VARCHAR(3));
+DROP FUNCTION recompute_player_scores(VARCHAR(32));
+DROP FUNCTION recompute_nation_scores(VARCHAR(32));
+DROP FUNCTION recompute_subdivision_scores(VARCHAR(32));
+DROP VIEW score_giving;
+
+ALTER TABLE player_score_history DROP CONSTRAINT player_score_history_pkey;
+ALTER TABLE player_score_history DROP COLUMN list;
+ALTER TABLE player_score_history ADD PRIMARY KEY (player, time);
+
+ALTER TABLE nation_score_history DROP CONSTRAINT nation_score_history_pkey;
+ALTER TABLE nation_score_history DROP COLUMN list;
+ALTER TABLE nation_score_history ADD PRIMARY KEY (nation, time);
+
+ALTER TABLE players ADD COLUMN score DOUBLE PRECISION DEFAULT 0 NOT NULL;
+ALTER TABLE nationalities ADD COLUMN score DOUBLE PRECISION NOT NULL DEFAULT 0.0;
+ALTER TABLE subdivisions ADD COLUMN score DOUBLE PRECISION NOT NULL DEFAULT 0.0;
+
+UPDATE players SET score = player_scores.score FROM player_scores WHERE player_scores.player = players.id;
+UPDATE nationalities SET score = nation_scores.score FROM nation_scores WHERE nation_scores.nation = nationalities.iso_country_code;
+UPDATE subdivisions SET score = subdivision_scores.score FROM subdivision_scores
+    WHERE subdivision_scores.nation = subdivisions.nation AND subdivision_scores.subdivision = subdivisions.iso_code;
+
+DROP TABLE player_scores;
+DROP TABLE nation_scores;
+DROP TABLE subdivision_scores;
+
+ALTER TABLE demons DROP CONSTRAINT unique_position;
+ALTER TABLE demons ADD CONSTRAINT unique_position UNIQUE (position) DEFERRABLE INITIALLY IMMEDIATE;
+ALTER TABLE demons DROP COLUMN list;
+
+DROP TABLE lists;
+
+CREATE VIEW score_giving AS
+    SELECT records.progress, demons.position, demons.requirement, records.player
+    FROM records
+    INNER JOIN demons
+    ON demons.id = records.demon
+    WHERE records.status_ = 'APPROVED' AND (demons.position <= 75 OR records.progress = 100)
+
+    UNION
+
+    SELECT 100, demons.position, demons.requirement, demons.verifier
+    FROM demons;
+
+CREATE FUNCTION score_of_player(player_id INTEGER) RETURNS DOUBLE PRECISION AS $$
+    SELECT SUM(record_score(progress, position, 150, requirement))
+    FROM score_giving
+    WHERE player = player_id
+$$ LANGUAGE SQL;
+
+CREATE FUNCTION score_of_nation(iso_country_code VARCHAR(2)) RETURNS DOUBLE PRECISION AS $$
+    SELECT SUM(record_score(q.progress, q.position, 150, q.requirement))
+    FROM (
+        SELECT DISTINCT ON (position) * from score_giving
+        INNER JOIN players
+                ON players.id=player
+        WHERE players.nationality = iso_country_code
+        ORDER BY position, progress DESC
+    ) q
+$$ LANGUAGE SQL;
+
+CREATE FUNCTION score_of_subdivision(iso_country_code VARCHAR(2), iso_code VARCHAR(3)) RETURNS DOUBLE PRECISION AS $$
+    SELECT SUM(record_score(q.progress, q.position, 150, q.requirement))
+    FROM (
+        SELECT DISTINCT ON (position) * from score_giving
+        INNER JOIN players
+                ON players.id=player
+        WHERE players.nationality = iso_country_code
+          AND players.subdivision = iso_code
+        ORDER BY position, progress DESC
+    ) q
+$$ LANGUAGE SQL;
+
+CREATE FUNCTION recompute_player_scores() RETURNS void AS $$
+    UPDATE players
+    SET score = coalesce(q.score, 0)
+    FROM players p
+        LEFT OUTER JOIN (
+            SELECT player, SUM(record_score(progress, position, 150, requirement)) as score
+            FROM score_giving
+            GROUP BY player
+        ) q
+        ON q.player = p.id
+    WHERE players.id = p.id;
+$$ LANGUAGE SQL;
+
+CREATE FUNCTION recompute_nation_scores() RETURNS void AS $$
+    UPDATE nationalities
+    SET score = COALESCE(p.sum, 0)
+    FROM nationalities n
+        LEFT OUTER JOIN (
+            SELECT nationality, SUM(record_score(q.progress, q.position, 150, q.requirement))
+            FROM (
+                SELECT DISTINCT ON (position, nationality) * from score_giving
+                INNER JOIN players
+                        ON players.id=player
+                WHERE players.nationality IS NOT NULL
+                ORDER BY players.nationality, position, progress DESC
+            ) q
+            GROUP BY nationality
+        ) p
+        ON p.nationality = n.iso_country_code
+    WHERE n.iso_country_code = nationalities.iso_country_code
+$$ LANGUAGE SQL;
+
+CREATE FUNCTION recompute_subdivision_scores() RETURNS void AS $$
+    UPDATE subdivisions
+    SET score = COALESCE(p.sum, 0)
+    FROM subdivisions s
+        LEFT OUTER JOIN (
+            SELECT nationality, subdivision, SUM(record_score(q.progress, q.position, 150, q.requirement))
+            FROM (
+                SELECT DISTINCT ON (position, nationality, subdivision) * from score_giving
+                INNER JOIN players
+                        ON players.id=player
+                WHERE players.nationality IS NOT NULL
+                AND players.subdivision IS NOT NULL
+                ORDER BY players.nationality, players.subdivision, position, progress DESC
+            ) q
+            GROUP BY nationality, subdivision
+        ) p
+        ON s.nation = p.nationality AND s.iso_code = p.subdivision
+    WHERE s.nation = subdivisions.nation
+      AND s.iso_code = subdivisions.iso_code
+$$ LANGUAGE SQL;
+
+CREATE MATERIALIZED VIEW player_ranks AS
+       SELECT
+           RANK() OVER (ORDER BY score DESC) as rank,
+           id
+       FROM players
+       WHERE
+           score != 0 AND NOT banned;
+
+CREATE UNIQUE INDEX player_ranks_id_idx ON player_ranks(id);
+
+CREATE VIEW ranked_players AS
+SELECT
+    ROW_NUMBER() OVER(ORDER BY rank, id) AS index,
+    rank,
+    id, name, players.score, subdivision,
+    nationalities.iso_country_code,
+    nationalities.nation,
+    nationalities.continent
+FROM players
+LEFT OUTER JOIN nationalities
+             ON players.nationality = nationalities.iso_country_code
+NATURAL JOIN player_ranks;
+
+CREATE VIEW ranked_nations AS
+    SELECT
+        ROW_NUMBER() OVER(ORDER BY score DESC, iso_country_code) AS index,
+        RANK() OVER(ORDER BY score DESC) AS rank,
+        score,
+        iso_country_code,
+        nation,
+        continent
+    FROM nationalities
+    WHERE score > 0.0;
+
+CREATE FUNCTION list_at(TIMESTAMP WITHOUT TIME ZONE)
+RETURNS TABLE (
+    name CITEXT,
+    position_ SMALLINT,
+    requirement SMALLINT,
+    video VARCHAR(200),
+    thumbnail TEXT,
+    verifier INTEGER,
+    publisher INTEGER,
+    id INTEGER,
+    level_id BIGINT,
+    current_position SMALLINT
+)
+AS $$
+    SELECT name, CASE WHEN t.position IS NULL THEN demons.position ELSE t.position END, requirement, video, thumbnail, verifier, publisher, demons.id, level_id, demons.position AS current_position
+    FROM demons
+    LEFT OUTER JOIN (
+        SELECT DISTINCT ON (id) id, position
+        FROM demon_modifications
+        WHERE time >= $1 AND position != -1
+        ORDER BY id, time
+    ) t
+    ON demons.id = t.id
+    WHERE NOT EXISTS (SELECT 1 FROM demon_additions WHERE demon_additions.id = demons.id AND time >= $1)
+$$
+LANGUAGE SQL
+STABLE;
+
+CREATE OR REPLACE FUNCTION snapshot_scores(include_rank_changes BOOLEAN) RETURNS VOID AS
+$$
+    INSERT INTO player_score_history (player, score, rank)
+    SELECT players.id, players.score, player_ranks.rank
+    FROM players
+    LEFT OUTER JOIN player_ranks ON player_ranks.id = players.id
+    LEFT JOIN LATERAL (
+        SELECT score, rank FROM player_score_history WHERE player = players.id ORDER BY time DESC LIMIT 1
+    ) AS latest ON TRUE
+    WHERE (latest.score IS NULL AND players.score != 0)
+       OR latest.score != players.score
+       OR (include_rank_changes AND latest.rank IS DISTINCT FROM player_ranks.rank)
+    ON CONFLICT (player, time) DO UPDATE SET score = EXCLUDED.score, rank = EXCLUDED.rank;
+
+    INSERT INTO nation_score_history (nation, score, rank)
+    SELECT nationalities.iso_country_code, nationalities.score, ranked_nations.rank
+    FROM nationalities
+    LEFT OUTER JOIN ranked_nations ON ranked_nations.iso_country_code = nationalities.iso_country_code
+    LEFT JOIN LATERAL (
+        SELECT score, rank FROM nation_score_history WHERE nation = nationalities.iso_country_code ORDER BY time DESC LIMIT 1
+    ) AS latest ON TRUE
+    WHERE (latest.score IS NULL AND nationalities.score != 0)
+       OR latest.score != nationalities.score
+       OR (include_rank_changes AND latest.rank IS DISTINCT FROM ranked_nations.rank)
+    ON CONFLICT (nation, time) DO UPDATE SET score = EXCLUDED.score, rank = EXCLUDED.rank;
+$$ LANGUAGE SQL;
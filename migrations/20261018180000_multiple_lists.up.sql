@@ -0,0 +1,302 @@
+-- Add up migration script here
+
+CREATE TABLE lists (
+    id VARCHAR(32) PRIMARY KEY CHECK (id ~ '^[a-z0-9]+(-[a-z0-9]+)*$'),
+    name TEXT NOT NULL,
+    list_size SMALLINT NOT NULL CHECK (list_size > 0),
+    extended_list_size SMALLINT NOT NULL,
+    CHECK (extended_list_size >= list_size)
+);
+
+-- The list all already existing demons are part of. The sizes are the ones the score_giving view and record_score
+-- function used to hardcode.
+INSERT INTO lists (id, name, list_size, extended_list_size) VALUES ('main', 'Demonlist', 75, 150);
+
+-- A demon never moves between lists, so there is no need to track this column in the audit log
+ALTER TABLE demons ADD COLUMN list VARCHAR(32) NOT NULL DEFAULT 'main' REFERENCES lists(id) ON UPDATE CASCADE;
+ALTER TABLE demons DROP CONSTRAINT unique_position;
+ALTER TABLE demons ADD CONSTRAINT unique_position UNIQUE (list, position) DEFERRABLE INITIALLY IMMEDIATE;
+
+-- Scores are now stored per list. Only non-zero scores are stored, a missing row means a score of zero.
+CREATE TABLE player_scores (
+    list VARCHAR(32) NOT NULL REFERENCES lists(id) ON UPDATE CASCADE ON DELETE CASCADE,
+    player INTEGER NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    score DOUBLE PRECISION NOT NULL,
+    PRIMARY KEY (list, player)
+);
+
+CREATE INDEX player_scores_player_idx ON player_scores(player);
+
+CREATE TABLE nation_scores (
+    list VARCHAR(32) NOT NULL REFERENCES lists(id) ON UPDATE CASCADE ON DELETE CASCADE,
+    nation VARCHAR(2) NOT NULL REFERENCES nationalities(iso_country_code) ON DELETE CASCADE,
+    score DOUBLE PRECISION NOT NULL,
+    PRIMARY KEY (list, nation)
+);
+
+CREATE TABLE subdivision_scores (
+    list VARCHAR(32) NOT NULL REFERENCES lists(id) ON UPDATE CASCADE ON DELETE CASCADE,
+    nation VARCHAR(2) NOT NULL,
+    subdivision VARCHAR(3) NOT NULL,
+    score DOUBLE PRECISION NOT NULL,
+    PRIMARY KEY (list, nation, subdivision),
+    FOREIGN KEY (subdivision, nation) REFERENCES subdivisions(iso_code, nation) ON DELETE CASCADE
+);
+
+INSERT INTO player_scores (list, player, score) SELECT 'main', id, score FROM players WHERE score > 0;
+INSERT INTO nation_scores (list, nation, score) SELECT 'main', iso_country_code, score FROM nationalities WHERE score > 0;
+INSERT INTO subdivision_scores (list, nation, subdivision, score) SELECT 'main', nation, iso_code, score FROM subdivisions WHERE score > 0;
+
+DROP VIEW ranked_players;
+DROP VIEW ranked_nations;
+DROP MATERIALIZED VIEW player_ranks;
+DROP VIEW score_giving;
+
+DROP FUNCTION score_of_player(INTEGER);
+DROP FUNCTION score_of_nation(VARCHAR(2));
+DROP FUNCTION score_of_subdivision(VARCHAR(2), VARCHAR(3));
+DROP FUNCTION recompute_player_scores();
+DROP FUNCTION recompute_nation_scores();
+DROP FUNCTION recompute_subdivision_scores();
+DROP FUNCTION list_at(TIMESTAMP WITHOUT TIME ZONE);
+
+ALTER TABLE players DROP COLUMN score;
+ALTER TABLE nationalities DROP COLUMN score;
+ALTER TABLE subdivisions DROP COLUMN score;
+
+CREATE VIEW score_giving AS
+    SELECT records.progress, demons.position, demons.requirement, records.player, demons.list, lists.extended_list_size
+    FROM records
+    INNER JOIN demons
+    ON demons.id = records.demon
+    INNER JOIN lists
+    ON lists.id = demons.list
+    WHERE records.status_ = 'APPROVED'
+      AND demons.position <= lists.extended_list_size
+      AND (demons.position <= lists.list_size OR records.progress = 100)
+
+    UNION
+
+    SELECT 100, demons.position, demons.requirement, demons.verifier, demons.list, lists.extended_list_size
+    FROM demons
+    INNER JOIN lists
+    ON lists.id = demons.list
+    WHERE demons.position <= lists.extended_list_size;
+
+-- Recomputes the scores of all players, nations and subdivisions in the given list. Only needs to be called when demons
+-- are being moved around (or the size of a list changes).
+CREATE FUNCTION recompute_player_scores(list_id VARCHAR(32)) RETURNS VOID AS $$
+    DELETE FROM player_scores WHERE list = list_id;
+
+    INSERT INTO player_scores (list, player, score)
+    SELECT list_id, player, SUM(record_score(progress, position, extended_list_size, requirement))
+    FROM score_giving
+    WHERE list = list_id
+    GROUP BY player
+    HAVING SUM(record_score(progress, position, extended_list_size, requirement)) > 0;
+$$ LANGUAGE SQL;
+
+CREATE FUNCTION recompute_nation_scores(list_id VARCHAR(32)) RETURNS VOID AS $$
+    DELETE FROM nation_scores WHERE list = list_id;
+
+    INSERT INTO nation_scores (list, nation, score)
+    SELECT list_id, nationality, SUM(record_score(q.progress, q.position, q.extended_list_size, q.requirement))
+    FROM (
+        SELECT DISTINCT ON (position, nationality) progress, position, extended_list_size, requirement, nationality
+        FROM score_giving
+        INNER JOIN players
+                ON players.id = player
+        WHERE list = list_id AND players.nationality IS NOT NULL
+        ORDER BY players.nationality, position, progress DESC
+    ) q
+    GROUP BY nationality
+    HAVING SUM(record_score(q.progress, q.position, q.extended_list_size, q.requirement)) > 0;
+$$ LANGUAGE SQL;
+
+CREATE FUNCTION recompute_subdivision_scores(list_id VARCHAR(32)) RETURNS VOID AS $$
+    DELETE FROM subdivision_scores WHERE list = list_id;
+
+    INSERT INTO subdivision_scores (list, nation, subdivision, score)
+    SELECT list_id, nationality, subdivision, SUM(record_score(q.progress, q.position, q.extended_list_size, q.requirement))
+    FROM (
+        SELECT DISTINCT ON (position, nationality, subdivision) progress, position, extended_list_size, requirement, nationality, subdivision
+        FROM score_giving
+        INNER JOIN players
+                ON players.id = player
+        WHERE list = list_id AND players.nationality IS NOT NULL AND players.subdivision IS NOT NULL
+        ORDER BY players.nationality, players.subdivision, position, progress DESC
+    ) q
+    GROUP BY nationality, subdivision
+    HAVING SUM(record_score(q.progress, q.position, q.extended_list_size, q.requirement)) > 0;
+$$ LANGUAGE SQL;
+
+-- Updates the scores of a single player (or nation, or subdivision) in all lists
+CREATE FUNCTION update_player_scores(player_id INTEGER) RETURNS VOID AS $$
+    DELETE FROM player_scores WHERE player = player_id;
+
+    INSERT INTO player_scores (list, player, score)
+    SELECT list, player_id, SUM(record_score(progress, position, extended_list_size, requirement))
+    FROM score_giving
+    WHERE player = player_id
+    GROUP BY list
+    HAVING SUM(record_score(progress, position, extended_list_size, requirement)) > 0;
+$$ LANGUAGE SQL;
+
+CREATE FUNCTION update_nation_scores(iso_code VARCHAR(2)) RETURNS VOID AS $$
+    DELETE FROM nation_scores WHERE nation = iso_code;
+
+    INSERT INTO nation_scores (list, nation, score)
+    SELECT list, iso_code, SUM(record_score(q.progress, q.position, q.extended_list_size, q.requirement))
+    FROM (
+        SELECT DISTINCT ON (list, position) list, progress, position, extended_list_size, requirement
+        FROM score_giving
+        INNER JOIN players
+                ON players.id = player
+        WHERE players.nationality = iso_code
+        ORDER BY list, position, progress DESC
+    ) q
+    GROUP BY list
+    HAVING SUM(record_score(q.progress, q.position, q.extended_list_size, q.requirement)) > 0;
+$$ LANGUAGE SQL;
+
+CREATE FUNCTION update_subdivision_scores(nation_code VARCHAR(2), subdivision_code VARCHAR(3)) RETURNS VOID AS $$
+    DELETE FROM subdivision_scores WHERE nation = nation_code AND subdivision = subdivision_code;
+
+    INSERT INTO subdivision_scores (list, nation, subdivision, score)
+    SELECT list, nation_code, subdivision_code, SUM(record_score(q.progress, q.position, q.extended_list_size, q.requirement))
+    FROM (
+        SELECT DISTINCT ON (list, position) list, progress, position, extended_list_size, requirement
+        FROM score_giving
+        INNER JOIN players
+                ON players.id = player
+        WHERE players.nationality = nation_code AND players.subdivision = subdivision_code
+        ORDER BY list, position, progress DESC
+    ) q
+    GROUP BY list
+    HAVING SUM(record_score(q.progress, q.position, q.extended_list_size, q.requirement)) > 0;
+$$ LANGUAGE SQL;
+
+CREATE MATERIALIZED VIEW player_ranks AS
+    SELECT
+        RANK() OVER (PARTITION BY list ORDER BY score DESC) AS rank,
+        list,
+        player AS id
+    FROM player_scores
+    INNER JOIN players
+            ON players.id = player_scores.player
+    WHERE NOT players.banned;
+
+CREATE UNIQUE INDEX player_ranks_list_id_idx ON player_ranks(list, id);
+
+CREATE VIEW ranked_players AS
+    SELECT
+        ROW_NUMBER() OVER (PARTITION BY player_ranks.list ORDER BY rank, players.id) AS index,
+        rank,
+        player_ranks.list,
+        players.id, players.name, player_scores.score, players.subdivision,
+        nationalities.iso_country_code,
+        nationalities.nation,
+        nationalities.continent
+    FROM player_ranks
+    INNER JOIN players
+            ON players.id = player_ranks.id
+    INNER JOIN player_scores
+            ON player_scores.list = player_ranks.list AND player_scores.player = player_ranks.id
+    LEFT OUTER JOIN nationalities
+                 ON players.nationality = nationalities.iso_country_code;
+
+CREATE VIEW ranked_nations AS
+    SELECT
+        ROW_NUMBER() OVER (PARTITION BY list ORDER BY score DESC, iso_country_code) AS index,
+        RANK() OVER (PARTITION BY list ORDER BY score DESC) AS rank,
+        list,
+        score,
+        iso_country_code,
+        nationalities.nation,
+        continent
+    FROM nation_scores
+    INNER JOIN nationalities
+            ON nationalities.iso_country_code = nation_scores.nation;
+
+CREATE FUNCTION list_at(list_id VARCHAR(32), at TIMESTAMP WITHOUT TIME ZONE)
+RETURNS TABLE (
+    name CITEXT,
+    position_ SMALLINT,
+    requirement SMALLINT,
+    video VARCHAR(200),
+    thumbnail TEXT,
+    verifier INTEGER,
+    publisher INTEGER,
+    id INTEGER,
+    level_id BIGINT,
+    current_position SMALLINT
+)
+AS $$
+    SELECT name, CASE WHEN t.position IS NULL THEN demons.position ELSE t.position END, requirement, video, thumbnail, verifier, publisher, demons.id, level_id, demons.position AS current_position
+    FROM demons
+    LEFT OUTER JOIN (
+        SELECT DISTINCT ON (id) id, position
+        FROM demon_modifications
+        WHERE time >= at AND position != -1
+        ORDER BY id, time
+    ) t
+    ON demons.id = t.id
+    WHERE demons.list = list_id
+      AND NOT EXISTS (SELECT 1 FROM demon_additions WHERE demon_additions.id = demons.id AND time >= at)
+$$
+LANGUAGE SQL
+STABLE;
+
+-- Score history is kept per list as well
+ALTER TABLE player_score_history ADD COLUMN list VARCHAR(32) NOT NULL DEFAULT 'main' REFERENCES lists(id) ON UPDATE CASCADE ON DELETE CASCADE;
+ALTER TABLE player_score_history ALTER COLUMN list DROP DEFAULT;
+ALTER TABLE player_score_history DROP CONSTRAINT player_score_history_pkey;
+ALTER TABLE player_score_history ADD PRIMARY KEY (list, player, time);
+
+ALTER TABLE nation_score_history ADD COLUMN list VARCHAR(32) NOT NULL DEFAULT 'main' REFERENCES lists(id) ON UPDATE CASCADE ON DELETE CASCADE;
+ALTER TABLE nation_score_history ALTER COLUMN list DROP DEFAULT;
+ALTER TABLE nation_score_history DROP CONSTRAINT nation_score_history_pkey;
+ALTER TABLE nation_score_history ADD PRIMARY KEY (list, nation, time);
+
+-- Since only non-zero scores are stored, players and nations whose score dropped to zero since their last snapshot are
+-- found via the history table itself.
+CREATE OR REPLACE FUNCTION snapshot_scores(include_rank_changes BOOLEAN) RETURNS VOID AS
+$$
+    INSERT INTO player_score_history (list, player, score, rank)
+    SELECT current.list, current.player, current.score, player_ranks.rank
+    FROM (
+        SELECT list, player, score FROM player_scores
+
+        UNION
+
+        SELECT DISTINCT list, player, 0.0 FROM player_score_history
+        WHERE NOT EXISTS (SELECT 1 FROM player_scores WHERE player_scores.list = player_score_history.list AND player_scores.player = player_score_history.player)
+    ) AS current
+    LEFT OUTER JOIN player_ranks ON player_ranks.list = current.list AND player_ranks.id = current.player
+    LEFT JOIN LATERAL (
+        SELECT score, rank FROM player_score_history WHERE list = current.list AND player = current.player ORDER BY time DESC LIMIT 1
+    ) AS latest ON TRUE
+    WHERE latest.score IS NULL
+       OR latest.score != current.score
+       OR (include_rank_changes AND latest.rank IS DISTINCT FROM player_ranks.rank)
+    ON CONFLICT (list, player, time) DO UPDATE SET score = EXCLUDED.score, rank = EXCLUDED.rank;
+
+    INSERT INTO nation_score_history (list, nation, score, rank)
+    SELECT current.list, current.nation, current.score, ranked_nations.rank
+    FROM (
+        SELECT list, nation, score FROM nation_scores
+
+        UNION
+
+        SELECT DISTINCT list, nation, 0.0 FROM nation_score_history
+        WHERE NOT EXISTS (SELECT 1 FROM nation_scores WHERE nation_scores.list = nation_score_history.list AND nation_scores.nation = nation_score_history.nation)
+    ) AS current
+    LEFT OUTER JOIN ranked_nations ON ranked_nations.list = current.list AND ranked_nations.iso_country_code = current.nation
+    LEFT JOIN LATERAL (
+        SELECT score, rank FROM nation_score_history WHERE list = current.list AND nation = current.nation ORDER BY time DESC LIMIT 1
+    ) AS latest ON TRUE
+    WHERE latest.score IS NULL
+       OR latest.score != current.score
+       OR (include_rank_changes AND latest.rank IS DISTINCT FROM ranked_nations.rank)
+    ON CONFLICT (list, nation, time) DO UPDATE SET score = EXCLUDED.score, rank = EXCLUDED.rank;
+$$ LANGUAGE SQL;
@@ -0,0 +1,4 @@
+-- Add down migration script here
+
+DROP TABLE list_tiers;
+DROP TYPE TIER_SUBMISSIONS;
@@ -0,0 +1,28 @@
+-- Add up migration script here
+
+CREATE TYPE TIER_SUBMISSIONS AS ENUM ('OPEN', 'COMPLETIONS_ONLY', 'CLOSED');
+
+-- A tier covers all positions after the previous tier's last position, up to and including its own. The tier without a
+-- last position covers all remaining positions.
+CREATE TABLE list_tiers (
+    list VARCHAR(32) NOT NULL REFERENCES lists(id) ON UPDATE CASCADE ON DELETE CASCADE,
+    id VARCHAR(32) NOT NULL CHECK (id ~ '^[a-z0-9]+(-[a-z0-9]+)*$'),
+    -- Fluent text id of the tier's name. The description displayed on the demonlist overview is looked up via its `info` attribute
+    label TEXT NOT NULL,
+    last_position SMALLINT NULL CHECK (last_position > 0),
+    submissions TIER_SUBMISSIONS NOT NULL,
+    -- The bit of the permission required to modify records on demons in this tier
+    edit_permission SMALLINT NOT NULL,
+    PRIMARY KEY (list, id),
+    UNIQUE (list, last_position)
+);
+
+CREATE UNIQUE INDEX list_tiers_unbounded_idx ON list_tiers(list) WHERE last_position IS NULL;
+
+-- Reproduce the previously hardcoded main/extended/legacy split for all existing lists
+INSERT INTO list_tiers (list, id, label, last_position, submissions, edit_permission)
+SELECT id, 'main', 'main-list', list_size, 'OPEN'::TIER_SUBMISSIONS, 2 FROM lists
+UNION ALL
+SELECT id, 'extended', 'extended-list', extended_list_size, 'COMPLETIONS_ONLY'::TIER_SUBMISSIONS, 2 FROM lists WHERE extended_list_size > list_size
+UNION ALL
+SELECT id, 'legacy', 'legacy-list', NULL, 'CLOSED'::TIER_SUBMISSIONS, 4 FROM lists;
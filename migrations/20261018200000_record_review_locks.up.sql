@@ -0,0 +1,11 @@
+-- Add up migration script here
+
+-- At most one reviewer can hold a lock on a record at a time. Locks whose `locked_until` lies in the past are expired and
+-- treated as if they did not exist; they get overwritten the next time someone locks the record.
+CREATE TABLE record_locks (
+    record INTEGER PRIMARY KEY REFERENCES records(id) ON DELETE CASCADE,
+    reviewer INTEGER NOT NULL REFERENCES members(member_id) ON DELETE CASCADE,
+    locked_until TIMESTAMP WITHOUT TIME ZONE NOT NULL
+);
+
+CREATE INDEX record_locks_reviewer_idx ON record_locks(reviewer);
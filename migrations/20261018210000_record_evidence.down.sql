@@ -0,0 +1,19 @@
+-- Add down migration script here
+
+ALTER TABLE records ADD COLUMN raw_footage TEXT;
+
+UPDATE records SET raw_footage = (
+    SELECT url FROM record_evidence WHERE record_evidence.record = records.id AND kind = 'RAW_FOOTAGE' ORDER BY id LIMIT 1
+);
+
+DROP TABLE record_evidence_deletions;
+DROP FUNCTION audit_record_evidence_deletion() CASCADE;
+
+DROP TABLE record_evidence_modifications;
+DROP FUNCTION audit_record_evidence_modification() CASCADE;
+
+DROP TABLE record_evidence_additions;
+DROP FUNCTION audit_record_evidence_addition() CASCADE;
+
+DROP TABLE record_evidence;
+DROP TYPE EVIDENCE_KIND;
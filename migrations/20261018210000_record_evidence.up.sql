@@ -0,0 +1,104 @@
+-- Add up migration script here
+
+CREATE TYPE EVIDENCE_KIND AS ENUM ('RAW_FOOTAGE', 'CLICK_AUDIO', 'SECOND_ANGLE');
+
+-- Additional evidence for a record besides its main video (which stays in `records.video`, as it is what gets displayed
+-- on the list and what duplicate submissions are detected by)
+CREATE TABLE record_evidence (
+    id SERIAL PRIMARY KEY,
+    record INTEGER NOT NULL REFERENCES records(id) ON DELETE CASCADE,
+    kind EVIDENCE_KIND NOT NULL,
+    url TEXT NOT NULL,
+    -- Whether this link is visible to everyone, or only to the list team
+    public BOOLEAN NOT NULL DEFAULT FALSE,
+    -- Whether this link was originally provided for a different record, and got transferred to this one when the other
+    -- record was deleted as a duplicate of it
+    transferred BOOLEAN NOT NULL DEFAULT FALSE
+);
+
+CREATE INDEX record_evidence_record_idx ON record_evidence(record);
+
+INSERT INTO record_evidence (record, kind, url)
+SELECT id, 'RAW_FOOTAGE'::EVIDENCE_KIND, raw_footage FROM records WHERE raw_footage IS NOT NULL;
+
+ALTER TABLE records DROP COLUMN raw_footage;
+
+-- Evidence is audited the same way records themselves are, with modifications storing the old values of all changed
+-- columns. The raw footage links migrated above are not logged, as they were already part of their records before.
+
+CREATE TABLE record_evidence_additions (
+    id INTEGER NOT NULL, -- REFERENCES record_evidence(id)
+    record INTEGER NOT NULL -- REFERENCES records(id)
+) INHERITS (audit_log2);
+
+CREATE FUNCTION audit_record_evidence_addition() RETURNS trigger AS $record_evidence_add_trigger$
+    BEGIN
+        INSERT INTO record_evidence_additions (userid, id, record) (SELECT id, NEW.id, NEW.record FROM active_user LIMIT 1);
+        RETURN NEW;
+    END;
+$record_evidence_add_trigger$ LANGUAGE plpgsql;
+
+CREATE TRIGGER record_evidence_addition_trigger AFTER INSERT ON record_evidence FOR EACH ROW EXECUTE PROCEDURE audit_record_evidence_addition();
+
+CREATE TABLE record_evidence_modifications (
+    id INTEGER NOT NULL, -- REFERENCES record_evidence(id)
+
+    record INTEGER NULL, -- REFERENCES records(id)
+    kind EVIDENCE_KIND NULL,
+    url TEXT NULL,
+    public BOOLEAN NULL,
+    transferred BOOLEAN NULL
+) INHERITS (audit_log2);
+
+CREATE FUNCTION audit_record_evidence_modification() RETURNS trigger AS $record_evidence_modification_trigger$
+    DECLARE
+        record_change INTEGER;
+        url_change TEXT;
+        public_change BOOLEAN;
+        transferred_change BOOLEAN;
+    BEGIN
+        IF (OLD.record <> NEW.record) THEN
+            record_change = OLD.record;
+        END IF;
+
+        IF (OLD.url <> NEW.url) THEN
+            url_change = OLD.url;
+        END IF;
+
+        IF (OLD.public <> NEW.public) THEN
+            public_change = OLD.public;
+        END IF;
+
+        IF (OLD.transferred <> NEW.transferred) THEN
+            transferred_change = OLD.transferred;
+        END IF;
+
+        INSERT INTO record_evidence_modifications (userid, id, record, url, public, transferred)
+            (SELECT id, NEW.id, record_change, url_change, public_change, transferred_change
+            FROM active_user LIMIT 1);
+
+        RETURN NEW;
+    END;
+$record_evidence_modification_trigger$ LANGUAGE plpgsql;
+
+CREATE TRIGGER record_evidence_modification_trigger AFTER UPDATE ON record_evidence FOR EACH ROW EXECUTE PROCEDURE audit_record_evidence_modification();
+
+-- Before deletion we add a `record_evidence_modifications` entry that's a copy of the evidence directly before deletion
+CREATE TABLE record_evidence_deletions (
+    id INTEGER NOT NULL -- REFERENCES record_evidence(id)
+) INHERITS (audit_log2);
+
+CREATE FUNCTION audit_record_evidence_deletion() RETURNS trigger AS $record_evidence_deletion_trigger$
+    BEGIN
+        INSERT INTO record_evidence_modifications (userid, id, record, kind, url, public, transferred)
+            (SELECT id, OLD.id, OLD.record, OLD.kind, OLD.url, OLD.public, OLD.transferred
+            FROM active_user LIMIT 1);
+
+        INSERT INTO record_evidence_deletions (userid, id)
+            (SELECT id, OLD.id FROM active_user LIMIT 1);
+
+        RETURN NULL;
+    END;
+$record_evidence_deletion_trigger$ LANGUAGE plpgsql;
+
+CREATE TRIGGER record_evidence_deletion_trigger AFTER DELETE ON record_evidence FOR EACH ROW EXECUTE PROCEDURE audit_record_evidence_deletion();
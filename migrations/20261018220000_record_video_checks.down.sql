@@ -0,0 +1,4 @@
+-- Add down migration script here
+
+DROP TABLE record_video_checks;
+DROP TYPE VIDEO_CHECK_RESULT;
@@ -0,0 +1,12 @@
+-- Add up migration script here
+
+CREATE TYPE VIDEO_CHECK_RESULT AS ENUM ('AVAILABLE', 'MISSING', 'INCONCLUSIVE');
+
+-- Results of the automatic check, run after a record is submitted, of whether the record's video actually exists
+CREATE TABLE record_video_checks (
+    record INTEGER PRIMARY KEY REFERENCES records(id) ON DELETE CASCADE,
+    -- The video that was checked. Once the record's video changes, the check no longer applies to it.
+    video TEXT NOT NULL,
+    result VIDEO_CHECK_RESULT NOT NULL,
+    checked_at TIMESTAMP WITHOUT TIME ZONE NOT NULL DEFAULT (NOW() AT TIME ZONE 'utc')
+);
@@ -0,0 +1,14 @@
+-- Add down migration script here
+
+ALTER TABLE record_notes DROP COLUMN automatic;
+
+DROP INDEX record_video_checks_channel;
+DROP INDEX record_video_checks_video_id;
+
+ALTER TABLE record_video_checks
+    DROP COLUMN video_host,
+    DROP COLUMN video_id,
+    DROP COLUMN title,
+    DROP COLUMN channel,
+    DROP COLUMN uploaded_at,
+    DROP COLUMN duration;
@@ -0,0 +1,20 @@
+-- Add up migration script here
+
+-- Metadata about the checked video, as far as its host told us about it
+ALTER TABLE record_video_checks
+    -- The host the video is uploaded to, and the id of the video on that host. Unlike the video link, these are the
+    -- same for all links pointing to the video
+    ADD COLUMN video_host TEXT NULL,
+    ADD COLUMN video_id TEXT NULL,
+    ADD COLUMN title TEXT NULL,
+    -- Some host specific identifier of the channel that uploaded the video (usually a link to it)
+    ADD COLUMN channel TEXT NULL,
+    ADD COLUMN uploaded_at TIMESTAMP WITHOUT TIME ZONE NULL,
+    -- The length of the video, in seconds
+    ADD COLUMN duration INTEGER NULL;
+
+CREATE INDEX record_video_checks_video_id ON record_video_checks(video_host, video_id);
+CREATE INDEX record_video_checks_channel ON record_video_checks(channel);
+
+-- Notes generated by pointercrate itself, e.g. when a submission was flagged as suspicious
+ALTER TABLE record_notes ADD COLUMN automatic BOOLEAN NOT NULL DEFAULT FALSE;
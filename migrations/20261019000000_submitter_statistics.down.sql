@@ -0,0 +1,5 @@
+-- Add down migration script here
+
+DROP VIEW submitter_statistics;
+DROP INDEX records_submitter;
+DROP TABLE video_check_deletions;
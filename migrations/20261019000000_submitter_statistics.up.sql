@@ -0,0 +1,30 @@
+-- Add up migration script here
+
+-- Submissions that were deleted because the host of their video reported it as missing. We only remember these
+-- for the statistics of their submitter.
+CREATE TABLE video_check_deletions (
+    submitter INTEGER NOT NULL REFERENCES submitters(submitter_id) ON DELETE CASCADE,
+    video TEXT NOT NULL,
+    deleted_at TIMESTAMP WITHOUT TIME ZONE NOT NULL DEFAULT (NOW() AT TIME ZONE 'utc')
+);
+
+CREATE INDEX video_check_deletions_submitter ON video_check_deletions(submitter);
+CREATE INDEX records_submitter ON records(submitter);
+
+CREATE VIEW submitter_statistics AS
+SELECT submitters.submitter_id AS submitter,
+       record_counts.total + deletion_counts.deleted AS submitted,
+       record_counts.approved,
+       record_counts.rejected,
+       deletion_counts.deleted AS deleted_by_video_check
+FROM submitters
+CROSS JOIN LATERAL (
+    SELECT COUNT(*) AS total,
+           COUNT(*) FILTER (WHERE status_ = 'APPROVED') AS approved,
+           COUNT(*) FILTER (WHERE status_ = 'REJECTED') AS rejected
+    FROM records
+    WHERE records.submitter = submitters.submitter_id
+) AS record_counts
+CROSS JOIN LATERAL (
+    SELECT COUNT(*) AS deleted FROM video_check_deletions WHERE video_check_deletions.submitter = submitters.submitter_id
+) AS deletion_counts;
@@ -0,0 +1,5 @@
+-- Add down migration script here
+
+DROP TABLE submission_bans;
+DROP INDEX submitters_ip_address;
+ALTER TABLE submitters DROP COLUMN member;
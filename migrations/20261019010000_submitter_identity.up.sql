@@ -0,0 +1,18 @@
+-- Add up migration script here
+
+-- Submissions made while logged in are attributed to the user account instead of the IP address. For such submitters,
+-- ip_address is the address from which the first submission was made.
+ALTER TABLE submitters ADD COLUMN member INTEGER NULL UNIQUE REFERENCES members(member_id) ON DELETE SET NULL;
+
+-- Anonymous submitters can be identified by a whole network (e.g. an IPv6 /64) instead of a single address
+CREATE INDEX submitters_ip_address ON submitters USING gist (ip_address inet_ops);
+
+-- Bans from submitting records that target either a user account, or an IP address or network (regardless of which
+-- submitters exist for it)
+CREATE TABLE submission_bans (
+    id SERIAL PRIMARY KEY,
+    member INTEGER NULL REFERENCES members(member_id) ON DELETE CASCADE,
+    network INET NULL,
+    banned_at TIMESTAMP WITHOUT TIME ZONE NOT NULL DEFAULT (NOW() AT TIME ZONE 'utc'),
+    CHECK ((member IS NULL) <> (network IS NULL))
+);
@@ -0,0 +1,7 @@
+DROP TABLE player_claim_verifications;
+
+ALTER TABLE player_claims
+    DROP COLUMN verification_code,
+    DROP COLUMN verification_code_issued_at;
+
+ALTER TABLE players DROP COLUMN gd_account_id;
@@ -0,0 +1,16 @@
+-- The Geometry Dash account belonging to a player. Linked once a claim on the player is verified
+-- through that account.
+ALTER TABLE players ADD COLUMN gd_account_id BIGINT NULL UNIQUE;
+
+-- One-time codes claimants post on their Geometry Dash account to prove they own it
+ALTER TABLE player_claims
+    ADD COLUMN verification_code TEXT NULL,
+    ADD COLUMN verification_code_issued_at TIMESTAMP WITHOUT TIME ZONE NULL;
+
+-- Audit log of claim verifications. 'gd_account_id' is set if the claim was verified automatically
+-- through the player's Geometry Dash account, and NULL if it was verified manually by a moderator.
+CREATE TABLE player_claim_verifications (
+    member_id INTEGER NOT NULL, -- REFERENCES members(member_id)
+    player_id INTEGER NOT NULL, -- REFERENCES players(id)
+    gd_account_id BIGINT NULL
+) INHERITS (audit_log2);
@@ -0,0 +1,4 @@
+DROP TABLE pending_submission_evidence;
+DROP TABLE pending_submissions;
+
+ALTER TABLE player_claims DROP COLUMN third_party_approval;
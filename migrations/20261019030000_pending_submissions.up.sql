@@ -0,0 +1,28 @@
+-- Whether third-party submissions for a player with locked submissions should be held for the claimant's approval,
+-- instead of being rejected outright. Has no effect unless 'lock_submissions' is set.
+ALTER TABLE player_claims ADD COLUMN third_party_approval BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Third-party submissions waiting for the approval of the player's claimant. These only become records (and thus
+-- only reach the list team) once the claimant accepts them.
+CREATE TABLE pending_submissions (
+    id SERIAL PRIMARY KEY,
+    progress SMALLINT NOT NULL,
+    video TEXT NULL,
+    player INTEGER NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    demon INTEGER NOT NULL REFERENCES demons(id) ON DELETE CASCADE,
+    submitter INTEGER NOT NULL REFERENCES submitters(submitter_id) ON DELETE CASCADE,
+    note TEXT NULL,
+    submitted_at TIMESTAMP WITHOUT TIME ZONE NOT NULL DEFAULT (NOW() AT TIME ZONE 'utc')
+);
+
+CREATE INDEX pending_submissions_player_idx ON pending_submissions(player);
+
+CREATE TABLE pending_submission_evidence (
+    id SERIAL PRIMARY KEY,
+    submission INTEGER NOT NULL REFERENCES pending_submissions(id) ON DELETE CASCADE,
+    kind EVIDENCE_KIND NOT NULL,
+    url TEXT NOT NULL,
+    public BOOLEAN NOT NULL DEFAULT FALSE
+);
+
+CREATE INDEX pending_submission_evidence_submission_idx ON pending_submission_evidence(submission);
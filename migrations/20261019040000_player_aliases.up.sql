@@ -0,0 +1,13 @@
+-- Previous names of players, and the ids of players that were merged into them. Renaming a player (or merging another
+-- player into it) no longer loses the old name and id, instead they keep resolving to the current player.
+CREATE TABLE player_aliases (
+    id SERIAL PRIMARY KEY,
+    player INTEGER NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    name CITEXT NOT NULL,
+    -- The id of the player that was merged into 'player', if this alias was created by a merge
+    former_id INTEGER NULL UNIQUE,
+    created_at TIMESTAMP WITHOUT TIME ZONE NOT NULL DEFAULT (NOW() AT TIME ZONE 'utc')
+);
+
+CREATE INDEX player_aliases_player_idx ON player_aliases(player);
+CREATE INDEX player_aliases_name_idx ON player_aliases(name);
@@ -0,0 +1,22 @@
+-- Every merge of one player into another, together with a snapshot of all rows the merge touched, as they were before
+-- the merge (and, for records, also as they were after it). Allows administrators to undo merges of the wrong players.
+CREATE TABLE player_merges (
+    id SERIAL PRIMARY KEY,
+    -- The player that remained after the merge. Deliberately not a foreign key, so that the merge stays on record if this
+    -- player is itself merged into yet another player.
+    player INTEGER NOT NULL,
+    -- The player that was merged into 'player' and deleted
+    merged_id INTEGER NOT NULL,
+    merged_name CITEXT NOT NULL,
+    -- NULL if the merge was not performed by a user, e.g. because it happened from the command line
+    merged_by INTEGER NULL, -- REFERENCES members(member_id)
+    merged_at TIMESTAMP WITHOUT TIME ZONE NOT NULL DEFAULT (NOW() AT TIME ZONE 'utc'),
+    undone_by INTEGER NULL, -- REFERENCES members(member_id)
+    undone_at TIMESTAMP WITHOUT TIME ZONE NULL,
+    -- Records that had been modified since the merge when it was undone, and were thus left as they were
+    conflicting_records INTEGER[] NULL,
+    -- NULL once the merge is too old to be undone
+    snapshot JSONB NULL
+);
+
+CREATE INDEX player_merges_player_idx ON player_merges(player);
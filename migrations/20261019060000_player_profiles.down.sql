@@ -0,0 +1,2 @@
+DROP TABLE pending_player_profiles;
+DROP TABLE player_profiles;
@@ -0,0 +1,23 @@
+-- Profiles claimants can set up for their claimed player. Changes made by the claimant end up in 'pending_player_profiles'
+-- and only replace the player's live profile once a moderator approves them.
+CREATE TABLE player_profiles (
+    player INTEGER PRIMARY KEY REFERENCES players(id) ON DELETE CASCADE,
+    display_name TEXT NULL,
+    bio TEXT NULL,
+    youtube TEXT NULL,
+    twitch TEXT NULL,
+    discord TEXT NULL,
+    gd_account TEXT NULL
+);
+
+CREATE TABLE pending_player_profiles (
+    player INTEGER PRIMARY KEY REFERENCES players(id) ON DELETE CASCADE,
+    display_name TEXT NULL,
+    bio TEXT NULL,
+    youtube TEXT NULL,
+    twitch TEXT NULL,
+    discord TEXT NULL,
+    gd_account TEXT NULL,
+    submitted_by INTEGER NOT NULL, -- REFERENCES members(member_id)
+    submitted_at TIMESTAMP WITHOUT TIME ZONE NOT NULL DEFAULT (NOW() AT TIME ZONE 'utc')
+);
@@ -0,0 +1,65 @@
+-- Ranks subdivisions within their nation, analogous to 'ranked_nations'
+CREATE VIEW ranked_subdivisions AS
+SELECT ROW_NUMBER() OVER (PARTITION BY subdivision_scores.list, subdivision_scores.nation ORDER BY subdivision_scores.score DESC, subdivisions.iso_code) AS index,
+       RANK() OVER (PARTITION BY subdivision_scores.list, subdivision_scores.nation ORDER BY subdivision_scores.score DESC) AS rank,
+       subdivision_scores.list,
+       subdivision_scores.score,
+       subdivisions.nation,
+       subdivisions.iso_code,
+       subdivisions.name
+FROM subdivision_scores
+INNER JOIN subdivisions
+        ON subdivisions.iso_code = subdivision_scores.subdivision AND subdivisions.nation = subdivision_scores.nation;
+
+-- Continent scores are computed the same way as nation scores (only the best record on each demon by any player from the
+-- continent counts). There are only a handful of continents, so unlike nation scores these are not cached.
+CREATE VIEW ranked_continents AS
+SELECT RANK() OVER (PARTITION BY scores.list ORDER BY scores.score DESC) AS rank,
+       scores.list,
+       scores.continent,
+       scores.score,
+       (SELECT COUNT(*) FROM nation_scores INNER JOIN nationalities ON nationalities.iso_country_code = nation_scores.nation
+        WHERE nation_scores.list = scores.list AND nationalities.continent = scores.continent) AS nations
+FROM (
+    SELECT list, continent, SUM(record_score(q.progress, q.position, q.extended_list_size, q.requirement)) AS score
+    FROM (
+        SELECT DISTINCT ON (list, continent, position) list, continent, progress, position, extended_list_size, requirement
+        FROM score_giving
+        INNER JOIN players
+                ON players.id = player
+        INNER JOIN nationalities
+                ON nationalities.iso_country_code = players.nationality
+        ORDER BY list, continent, position, progress DESC
+    ) q
+    GROUP BY list, continent
+    HAVING SUM(record_score(q.progress, q.position, q.extended_list_size, q.requirement)) > 0
+) scores;
+
+-- Allow restricting the best records of a nation to one of its subdivisions
+DROP FUNCTION best_records_in(VARCHAR(2));
+
+CREATE FUNCTION best_records_in(country VARCHAR(2), subdivision_code VARCHAR(3) DEFAULT NULL)
+    RETURNS TABLE (
+        id integer ,
+        progress smallint ,
+        video character varying(200),
+        status_ public.record_status ,
+        player integer ,
+        submitter integer ,
+        demon integer
+    )
+    AS
+$body$
+    WITH grp AS (
+        SELECT records.*,
+               RANK() OVER (PARTITION BY demon ORDER BY demon, progress DESC) AS rk
+        FROM records
+        INNER JOIN players
+        ON players.id = player
+        WHERE status_='APPROVED' AND players.nationality = country AND (players.subdivision = subdivision_code OR subdivision_code IS NULL)
+    )
+    SELECT id, progress, video, status_, player, submitter, demon
+    FROM grp
+    WHERE rk = 1;
+$body$
+LANGUAGE SQL;
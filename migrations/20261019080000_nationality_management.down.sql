@@ -0,0 +1,14 @@
+DROP TABLE subdivision_deletions;
+DROP FUNCTION audit_subdivision_deletion() CASCADE;
+
+DROP TABLE subdivision_modifications;
+DROP FUNCTION audit_subdivision_modification() CASCADE;
+
+DROP TABLE subdivision_additions;
+DROP FUNCTION audit_subdivision_addition() CASCADE;
+
+DROP TABLE nationality_modifications;
+DROP FUNCTION audit_nationality_modification() CASCADE;
+
+DROP TABLE nationality_additions;
+DROP FUNCTION audit_nationality_addition() CASCADE;
@@ -0,0 +1,103 @@
+-- Audit logs for nationalities and subdivisions, which can now be managed through the API instead of
+-- only through migrations. As with all other audit logs, countries and subdivisions are referenced by
+-- their (non-modifiable) ISO codes, which are not used as foreign keys.
+
+CREATE TABLE nationality_additions (
+    iso_country_code VARCHAR(2) NOT NULL -- REFERENCES nationalities(iso_country_code)
+) INHERITS (audit_log2);
+
+CREATE FUNCTION audit_nationality_addition() RETURNS trigger AS $nationality_add_trigger$
+    BEGIN
+        INSERT INTO nationality_additions (userid, iso_country_code) (SELECT id, NEW.iso_country_code FROM active_user LIMIT 1);
+        RETURN NEW;
+    END;
+$nationality_add_trigger$ LANGUAGE plpgsql;
+
+CREATE TRIGGER nationality_addition_trigger AFTER INSERT ON nationalities FOR EACH ROW EXECUTE PROCEDURE audit_nationality_addition();
+
+CREATE TABLE nationality_modifications (
+    iso_country_code VARCHAR(2) NOT NULL, -- REFERENCES nationalities(iso_country_code)
+
+    nation CITEXT NULL,
+    continent continent NULL
+) INHERITS (audit_log2);
+
+CREATE FUNCTION audit_nationality_modification() RETURNS trigger AS $nationality_modification_trigger$
+    DECLARE
+        nation_change CITEXT;
+        continent_change continent;
+    BEGIN
+        IF (OLD.nation <> NEW.nation) THEN
+            nation_change = OLD.nation;
+        END IF;
+
+        IF (OLD.continent <> NEW.continent) THEN
+            continent_change = OLD.continent;
+        END IF;
+
+        INSERT INTO nationality_modifications (userid, iso_country_code, nation, continent)
+            (SELECT id, NEW.iso_country_code, nation_change, continent_change FROM active_user LIMIT 1);
+
+        RETURN NEW;
+    END;
+$nationality_modification_trigger$ LANGUAGE plpgsql;
+
+CREATE TRIGGER nationality_modification_trigger AFTER UPDATE ON nationalities FOR EACH ROW EXECUTE PROCEDURE audit_nationality_modification();
+
+CREATE TABLE subdivision_additions (
+    nation VARCHAR(2) NOT NULL, -- REFERENCES nationalities(iso_country_code)
+    iso_code VARCHAR(3) NOT NULL
+) INHERITS (audit_log2);
+
+CREATE FUNCTION audit_subdivision_addition() RETURNS trigger AS $subdivision_add_trigger$
+    BEGIN
+        INSERT INTO subdivision_additions (userid, nation, iso_code) (SELECT id, NEW.nation, NEW.iso_code FROM active_user LIMIT 1);
+        RETURN NEW;
+    END;
+$subdivision_add_trigger$ LANGUAGE plpgsql;
+
+CREATE TRIGGER subdivision_addition_trigger AFTER INSERT ON subdivisions FOR EACH ROW EXECUTE PROCEDURE audit_subdivision_addition();
+
+CREATE TABLE subdivision_modifications (
+    nation VARCHAR(2) NOT NULL, -- REFERENCES nationalities(iso_country_code)
+    iso_code VARCHAR(3) NOT NULL,
+
+    name CITEXT NULL
+) INHERITS (audit_log2);
+
+CREATE FUNCTION audit_subdivision_modification() RETURNS trigger AS $subdivision_modification_trigger$
+    DECLARE
+        name_change CITEXT;
+    BEGIN
+        IF (OLD.name <> NEW.name) THEN
+            name_change = OLD.name;
+        END IF;
+
+        INSERT INTO subdivision_modifications (userid, nation, iso_code, name)
+            (SELECT id, NEW.nation, NEW.iso_code, name_change FROM active_user LIMIT 1);
+
+        RETURN NEW;
+    END;
+$subdivision_modification_trigger$ LANGUAGE plpgsql;
+
+CREATE TRIGGER subdivision_modification_trigger AFTER UPDATE ON subdivisions FOR EACH ROW EXECUTE PROCEDURE audit_subdivision_modification();
+
+-- Before deletion we add a `subdivision_modifications` entry that's a copy of the subdivision directly before deletion
+CREATE TABLE subdivision_deletions (
+    nation VARCHAR(2) NOT NULL, -- REFERENCES nationalities(iso_country_code)
+    iso_code VARCHAR(3) NOT NULL
+) INHERITS (audit_log2);
+
+CREATE FUNCTION audit_subdivision_deletion() RETURNS trigger AS $subdivision_deletion_trigger$
+    BEGIN
+        INSERT INTO subdivision_modifications (userid, nation, iso_code, name)
+            (SELECT id, OLD.nation, OLD.iso_code, OLD.name FROM active_user LIMIT 1);
+
+        INSERT INTO subdivision_deletions (userid, nation, iso_code)
+            (SELECT id, OLD.nation, OLD.iso_code FROM active_user LIMIT 1);
+
+        RETURN NULL;
+    END;
+$subdivision_deletion_trigger$ LANGUAGE plpgsql;
+
+CREATE TRIGGER subdivision_deletion_trigger AFTER DELETE ON subdivisions FOR EACH ROW EXECUTE PROCEDURE audit_subdivision_deletion();
@@ -0,0 +1,7 @@
+ALTER TABLE creators
+    DROP CONSTRAINT creators_part_check,
+    DROP COLUMN roles,
+    DROP COLUMN part_start,
+    DROP COLUMN part_end;
+
+DROP TYPE creator_role;
@@ -0,0 +1,9 @@
+CREATE TYPE creator_role AS ENUM ('host', 'gameplay', 'decoration', 'effects', 'verifier_of_part');
+
+-- The part of a level a creator worked on is given as a range of percentages, e.g. 20 to 35. Both
+-- ends are NULL if the creator worked on the whole level (or if the part is unknown)
+ALTER TABLE creators
+    ADD COLUMN roles creator_role[] NOT NULL DEFAULT '{}',
+    ADD COLUMN part_start SMALLINT NULL,
+    ADD COLUMN part_end SMALLINT NULL,
+    ADD CONSTRAINT creators_part_check CHECK ((part_start IS NULL) = (part_end IS NULL) AND 0 <= part_start AND part_start < part_end AND part_end <= 100);
@@ -0,0 +1,18 @@
+DROP TABLE demon_tag_deletions;
+DROP FUNCTION audit_demon_tag_deletion() CASCADE;
+
+DROP TABLE demon_tag_additions;
+DROP FUNCTION audit_demon_tag_addition() CASCADE;
+
+DROP TABLE tag_name_modifications;
+DROP FUNCTION audit_tag_name_modification() CASCADE;
+
+DROP TABLE tag_deletions;
+DROP FUNCTION audit_tag_deletion() CASCADE;
+
+DROP TABLE tag_additions;
+DROP FUNCTION audit_tag_addition() CASCADE;
+
+DROP TABLE demon_tags;
+DROP TABLE tag_names;
+DROP TABLE tags;
@@ -0,0 +1,105 @@
+-- Tags describe the gameplay style of a demon (e.g. "wave", "memory", "timings"). Tag definitions are
+-- managed by the list team, and each tag can have a name in each of the languages the site is
+-- available in. Like list IDs, tag IDs are used in URLs and cannot be changed.
+
+CREATE TABLE tags (
+    id VARCHAR(32) PRIMARY KEY
+);
+
+CREATE TABLE tag_names (
+    tag VARCHAR(32) NOT NULL REFERENCES tags(id) ON DELETE CASCADE,
+    language VARCHAR(3) NOT NULL,
+    name TEXT NOT NULL,
+
+    PRIMARY KEY (tag, language)
+);
+
+CREATE TABLE demon_tags (
+    demon INTEGER NOT NULL REFERENCES demons(id) ON DELETE CASCADE,
+    tag VARCHAR(32) NOT NULL REFERENCES tags(id) ON DELETE CASCADE,
+
+    PRIMARY KEY (demon, tag)
+);
+
+CREATE INDEX demon_tags_tag_idx ON demon_tags(tag);
+
+-- As with all other audit logs, tags are referenced by their ID, which is not used as a foreign key
+-- so that the log survives the deletion of the tag.
+
+CREATE TABLE tag_additions (
+    tag VARCHAR(32) NOT NULL -- REFERENCES tags(id)
+) INHERITS (audit_log2);
+
+CREATE FUNCTION audit_tag_addition() RETURNS trigger AS $tag_add_trigger$
+    BEGIN
+        INSERT INTO tag_additions (userid, tag) (SELECT id, NEW.id FROM active_user LIMIT 1);
+        RETURN NEW;
+    END;
+$tag_add_trigger$ LANGUAGE plpgsql;
+
+CREATE TRIGGER tag_addition_trigger AFTER INSERT ON tags FOR EACH ROW EXECUTE PROCEDURE audit_tag_addition();
+
+CREATE TABLE tag_deletions (
+    tag VARCHAR(32) NOT NULL -- REFERENCES tags(id)
+) INHERITS (audit_log2);
+
+CREATE FUNCTION audit_tag_deletion() RETURNS trigger AS $tag_deletion_trigger$
+    BEGIN
+        INSERT INTO tag_deletions (userid, tag) (SELECT id, OLD.id FROM active_user LIMIT 1);
+        RETURN NULL;
+    END;
+$tag_deletion_trigger$ LANGUAGE plpgsql;
+
+CREATE TRIGGER tag_deletion_trigger AFTER DELETE ON tags FOR EACH ROW EXECUTE PROCEDURE audit_tag_deletion();
+
+-- Each entry stores the name a tag had in some language before the change, or NULL if the tag had no
+-- name in that language before
+CREATE TABLE tag_name_modifications (
+    tag VARCHAR(32) NOT NULL, -- REFERENCES tags(id)
+    language VARCHAR(3) NOT NULL,
+    name TEXT NULL
+) INHERITS (audit_log2);
+
+CREATE FUNCTION audit_tag_name_modification() RETURNS trigger AS $tag_name_modification_trigger$
+    BEGIN
+        IF (TG_OP = 'INSERT') THEN
+            INSERT INTO tag_name_modifications (userid, tag, language, name)
+                (SELECT id, NEW.tag, NEW.language, NULL FROM active_user LIMIT 1);
+        ELSIF (TG_OP = 'DELETE' OR OLD.name <> NEW.name) THEN
+            INSERT INTO tag_name_modifications (userid, tag, language, name)
+                (SELECT id, OLD.tag, OLD.language, OLD.name FROM active_user LIMIT 1);
+        END IF;
+
+        RETURN NULL;
+    END;
+$tag_name_modification_trigger$ LANGUAGE plpgsql;
+
+CREATE TRIGGER tag_name_modification_trigger AFTER INSERT OR UPDATE OR DELETE ON tag_names FOR EACH ROW EXECUTE PROCEDURE audit_tag_name_modification();
+
+CREATE TABLE demon_tag_additions (
+    demon INTEGER NOT NULL, -- REFERENCES demons(id)
+    tag VARCHAR(32) NOT NULL -- REFERENCES tags(id)
+) INHERITS (audit_log2);
+
+CREATE FUNCTION audit_demon_tag_addition() RETURNS trigger AS $demon_tag_add_trigger$
+    BEGIN
+        INSERT INTO demon_tag_additions (userid, demon, tag) (SELECT id, NEW.demon, NEW.tag FROM active_user LIMIT 1);
+        RETURN NEW;
+    END;
+$demon_tag_add_trigger$ LANGUAGE plpgsql;
+
+CREATE TRIGGER demon_tag_addition_trigger AFTER INSERT ON demon_tags FOR EACH ROW EXECUTE PROCEDURE audit_demon_tag_addition();
+
+CREATE TABLE demon_tag_deletions (
+    demon INTEGER NOT NULL, -- REFERENCES demons(id)
+    tag VARCHAR(32) NOT NULL -- REFERENCES tags(id)
+) INHERITS (audit_log2);
+
+CREATE FUNCTION audit_demon_tag_deletion() RETURNS trigger AS $demon_tag_deletion_trigger$
+    BEGIN
+        INSERT INTO demon_tag_deletions (userid, demon, tag) (SELECT id, OLD.demon, OLD.tag FROM active_user LIMIT 1);
+        RETURN NULL;
+    END;
+$demon_tag_deletion_trigger$ LANGUAGE plpgsql;
+
+CREATE TRIGGER demon_tag_deletion_trigger AFTER DELETE ON demon_tags FOR EACH ROW EXECUTE PROCEDURE audit_demon_tag_deletion();
@@ -0,0 +1,177 @@
+//! Command line tool for routine administrative tasks that would otherwise require raw SQL
+//!
+//! All changes are performed inside a single transaction, which is attributed to the member
+//! specified via `--actor` in the audit logs. If no actor is given, changes are attributed to the
+//! user with ID 0, same as unauthenticated API requests are.
+//!
+//! Like the server, this tool reads the `DATABASE_URL` environment variable (and loads it from a
+//! `.env` file, if one is present).
+
+use clap::{Parser, Subcommand};
+use pointercrate_core::pool::{audit_connection, PointercratePool};
+use pointercrate_demonlist::{
+    list::{List, PostList},
+    player::{recompute_scores, refresh_player_ranks, snapshot_scores},
+    submitter::Submitter,
+};
+use pointercrate_user::{auth::AuthenticatedUser, User, ADMINISTRATOR};
+use std::error::Error;
+
+#[derive(Parser)]
+#[command(version, about = "Administrative tasks for pointercrate instances")]
+struct Cli {
+    /// ID of the member to which all changes will be attributed in the audit logs
+    #[arg(long, default_value_t = 0)]
+    actor: i32,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Applies all pending database migrations
+    Migrate,
+
+    /// Grants the `ADMINISTRATOR` permission to the member with the given username
+    GrantAdmin { username: String },
+
+    /// Recomputes the scores of all players, nations and subdivisions, and refreshes the player
+    /// rankings
+    RecomputeScores {
+        /// ID of the list to recompute scores for. If not given, scores on all lists are
+        /// recomputed
+        #[arg(long)]
+        list: Option<String>,
+    },
+
+    /// Creates a new, empty list
+    CreateList {
+        /// The list's ID, as used in URLs. Must consist of lowercase letters, digits and dashes
+        id: String,
+
+        /// The list's human-readable name
+        name: String,
+
+        #[arg(long, default_value_t = 75)]
+        list_size: i16,
+
+        #[arg(long, default_value_t = 150)]
+        extended_list_size: i16,
+    },
+
+    /// Refreshes the `player_ranks` materialized view without recomputing any scores, and
+    /// snapshots the resulting rank changes. Snapshots are taken automatically whenever scores
+    /// change, but running this periodically (e.g. as a cron job) also captures changes made
+    /// directly in the database
+    RefreshRanks,
+
+    /// Invalidates all access tokens of the member with the given username, logging them out
+    /// everywhere
+    InvalidateTokens { username: String },
+
+    /// Lifts the submission ban of the submitter with the given ID
+    UnbanSubmitter { submitter_id: i32 },
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    // The .env file is optional here, as the environment might already be set up correctly
+    dotenv::dotenv().ok();
+
+    let cli = Cli::parse();
+    let pool = PointercratePool::connect().await;
+
+    if let Command::Migrate = cli.command {
+        pool.run_migrations().await;
+
+        println!("Successfully applied all pending migrations");
+
+        return Ok(());
+    }
+
+    let mut connection = pool.transaction().await?;
+
+    // Make sure we do not attribute changes to a non-existing account
+    if cli.actor != 0 {
+        User::by_id(cli.actor, &mut connection).await?;
+    }
+
+    audit_connection(&mut connection, cli.actor).await?;
+
+    match cli.command {
+        Command::Migrate => unreachable!(),
+        Command::GrantAdmin { username } => {
+            let mut user = User::by_name(&username, &mut connection).await?;
+
+            if user.has_permission(ADMINISTRATOR) {
+                println!("{} already is an administrator", user);
+            } else {
+                user.set_permissions(user.permissions | ADMINISTRATOR.bit(), &mut connection)
+                    .await?;
+
+                println!("Granted administrator permissions to {}", user);
+            }
+        },
+        Command::RecomputeScores { list } => {
+            let lists = match list {
+                Some(list_id) => vec![List::by_id(&list_id, &mut connection).await?],
+                None => List::all(&mut connection).await?,
+            };
+
+            for list in lists {
+                recompute_scores(&list.id, &mut connection).await?;
+
+                println!("Successfully recomputed all scores on {}", list);
+            }
+        },
+        Command::CreateList {
+            id,
+            name,
+            list_size,
+            extended_list_size,
+        } => {
+            let list = List::create_from(
+                PostList {
+                    id,
+                    name,
+                    list_size,
+                    extended_list_size,
+                },
+                &mut connection,
+            )
+            .await?;
+
+            println!("Created list {}", list);
+        },
+        Command::RefreshRanks => {
+            refresh_player_ranks(&mut connection).await?;
+            snapshot_scores(true, &mut connection).await?;
+
+            println!("Successfully refreshed player ranks");
+        },
+        Command::InvalidateTokens { username } => {
+            let user = AuthenticatedUser::by_name(&username, &mut connection).await?;
+            let description = user.user().to_string();
+
+            user.invalidate_all_tokens(&mut connection).await?;
+
+            println!("Invalidated all access tokens of {}", description);
+        },
+        Command::UnbanSubmitter { submitter_id } => {
+            let mut submitter = Submitter::by_id(submitter_id, &mut connection).await?;
+
+            if !submitter.banned {
+                println!("Submitter #{} is not banned", submitter.id);
+            } else {
+                submitter.unban(&mut connection).await?;
+
+                println!("Unbanned submitter #{}", submitter.id);
+            }
+        },
+    }
+
+    connection.commit().await?;
+
+    Ok(())
+}
@@ -11,14 +11,14 @@ use crate::response::Response2;
 
 #[derive(Debug)]
 pub struct LinksBuilder {
-    endpoint: &'static str,
+    endpoint: String,
     rels: BTreeMap<&'static str, PaginationParameters>,
 }
 
 impl LinksBuilder {
-    pub fn new(endpoint: &'static str) -> Self {
+    pub fn new(endpoint: impl Into<String>) -> Self {
         LinksBuilder {
-            endpoint,
+            endpoint: endpoint.into(),
             rels: BTreeMap::new(),
         }
     }
@@ -99,7 +99,7 @@ impl LinksBuilder {
 }
 
 pub async fn pagination_response<Q: PaginationQuery, P: Paginatable<Q>>(
-    endpoint: &'static str, query: Q, connection: &mut PgConnection,
+    endpoint: impl Into<String>, query: Q, connection: &mut PgConnection,
 ) -> Result<Response2<Json<Vec<P>>>, CoreError> {
     let parameters = query.parameters();
 
@@ -109,7 +109,7 @@ pub async fn pagination_response<Q: PaginationQuery, P: Paginatable<Q>>(
 
     let mut links = LinksBuilder::new(endpoint);
 
-    if let Some((min_id, max_id)) = P::first_and_last(connection).await? {
+    if let Some((min_id, max_id)) = P::first_and_last_matching(&query, connection).await? {
         links = links.with_first(min_id - 1).with_last(max_id + 1);
     }
 
@@ -232,6 +232,29 @@ pub fn tr(text_id: &str) -> String {
         })
 }
 
+/// Like [`tr`], but returns `None` instead of reporting an error if no translation for the given
+/// text id (or attribute) exists. Useful for text ids that are not known at compile time.
+pub fn try_tr(text_id: &str) -> Option<String> {
+    LANGUAGE
+        .try_with(|lang| {
+            let config = LocaleConfiguration::get();
+            let (key, maybe_attr) = match text_id.split_once(".") {
+                Some((key, attr)) => (key, Some(attr)),
+                None => (text_id, None),
+            };
+
+            let (_, message) = config.get_message(lang, key)?;
+            let exists = match maybe_attr {
+                Some(attr) => message.get_attribute(attr).is_some(),
+                None => message.value().is_some(),
+            };
+
+            exists.then(|| config.lookup(lang, text_id, None))
+        })
+        .ok()
+        .flatten()
+}
+
 /// Like [`tr`], except this function must be used for fetching translations
 /// containing variables.
 ///
@@ -138,6 +138,14 @@ pub trait Paginatable<Q: PaginationQuery>: Serialize + Sized {
 
     async fn first_and_last(connection: &mut PgConnection) -> Result<Option<(i32, i32)>, sqlx::Error>;
 
+    /// Like [`Paginatable::first_and_last`], but for objects whose ID range depends on the query
+    /// (e.g. positions, which are only unique within a single list).
+    ///
+    /// Defaults to ignoring the query.
+    async fn first_and_last_matching(_query: &Q, connection: &mut PgConnection) -> Result<Option<(i32, i32)>, sqlx::Error> {
+        Self::first_and_last(connection).await
+    }
+
     fn pagination_id(&self) -> i32;
 }
 
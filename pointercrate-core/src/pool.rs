@@ -11,21 +11,29 @@ impl PointercratePool {
         self.connection_pool.clone()
     }
 
+    /// Connects to the database specified by the `DATABASE_URL` environment variable and applies all
+    /// pending migrations
     pub async fn init() -> Self {
-        let pool = PointercratePool {
+        let pool = Self::connect().await;
+
+        pool.run_migrations().await;
+
+        pool
+    }
+
+    /// Connects to the database specified by the `DATABASE_URL` environment variable without
+    /// touching the database schema
+    pub async fn connect() -> Self {
+        PointercratePool {
             connection_pool: PgPoolOptions::default()
                 .max_connections(20)
                 .connect(&config::database_url())
                 .await
                 .expect("Failed to connect to pointercrate database"),
-        };
-
-        pool.run_migrations().await;
-
-        pool
+        }
     }
 
-    async fn run_migrations(&self) {
+    pub async fn run_migrations(&self) {
         let row = sqlx::query!(
             r#"
 SELECT EXISTS (
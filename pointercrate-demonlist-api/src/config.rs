@@ -1,3 +1,71 @@
+use pointercrate_core::util::from_env_or_default;
+use pointercrate_demonlist::submitter::ReputationThresholds;
+
+/// The number of minutes a review lock on a record lasts before it expires
+pub fn review_lock_minutes() -> i32 {
+    from_env_or_default("REVIEW_LOCK_MINUTES", 30)
+}
+
+/// The number of days for which merges of players can be undone
+pub fn merge_undo_days() -> i64 {
+    from_env_or_default("MERGE_UNDO_DAYS", 30)
+}
+
+/// Whether all submissions need to include raw footage
+///
+/// Enabled by default, matching the list's submission rules. Lists that do not require raw footage
+/// from everyone can disable this and instead only demand it from submitters with a low reputation
+/// (see [`reputation_thresholds`]), which is why this is configurable at all: as long as raw
+/// footage is required in general, the `REPUTATION_RAW_FOOTAGE_THRESHOLD` has no effect.
+pub fn raw_footage_required() -> bool {
+    from_env_or_default("REQUIRE_RAW_FOOTAGE", true)
+}
+
+/// API key for the YouTube Data API, which is used to retrieve the upload dates, durations and
+/// channels of YouTube videos, as YouTube's oEmbed endpoint does not provide them
+pub fn youtube_api_key() -> Option<String> {
+    std::env::var("YOUTUBE_API_KEY").ok()
+}
+
+/// The thresholds on the number of unsuccessful submissions of a submitter from which on their new
+/// submissions get extra scrutiny. Unset thresholds are disabled.
+pub fn reputation_thresholds() -> ReputationThresholds {
+    ReputationThresholds {
+        under_consideration: std::env::var("REPUTATION_UNDER_CONSIDERATION_THRESHOLD")
+            .ok()
+            .map(|threshold| threshold.parse().unwrap()),
+        raw_footage: std::env::var("REPUTATION_RAW_FOOTAGE_THRESHOLD")
+            .ok()
+            .map(|threshold| threshold.parse().unwrap()),
+    }
+}
+
+/// The length of the network prefix by which anonymous IPv6 submitters are identified
+pub fn ipv6_submitter_prefix_length() -> i32 {
+    from_env_or_default("SUBMITTER_IPV6_PREFIX_LENGTH", 64)
+}
+
+/// Path to a file listing the IP ranges of VPNs, proxies and datacenters from which anonymous
+/// submissions are not accepted (see [`crate::CidrProxyDetector`])
+pub fn proxy_ranges_file() -> Option<String> {
+    std::env::var("PROXY_RANGES_FILE").ok()
+}
+
+/// The sizes of the list used to be configured via the `LIST_SIZE` and `EXTENDED_LIST_SIZE`
+/// environment variables. They are now stored per list in the database, so any values still
+/// configured this way are ignored.
+pub fn warn_about_removed_list_sizes() {
+    for variable in ["LIST_SIZE", "EXTENDED_LIST_SIZE"] {
+        if let Ok(value) = std::env::var(variable) {
+            log::warn!(
+                "{}={} is set, but list sizes are no longer read from the environment. Use 'PATCH /api/v2/lists/main/' to configure the sizes of the main list instead.",
+                variable,
+                value
+            );
+        }
+    }
+}
+
 pub fn submission_webhook() -> Option<String> {
     std::env::var("DISCORD_WEBHOOK").ok()
 }
@@ -12,10 +12,11 @@ use pointercrate_demonlist::{
     creator::{Creator, PostCreator},
     demon::{
         audit::{DemonModificationData, MovementLogEntry},
-        Demon, DemonIdPagination, DemonPositionPagination, FullDemon, PatchDemon, PostDemon,
+        ChangelogQuery, Demon, DemonIdPagination, DemonPositionPagination, FullDemon, ListChangelog, PatchDemon, PostDemon,
     },
     error::DemonlistError,
     player::DatabasePlayer,
+    tag::{PostDemonTag, Tag},
     LIST_ADMINISTRATOR, LIST_MODERATOR,
 };
 use pointercrate_user::auth::ApiToken;
@@ -68,6 +69,12 @@ pub async fn movement_log(demon_id: i32, pool: &State<PointercratePool>) -> Resu
     Ok(Json(log))
 }
 
+#[localized]
+#[rocket::get("/changelog/")]
+pub async fn changelog(pool: &State<PointercratePool>, query: Query<ChangelogQuery>) -> Result<Json<ListChangelog>> {
+    Ok(Json(query.0.changelog(&mut *pool.connection().await?).await?))
+}
+
 #[localized]
 #[rocket::post("/", data = "<data>")]
 pub async fn post(
@@ -112,9 +119,10 @@ pub async fn post_creator(demon_id: i32, mut auth: Auth<ApiToken>, creator: Json
     auth.require_permission(LIST_MODERATOR)?;
 
     let demon = Demon::by_id(demon_id, &mut auth.connection).await?;
+    let creator = creator.into_inner();
     let player = DatabasePlayer::by_name_or_create(&creator.creator, &mut auth.connection).await?;
 
-    Creator::insert(&demon.base, &player, &mut auth.connection).await?;
+    Creator::insert(&demon.base, &player, creator.roles, creator.part, &mut auth.connection).await?;
 
     auth.commit().await?;
 
@@ -141,3 +149,37 @@ pub async fn delete_creator(demon_id: i32, player_id: i32, mut auth: Auth<ApiTok
 
     Ok(Status::NoContent)
 }
+
+#[localized]
+#[rocket::post("/<demon_id>/tags/", data = "<tag>")]
+pub async fn post_tag(demon_id: i32, mut auth: Auth<ApiToken>, tag: Json<PostDemonTag>) -> Result<Response2<Json<Tag>>> {
+    auth.require_permission(LIST_MODERATOR)?;
+
+    let demon = Demon::by_id(demon_id, &mut auth.connection).await?;
+    let tag = Tag::by_id(&tag.tag, &mut auth.connection).await?;
+
+    tag.assign(&demon.base, &mut auth.connection).await?;
+
+    auth.commit().await?;
+
+    let location = format!("/api/v2/demons/{}/tags/{}/", demon.base.id, tag.id);
+
+    Ok(Response2::json(tag).status(Status::Created).with_header("Location", location))
+}
+
+#[localized]
+#[rocket::delete("/<demon_id>/tags/<tag_id>/")]
+pub async fn delete_tag(demon_id: i32, tag_id: &str, mut auth: Auth<ApiToken>) -> Result<Status> {
+    auth.require_permission(LIST_MODERATOR)?;
+
+    let demon = Demon::by_id(demon_id, &mut auth.connection).await?;
+
+    Tag::by_id(tag_id, &mut auth.connection)
+        .await?
+        .unassign(&demon.base, &mut auth.connection)
+        .await?;
+
+    auth.commit().await?;
+
+    Ok(Status::NoContent)
+}
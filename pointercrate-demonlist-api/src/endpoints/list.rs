@@ -0,0 +1,259 @@
+//! Endpoints for the lists hosted by this instance
+//!
+//! All list-specific endpoints are namespaced under `/api/v2/lists/<list_id>/`. The endpoints that
+//! predate support for multiple lists (e.g. `/api/v2/demons/`) keep working and operate on the
+//! default list.
+
+use crate::ratelimits::DemonlistRatelimits;
+use pointercrate_core::pool::PointercratePool;
+use pointercrate_core_api::{
+    error::Result,
+    etag::{Precondition, TaggableExt, Tagged},
+    pagination::pagination_response,
+    query::Query,
+    response::Response2,
+};
+use pointercrate_core_macros::localized;
+use pointercrate_demonlist::{
+    demon::{ChangelogQuery, Demon, DemonIdPagination, DemonPositionPagination, FullDemon, ListChangelog, PostDemon},
+    list::{List, PatchList, PostList, PostTier, Tiers},
+    nationality::{
+        Heatmap, HeatmapQuery, Nationality, NationalityRankingPagination, RankedContinent, RankedNation, RankedSubdivision,
+        SubdivisionRankingPagination,
+    },
+    player::{DatabasePlayer, RankedPlayer, RankingPagination, ScoreSnapshot},
+    LIST_ADMINISTRATOR, LIST_MODERATOR,
+};
+use pointercrate_user::auth::ApiToken;
+use pointercrate_user_api::auth::Auth;
+use rocket::{http::Status, serde::json::Json, State};
+
+#[localized]
+#[rocket::get("/")]
+pub async fn all(pool: &State<PointercratePool>) -> Result<Json<Vec<List>>> {
+    Ok(Json(List::all(&mut *pool.connection().await?).await?))
+}
+
+#[localized]
+#[rocket::post("/", data = "<data>")]
+pub async fn post(mut auth: Auth<ApiToken>, data: Json<PostList>) -> Result<Response2<Tagged<List>>> {
+    auth.require_permission(LIST_ADMINISTRATOR)?;
+
+    let list = List::create_from(data.0, &mut auth.connection).await?;
+
+    auth.commit().await?;
+
+    let location = format!("/api/v2/lists/{}/", list.id);
+
+    Ok(Response2::tagged(list).status(Status::Created).with_header("Location", location))
+}
+
+#[localized]
+#[rocket::get("/<list_id>/")]
+pub async fn get(list_id: &str, pool: &State<PointercratePool>) -> Result<Tagged<List>> {
+    Ok(Tagged(List::by_id(list_id, &mut *pool.connection().await?).await?))
+}
+
+#[localized]
+#[rocket::patch("/<list_id>/", data = "<patch>")]
+pub async fn patch(list_id: &str, mut auth: Auth<ApiToken>, precondition: Precondition, patch: Json<PatchList>) -> Result<Tagged<List>> {
+    auth.require_permission(LIST_ADMINISTRATOR)?;
+
+    let list = List::by_id(list_id, &mut auth.connection)
+        .await?
+        .require_match(precondition)?
+        .apply_patch(patch.0, &mut auth.connection)
+        .await?;
+
+    auth.commit().await?;
+
+    Ok(Tagged(list))
+}
+
+#[localized]
+#[rocket::get("/<list_id>/tiers/")]
+pub async fn tiers(list_id: &str, pool: &State<PointercratePool>) -> Result<Tagged<Tiers>> {
+    let mut connection = pool.connection().await?;
+
+    let list = List::by_id(list_id, &mut connection).await?;
+
+    Ok(Tagged(list.tiers(&mut connection).await?))
+}
+
+#[localized]
+#[rocket::put("/<list_id>/tiers/", data = "<tiers>")]
+pub async fn put_tiers(
+    list_id: &str, mut auth: Auth<ApiToken>, precondition: Precondition, tiers: Json<Vec<PostTier>>,
+) -> Result<Tagged<Tiers>> {
+    auth.require_permission(LIST_ADMINISTRATOR)?;
+
+    let list = List::by_id(list_id, &mut auth.connection).await?;
+
+    list.tiers(&mut auth.connection).await?.require_match(precondition)?;
+
+    let tiers = list.set_tiers(tiers.0, &mut auth.connection).await?;
+
+    auth.commit().await?;
+
+    Ok(Tagged(tiers))
+}
+
+#[localized]
+#[rocket::get("/<list_id>/demons/")]
+pub async fn paginate_demons(
+    list_id: &str, pool: &State<PointercratePool>, pagination: Query<DemonIdPagination>,
+) -> Result<Response2<Json<Vec<Demon>>>> {
+    let mut connection = pool.connection().await?;
+
+    let list = List::by_id(list_id, &mut connection).await?;
+    let endpoint = format!("/api/v2/lists/{}/demons/", list.id);
+
+    let mut pagination = pagination.0;
+    pagination.list = Some(list.id);
+
+    Ok(pagination_response(endpoint, pagination, &mut connection).await?)
+}
+
+#[localized]
+#[rocket::get("/<list_id>/demons/listed/")]
+pub async fn paginate_listed_demons(
+    list_id: &str, pool: &State<PointercratePool>, pagination: Query<DemonPositionPagination>,
+) -> Result<Response2<Json<Vec<Demon>>>> {
+    let mut connection = pool.connection().await?;
+
+    let list = List::by_id(list_id, &mut connection).await?;
+    let endpoint = format!("/api/v2/lists/{}/demons/listed/", list.id);
+
+    let mut pagination = pagination.0;
+    pagination.list = Some(list.id);
+
+    Ok(pagination_response(endpoint, pagination, &mut connection).await?)
+}
+
+#[localized]
+#[rocket::post("/<list_id>/demons/", data = "<data>")]
+pub async fn post_demon(
+    list_id: &str, mut auth: Auth<ApiToken>, data: Json<PostDemon>, ratelimits: &State<DemonlistRatelimits>,
+) -> Result<Response2<Tagged<FullDemon>>> {
+    auth.require_permission(LIST_MODERATOR)?;
+
+    let list = List::by_id(list_id, &mut auth.connection).await?;
+
+    ratelimits.add_demon()?;
+
+    let mut data = data.0;
+    data.list = Some(list.id);
+
+    let demon = FullDemon::create_from(data, &mut auth.connection).await?;
+
+    auth.commit().await?;
+
+    let demon_id = demon.demon.base.id;
+
+    Ok(Response2::tagged(demon)
+        .status(Status::Created)
+        .with_header("Location", format!("/api/v2/demons/{}/", demon_id)))
+}
+
+#[localized]
+#[rocket::get("/<list_id>/changelog/")]
+pub async fn changelog(list_id: &str, pool: &State<PointercratePool>, query: Query<ChangelogQuery>) -> Result<Json<ListChangelog>> {
+    let mut query = query.0;
+    query.list = Some(list_id.to_string());
+
+    Ok(Json(query.changelog(&mut *pool.connection().await?).await?))
+}
+
+#[localized]
+#[rocket::get("/<list_id>/players/ranking/")]
+pub async fn player_ranking(
+    list_id: &str, pool: &State<PointercratePool>, query: Query<RankingPagination>,
+) -> Result<Response2<Json<Vec<RankedPlayer>>>> {
+    let mut connection = pool.connection().await?;
+
+    let list = List::by_id(list_id, &mut connection).await?;
+    let endpoint = format!("/api/v2/lists/{}/players/ranking/", list.id);
+
+    let mut pagination = query.0;
+    pagination.list = Some(list.id);
+
+    Ok(pagination_response(endpoint, pagination, &mut connection).await?)
+}
+
+#[localized]
+#[rocket::get("/<list_id>/players/<player_id>/history/")]
+pub async fn player_history(list_id: &str, player_id: i32, pool: &State<PointercratePool>) -> Result<Json<Vec<ScoreSnapshot>>> {
+    let mut connection = pool.connection().await?;
+
+    let list = List::by_id(list_id, &mut connection).await?;
+    let player = DatabasePlayer::by_id(player_id, &mut connection).await?;
+
+    Ok(Json(player.score_history(&list.id, &mut connection).await?))
+}
+
+#[localized]
+#[rocket::get("/<list_id>/nationalities/ranking/")]
+pub async fn nation_ranking(
+    list_id: &str, pool: &State<PointercratePool>, pagination: Query<NationalityRankingPagination>,
+) -> Result<Json<Vec<RankedNation>>> {
+    let mut connection = pool.connection().await?;
+
+    let list = List::by_id(list_id, &mut connection).await?;
+
+    let mut pagination = pagination.0;
+    pagination.list = Some(list.id);
+
+    Ok(Json(pagination.page(&mut connection).await?))
+}
+
+#[localized]
+#[rocket::get("/<list_id>/nationalities/<iso_code>/history/")]
+pub async fn nation_history(list_id: &str, iso_code: String, pool: &State<PointercratePool>) -> Result<Json<Vec<ScoreSnapshot>>> {
+    let mut connection = pool.connection().await?;
+
+    let list = List::by_id(list_id, &mut connection).await?;
+    let nationality = Nationality::by_country_code_or_name(iso_code.to_uppercase().as_ref(), &mut connection).await?;
+
+    Ok(Json(nationality.score_history(&list.id, &mut connection).await?))
+}
+
+#[localized]
+#[rocket::get("/<list_id>/nationalities/<iso_code>/subdivisions/ranking/")]
+pub async fn subdivision_ranking(
+    list_id: &str, iso_code: String, pool: &State<PointercratePool>, pagination: Query<SubdivisionRankingPagination>,
+) -> Result<Response2<Json<Vec<RankedSubdivision>>>> {
+    let mut connection = pool.connection().await?;
+
+    let list = List::by_id(list_id, &mut connection).await?;
+    let nationality = Nationality::by_country_code_or_name(iso_code.to_uppercase().as_ref(), &mut connection).await?;
+    let endpoint = format!(
+        "/api/v2/lists/{}/nationalities/{}/subdivisions/ranking/",
+        list.id, nationality.iso_country_code
+    );
+
+    let mut pagination = pagination.0;
+    pagination.list = Some(list.id);
+    pagination.nation = nationality.iso_country_code;
+
+    Ok(pagination_response(endpoint, pagination, &mut connection).await?)
+}
+
+#[localized]
+#[rocket::get("/<list_id>/continents/ranking/")]
+pub async fn continent_ranking(list_id: &str, pool: &State<PointercratePool>) -> Result<Json<Vec<RankedContinent>>> {
+    let mut connection = pool.connection().await?;
+
+    let list = List::by_id(list_id, &mut connection).await?;
+
+    Ok(Json(RankedContinent::all(&list.id, &mut connection).await?))
+}
+
+#[localized]
+#[rocket::get("/<list_id>/nationalities/heatmap/")]
+pub async fn heatmap(list_id: &str, pool: &State<PointercratePool>, query: Query<HeatmapQuery>) -> Result<Json<Heatmap>> {
+    let mut connection = pool.connection().await?;
+
+    let list = List::by_id(list_id, &mut connection).await?;
+
+    Ok(Json(Heatmap::load(&list.id, query.0.metric, &mut connection).await?))
+}
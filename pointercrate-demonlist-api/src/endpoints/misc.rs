@@ -1,15 +1,21 @@
-use pointercrate_demonlist::config;
-use rocket::serde::json::Json;
+use pointercrate_core::pool::PointercratePool;
+use pointercrate_core_api::error::Result;
+use pointercrate_demonlist::list::List;
+use rocket::{serde::json::Json, State};
 use serde_json::{json, Value};
 
 #[rocket::get("/")]
-pub fn list_information() -> Json<Value> {
+pub async fn list_information(pool: &State<PointercratePool>) -> Result<Json<Value>> {
+    let mut connection = pool.connection().await?;
+
+    let list = List::default_list(&mut connection).await?;
+
     let data = json! {
         {
-            "list_size": config::list_size(),
-            "extended_list_size": config::extended_list_size()
+            "list_size": list.list_size,
+            "extended_list_size": list.extended_list_size
         }
     };
 
-    Json(data)
+    Ok(Json(data))
 }
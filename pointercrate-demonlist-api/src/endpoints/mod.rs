@@ -1,6 +1,8 @@
 pub(crate) mod demon;
+pub(crate) mod list;
 pub(crate) mod misc;
 pub(crate) mod nationality;
 pub(crate) mod player;
 pub(crate) mod record;
 pub(crate) mod submitter;
+pub(crate) mod tag;
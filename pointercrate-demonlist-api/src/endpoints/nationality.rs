@@ -1,8 +1,19 @@
 use pointercrate_core::pool::PointercratePool;
-use pointercrate_core_api::{error::Result, etag::Tagged, query::Query};
+use pointercrate_core_api::{error::Result, etag::Tagged, pagination::pagination_response, query::Query, response::Response2};
 use pointercrate_core_macros::localized;
-use pointercrate_demonlist::nationality::{Nationality, NationalityRankingPagination, NationalityRecord, RankedNation, Subdivision};
-use rocket::{serde::json::Json, State};
+use pointercrate_demonlist::{
+    list::DEFAULT_LIST,
+    nationality::{
+        Heatmap, HeatmapQuery, Nationality, NationalityAuditLogEntry, NationalityRankingPagination, NationalityRecord,
+        NationalityWithContinent, PatchNationality, PatchSubdivision, PostNationality, PostSubdivision, RankedContinent, RankedNation,
+        RankedSubdivision, Subdivision, SubdivisionRankingPagination,
+    },
+    player::ScoreSnapshot,
+    LIST_ADMINISTRATOR,
+};
+use pointercrate_user::auth::ApiToken;
+use pointercrate_user_api::auth::Auth;
+use rocket::{http::Status, serde::json::Json, State};
 
 #[localized]
 #[rocket::get("/<iso_code>/subdivisions/")]
@@ -15,6 +26,51 @@ pub async fn subdivisions(pool: &State<PointercratePool>, iso_code: String) -> R
     Ok(Json(nationality.subdivisions(&mut connection).await?))
 }
 
+#[localized]
+#[rocket::get("/<iso_code>/subdivisions/ranking/")]
+pub async fn subdivision_ranking(
+    pool: &State<PointercratePool>, iso_code: String, pagination: Query<SubdivisionRankingPagination>,
+) -> Result<Response2<Json<Vec<RankedSubdivision>>>> {
+    let mut connection = pool.connection().await?;
+
+    let nationality = Nationality::by_country_code_or_name(iso_code.to_uppercase().as_ref(), &mut connection).await?;
+    let endpoint = format!("/api/v1/nationalities/{}/subdivisions/ranking/", nationality.iso_country_code);
+
+    let mut pagination = pagination.0;
+    pagination.nation = nationality.iso_country_code;
+
+    Ok(pagination_response(endpoint, pagination, &mut connection).await?)
+}
+
+#[localized]
+#[rocket::get("/<iso_code>/subdivisions/<subdivision_code>/", rank = 1)]
+pub async fn subdivision(pool: &State<PointercratePool>, iso_code: String, subdivision_code: String) -> Result<Tagged<NationalityRecord>> {
+    let mut connection = pool.connection().await?;
+
+    let mut nationality = Nationality::by_country_code_or_name(iso_code.to_uppercase().as_ref(), &mut connection).await?;
+    let subdivision = nationality
+        .subdivision_by_code(subdivision_code.to_uppercase().as_ref(), &mut connection)
+        .await?;
+
+    nationality.subdivision = Some(subdivision);
+
+    Ok(Tagged(nationality.upgrade(&mut connection).await?))
+}
+
+#[localized]
+#[rocket::get("/continents/ranking/")]
+pub async fn continent_ranking(pool: &State<PointercratePool>) -> Result<Json<Vec<RankedContinent>>> {
+    Ok(Json(RankedContinent::all(DEFAULT_LIST, &mut *pool.connection().await?).await?))
+}
+
+#[localized]
+#[rocket::get("/heatmap/")]
+pub async fn heatmap(pool: &State<PointercratePool>, query: Query<HeatmapQuery>) -> Result<Json<Heatmap>> {
+    Ok(Json(
+        Heatmap::load(DEFAULT_LIST, query.0.metric, &mut *pool.connection().await?).await?,
+    ))
+}
+
 #[localized]
 #[rocket::get("/ranking/")]
 pub async fn ranking(pool: &State<PointercratePool>, pagination: Query<NationalityRankingPagination>) -> Result<Json<Vec<RankedNation>>> {
@@ -31,3 +87,112 @@ pub async fn nation(pool: &State<PointercratePool>, iso_code: String) -> Result<
 
     Ok(Tagged(nationality.upgrade(&mut connection).await?))
 }
+
+#[localized]
+#[rocket::get("/<iso_code>/history/")]
+pub async fn history(pool: &State<PointercratePool>, iso_code: String) -> Result<Json<Vec<ScoreSnapshot>>> {
+    let mut connection = pool.connection().await?;
+
+    let nationality = Nationality::by_country_code_or_name(iso_code.to_uppercase().as_ref(), &mut connection).await?;
+
+    Ok(Json(nationality.score_history(DEFAULT_LIST, &mut connection).await?))
+}
+
+#[localized]
+#[rocket::post("/", data = "<data>")]
+pub async fn post(mut auth: Auth<ApiToken>, data: Json<PostNationality>) -> Result<Response2<Json<NationalityWithContinent>>> {
+    auth.require_permission(LIST_ADMINISTRATOR)?;
+
+    let nationality = Nationality::create_from(data.0, &mut auth.connection).await?;
+
+    auth.commit().await?;
+
+    let location = format!("/api/v1/nationalities/{}/", nationality.nationality.iso_country_code);
+
+    Ok(Response2::json(nationality)
+        .status(Status::Created)
+        .with_header("Location", location))
+}
+
+#[localized]
+#[rocket::patch("/<iso_code>/", data = "<patch>")]
+pub async fn patch(iso_code: String, mut auth: Auth<ApiToken>, patch: Json<PatchNationality>) -> Result<Json<NationalityWithContinent>> {
+    auth.require_permission(LIST_ADMINISTRATOR)?;
+
+    let nationality = Nationality::by_country_code_or_name(iso_code.to_uppercase().as_ref(), &mut auth.connection)
+        .await?
+        .with_continent(&mut auth.connection)
+        .await?
+        .apply_patch(patch.0, &mut auth.connection)
+        .await?;
+
+    auth.commit().await?;
+
+    Ok(Json(nationality))
+}
+
+#[localized]
+#[rocket::post("/<iso_code>/subdivisions/", data = "<data>")]
+pub async fn post_subdivision(
+    iso_code: String, mut auth: Auth<ApiToken>, data: Json<PostSubdivision>,
+) -> Result<Response2<Json<Subdivision>>> {
+    auth.require_permission(LIST_ADMINISTRATOR)?;
+
+    let nationality = Nationality::by_country_code_or_name(iso_code.to_uppercase().as_ref(), &mut auth.connection).await?;
+    let subdivision = nationality.create_subdivision(data.0, &mut auth.connection).await?;
+
+    auth.commit().await?;
+
+    let location = format!(
+        "/api/v1/nationalities/{}/subdivisions/{}/",
+        nationality.iso_country_code, subdivision.iso_code
+    );
+
+    Ok(Response2::json(subdivision)
+        .status(Status::Created)
+        .with_header("Location", location))
+}
+
+#[localized]
+#[rocket::patch("/<iso_code>/subdivisions/<subdivision_code>/", data = "<patch>")]
+pub async fn patch_subdivision(
+    iso_code: String, subdivision_code: String, mut auth: Auth<ApiToken>, patch: Json<PatchSubdivision>,
+) -> Result<Json<Subdivision>> {
+    auth.require_permission(LIST_ADMINISTRATOR)?;
+
+    let nationality = Nationality::by_country_code_or_name(iso_code.to_uppercase().as_ref(), &mut auth.connection).await?;
+    let subdivision = nationality
+        .subdivision_by_code(subdivision_code.to_uppercase().as_ref(), &mut auth.connection)
+        .await?;
+    let subdivision = nationality.patch_subdivision(subdivision, patch.0, &mut auth.connection).await?;
+
+    auth.commit().await?;
+
+    Ok(Json(subdivision))
+}
+
+#[localized]
+#[rocket::delete("/<iso_code>/subdivisions/<subdivision_code>/")]
+pub async fn delete_subdivision(iso_code: String, subdivision_code: String, mut auth: Auth<ApiToken>) -> Result<Status> {
+    auth.require_permission(LIST_ADMINISTRATOR)?;
+
+    let nationality = Nationality::by_country_code_or_name(iso_code.to_uppercase().as_ref(), &mut auth.connection).await?;
+    let subdivision = nationality
+        .subdivision_by_code(subdivision_code.to_uppercase().as_ref(), &mut auth.connection)
+        .await?;
+
+    nationality.delete_subdivision(subdivision, &mut auth.connection).await?;
+    auth.commit().await?;
+
+    Ok(Status::NoContent)
+}
+
+#[localized]
+#[rocket::get("/<iso_code>/audit/")]
+pub async fn audit(iso_code: String, mut auth: Auth<ApiToken>) -> Result<Json<Vec<NationalityAuditLogEntry>>> {
+    auth.require_permission(LIST_ADMINISTRATOR)?;
+
+    let nationality = Nationality::by_country_code_or_name(iso_code.to_uppercase().as_ref(), &mut auth.connection).await?;
+
+    Ok(Json(nationality.audit_log(&mut auth.connection).await?))
+}
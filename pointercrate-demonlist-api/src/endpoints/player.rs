@@ -1,5 +1,11 @@
-use crate::claims::AuthWithClaim;
-use pointercrate_core::pool::PointercratePool;
+use crate::{
+    claims::AuthWithClaim,
+    endpoints::record::{release_held_submissions, verify_released},
+    ratelimits::DemonlistRatelimits,
+    verification::VideoVerifier,
+};
+use log::warn;
+use pointercrate_core::{error::CoreError, pool::PointercratePool};
 use pointercrate_core_api::{
     error::Result,
     etag::{Precondition, TaggableExt, Tagged},
@@ -10,15 +16,23 @@ use pointercrate_core_api::{
 use pointercrate_core_macros::localized;
 use pointercrate_demonlist::{
     error::DemonlistError,
+    list::DEFAULT_LIST,
     player::{
         claim::{ListedClaim, PatchPlayerClaim, PlayerClaim, PlayerClaimPagination},
-        DatabasePlayer, FullPlayer, PatchPlayer, Player, PlayerPagination, RankedPlayer, RankingPagination,
+        DatabasePlayer, FullPlayer, MergePreview, PatchPlayer, PatchPlayerProfile, PendingProfile, Player, PlayerMerge, PlayerPagination,
+        PlayerProfile, RankedPlayer, RankingPagination, ScoreSnapshot,
     },
-    LIST_HELPER,
+    record::heuristics::SubmissionHeuristics,
+    submitter::ReputationThresholds,
+    LIST_ADMINISTRATOR, LIST_HELPER, LIST_MODERATOR,
 };
+use pointercrate_integrate::account::{GdBackend, GdBackendError};
 use pointercrate_user::{auth::ApiToken, MODERATOR};
 use pointercrate_user_api::auth::Auth;
-use rocket::{http::Status, serde::json::Json, State};
+use rocket::{http::Status, response::Redirect, serde::json::Json, Either, State};
+use serde_json::Value;
+use sqlx::PgConnection;
+use std::sync::Arc;
 
 #[localized]
 #[rocket::get("/")]
@@ -55,20 +69,40 @@ pub async fn get_me(auth: AuthWithClaim<ApiToken, false>) -> Result<Tagged<FullP
     Ok(Tagged(full_player))
 }
 
+/// Gets the player with the given id. Ids of players that were merged into other players
+/// permanently redirect to the player they were merged into.
 #[localized]
 #[rocket::get("/<player_id>/")]
-pub async fn get(player_id: i32, pool: &State<PointercratePool>) -> Result<Tagged<FullPlayer>> {
+pub async fn get(player_id: i32, pool: &State<PointercratePool>) -> Result<Either<Tagged<FullPlayer>, Redirect>> {
+    let mut connection = pool.connection().await?;
+
+    let player = match Player::by_id(player_id, &mut connection).await {
+        Err(DemonlistError::PlayerNotFound { .. }) => match DatabasePlayer::merged_into(player_id, &mut connection).await? {
+            Some(player) => return Ok(Either::Right(Redirect::permanent(format!("/api/v1/players/{}/", player.id)))),
+            None => return Err(DemonlistError::PlayerNotFound { player_id }.into()),
+        },
+        result => result?,
+    };
+
+    Ok(Either::Left(Tagged(player.upgrade(&mut connection).await?)))
+}
+
+#[localized]
+#[rocket::get("/<player_id>/history/")]
+pub async fn history(player_id: i32, pool: &State<PointercratePool>) -> Result<Json<Vec<ScoreSnapshot>>> {
     let mut connection = pool.connection().await?;
 
-    Ok(Tagged(
-        Player::by_id(player_id, &mut connection).await?.upgrade(&mut connection).await?,
-    ))
+    let player = DatabasePlayer::by_id(player_id, &mut connection).await?;
+
+    Ok(Json(player.score_history(DEFAULT_LIST, &mut connection).await?))
 }
 
 #[localized]
 #[rocket::patch("/<player_id>/", data = "<patch>")]
+#[allow(clippy::too_many_arguments)]
 pub async fn patch(
-    player_id: i32, mut auth: Auth<ApiToken>, precondition: Precondition, patch: Json<PatchPlayer>,
+    player_id: i32, mut auth: Auth<ApiToken>, precondition: Precondition, patch: Json<PatchPlayer>, pool: &State<PointercratePool>,
+    verifier: &State<Arc<dyn VideoVerifier>>, heuristics: &State<Arc<SubmissionHeuristics>>, thresholds: &State<ReputationThresholds>,
 ) -> Result<Tagged<FullPlayer>> {
     let player = Player::by_id(player_id, &mut auth.connection)
         .await?
@@ -78,11 +112,68 @@ pub async fn patch(
         .apply_patch(patch.0, &mut auth.connection)
         .await?;
 
+    // Merging moves the merged player's held submissions (and possibly claims) to this player
+    let released = release_held_submissions(player.player.base.id, thresholds, &mut auth.connection).await?;
+
+    // Merges only ever happen here, so this is also where snapshots of merges that can no longer be undone get cleaned up
+    PlayerMerge::expire_snapshots(crate::config::merge_undo_days(), &mut auth.connection).await?;
+
     auth.commit().await?;
 
+    verify_released(&released, pool, verifier, heuristics).await?;
+
     Ok(Tagged(player))
 }
 
+/// Previews what merging the player with id `merged_id` into the player with id `player_id` (as
+/// happens when renaming the latter to the former's name) would do, without modifying anything
+#[localized]
+#[rocket::get("/<player_id>/merge-preview/<merged_id>/")]
+pub async fn merge_preview(player_id: i32, merged_id: i32, mut auth: Auth<ApiToken>) -> Result<Json<MergePreview>> {
+    auth.require_permission(LIST_MODERATOR)?;
+
+    if player_id == merged_id {
+        return Err(CoreError::UnprocessableEntity.into());
+    }
+
+    let player = Player::by_id(player_id, &mut auth.connection)
+        .await?
+        .upgrade(&mut auth.connection)
+        .await?;
+    let merged = DatabasePlayer::by_id(merged_id, &mut auth.connection).await?;
+
+    Ok(Json(player.preview_merge(merged, &mut auth.connection).await?))
+}
+
+#[localized]
+#[rocket::get("/<player_id>/merges/")]
+pub async fn merges(player_id: i32, mut auth: Auth<ApiToken>) -> Result<Json<Vec<PlayerMerge>>> {
+    auth.require_permission(LIST_MODERATOR)?;
+
+    Ok(Json(PlayerMerge::into_player(player_id, &mut auth.connection).await?))
+}
+
+/// Undoes a merge of some player into the player with id `player_id`, restoring the merged player
+#[localized]
+#[rocket::post("/<player_id>/merges/<merge_id>/undo/")]
+pub async fn undo_merge(player_id: i32, merge_id: i32, mut auth: Auth<ApiToken>) -> Result<Json<PlayerMerge>> {
+    auth.require_permission(LIST_ADMINISTRATOR)?;
+
+    let merge = PlayerMerge::by_id(merge_id, &mut auth.connection).await?;
+
+    if merge.player_id != player_id {
+        return Err(DemonlistError::MergeNotFound { id: merge_id }.into());
+    }
+
+    let merge = merge
+        .undo(crate::config::merge_undo_days(), auth.user.user().id, &mut auth.connection)
+        .await?;
+
+    auth.commit().await?;
+
+    Ok(Json(merge))
+}
+
 #[localized]
 #[rocket::put("/<player_id>/claims/")]
 pub async fn put_claim(player_id: i32, mut auth: Auth<ApiToken>) -> Result<Response2<Json<PlayerClaim>>> {
@@ -102,8 +193,10 @@ pub async fn put_claim(player_id: i32, mut auth: Auth<ApiToken>) -> Result<Respo
 /// player, put in a new `PUT` request)
 #[localized]
 #[rocket::patch("/<player_id>/claims/<user_id>/", data = "<data>")]
+#[allow(clippy::too_many_arguments)]
 pub async fn patch_claim(
-    player_id: i32, user_id: i32, mut auth: Auth<ApiToken>, data: Json<PatchPlayerClaim>,
+    player_id: i32, user_id: i32, mut auth: Auth<ApiToken>, data: Json<PatchPlayerClaim>, pool: &State<PointercratePool>,
+    verifier: &State<Arc<dyn VideoVerifier>>, heuristics: &State<Arc<SubmissionHeuristics>>, thresholds: &State<ReputationThresholds>,
 ) -> Result<Json<PlayerClaim>> {
     let claim = PlayerClaim::get(user_id, player_id, &mut auth.connection).await;
 
@@ -112,7 +205,7 @@ pub async fn patch_claim(
     }
 
     let claim = match claim {
-        Ok(claim) if data.lock_submissions.is_some() => {
+        Ok(claim) if data.lock_submissions.is_some() || data.third_party_approval.is_some() => {
             if claim.user_id != auth.user.user().id {
                 return Err(DemonlistError::ClaimNotFound {
                     member_id: user_id,
@@ -138,22 +231,124 @@ pub async fn patch_claim(
     };
 
     let claim = claim.apply_patch(data.0, &mut auth.connection).await?;
+    let released = release_held_submissions(player_id, thresholds, &mut auth.connection).await?;
+
+    auth.commit().await?;
+
+    verify_released(&released, pool, verifier, heuristics).await?;
+
+    Ok(Json(claim))
+}
+
+/// Issues a new one-time code which the claimant has to post on the Geometry Dash account of the
+/// claimed player to have their claim verified automatically
+#[localized]
+#[rocket::post("/<player_id>/claims/<user_id>/verification-code/")]
+pub async fn issue_verification_code(player_id: i32, user_id: i32, mut auth: Auth<ApiToken>) -> Result<Response2<Json<Value>>> {
+    if user_id != auth.user.user().id {
+        return Err(DemonlistError::ClaimNotFound {
+            member_id: user_id,
+            player_id,
+        }
+        .into());
+    }
+
+    let claim = PlayerClaim::get(user_id, player_id, &mut auth.connection).await?;
+    let code = claim.issue_verification_code(&mut auth.connection).await?;
+
+    auth.commit().await?;
+
+    Ok(Response2::json(serde_json::json! {{"code": code}}).status(Status::Created))
+}
+
+/// Checks whether the claim's verification code was posted on the Geometry Dash account of the
+/// claimed player, and verifies the claim if so
+#[localized]
+#[rocket::post("/<player_id>/claims/<user_id>/verify/")]
+#[allow(clippy::too_many_arguments)]
+pub async fn verify_claim(
+    player_id: i32, user_id: i32, mut auth: Auth<ApiToken>, backend: &State<Arc<dyn GdBackend>>, ratelimits: &State<DemonlistRatelimits>,
+    pool: &State<PointercratePool>, verifier: &State<Arc<dyn VideoVerifier>>, heuristics: &State<Arc<SubmissionHeuristics>>,
+    thresholds: &State<ReputationThresholds>,
+) -> Result<Json<PlayerClaim>> {
+    if user_id != auth.user.user().id {
+        return Err(DemonlistError::ClaimNotFound {
+            member_id: user_id,
+            player_id,
+        }
+        .into());
+    }
+
+    let mut claim = PlayerClaim::get(user_id, player_id, &mut auth.connection).await?;
+
+    if claim.verified {
+        return Err(DemonlistError::AlreadyClaimed.into());
+    }
+
+    let code = claim
+        .verification_code(&mut auth.connection)
+        .await?
+        .ok_or(DemonlistError::NoVerificationCode)?;
+
+    ratelimits.claim_verification(user_id)?;
+
+    let player = DatabasePlayer::by_id(player_id, &mut auth.connection).await?;
+
+    // Once a player has a linked account, we stick with it even if the player (or account) is renamed
+    let gd_account_id = match player.gd_account_id(&mut auth.connection).await? {
+        Some(gd_account_id) => gd_account_id as u64,
+        None => {
+            backend
+                .find_account(&player.name)
+                .await
+                .map_err(gd_unavailable)?
+                .ok_or_else(|| DemonlistError::GdAccountNotFound {
+                    player_name: player.name.clone(),
+                })?
+                .account_id
+        },
+    };
+
+    if !backend.displays_code(gd_account_id, &code).await.map_err(gd_unavailable)? {
+        return Err(DemonlistError::ClaimVerificationFailed.into());
+    }
+
+    claim.verify_through_gd_account(gd_account_id as i64, &mut auth.connection).await?;
+
+    // Verifying removes all other claims on the player, including the one whose holder the submissions were held for
+    let released = release_held_submissions(player_id, thresholds, &mut auth.connection).await?;
 
     auth.commit().await?;
 
+    verify_released(&released, pool, verifier, heuristics).await?;
+
     Ok(Json(claim))
 }
 
+fn gd_unavailable(err: GdBackendError) -> DemonlistError {
+    warn!("Failed to look up Geometry Dash account for claim verification: {}", err);
+
+    DemonlistError::GeometryDashUnavailable
+}
+
 #[localized]
 #[rocket::delete("/<player_id>/claims/<user_id>/")]
-pub async fn delete_claim(player_id: i32, user_id: i32, mut auth: Auth<ApiToken>) -> Result<Status> {
+pub async fn delete_claim(
+    player_id: i32, user_id: i32, mut auth: Auth<ApiToken>, pool: &State<PointercratePool>, verifier: &State<Arc<dyn VideoVerifier>>,
+    heuristics: &State<Arc<SubmissionHeuristics>>, thresholds: &State<ReputationThresholds>,
+) -> Result<Status> {
     auth.require_permission(MODERATOR)?;
 
     let claim = PlayerClaim::get(user_id, player_id, &mut auth.connection).await?;
 
     claim.delete(&mut auth.connection).await?;
+
+    let released = release_held_submissions(player_id, thresholds, &mut auth.connection).await?;
+
     auth.commit().await?;
 
+    verify_released(&released, pool, verifier, heuristics).await?;
+
     Ok(Status::NoContent)
 }
 
@@ -167,6 +362,104 @@ pub async fn paginate_claims(
     Ok(pagination_response("/api/v1/players/claims/", pagination.0, &mut auth.connection).await?)
 }
 
+/// Gets the live profile of the player claimed by the current user, together with any changes to
+/// it that are still waiting for a moderator's approval
+#[localized]
+#[rocket::get("/me/profile/")]
+pub async fn get_my_profile(auth: AuthWithClaim<ApiToken, true>) -> Result<Json<Value>> {
+    let AuthWithClaim(mut auth, claim) = auth;
+
+    let profile = PlayerProfile::of(claim.player.id, &mut auth.connection).await?;
+    let pending = PendingProfile::of(&claim.player, &mut auth.connection).await?;
+
+    Ok(Json(serde_json::json! {{"profile": profile, "pending": pending}}))
+}
+
+/// Changes the profile of the player claimed by the current user. The changes only become visible
+/// once a moderator approves them.
+#[localized]
+#[rocket::patch("/me/profile/", data = "<patch>")]
+pub async fn patch_my_profile(
+    auth: AuthWithClaim<ApiToken, true>, patch: Json<PatchPlayerProfile>,
+) -> Result<Response2<Json<PendingProfile>>> {
+    let AuthWithClaim(mut auth, claim) = auth;
+
+    // Build upon earlier changes that have not been approved yet, so that claimants can edit their profile piece by piece
+    let profile = match PendingProfile::of(&claim.player, &mut auth.connection).await? {
+        Some(pending) => pending.profile,
+        None => PlayerProfile::of(claim.player.id, &mut auth.connection).await?.unwrap_or_default(),
+    };
+
+    let pending = profile
+        .apply_patch(patch.0)?
+        .submit(&claim.player, auth.user.user().id, &mut auth.connection)
+        .await?;
+
+    auth.commit().await?;
+
+    Ok(Response2::json(pending).status(Status::Accepted))
+}
+
+#[localized]
+#[rocket::get("/profiles/pending/")]
+pub async fn pending_profiles(mut auth: Auth<ApiToken>) -> Result<Json<Vec<PendingProfile>>> {
+    auth.require_permission(MODERATOR)?;
+
+    Ok(Json(PendingProfile::all(&mut auth.connection).await?))
+}
+
+#[localized]
+#[rocket::post("/<player_id>/profile/approve/")]
+pub async fn approve_profile(player_id: i32, mut auth: Auth<ApiToken>) -> Result<Json<Option<PlayerProfile>>> {
+    auth.require_permission(MODERATOR)?;
+
+    let pending = pending_profile_of(player_id, &mut auth.connection).await?;
+    let profile = pending.approve(&mut auth.connection).await?;
+
+    auth.commit().await?;
+
+    Ok(Json(profile))
+}
+
+#[localized]
+#[rocket::post("/<player_id>/profile/reject/")]
+pub async fn reject_profile(player_id: i32, mut auth: Auth<ApiToken>) -> Result<Status> {
+    auth.require_permission(MODERATOR)?;
+
+    pending_profile_of(player_id, &mut auth.connection)
+        .await?
+        .reject(&mut auth.connection)
+        .await?;
+
+    auth.commit().await?;
+
+    Ok(Status::NoContent)
+}
+
+/// Removes a player's live profile, e.g. because it was reported for abuse
+#[localized]
+#[rocket::delete("/<player_id>/profile/")]
+pub async fn delete_profile(player_id: i32, mut auth: Auth<ApiToken>) -> Result<Status> {
+    auth.require_permission(MODERATOR)?;
+
+    let player = DatabasePlayer::by_id(player_id, &mut auth.connection).await?;
+
+    PlayerProfile::remove(&player, &mut auth.connection).await?;
+
+    auth.commit().await?;
+
+    Ok(Status::NoContent)
+}
+
+async fn pending_profile_of(player_id: i32, connection: &mut PgConnection) -> Result<PendingProfile> {
+    let player = DatabasePlayer::by_id(player_id, &mut *connection).await?;
+
+    match PendingProfile::of(&player, connection).await? {
+        Some(pending) => Ok(pending),
+        None => Err(DemonlistError::ProfileNotFound { player_id }.into()),
+    }
+}
+
 #[cfg(feature = "geolocation")]
 #[localized]
 #[rocket::post("/me/geolocate/")]
@@ -1,5 +1,5 @@
-use crate::ratelimits::DemonlistRatelimits;
-use log::{debug, error, warn};
+use crate::{claims::AuthWithClaim, proxy::ProxyDetector, ratelimits::DemonlistRatelimits, verification::VideoVerifier};
+use log::{debug, error, info, warn};
 use pointercrate_core::{audit::AuditLogEntry, error::CoreError, pool::PointercratePool};
 use pointercrate_core_api::{
     error::Result,
@@ -11,25 +11,33 @@ use pointercrate_core_api::{
 use pointercrate_core_macros::localized;
 use pointercrate_demonlist::{
     error::DemonlistError,
+    list::Tiers,
     player::claim::PlayerClaim,
     record::{
         audit::RecordModificationData,
+        evidence::{Evidence, NewEvidence, PatchEvidence},
+        heuristics::SubmissionHeuristics,
         note::{notes_on, NewNote, Note, PatchNote},
-        submission_count, FullRecord, MinimalRecordPD, PatchRecord, RecordPagination, RecordStatus, Submission,
+        pending::PendingSubmission,
+        stats::{ModerationStats, ModerationStatsQuery},
+        submission_count, FullRecord, MinimalRecordPD, PatchRecord, RecordPagination, RecordStatus, ReviewLock, Submission, VideoCheck,
+        VideoCheckResult,
     },
-    submitter::Submitter,
+    submitter::{ReputationThresholds, SubmissionBan, Submitter},
     LIST_ADMINISTRATOR, LIST_HELPER, LIST_MODERATOR,
 };
 use pointercrate_user::auth::ApiToken;
 use pointercrate_user_api::auth::Auth;
-use rocket::{http::Status, serde::json::Json, tokio, State};
-use sqlx::{pool::PoolConnection, Postgres};
-use std::net::IpAddr;
+use rocket::{http::Status, serde::json::Json, tokio, Either, State};
+use sqlx::{pool::PoolConnection, Connection, PgConnection, Postgres};
+use std::{net::IpAddr, sync::Arc};
 
 /// Pagination endpoint for records in case authentication is provided
 ///
 /// Subject to the following constraints
 /// + Only users with `LIST_MODERATOR` permissions can filter by submitter.
+/// + Only users with `LIST_HELPER` permissions can filter by review assignment or video check
+///   result.
 /// + Only users with `LIST_HELPER` permissions can filter by record status. For all other users,
 /// the `status` property defaults to `APPROVED` (although explicitly setting the status to
 /// `APPROVED` is allowed, UNLESS we also filter by player and the player we filter by match a
@@ -44,6 +52,16 @@ pub async fn paginate(mut auth: Auth<ApiToken>, query: Query<RecordPagination>)
         auth.require_permission(LIST_MODERATOR)?;
     }
 
+    if pagination.assigned.is_some() {
+        auth.require_permission(LIST_HELPER)?;
+
+        pagination.reviewer = Some(auth.user.user().id);
+    }
+
+    if pagination.video_check.is_some() {
+        auth.require_permission(LIST_HELPER)?;
+    }
+
     let claim = PlayerClaim::by_user(auth.user.user().id, &mut auth.connection)
         .await?
         .filter(|c| c.verified);
@@ -67,7 +85,7 @@ pub async fn unauthed_pagination(
     let mut connection = pool.connection().await?;
     let mut pagination = query.0;
 
-    if pagination.submitter.is_some() {
+    if pagination.submitter.is_some() || pagination.assigned.is_some() || pagination.video_check.is_some() {
         return Err(CoreError::Unauthorized.into());
     }
 
@@ -82,10 +100,12 @@ pub async fn unauthed_pagination(
 
 #[localized]
 #[rocket::post("/", data = "<submission>")]
+#[allow(clippy::too_many_arguments)]
 pub async fn submit(
     ip: IpAddr, auth: Option<Auth<ApiToken>>, submission: Json<Submission>, pool: &State<PointercratePool>,
-    ratelimits: &State<DemonlistRatelimits>,
-) -> Result<Response2<Tagged<FullRecord>>> {
+    ratelimits: &State<DemonlistRatelimits>, verifier: &State<Arc<dyn VideoVerifier>>, heuristics: &State<Arc<SubmissionHeuristics>>,
+    thresholds: &State<ReputationThresholds>, proxy_detector: &State<Box<dyn ProxyDetector>>,
+) -> Result<Either<Response2<Tagged<FullRecord>>, Response2<Json<PendingSubmission>>>> {
     let submission = submission.0;
     let status_is_submitted = submission.status() == RecordStatus::Submitted;
     let (is_team_member, user_id) = match auth {
@@ -100,38 +120,77 @@ pub async fn submit(
         }
     }
 
+    // Banned submitters could otherwise simply evade their ban by switching IPs
+    if auth.is_none() && proxy_detector.is_proxy(ip).await {
+        warn!("Rejecting anonymous submission from {}, as it originates from a VPN or proxy", ip);
+
+        return Err(DemonlistError::VpsDetected.into());
+    }
+
     let mut connection = match auth {
         Some(auth) => auth.connection,
         None => pool.transaction().await?,
     };
 
-    let submitter = match Submitter::by_ip(ip, &mut connection).await? {
-        Some(submitter) => submitter,
-        None => {
-            ratelimits.new_submitters()?;
+    // Submissions made while logged in are attributed to the user account, regardless of IP
+    let submitter = match user_id {
+        Some(user_id) => match Submitter::by_member(user_id, &mut connection).await? {
+            Some(submitter) => submitter,
+            None => Submitter::create_for_member(user_id, ip, &mut connection).await?,
+        },
+        None => match Submitter::by_ip(ip, &mut connection).await? {
+            Some(submitter) => submitter,
+            None => {
+                ratelimits.new_submitters()?;
 
-            Submitter::create_submitter(ip, &mut connection).await?
+                Submitter::create_submitter(ip, crate::config::ipv6_submitter_prefix_length(), &mut connection).await?
+            },
         },
     };
 
-    // Banned submitters cannot submit records
-    if submitter.banned {
+    // Banned submitters cannot submit records, and neither can anyone covered by a ban on their account or IP
+    if submitter.banned || SubmissionBan::applies_to(ip, user_id, &mut connection).await? {
         return Err(DemonlistError::BannedFromSubmissions.into());
     }
 
     let normalized = submission.normalize(&mut connection).await?;
 
-    // check if the player is claimed with submissions locked
+    // check if the player is claimed with submissions locked. Depending on the claimant's choice, third-party submissions are
+    // either rejected, or held for the claimant's approval
+    let mut hold_for_claimant = false;
+
     if let Some(claim) = normalized.verified_player_claim(&mut connection).await? {
         if claim.lock_submissions {
             match user_id {
                 Some(user_id) if user_id == claim.user_id => (),
+                _ if claim.third_party_approval && status_is_submitted => hold_for_claimant = true,
                 _ => return Err(DemonlistError::NoThirdPartySubmissions.into()),
             }
         }
     }
 
-    let validated = normalized.validate(&mut connection).await?;
+    // Submissions of submitters with a bad track record get extra scrutiny. Team members are exempt, as records they
+    // directly add as rejected count towards the statistics of their submitter.
+    let (low_reputation_raw, low_reputation_consideration) = match is_team_member {
+        true => (false, false),
+        false => (
+            thresholds.requires_raw_footage(&submitter.statistics),
+            thresholds.puts_under_consideration(&submitter.statistics),
+        ),
+    };
+
+    let mut validated = normalized
+        .validate(crate::config::raw_footage_required() || low_reputation_raw, &mut connection)
+        .await?;
+
+    if low_reputation_consideration {
+        debug!(
+            "Submitter {} has a low reputation, putting submission under consideration",
+            submitter
+        );
+
+        validated.put_under_consideration();
+    }
 
     if !is_team_member {
         // Check ratelimits before any change is made to the database so that the transaction rollback is
@@ -142,6 +201,14 @@ pub async fn submit(
         ratelimits.record_submission_global()?;
     }
 
+    if hold_for_claimant {
+        let pending = validated.hold_for_claimant(&submitter, &mut connection).await?;
+
+        connection.commit().await.map_err(DemonlistError::from)?;
+
+        return Ok(Either::Right(Response2::json(pending).status(Status::Accepted)));
+    }
+
     let mut record = validated.create(submitter, &mut connection).await?;
 
     connection.commit().await.map_err(DemonlistError::from)?;
@@ -149,9 +216,11 @@ pub async fn submit(
     // FIXME: This is fucking stupid
     if status_is_submitted {
         if let Some(ref video) = record.video {
-            tokio::spawn(validate(
+            tokio::spawn(verify_submission(
                 record.id,
                 video.to_string(),
+                Arc::clone(verifier),
+                Arc::clone(heuristics),
                 webhook_embed(&record),
                 pool.connection().await?,
             ));
@@ -171,7 +240,158 @@ pub async fn submit(
         );
     }
 
-    Ok(response)
+    Ok(Either::Left(response))
+}
+
+/// Lists the third-party submissions held for the approval of the authenticated user, as the
+/// claimant of their player
+#[localized]
+#[rocket::get("/pending/")]
+pub async fn pending(auth: AuthWithClaim<ApiToken, true>) -> Result<Json<Vec<PendingSubmission>>> {
+    let AuthWithClaim(mut auth, claim) = auth;
+
+    Ok(Json(PendingSubmission::for_player(claim.player.id, &mut auth.connection).await?))
+}
+
+/// Accepts a held third-party submission, turning it into a regular submission
+///
+/// The submission is validated again, and then handled exactly like a submission that was never
+/// held.
+#[localized]
+#[rocket::post("/pending/<submission_id>/accept/")]
+pub async fn accept_pending(
+    submission_id: i32, auth: AuthWithClaim<ApiToken, true>, pool: &State<PointercratePool>, verifier: &State<Arc<dyn VideoVerifier>>,
+    heuristics: &State<Arc<SubmissionHeuristics>>, thresholds: &State<ReputationThresholds>,
+) -> Result<Tagged<FullRecord>> {
+    let AuthWithClaim(mut auth, claim) = auth;
+    let is_team_member = auth.has_permission(LIST_HELPER);
+
+    let pending = pending_submission_of(submission_id, claim.player.id, &mut auth.connection).await?;
+    let mut record = release(pending, thresholds, &mut auth.connection).await?;
+
+    auth.commit().await?;
+
+    verify_released(std::slice::from_ref(&record), pool, verifier, heuristics).await?;
+
+    if !is_team_member {
+        record.submitter = None;
+    }
+
+    Ok(Tagged(record))
+}
+
+/// Turns the given held submission into a regular submission, handling it exactly like a
+/// submission that was never held (apart from verifying its video, see [`verify_released`])
+async fn release(pending: PendingSubmission, thresholds: &ReputationThresholds, connection: &mut PgConnection) -> Result<FullRecord> {
+    let submitter = Submitter::by_id(pending.submitter_id, &mut *connection).await?;
+
+    // The submitter might have been banned while the submission was waiting for approval
+    if submitter.banned {
+        return Err(DemonlistError::BannedFromSubmissions.into());
+    }
+
+    pending.delete(&mut *connection).await?;
+
+    let raw_footage_required = crate::config::raw_footage_required() || thresholds.requires_raw_footage(&submitter.statistics);
+    let mut validated = pending.into_submission().validate(raw_footage_required, &mut *connection).await?;
+
+    if thresholds.puts_under_consideration(&submitter.statistics) {
+        validated.put_under_consideration();
+    }
+
+    Ok(validated.create(submitter, connection).await?)
+}
+
+/// Releases all submissions held for the given player into the regular submission queue, unless
+/// the player (still) has a verified claim whose holder wants to approve third-party submissions
+///
+/// Needs to be called whenever claims on the player change, as held submissions would otherwise
+/// be stuck with no one able to accept them. Submissions that are not valid anymore (e.g. because
+/// their submitter got banned) are discarded. The caller is responsible for calling
+/// [`verify_released`] on the returned records once the transaction is committed.
+pub(crate) async fn release_held_submissions(
+    player_id: i32, thresholds: &ReputationThresholds, connection: &mut PgConnection,
+) -> Result<Vec<FullRecord>> {
+    if let Some(claim) = PlayerClaim::verified_claim_on(player_id, &mut *connection).await? {
+        if claim.lock_submissions && claim.third_party_approval {
+            return Ok(Vec::new());
+        }
+    }
+
+    let mut records = Vec::new();
+
+    for pending in PendingSubmission::for_player(player_id, &mut *connection).await? {
+        // Release each submission in a nested transaction, so that a failed validation does not abort the whole transaction
+        let mut transaction = connection.begin().await?;
+
+        match release(pending.clone(), thresholds, &mut *transaction).await {
+            Ok(record) => {
+                transaction.commit().await?;
+                records.push(record);
+            },
+            Err(err) => {
+                transaction.rollback().await?;
+
+                info!(
+                    "Discarding held submission {} for player {}, as it is not valid anymore: {:?}",
+                    pending.id, player_id, err
+                );
+
+                pending.delete(&mut *connection).await?;
+            },
+        }
+    }
+
+    Ok(records)
+}
+
+/// Verifies the videos of the given records, which were just released from being held for the
+/// claimant's approval
+pub(crate) async fn verify_released(
+    records: &[FullRecord], pool: &PointercratePool, verifier: &Arc<dyn VideoVerifier>, heuristics: &Arc<SubmissionHeuristics>,
+) -> Result<()> {
+    for record in records {
+        if let Some(ref video) = record.video {
+            tokio::spawn(verify_submission(
+                record.id,
+                video.to_string(),
+                Arc::clone(verifier),
+                Arc::clone(heuristics),
+                webhook_embed(record),
+                pool.connection().await?,
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Declines a held third-party submission, discarding it
+#[localized]
+#[rocket::post("/pending/<submission_id>/decline/")]
+pub async fn decline_pending(submission_id: i32, auth: AuthWithClaim<ApiToken, true>) -> Result<Status> {
+    let AuthWithClaim(mut auth, claim) = auth;
+
+    pending_submission_of(submission_id, claim.player.id, &mut auth.connection)
+        .await?
+        .delete(&mut auth.connection)
+        .await?;
+
+    auth.commit().await?;
+
+    Ok(Status::NoContent)
+}
+
+/// Gets the given held submission, making sure it is for the given player. Submissions for other
+/// players are reported as non-existent.
+async fn pending_submission_of(submission_id: i32, player_id: i32, connection: &mut PgConnection) -> Result<PendingSubmission> {
+    let pending = PendingSubmission::by_id(submission_id, connection).await?;
+
+    if pending.player.id != player_id {
+        return Err(DemonlistError::PendingSubmissionNotFound { id: submission_id }.into());
+    }
+
+    Ok(pending)
 }
 
 #[localized]
@@ -192,12 +412,23 @@ pub async fn get(record_id: i32, auth: Option<Auth<ApiToken>>, pool: &State<Poin
             return Err(DemonlistError::RecordNotFound { record_id }.into());
         }
         record.submitter = None;
-        record.raw_footage = None;
+        record.evidence.retain(|evidence| evidence.is_public);
+        record.update_raw_footage();
+        record.lock = None;
+        record.video_check = None;
     }
 
     Ok(Tagged(record))
 }
 
+#[localized]
+#[rocket::get("/stats/")]
+pub async fn stats(mut auth: Auth<ApiToken>, query: Query<ModerationStatsQuery>) -> Result<Json<ModerationStats>> {
+    auth.require_permission(LIST_ADMINISTRATOR)?;
+
+    Ok(Json(query.0.stats(&mut auth.connection).await?))
+}
+
 #[localized]
 #[rocket::get("/<record_id>/audit/")]
 pub async fn audit(record_id: i32, mut auth: Auth<ApiToken>) -> Result<Json<Vec<AuditLogEntry<RecordModificationData>>>> {
@@ -219,22 +450,75 @@ pub async fn patch(
 ) -> Result<Tagged<FullRecord>> {
     let record = FullRecord::by_id(record_id, &mut auth.connection).await?;
 
-    if record.demon.position > pointercrate_demonlist::config::extended_list_size() {
-        auth.require_permission(LIST_MODERATOR)?;
-    } else {
-        auth.require_permission(LIST_HELPER)?;
+    let tiers = Tiers::of_list(&record.demon.list, &mut auth.connection).await?;
+
+    auth.require_permission(tiers.edit_permission(record.demon.position))?;
+
+    if !auth.has_permission(LIST_MODERATOR) {
+        record.require_unlocked_for(auth.user.user().id)?;
     }
 
-    let record = record
+    let mut record = record
         .require_match(precondition)?
         .apply_patch(patch.0, &mut auth.connection)
         .await?;
 
+    // Once a record is approved or rejected, there is nothing left to review
+    if matches!(record.status, RecordStatus::Approved | RecordStatus::Rejected) && record.lock.is_some() {
+        record.release_lock(&mut auth.connection).await?;
+    }
+
     auth.commit().await?;
 
     Ok(Tagged(record))
 }
 
+/// Locks a record for review by the user making the request
+///
+/// Locking a record that the user already holds the lock on renews the lock.
+#[localized]
+#[rocket::put("/<record_id>/lock/")]
+pub async fn lock(record_id: i32, mut auth: Auth<ApiToken>) -> Result<Json<ReviewLock>> {
+    let mut record = FullRecord::by_id(record_id, &mut auth.connection).await?;
+
+    let tiers = Tiers::of_list(&record.demon.list, &mut auth.connection).await?;
+
+    auth.require_permission(tiers.edit_permission(record.demon.position))?;
+
+    let lock = record
+        .lock_for_review(auth.user.user().id, crate::config::review_lock_minutes(), &mut auth.connection)
+        .await?;
+
+    auth.commit().await?;
+
+    Ok(Json(lock))
+}
+
+/// Releases the review lock on a record
+///
+/// Users can always release their own locks (given they still have the permission required to lock
+/// the record), but only list moderators can break locks held by someone else.
+#[localized]
+#[rocket::delete("/<record_id>/lock/")]
+pub async fn unlock(record_id: i32, mut auth: Auth<ApiToken>) -> Result<Status> {
+    let mut record = FullRecord::by_id(record_id, &mut auth.connection).await?;
+
+    // Anyone who cannot lock a record should not be able to unlock it either
+    let tiers = Tiers::of_list(&record.demon.list, &mut auth.connection).await?;
+
+    auth.require_permission(tiers.edit_permission(record.demon.position))?;
+
+    if record.require_unlocked_for(auth.user.user().id).is_err() {
+        auth.require_permission(LIST_MODERATOR)?;
+    }
+
+    record.release_lock(&mut auth.connection).await?;
+
+    auth.commit().await?;
+
+    Ok(Status::NoContent)
+}
+
 #[localized]
 #[rocket::delete("/<record_id>/")]
 pub async fn delete(record_id: i32, mut auth: Auth<ApiToken>, precondition: Precondition) -> Result<Status> {
@@ -246,6 +530,10 @@ pub async fn delete(record_id: i32, mut auth: Auth<ApiToken>, precondition: Prec
         auth.require_permission(LIST_MODERATOR)?;
     }
 
+    if !auth.has_permission(LIST_MODERATOR) {
+        record.require_unlocked_for(auth.user.user().id)?;
+    }
+
     precondition.require_etag_match(&record)?;
 
     record.delete(&mut auth.connection).await?;
@@ -338,37 +626,119 @@ pub async fn delete_note(record_id: i32, note_id: i32, mut auth: Auth<ApiToken>)
     Ok(Status::NoContent)
 }
 
-async fn validate(record_id: i32, video: String, body: serde_json::Value, mut connection: PoolConnection<Postgres>) {
-    debug!("Verifying that submission {} with video {} actually is valid", record_id, video);
+/// Checks whether the user making the request is allowed to modify the given record's evidence
+///
+/// This requires the same permissions as patching the record itself.
+async fn require_evidence_access(record: &FullRecord, auth: &mut Auth<ApiToken>) -> Result<()> {
+    let tiers = Tiers::of_list(&record.demon.list, &mut auth.connection).await?;
 
-    match reqwest::get(&video).await {
-        Ok(response) => {
-            let status = response.status().as_u16();
+    auth.require_permission(tiers.edit_permission(record.demon.position))?;
 
-            if (200..400).contains(&status) {
-                debug!("GET request yielded some sort of successful response, executing webhook");
+    if !auth.has_permission(LIST_MODERATOR) {
+        record.require_unlocked_for(auth.user.user().id)?;
+    }
 
-                execute_webhook(body).await;
-            } else {
-                warn!("Server response to 'GET {}' was {:?}, deleting submission!", video, response);
+    Ok(())
+}
 
-                match FullRecord::delete_by_id(record_id, &mut connection).await {
-                    Ok(_) => (),
-                    Err(error) => error!("INTERNAL SERVER ERROR: Failure to delete record - {:?}!", error),
-                }
-            }
-        },
-        Err(error) => {
-            error!(
-                "INTERNAL SERVER ERROR: GET request to verify video failed: {:?}. Deleting submission",
-                error
-            );
-
-            match FullRecord::delete_by_id(record_id, &mut connection).await {
-                Ok(_) => (),
-                Err(error) => error!("INTERNAL SERVER ERROR: Failure to delete record - {:?}!", error),
+#[localized]
+#[rocket::post("/<record_id>/evidence/", data = "<data>")]
+pub async fn add_evidence(record_id: i32, mut auth: Auth<ApiToken>, data: Json<NewEvidence>) -> Result<Response2<Tagged<Evidence>>> {
+    let record = FullRecord::by_id(record_id, &mut auth.connection).await?;
+
+    require_evidence_access(&record, &mut auth).await?;
+
+    let evidence = Evidence::create_on(&record, data.0, &mut auth.connection).await?;
+    let evidence_id = evidence.id;
+
+    auth.commit().await?;
+
+    Ok(Response2::tagged(evidence)
+        .status(Status::Created)
+        .with_header("Location", format!("/api/v1/records/{}/evidence/{}/", record.id, evidence_id)))
+}
+
+#[localized]
+#[rocket::patch("/<record_id>/evidence/<evidence_id>/", data = "<patch>")]
+pub async fn patch_evidence(
+    record_id: i32, evidence_id: i32, mut auth: Auth<ApiToken>, precondition: Precondition, patch: Json<PatchEvidence>,
+) -> Result<Tagged<Evidence>> {
+    let record = FullRecord::by_id(record_id, &mut auth.connection).await?;
+
+    require_evidence_access(&record, &mut auth).await?;
+
+    let evidence = Evidence::by_id(record_id, evidence_id, &mut auth.connection)
+        .await?
+        .require_match(precondition)?
+        .apply_patch(patch.0, &mut auth.connection)
+        .await?;
+
+    auth.commit().await?;
+
+    Ok(Tagged(evidence))
+}
+
+#[localized]
+#[rocket::delete("/<record_id>/evidence/<evidence_id>/")]
+pub async fn delete_evidence(record_id: i32, evidence_id: i32, mut auth: Auth<ApiToken>) -> Result<Status> {
+    let record = FullRecord::by_id(record_id, &mut auth.connection).await?;
+
+    require_evidence_access(&record, &mut auth).await?;
+
+    Evidence::by_id(record_id, evidence_id, &mut auth.connection)
+        .await?
+        .delete(&mut auth.connection)
+        .await?;
+
+    auth.commit().await?;
+
+    Ok(Status::NoContent)
+}
+
+async fn verify_submission(
+    record_id: i32, video: String, verifier: Arc<dyn VideoVerifier>, heuristics: Arc<SubmissionHeuristics>, body: serde_json::Value,
+    mut connection: PoolConnection<Postgres>,
+) {
+    debug!("Verifying that submission {} with video {} actually is valid", record_id, video);
+
+    let (result, metadata) = verifier.verify(&video).await;
+
+    match result {
+        VideoCheckResult::Missing => {
+            warn!("Video {} of submission {} does not exist, deleting submission!", video, record_id);
+
+            if let Err(error) = FullRecord::delete_missing_video(record_id, &mut connection).await {
+                error!("INTERNAL SERVER ERROR: Failure to delete record - {:?}!", error);
             }
+
+            return;
         },
+        VideoCheckResult::Inconclusive => warn!(
+            "Could not verify video {} of submission {}, leaving it for manual review",
+            video, record_id
+        ),
+        VideoCheckResult::Available => debug!("Video {} of submission {} exists, executing webhook", video, record_id),
+    }
+
+    match VideoCheck::store(record_id, &video, result, metadata, &mut connection).await {
+        Ok(video_check) => flag_submission(record_id, &video_check, &heuristics, &mut connection).await,
+        Err(error) => error!("INTERNAL SERVER ERROR: Failure to store video check result - {:?}!", error),
+    }
+
+    execute_webhook(body).await;
+}
+
+async fn flag_submission(record_id: i32, video_check: &VideoCheck, heuristics: &SubmissionHeuristics, connection: &mut PgConnection) {
+    let result = match FullRecord::by_id(record_id, &mut *connection).await {
+        Ok(record) => heuristics.flag(&record, video_check, connection).await,
+        Err(error) => Err(error),
+    };
+
+    if let Err(error) = result {
+        error!(
+            "INTERNAL SERVER ERROR: Failure to run heuristics on submission {} - {:?}!",
+            record_id, error
+        );
     }
 }
 
@@ -7,12 +7,12 @@ use pointercrate_core_api::{
 };
 use pointercrate_core_macros::localized;
 use pointercrate_demonlist::{
-    submitter::{PatchSubmitter, Submitter, SubmitterPagination},
+    submitter::{NewSubmissionBan, PatchSubmitter, SubmissionBan, Submitter, SubmitterPagination},
     LIST_MODERATOR,
 };
 use pointercrate_user::auth::ApiToken;
 use pointercrate_user_api::auth::Auth;
-use rocket::serde::json::Json;
+use rocket::{http::Status, serde::json::Json};
 
 #[localized]
 #[rocket::get("/")]
@@ -47,3 +47,41 @@ pub async fn patch(
 
     Ok(Tagged(submitter))
 }
+
+#[localized]
+#[rocket::get("/bans/")]
+pub async fn bans(mut auth: Auth<ApiToken>) -> Result<Response2<Json<Vec<SubmissionBan>>>> {
+    auth.require_permission(LIST_MODERATOR)?;
+
+    Ok(Response2::json(SubmissionBan::all(&mut auth.connection).await?))
+}
+
+#[localized]
+#[rocket::post("/bans/", data = "<ban>")]
+pub async fn ban(mut auth: Auth<ApiToken>, ban: Json<NewSubmissionBan>) -> Result<Response2<Tagged<SubmissionBan>>> {
+    auth.require_permission(LIST_MODERATOR)?;
+
+    let ban = SubmissionBan::create(ban.0, &mut auth.connection).await?;
+    let ban_id = ban.id;
+
+    auth.commit().await?;
+
+    Ok(Response2::tagged(ban)
+        .status(Status::Created)
+        .with_header("Location", format!("/api/v1/submitters/bans/{}/", ban_id)))
+}
+
+#[localized]
+#[rocket::delete("/bans/<ban_id>/")]
+pub async fn lift_ban(ban_id: i32, mut auth: Auth<ApiToken>) -> Result<Status> {
+    auth.require_permission(LIST_MODERATOR)?;
+
+    SubmissionBan::by_id(ban_id, &mut auth.connection)
+        .await?
+        .delete(&mut auth.connection)
+        .await?;
+
+    auth.commit().await?;
+
+    Ok(Status::NoContent)
+}
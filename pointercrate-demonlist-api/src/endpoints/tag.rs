@@ -0,0 +1,95 @@
+//! Endpoints for managing the tags that can be assigned to demons
+//!
+//! Assigning tags to demons happens through the `/api/v2/demons/<demon_id>/tags/` endpoints.
+
+use pointercrate_core::pool::PointercratePool;
+use pointercrate_core_api::{
+    error::Result,
+    etag::{Precondition, TaggableExt, Tagged},
+    response::Response2,
+};
+use pointercrate_core_macros::localized;
+use pointercrate_demonlist::{
+    error::DemonlistError,
+    tag::{audit_log_for_tag, PatchTag, PostTag, Tag, TagAuditLogEntry},
+    LIST_ADMINISTRATOR,
+};
+use pointercrate_user::auth::ApiToken;
+use pointercrate_user_api::auth::Auth;
+use rocket::{http::Status, serde::json::Json, State};
+
+#[localized]
+#[rocket::get("/")]
+pub async fn all(pool: &State<PointercratePool>) -> Result<Json<Vec<Tag>>> {
+    Ok(Json(Tag::all(&mut *pool.connection().await?).await?))
+}
+
+#[localized]
+#[rocket::post("/", data = "<data>")]
+pub async fn post(mut auth: Auth<ApiToken>, data: Json<PostTag>) -> Result<Response2<Tagged<Tag>>> {
+    auth.require_permission(LIST_ADMINISTRATOR)?;
+
+    let tag = Tag::create_from(data.0, &mut auth.connection).await?;
+
+    auth.commit().await?;
+
+    let location = format!("/api/v2/tags/{}/", tag.id);
+
+    Ok(Response2::tagged(tag).status(Status::Created).with_header("Location", location))
+}
+
+#[localized]
+#[rocket::get("/<tag_id>/")]
+pub async fn get(tag_id: &str, pool: &State<PointercratePool>) -> Result<Tagged<Tag>> {
+    Ok(Tagged(Tag::by_id(tag_id, &mut *pool.connection().await?).await?))
+}
+
+#[localized]
+#[rocket::patch("/<tag_id>/", data = "<patch>")]
+pub async fn patch(tag_id: &str, mut auth: Auth<ApiToken>, precondition: Precondition, patch: Json<PatchTag>) -> Result<Tagged<Tag>> {
+    auth.require_permission(LIST_ADMINISTRATOR)?;
+
+    let tag = Tag::by_id(tag_id, &mut auth.connection)
+        .await?
+        .require_match(precondition)?
+        .apply_patch(patch.0, &mut auth.connection)
+        .await?;
+
+    auth.commit().await?;
+
+    Ok(Tagged(tag))
+}
+
+#[localized]
+#[rocket::delete("/<tag_id>/")]
+pub async fn delete(tag_id: &str, mut auth: Auth<ApiToken>, precondition: Precondition) -> Result<Status> {
+    auth.require_permission(LIST_ADMINISTRATOR)?;
+
+    Tag::by_id(tag_id, &mut auth.connection)
+        .await?
+        .require_match(precondition)?
+        .delete(&mut auth.connection)
+        .await?;
+
+    auth.commit().await?;
+
+    Ok(Status::NoContent)
+}
+
+#[localized]
+#[rocket::get("/<tag_id>/audit/")]
+pub async fn audit(tag_id: &str, mut auth: Auth<ApiToken>) -> Result<Json<Vec<TagAuditLogEntry>>> {
+    auth.require_permission(LIST_ADMINISTRATOR)?;
+
+    // Deliberately does not look up the tag itself, so that the history of deleted tags stays available
+    let log = audit_log_for_tag(tag_id, &mut auth.connection).await?;
+
+    if log.is_empty() {
+        return Err(DemonlistError::TagNotFound {
+            tag_id: tag_id.to_string(),
+        }
+        .into());
+    }
+
+    Ok(Json(log))
+}
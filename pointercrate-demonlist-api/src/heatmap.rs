@@ -0,0 +1,129 @@
+use pointercrate_demonlist::nationality::{Heatmap, HeatmapMetric};
+use std::{collections::HashMap, sync::Mutex};
+
+/// The maximal number of rendered heatmaps kept around. Once reached, the oldest rendering is evicted.
+const MAX_CACHED_RENDERS: usize = 32;
+
+/// A cached rendering, keyed by the list, metric and width it was rendered for
+type RenderKey = (String, HeatmapMetric, u32);
+
+/// Cache for rendered heatmap images, as rasterizing the world map is expensive
+///
+/// Each rendering is stored together with the [`Heatmap`] it was rendered from. Since loading a
+/// heatmap is cheap compared to rendering it, a cached rendering is only reused if the heatmap
+/// loaded for a request is still equal to the one it was rendered from. This way, renderings are
+/// invalidated as soon as (nation or subdivision) scores are recomputed.
+#[derive(Debug, Default)]
+pub struct HeatmapCache {
+    renders: Mutex<Renders>,
+}
+
+#[derive(Debug, Default)]
+struct Renders {
+    /// Incremented on each insertion, so that the oldest rendering can be found
+    insertions: u64,
+
+    /// The cached renderings, each together with the value of `insertions` at the time it was inserted
+    entries: HashMap<RenderKey, (u64, Heatmap, Vec<u8>)>,
+}
+
+impl HeatmapCache {
+    /// Gets the rendering of the given list's heatmap at the given width, if it is cached
+    pub fn get(&self, list: &str, heatmap: &Heatmap, width: u32) -> Option<Vec<u8>> {
+        let renders = self.renders.lock().unwrap();
+
+        match renders.entries.get(&(list.to_string(), heatmap.metric, width)) {
+            Some((_, rendered, png)) if rendered == heatmap => Some(png.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn insert(&self, list: &str, heatmap: Heatmap, width: u32, png: Vec<u8>) {
+        let mut renders = self.renders.lock().unwrap();
+
+        // Renderings of outdated heatmaps are never going to be used again
+        renders.entries.retain(|(rendered_list, metric, _), (_, rendered, _)| {
+            rendered_list != list || *metric != heatmap.metric || *rendered == heatmap
+        });
+
+        let key = (list.to_string(), heatmap.metric, width);
+
+        if !renders.entries.contains_key(&key) && renders.entries.len() >= MAX_CACHED_RENDERS {
+            let oldest = renders
+                .entries
+                .iter()
+                .min_by_key(|(_, (inserted, ..))| *inserted)
+                .map(|(key, _)| key.clone());
+
+            if let Some(oldest) = oldest {
+                renders.entries.remove(&oldest);
+            }
+        }
+
+        renders.insertions += 1;
+
+        let insertion = renders.insertions;
+        renders.entries.insert(key, (insertion, heatmap, png));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HeatmapCache, MAX_CACHED_RENDERS};
+    use pointercrate_demonlist::nationality::{Heatmap, HeatmapEntry, HeatmapMetric};
+
+    fn heatmap(metric: HeatmapMetric, value: f64) -> Heatmap {
+        Heatmap {
+            metric,
+            nations: vec![HeatmapEntry {
+                country_code: "DE".to_string(),
+                subdivision_code: None,
+                value,
+                normalized: 1.0,
+            }],
+            subdivisions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_heatmap_cache() {
+        let cache = HeatmapCache::default();
+
+        cache.insert("main", heatmap(HeatmapMetric::Score, 100.0), 1280, vec![1]);
+        cache.insert("main", heatmap(HeatmapMetric::Players, 3.0), 1280, vec![2]);
+        cache.insert("platformer", heatmap(HeatmapMetric::Score, 50.0), 1280, vec![4]);
+
+        assert_eq!(cache.get("main", &heatmap(HeatmapMetric::Score, 100.0), 1280), Some(vec![1]));
+        assert_eq!(cache.get("main", &heatmap(HeatmapMetric::Score, 100.0), 640), None);
+        assert_eq!(cache.get("main", &heatmap(HeatmapMetric::Players, 3.0), 1280), Some(vec![2]));
+        assert_eq!(cache.get("platformer", &heatmap(HeatmapMetric::Score, 100.0), 1280), None);
+        assert_eq!(cache.get("platformer", &heatmap(HeatmapMetric::Score, 50.0), 1280), Some(vec![4]));
+
+        // Scores changed, so the old rendering must not be used anymore
+        assert_eq!(cache.get("main", &heatmap(HeatmapMetric::Score, 150.0), 1280), None);
+
+        cache.insert("main", heatmap(HeatmapMetric::Score, 150.0), 640, vec![3]);
+
+        assert_eq!(cache.renders.lock().unwrap().entries.len(), 3);
+        assert_eq!(cache.get("main", &heatmap(HeatmapMetric::Score, 150.0), 640), Some(vec![3]));
+        assert_eq!(cache.get("main", &heatmap(HeatmapMetric::Players, 3.0), 1280), Some(vec![2]));
+        assert_eq!(cache.get("platformer", &heatmap(HeatmapMetric::Score, 50.0), 1280), Some(vec![4]));
+    }
+
+    #[test]
+    fn test_heatmap_cache_evicts_oldest() {
+        let cache = HeatmapCache::default();
+
+        for width in 0..MAX_CACHED_RENDERS as u32 + 1 {
+            cache.insert("main", heatmap(HeatmapMetric::Score, 100.0), width, vec![width as u8]);
+        }
+
+        assert_eq!(cache.renders.lock().unwrap().entries.len(), MAX_CACHED_RENDERS);
+        assert_eq!(cache.get("main", &heatmap(HeatmapMetric::Score, 100.0), 0), None);
+        assert_eq!(cache.get("main", &heatmap(HeatmapMetric::Score, 100.0), 1), Some(vec![1]));
+        assert_eq!(
+            cache.get("main", &heatmap(HeatmapMetric::Score, 100.0), MAX_CACHED_RENDERS as u32),
+            Some(vec![MAX_CACHED_RENDERS as u8])
+        );
+    }
+}
@@ -1,23 +1,79 @@
-use crate::{endpoints::misc, ratelimits::DemonlistRatelimits};
+use crate::{endpoints::misc, heatmap::HeatmapCache, ratelimits::DemonlistRatelimits};
 use pointercrate_core::pool::PointercratePool;
-use pointercrate_integrate::gd::GeometryDashConnector;
+use pointercrate_demonlist::{record::heuristics::SubmissionHeuristics, submitter::ReputationThresholds};
+use pointercrate_integrate::{
+    account::{BoomlingsBackend, GdBackend},
+    gd::GeometryDashConnector,
+};
 use rocket::{Build, Rocket};
+use std::sync::Arc;
 
 pub(crate) mod claims;
 pub(crate) mod config;
 mod endpoints;
 #[cfg(feature = "geolocation")]
 mod geolocate;
+pub(crate) mod heatmap;
 pub(crate) mod pages;
+mod proxy;
 pub(crate) mod ratelimits;
+mod verification;
 
 #[cfg(feature = "geolocation")]
 pub use geolocate::GeolocationProvider;
+pub use proxy::{CidrProxyDetector, ProxyDetector};
+pub use verification::{OEmbedVerifier, VideoVerifier};
 
 pub fn setup(rocket: Rocket<Build>) -> Rocket<Build> {
     let ratelimits = DemonlistRatelimits::new();
     let dash_rs = GeometryDashConnector::new(rocket.state::<PointercratePool>().unwrap().clone_inner());
 
+    // Only use the default verifier if the embedding application did not configure its own
+    let rocket = match rocket.state::<Arc<dyn VideoVerifier>>() {
+        Some(_) => rocket,
+        None => rocket.manage(Arc::new(OEmbedVerifier::default()) as Arc<dyn VideoVerifier>),
+    };
+
+    let rocket = match rocket.state::<Arc<SubmissionHeuristics>>() {
+        Some(_) => rocket,
+        None => rocket.manage(Arc::new(SubmissionHeuristics::default())),
+    };
+
+    // Without a configured list of ranges, the built-in detector does not flag anything
+    let rocket = match rocket.state::<Box<dyn ProxyDetector>>() {
+        Some(_) => rocket,
+        None => {
+            let detector = match config::proxy_ranges_file() {
+                Some(path) => CidrProxyDetector::from_file(&path)
+                    .unwrap_or_else(|err| panic!("Failed to load proxy IP ranges from '{}': {}", path, err)),
+                None => CidrProxyDetector::default(),
+            };
+
+            rocket.manage(Box::new(detector) as Box<dyn ProxyDetector>)
+        },
+    };
+
+    let rocket = match rocket.state::<Arc<dyn GdBackend>>() {
+        Some(_) => rocket,
+        None => rocket.manage(Arc::new(BoomlingsBackend::default()) as Arc<dyn GdBackend>),
+    };
+
+    let rocket = match rocket.state::<ReputationThresholds>() {
+        Some(_) => rocket,
+        None => rocket.manage(config::reputation_thresholds()),
+    };
+
+    config::warn_about_removed_list_sizes();
+
+    let ipv6_prefix_length = config::ipv6_submitter_prefix_length();
+
+    if !(0..=128).contains(&ipv6_prefix_length) {
+        panic!(
+            "SUBMITTER_IPV6_PREFIX_LENGTH must be between 0 and 128, but is {}",
+            ipv6_prefix_length
+        );
+    }
+
     if let Some(endpoint) = config::gd_connector_endpoint() {
         pointercrate_integrate::set_gd_connector_endpoint(endpoint);
     }
@@ -25,6 +81,7 @@ pub fn setup(rocket: Rocket<Build>) -> Rocket<Build> {
     #[cfg_attr(not(feature = "geolocation"), allow(unused_mut))]
     let mut player_routes = rocket::routes![
         endpoints::player::get,
+        endpoints::player::history,
         endpoints::player::get_me,
         endpoints::player::paginate,
         endpoints::player::patch,
@@ -33,6 +90,17 @@ pub fn setup(rocket: Rocket<Build>) -> Rocket<Build> {
         endpoints::player::patch_claim,
         endpoints::player::paginate_claims,
         endpoints::player::delete_claim,
+        endpoints::player::issue_verification_code,
+        endpoints::player::verify_claim,
+        endpoints::player::merge_preview,
+        endpoints::player::merges,
+        endpoints::player::undo_merge,
+        endpoints::player::get_my_profile,
+        endpoints::player::patch_my_profile,
+        endpoints::player::pending_profiles,
+        endpoints::player::approve_profile,
+        endpoints::player::reject_profile,
+        endpoints::player::delete_profile,
     ];
 
     #[cfg(feature = "geolocation")]
@@ -41,13 +109,17 @@ pub fn setup(rocket: Rocket<Build>) -> Rocket<Build> {
     rocket
         .manage(ratelimits)
         .manage(dash_rs)
+        .manage(HeatmapCache::default())
         .mount("/api/v1/list_information/", rocket::routes![misc::list_information])
         .mount(
             "/api/v1/submitters/",
             rocket::routes![
                 endpoints::submitter::paginate,
                 endpoints::submitter::get,
-                endpoints::submitter::patch
+                endpoints::submitter::patch,
+                endpoints::submitter::bans,
+                endpoints::submitter::ban,
+                endpoints::submitter::lift_ban
             ],
         )
         .mount(
@@ -55,15 +127,24 @@ pub fn setup(rocket: Rocket<Build>) -> Rocket<Build> {
             rocket::routes![
                 endpoints::record::get_notes,
                 endpoints::record::add_note,
+                endpoints::record::add_evidence,
                 endpoints::record::audit,
                 endpoints::record::delete,
                 endpoints::record::delete_note,
+                endpoints::record::delete_evidence,
                 endpoints::record::get,
+                endpoints::record::lock,
+                endpoints::record::unlock,
                 endpoints::record::paginate,
                 endpoints::record::unauthed_pagination,
                 endpoints::record::patch,
                 endpoints::record::patch_note,
-                endpoints::record::submit
+                endpoints::record::patch_evidence,
+                endpoints::record::stats,
+                endpoints::record::submit,
+                endpoints::record::pending,
+                endpoints::record::accept_pending,
+                endpoints::record::decline_pending
             ],
         )
         .mount("/api/v1/players/", player_routes)
@@ -71,8 +152,19 @@ pub fn setup(rocket: Rocket<Build>) -> Rocket<Build> {
             "/api/v1/nationalities/",
             rocket::routes![
                 endpoints::nationality::subdivisions,
+                endpoints::nationality::subdivision_ranking,
+                endpoints::nationality::subdivision,
+                endpoints::nationality::continent_ranking,
+                endpoints::nationality::heatmap,
                 endpoints::nationality::ranking,
-                endpoints::nationality::nation
+                endpoints::nationality::nation,
+                endpoints::nationality::history,
+                endpoints::nationality::post,
+                endpoints::nationality::patch,
+                endpoints::nationality::post_subdivision,
+                endpoints::nationality::patch_subdivision,
+                endpoints::nationality::delete_subdivision,
+                endpoints::nationality::audit
             ],
         )
         .mount(
@@ -83,10 +175,46 @@ pub fn setup(rocket: Rocket<Build>) -> Rocket<Build> {
                 endpoints::demon::paginate_listed,
                 endpoints::demon::audit,
                 endpoints::demon::movement_log,
+                endpoints::demon::changelog,
                 endpoints::demon::patch,
                 endpoints::demon::post,
                 endpoints::demon::post_creator,
-                endpoints::demon::delete_creator
+                endpoints::demon::delete_creator,
+                endpoints::demon::post_tag,
+                endpoints::demon::delete_tag
+            ],
+        )
+        .mount(
+            "/api/v2/tags/",
+            rocket::routes![
+                endpoints::tag::all,
+                endpoints::tag::post,
+                endpoints::tag::get,
+                endpoints::tag::patch,
+                endpoints::tag::delete,
+                endpoints::tag::audit
+            ],
+        )
+        .mount(
+            "/api/v2/lists/",
+            rocket::routes![
+                endpoints::list::all,
+                endpoints::list::post,
+                endpoints::list::get,
+                endpoints::list::patch,
+                endpoints::list::tiers,
+                endpoints::list::put_tiers,
+                endpoints::list::paginate_demons,
+                endpoints::list::paginate_listed_demons,
+                endpoints::list::post_demon,
+                endpoints::list::changelog,
+                endpoints::list::player_ranking,
+                endpoints::list::player_history,
+                endpoints::list::nation_ranking,
+                endpoints::list::nation_history,
+                endpoints::list::subdivision_ranking,
+                endpoints::list::continent_ranking,
+                endpoints::list::heatmap
             ],
         )
         .mount(
@@ -97,7 +225,12 @@ pub fn setup(rocket: Rocket<Build>) -> Rocket<Build> {
                 pages::nation_stats_viewer,
                 pages::demon_page,
                 pages::demon_permalink,
-                pages::heatmap_css
+                pages::heatmap_css,
+                pages::heatmap_svg,
+                pages::heatmap_png,
+                pages::movements_feed,
+                pages::demon_records_atom_feed,
+                pages::player_records_atom_feed
             ],
         )
 }
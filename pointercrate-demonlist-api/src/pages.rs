@@ -1,35 +1,48 @@
-use std::collections::HashMap;
-
+use maud::Render;
 use pointercrate_core_macros::localized;
-use rocket::{response::Redirect, State};
+use rocket::{
+    http::Status,
+    response::{Redirect, Responder},
+    Request, Response, State,
+};
 
-use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, Utc};
-use pointercrate_core::{audit::AuditLogEntryType, pool::PointercratePool};
+use crate::{heatmap::HeatmapCache, ratelimits::DemonlistRatelimits};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+use pointercrate_core::{audit::AuditLogEntryType, error::CoreError, pool::PointercratePool};
 use pointercrate_core_api::{
     error::Result,
+    query::Query,
     response::{Page, Response2},
 };
 use pointercrate_demonlist::player::claim::PlayerClaim;
 use pointercrate_demonlist::player::{FullPlayer, Player};
 use pointercrate_demonlist::{
-    demon::{audit::audit_log_for_demon, current_list, list_at, FullDemon, MinimalDemon},
+    demon::{
+        audit::{audit_log_for_demon, recent_list_movements},
+        current_list, list_at, FullDemon, MinimalDemon,
+    },
     error::DemonlistError,
-    nationality::Nationality,
+    list::{List, DEFAULT_LIST},
+    nationality::{Heatmap, HeatmapMetric, HeatmapQuery, Nationality},
+    player::DatabasePlayer,
+    record::recently_approved_records,
     LIST_ADMINISTRATOR, LIST_HELPER, LIST_MODERATOR,
 };
 use pointercrate_demonlist_pages::{
     components::{team::Team, time_machine::Tardis},
     demon_page::{DemonMovement, DemonPage},
+    feeds::{demon_records_feed, list_movements_feed, player_records_feed, AtomFeed},
     overview::OverviewPage,
-    statsviewer::individual::IndividualStatsViewer,
+    statsviewer::{heatmap, individual::IndividualStatsViewer},
 };
 use pointercrate_integrate::gd::GeometryDashConnector;
 use pointercrate_user::auth::NonMutating;
 use pointercrate_user::User;
 use pointercrate_user_api::auth::Auth;
 use rand::Rng;
-use rocket::{futures::StreamExt, http::CookieJar};
+use rocket::http::CookieJar;
 use sqlx::PgConnection;
+use std::net::IpAddr;
 
 #[localized]
 #[rocket::get("/?<timemachine>&<submitter>")]
@@ -42,7 +55,8 @@ pub async fn overview(
 
     let mut connection = pool.connection().await?;
 
-    let demonlist = current_list(&mut connection).await?;
+    let list = List::default_list(&mut connection).await?;
+    let demonlist = current_list(&list.id, &mut connection).await?;
 
     let mut specified_when = cookies
         .get("when")
@@ -72,7 +86,7 @@ pub async fn overview(
     let mut tardis = Tardis::new(timemachine.unwrap_or(false));
 
     if let Some(destination) = specified_when {
-        let demons_then = list_at(&mut connection, destination.naive_utc()).await?;
+        let demons_then = list_at(&list.id, &mut connection, destination.naive_utc()).await?;
         tardis.activate(destination, demons_then, !is_april_1st)
     }
 
@@ -82,6 +96,8 @@ pub async fn overview(
             moderators: User::by_permission(LIST_MODERATOR, &mut connection).await?,
             helpers: User::by_permission(LIST_HELPER, &mut connection).await?,
         },
+        tiers: list.tiers(&mut connection).await?,
+        list,
         demonlist,
         time_machine: tardis,
         submitter_initially_visible: submitter.unwrap_or(false),
@@ -103,7 +119,14 @@ async fn claimed_full_player(user: &User, connection: &mut PgConnection) -> Opti
 pub async fn demon_permalink(demon_id: i32, pool: &State<PointercratePool>) -> Result<Redirect> {
     let mut connection = pool.connection().await?;
 
-    let position = MinimalDemon::by_id(demon_id, &mut connection).await?.position;
+    let demon = MinimalDemon::by_id(demon_id, &mut connection).await?;
+
+    // The demonlist pages only display the default list
+    if demon.list != DEFAULT_LIST {
+        return Err(DemonlistError::DemonNotFound { demon_id }.into());
+    }
+
+    let position = demon.position;
 
     Ok(Redirect::to(rocket::uri!("/demonlist", demon_page(position))))
 }
@@ -113,7 +136,8 @@ pub async fn demon_permalink(demon_id: i32, pool: &State<PointercratePool>) -> R
 pub async fn demon_page(position: i16, pool: &State<PointercratePool>, gd: &State<GeometryDashConnector>) -> Result<Page> {
     let mut connection = pool.connection().await?;
 
-    let full_demon = FullDemon::by_position(position, &mut connection).await?;
+    let list = List::default_list(&mut connection).await?;
+    let full_demon = FullDemon::by_position(&list.id, position, &mut connection).await?;
 
     let audit_log = audit_log_for_demon(full_demon.demon.base.id, &mut connection).await?;
 
@@ -157,7 +181,9 @@ pub async fn demon_page(position: i16, pool: &State<PointercratePool>, gd: &Stat
             moderators: User::by_permission(LIST_MODERATOR, &mut connection).await?,
             helpers: User::by_permission(LIST_HELPER, &mut connection).await?,
         },
-        demonlist: current_list(&mut connection).await?,
+        demonlist: current_list(&list.id, &mut connection).await?,
+        tiers: list.tiers(&mut connection).await?,
+        list,
         movements: modifications,
         integration: gd.load_level_for_demon(&full_demon.demon).await,
         data: full_demon,
@@ -183,56 +209,145 @@ pub async fn nation_stats_viewer() -> Page {
 #[localized]
 #[rocket::get("/statsviewer/heatmap.css")]
 pub async fn heatmap_css(pool: &State<PointercratePool>) -> Result<Response2<String>> {
-    let mut connection = pool.connection().await?;
-    let mut css = String::new();
+    let heatmap = Heatmap::load(DEFAULT_LIST, HeatmapMetric::Score, &mut *pool.connection().await?).await?;
 
-    let mut nation_scores = HashMap::new();
-    let mut nations_stream = sqlx::query!("SELECT iso_country_code, score FROM nationalities WHERE score > 0.0").fetch(&mut *connection);
+    Ok(Response2::new(heatmap::heatmap_css(&heatmap)).with_header("Content-Type", "text/css"))
+}
 
-    while let Some(row) = nations_stream.next().await {
-        let row = row.map_err(DemonlistError::from)?;
+#[localized]
+#[rocket::get("/statsviewer/heatmap.svg")]
+pub async fn heatmap_svg(pool: &State<PointercratePool>, query: Query<HeatmapQuery>) -> Result<Response2<String>> {
+    let heatmap = Heatmap::load(DEFAULT_LIST, query.0.metric, &mut *pool.connection().await?).await?;
 
-        nation_scores.insert(row.iso_country_code, row.score);
-    }
+    Ok(Response2::new(heatmap::world_map_svg(&heatmap))
+        .with_header("Content-Type", "image/svg+xml")
+        .with_header("Cache-Control", "public, max-age=3600"))
+}
 
-    let Some(&max_nation_score) = nation_scores.values().max_by(|a, b| a.total_cmp(b)) else {
-        // Not a single nation has a score > 0. This means there are no approved records. So return no CSS
-        return Ok(Response2::new(css).with_header("Content-Type", "text/css"));
+/// The widths heatmap images are rendered at, the largest being the native width of the world map.
+/// Requested widths are rounded up to one of these, so that only few distinct renderings exist.
+const HEATMAP_PNG_WIDTHS: [u32; 3] = [640, 1280, 2560];
+const HEATMAP_PNG_DEFAULT_WIDTH: u32 = 1280;
+
+#[localized]
+#[rocket::get("/statsviewer/heatmap.png?<width>&<list>")]
+pub async fn heatmap_png(
+    ip: IpAddr, pool: &State<PointercratePool>, cache: &State<HeatmapCache>, ratelimits: &State<DemonlistRatelimits>,
+    query: Query<HeatmapQuery>, width: Option<u32>, list: Option<&str>,
+) -> Result<Response2<Vec<u8>>> {
+    ratelimits.heatmap_png(ip)?;
+
+    let mut connection = pool.connection().await?;
+
+    let list = List::by_id(list.unwrap_or(DEFAULT_LIST), &mut connection).await?;
+    let heatmap = Heatmap::load(&list.id, query.0.metric, &mut connection).await?;
+
+    let width = width.unwrap_or(HEATMAP_PNG_DEFAULT_WIDTH);
+    let width = HEATMAP_PNG_WIDTHS
+        .into_iter()
+        .find(|&snapped| snapped >= width)
+        .unwrap_or(HEATMAP_PNG_WIDTHS[HEATMAP_PNG_WIDTHS.len() - 1]);
+
+    let png = match cache.get(&list.id, &heatmap, width) {
+        Some(png) => png,
+        None => {
+            // Rasterizing the world map takes a while, so do not block the async runtime with it
+            let (heatmap, png) =
+                rocket::tokio::task::spawn_blocking(move || heatmap::world_map_png(&heatmap, width).map(|png| (heatmap, png)))
+                    .await
+                    .ok()
+                    .flatten()
+                    .ok_or_else(|| CoreError::internal_server_error("Failed to render heatmap"))?;
+
+            cache.insert(&list.id, heatmap, width, png.clone());
+
+            png
+        },
     };
 
-    for (nation, &score) in &nation_scores {
-        css.push_str(&make_css_rule(&nation.to_lowercase(), score, max_nation_score));
-    }
+    Ok(Response2::new(png)
+        .with_header("Content-Type", "image/png")
+        .with_header("Cache-Control", "public, max-age=3600"))
+}
 
-    // un-borrow `connection`
-    drop(nations_stream);
+/// The number of entries included in each Atom feed
+const FEED_SIZE: i64 = 50;
 
-    let mut subdivisions_stream =
-        sqlx::query!("SELECT nation, iso_code, score FROM subdivisions WHERE score > 0.0").fetch(&mut *connection);
+/// An Atom feed response supporting conditional requests via `If-Modified-Since`, so that feed
+/// readers polling for updates do not need to download the entire feed each time
+pub struct AtomResponse {
+    content: String,
+    updated: NaiveDateTime,
+}
 
-    while let Some(row) = subdivisions_stream.next().await {
-        let row = row.map_err(DemonlistError::from)?;
+impl From<AtomFeed> for AtomResponse {
+    fn from(feed: AtomFeed) -> Self {
+        // Feeds need to be rendered inside the request handler, as outside of it no language is set
+        AtomResponse {
+            content: feed.render().into_string(),
+            updated: feed.updated(),
+        }
+    }
+}
 
-        css.push_str(&make_css_rule(
-            &format!("{}-{}", row.nation, row.iso_code),
-            row.score,
-            *nation_scores.get(&row.nation).unwrap_or(&f64::INFINITY),
-        ))
+impl<'r> Responder<'r, 'static> for AtomResponse {
+    fn respond_to(self, request: &'r Request<'_>) -> rocket::response::Result<'static> {
+        // HTTP dates only have second precision
+        let updated = self.updated.and_utc().timestamp();
+        let last_modified = self.updated.and_utc().format("%a, %d %b %Y %H:%M:%S GMT").to_string();
+
+        let not_modified = request
+            .headers()
+            .get_one("if-modified-since")
+            .and_then(|since| DateTime::parse_from_rfc2822(since).ok())
+            .is_some_and(|since| updated <= since.timestamp());
+
+        let response = if not_modified {
+            Response2::new(()).status(Status::NotModified).respond_to(request)?
+        } else {
+            Response2::new(self.content)
+                .with_header("Content-Type", "application/atom+xml; charset=utf-8")
+                .respond_to(request)?
+        };
+
+        Response::build_from(response)
+            .raw_header("Cache-Control", "public, max-age=900")
+            .raw_header("Last-Modified", last_modified)
+            // Feed titles are localized based on the language cookie
+            .raw_header("Vary", "Cookie")
+            .ok()
     }
+}
+
+#[localized]
+#[rocket::get("/feeds/movements/")]
+pub async fn movements_feed(pool: &State<PointercratePool>) -> Result<AtomResponse> {
+    let mut connection = pool.connection().await?;
+
+    let movements = recent_list_movements(DEFAULT_LIST, FEED_SIZE, &mut connection).await?;
 
-    Ok(Response2::new(css).with_header("Content-Type", "text/css"))
+    Ok(list_movements_feed(movements).into())
 }
 
-fn make_css_rule(code: &str, score: f64, highest_score: f64) -> String {
-    // Artificially adjust the highest score so that score/high_score is never 1. If it were 1, the resulting
-    // color will be equal to the "hover"/"selected" color, which looks bad.
-    let highest_score = highest_score * 1.5;
-
-    format!(
-        ".heatmapped #{0}, .heatmapped #{0} > path {{ fill: rgb({1}, {2}, {3}); }}",
-        code,
-        0xda as f64 + (0x08 - 0xda) as f64 * (score / highest_score),
-        0xdc as f64 + (0x81 - 0xdc) as f64 * (score / highest_score),
-        0xe0 as f64 + (0xc6 - 0xe0) as f64 * (score / highest_score),
-    )
+#[localized]
+#[rocket::get("/feeds/demons/<demon_id>/records/")]
+pub async fn demon_records_atom_feed(demon_id: i32, pool: &State<PointercratePool>) -> Result<AtomResponse> {
+    let mut connection = pool.connection().await?;
+
+    let demon = MinimalDemon::by_id(demon_id, &mut connection).await?;
+    let records = recently_approved_records(Some(demon_id), None, FEED_SIZE, &mut connection).await?;
+
+    Ok(demon_records_feed(&demon, records).into())
+}
+
+#[localized]
+#[rocket::get("/feeds/players/<player_id>/records/")]
+pub async fn player_records_atom_feed(player_id: i32, pool: &State<PointercratePool>) -> Result<AtomResponse> {
+    let mut connection = pool.connection().await?;
+
+    let player = DatabasePlayer::by_id(player_id, &mut connection).await?;
+
+    let records = recently_approved_records(None, Some(player_id), FEED_SIZE, &mut connection).await?;
+
+    Ok(player_records_feed(&player, records).into())
 }
@@ -0,0 +1,70 @@
+use ipnet::IpNet;
+use rocket::async_trait;
+use std::{io, net::IpAddr, path::Path};
+
+#[async_trait]
+pub trait ProxyDetector: Sync + Send {
+    /// Checks whether the given IP address belongs to a VPN, a proxy or some datacenter, meaning
+    /// that it is not the actual address of whoever is making the request
+    async fn is_proxy(&self, ip: IpAddr) -> bool;
+}
+
+/// A [`ProxyDetector`] that flags all addresses in a fixed list of IP ranges
+///
+/// The list is read from a file containing one range in CIDR notation (e.g. `192.0.2.0/24`) per
+/// line. Empty lines, and everything following a `#`, are ignored.
+#[derive(Debug, Default)]
+pub struct CidrProxyDetector {
+    ranges: Vec<IpNet>,
+}
+
+impl CidrProxyDetector {
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn parse(ranges: &str) -> io::Result<Self> {
+        let ranges = ranges
+            .lines()
+            .map(|line| line.split('#').next().unwrap_or_default().trim())
+            .filter(|line| !line.is_empty())
+            .map(|range| {
+                range
+                    .parse()
+                    .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, format!("invalid IP range '{}': {}", range, err)))
+            })
+            .collect::<io::Result<_>>()?;
+
+        Ok(CidrProxyDetector { ranges })
+    }
+}
+
+#[async_trait]
+impl ProxyDetector for CidrProxyDetector {
+    async fn is_proxy(&self, ip: IpAddr) -> bool {
+        // IPv4 clients connecting via IPv6 show up with an IPv4-mapped address
+        let ip = ip.to_canonical();
+
+        self.ranges.iter().any(|range| range.contains(&ip))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CidrProxyDetector, ProxyDetector};
+
+    #[rocket::async_test]
+    async fn test_cidr_proxy_detector() {
+        let detector = CidrProxyDetector::parse("# some datacenter\n192.0.2.0/24\n\n2001:db8::/32 # some VPN\n").unwrap();
+
+        assert!(detector.is_proxy("192.0.2.17".parse().unwrap()).await);
+        assert!(detector.is_proxy("::ffff:192.0.2.17".parse().unwrap()).await);
+        assert!(detector.is_proxy("2001:db8::1".parse().unwrap()).await);
+        assert!(!detector.is_proxy("198.51.100.1".parse().unwrap()).await);
+    }
+
+    #[test]
+    fn test_malformed_range() {
+        assert!(CidrProxyDetector::parse("192.0.2.0/33").is_err());
+    }
+}
@@ -11,6 +11,10 @@ ratelimits! {
         new_submitters[7u32 per 3600] => tr("error-demonlist-ratelimit-new-submitters"),
 
         add_demon[1u32 per 60] => tr("error-demonlist-ratelimit-add-demon"),
+
+        claim_verification[3u32 per 600 per i32] => tr("error-demonlist-ratelimit-claim-verification"),
+
+        heatmap_png[30u32 per 60 per IpAddr] => tr("error-demonlist-ratelimit-heatmap-png"),
     }
 }
 
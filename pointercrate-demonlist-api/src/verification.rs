@@ -0,0 +1,406 @@
+use chrono::{DateTime, NaiveDateTime};
+use log::{debug, warn};
+use pointercrate_demonlist::{
+    record::{VideoCheckResult, VideoMetadata},
+    video::{Video, VideoProvider, YouTube},
+};
+use reqwest::{Client, StatusCode, Url};
+use rocket::async_trait;
+use serde::Deserialize;
+use std::{error::Error, time::Duration};
+
+/// Checks whether the videos of newly submitted records actually exist
+#[async_trait]
+pub trait VideoVerifier: Sync + Send {
+    /// Checks whether the given (already validated) video exists, and retrieves whatever metadata
+    /// about it the host provides
+    ///
+    /// Implementations should only return [`VideoCheckResult::Missing`] if they are certain that
+    /// the video does not exist, as this causes the submission to be deleted.
+    async fn verify(&self, video: &str) -> (VideoCheckResult, VideoMetadata);
+}
+
+/// The parts of an oEmbed response we care about
+///
+/// `upload_date` and `duration` are not part of the oEmbed specification, but some hosts (e.g.
+/// Vimeo) include them anyway.
+#[derive(Deserialize)]
+struct OEmbedResponse {
+    title: Option<String>,
+    author_url: Option<String>,
+    upload_date: Option<String>,
+    duration: Option<i32>,
+}
+
+impl From<OEmbedResponse> for VideoMetadata {
+    fn from(response: OEmbedResponse) -> Self {
+        VideoMetadata {
+            title: response.title,
+            channel: response.author_url,
+            uploaded_at: response
+                .upload_date
+                .and_then(|date| NaiveDateTime::parse_from_str(&date, "%Y-%m-%d %H:%M:%S").ok()),
+            duration: response.duration,
+        }
+    }
+}
+
+/// The parts of a [YouTube Data API](https://developers.google.com/youtube/v3/docs/videos/list)
+/// response we care about
+#[derive(Deserialize)]
+struct YouTubeVideoList {
+    items: Vec<YouTubeVideo>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct YouTubeVideo {
+    snippet: YouTubeSnippet,
+    content_details: YouTubeContentDetails,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct YouTubeSnippet {
+    title: Option<String>,
+    channel_id: Option<String>,
+    published_at: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct YouTubeContentDetails {
+    /// The length of the video as an ISO 8601 duration, e.g. `PT4M13S`
+    duration: Option<String>,
+}
+
+impl From<YouTubeVideo> for VideoMetadata {
+    fn from(video: YouTubeVideo) -> Self {
+        VideoMetadata {
+            title: video.snippet.title,
+            channel: video
+                .snippet
+                .channel_id
+                .map(|channel_id| format!("https://www.youtube.com/channel/{}", channel_id)),
+            uploaded_at: video
+                .snippet
+                .published_at
+                .and_then(|published_at| DateTime::parse_from_rfc3339(&published_at).ok())
+                .map(|published_at| published_at.naive_utc()),
+            duration: video.content_details.duration.as_deref().and_then(parse_duration),
+        }
+    }
+}
+
+/// Parses an ISO 8601 duration of the form `P[n]DT[n]H[n]M[n]S` (as used by YouTube) into seconds
+fn parse_duration(duration: &str) -> Option<i32> {
+    let mut seconds = 0;
+    let mut number = String::new();
+    let mut in_time = false;
+
+    for c in duration.strip_prefix('P')?.chars() {
+        let unit = match c {
+            '0'..='9' => {
+                number.push(c);
+                continue;
+            },
+            'T' if !in_time && number.is_empty() => {
+                in_time = true;
+                continue;
+            },
+            'D' if !in_time => 86400,
+            'H' if in_time => 3600,
+            'M' if in_time => 60,
+            'S' if in_time => 1,
+            _ => return None,
+        };
+
+        seconds += number.parse::<i32>().ok()?.checked_mul(unit)?;
+        number.clear();
+    }
+
+    number.is_empty().then_some(seconds)
+}
+
+/// The HTTP client used by [`OEmbedVerifier`], abstracted so that tests do not need network access
+#[async_trait]
+trait HttpClient: Sync + Send {
+    /// Performs a `GET` request, returning the response's status and body
+    async fn get(&self, url: &str) -> Result<(StatusCode, String), Box<dyn Error + Sync + Send>>;
+}
+
+#[async_trait]
+impl HttpClient for Client {
+    async fn get(&self, url: &str) -> Result<(StatusCode, String), Box<dyn Error + Sync + Send>> {
+        let response = Client::get(self, url).send().await?;
+        let status = response.status();
+
+        Ok((status, response.text().await?))
+    }
+}
+
+/// A [`VideoVerifier`] that asks each host's oEmbed endpoint about videos
+///
+/// For hosts without an oEmbed endpoint, the video's page itself is requested instead, in which case
+/// no metadata is retrieved. Since YouTube's oEmbed endpoint only provides titles and channel
+/// names, metadata of available YouTube videos is retrieved from the YouTube Data API instead, if
+/// an API key is configured.
+pub struct OEmbedVerifier {
+    client: Box<dyn HttpClient>,
+    youtube_api_key: Option<String>,
+}
+
+impl Default for OEmbedVerifier {
+    fn default() -> Self {
+        OEmbedVerifier {
+            client: Box::new(
+                Client::builder()
+                    .timeout(Duration::from_secs(10))
+                    .build()
+                    .expect("failed to construct HTTP client"),
+            ),
+            youtube_api_key: crate::config::youtube_api_key(),
+        }
+    }
+}
+
+impl OEmbedVerifier {
+    /// Retrieves the metadata of the given video from the YouTube Data API
+    ///
+    /// Returns `None` if the video is not a YouTube video, no API key is configured or the API
+    /// did not return any metadata.
+    async fn youtube_metadata(&self, video: &Video) -> Option<VideoMetadata> {
+        let api_key = self.youtube_api_key.as_ref()?;
+
+        if video.host() != YouTube.name() {
+            return None;
+        }
+
+        let endpoint = Url::parse_with_params(
+            "https://www.googleapis.com/youtube/v3/videos",
+            &[("part", "snippet,contentDetails"), ("id", video.id()), ("key", api_key)],
+        )
+        .ok()?;
+
+        // Do not log the endpoint, as it contains the API key
+        match self.client.get(endpoint.as_str()).await {
+            Ok((status, body)) if status.is_success() => match serde_json::from_str::<YouTubeVideoList>(&body) {
+                Ok(list) => list.items.into_iter().next().map(Into::into),
+                Err(err) => {
+                    warn!("YouTube Data API returned malformed response for video {}: {:?}", video.id(), err);
+
+                    None
+                },
+            },
+            Ok((status, _)) => {
+                warn!("YouTube Data API responded with {} for video {}", status, video.id());
+
+                None
+            },
+            Err(err) => {
+                warn!("Request to YouTube Data API for video {} failed: {:?}", video.id(), err);
+
+                None
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl VideoVerifier for OEmbedVerifier {
+    async fn verify(&self, video: &str) -> (VideoCheckResult, VideoMetadata) {
+        let video = match Video::parse(video) {
+            Ok(video) => video,
+            Err(err) => {
+                warn!("Cannot verify video {}, as it is not supported anymore: {:?}", video, err);
+
+                return (VideoCheckResult::Inconclusive, VideoMetadata::default());
+            },
+        };
+
+        let (endpoint, is_oembed) = match video.oembed_url() {
+            Some(oembed) => (oembed, true),
+            None => (video.url(), false),
+        };
+
+        match self.client.get(&endpoint).await {
+            Ok((status, body)) => match status {
+                status if status.is_success() => {
+                    let metadata = match is_oembed {
+                        true => serde_json::from_str::<OEmbedResponse>(&body).map(Into::into).unwrap_or_else(|err| {
+                            warn!("'GET {}' returned malformed oEmbed response: {:?}", endpoint, err);
+
+                            VideoMetadata::default()
+                        }),
+                        false => VideoMetadata::default(),
+                    };
+
+                    (VideoCheckResult::Available, self.youtube_metadata(&video).await.unwrap_or(metadata))
+                },
+                // oEmbed endpoints respond with 400 BAD REQUEST if the video id is invalid
+                StatusCode::NOT_FOUND | StatusCode::GONE => (VideoCheckResult::Missing, VideoMetadata::default()),
+                StatusCode::BAD_REQUEST if is_oembed => (VideoCheckResult::Missing, VideoMetadata::default()),
+                status => {
+                    // This includes 401 UNAUTHORIZED and 403 FORBIDDEN for private videos
+                    debug!("'GET {}' responded with {}, video needs to be checked manually", endpoint, status);
+
+                    (VideoCheckResult::Inconclusive, VideoMetadata::default())
+                },
+            },
+            Err(err) => {
+                warn!("'GET {}' failed: {:?}, video needs to be checked manually", endpoint, err);
+
+                (VideoCheckResult::Inconclusive, VideoMetadata::default())
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_duration, HttpClient, OEmbedVerifier, VideoVerifier};
+    use pointercrate_demonlist::record::VideoCheckResult;
+    use reqwest::StatusCode;
+    use rocket::async_trait;
+    use std::error::Error;
+
+    /// Responds to every request with the given status and body, or fails if no status is given
+    struct StubClient(Option<StatusCode>, &'static str);
+
+    #[async_trait]
+    impl HttpClient for StubClient {
+        async fn get(&self, _url: &str) -> Result<(StatusCode, String), Box<dyn Error + Sync + Send>> {
+            match self.0 {
+                Some(status) => Ok((status, self.1.to_string())),
+                None => Err("connection refused".into()),
+            }
+        }
+    }
+
+    async fn verify(status: Option<StatusCode>, body: &'static str, video: &str) -> VideoCheckResult {
+        let verifier = OEmbedVerifier {
+            client: Box::new(StubClient(status, body)),
+            youtube_api_key: None,
+        };
+
+        verifier.verify(video).await.0
+    }
+
+    const YOUTUBE: &str = "https://www.youtube.com/watch?v=dQw4w9WgXcQ";
+    const TWITCH: &str = "https://www.twitch.tv/videos/123456789";
+
+    #[rocket::async_test]
+    async fn test_available() {
+        let verifier = OEmbedVerifier {
+            client: Box::new(StubClient(Some(StatusCode::OK), r#"{"title": "Bloodbath 100%"}"#)),
+            youtube_api_key: None,
+        };
+        let (result, metadata) = verifier.verify(YOUTUBE).await;
+
+        assert_eq!(result, VideoCheckResult::Available);
+        assert_eq!(metadata.title.as_deref(), Some("Bloodbath 100%"));
+
+        // Malformed metadata does not make the video any less available
+        assert_eq!(verify(Some(StatusCode::OK), "not json", YOUTUBE).await, VideoCheckResult::Available);
+        assert_eq!(verify(Some(StatusCode::OK), "", TWITCH).await, VideoCheckResult::Available);
+    }
+
+    #[rocket::async_test]
+    async fn test_missing() {
+        assert_eq!(verify(Some(StatusCode::NOT_FOUND), "", YOUTUBE).await, VideoCheckResult::Missing);
+        assert_eq!(verify(Some(StatusCode::GONE), "", TWITCH).await, VideoCheckResult::Missing);
+
+        // Only oEmbed endpoints use 400 BAD REQUEST to signal unknown videos
+        assert_eq!(verify(Some(StatusCode::BAD_REQUEST), "", YOUTUBE).await, VideoCheckResult::Missing);
+        assert_eq!(
+            verify(Some(StatusCode::BAD_REQUEST), "", TWITCH).await,
+            VideoCheckResult::Inconclusive
+        );
+    }
+
+    #[rocket::async_test]
+    async fn test_inconclusive() {
+        // Private videos
+        assert_eq!(
+            verify(Some(StatusCode::UNAUTHORIZED), "", YOUTUBE).await,
+            VideoCheckResult::Inconclusive
+        );
+        assert_eq!(
+            verify(Some(StatusCode::FORBIDDEN), "", YOUTUBE).await,
+            VideoCheckResult::Inconclusive
+        );
+
+        assert_eq!(
+            verify(Some(StatusCode::TOO_MANY_REQUESTS), "", YOUTUBE).await,
+            VideoCheckResult::Inconclusive
+        );
+        assert_eq!(
+            verify(Some(StatusCode::INTERNAL_SERVER_ERROR), "", YOUTUBE).await,
+            VideoCheckResult::Inconclusive
+        );
+        assert_eq!(verify(None, "", YOUTUBE).await, VideoCheckResult::Inconclusive);
+
+        // Videos that are not supported anymore are never requested
+        assert_eq!(
+            verify(Some(StatusCode::NOT_FOUND), "", "https://pointercrate.com/video.mp4").await,
+            VideoCheckResult::Inconclusive
+        );
+    }
+
+    /// Answers oEmbed requests with an empty object, and YouTube Data API requests with the given body
+    struct YouTubeStubClient(&'static str);
+
+    #[async_trait]
+    impl HttpClient for YouTubeStubClient {
+        async fn get(&self, url: &str) -> Result<(StatusCode, String), Box<dyn Error + Sync + Send>> {
+            match url.starts_with("https://www.googleapis.com/youtube/v3/videos?") {
+                true => Ok((StatusCode::OK, self.0.to_string())),
+                false => Ok((StatusCode::OK, r#"{"title": "Bloodbath 100%"}"#.to_string())),
+            }
+        }
+    }
+
+    #[rocket::async_test]
+    async fn test_youtube_metadata() {
+        let verifier = OEmbedVerifier {
+            client: Box::new(YouTubeStubClient(
+                r#"{"items": [{"snippet": {"title": "Bloodbath 100% (Extreme Demon)", "channelId": "UCkmH7B9c6lXlRu1KyJgHWzw", "publishedAt": "2015-08-05T12:34:56Z"}, "contentDetails": {"duration": "PT1M42S"}}]}"#,
+            )),
+            youtube_api_key: Some("key".to_string()),
+        };
+        let (result, metadata) = verifier.verify(YOUTUBE).await;
+
+        assert_eq!(result, VideoCheckResult::Available);
+        assert_eq!(metadata.title.as_deref(), Some("Bloodbath 100% (Extreme Demon)"));
+        assert_eq!(
+            metadata.channel.as_deref(),
+            Some("https://www.youtube.com/channel/UCkmH7B9c6lXlRu1KyJgHWzw")
+        );
+        assert_eq!(metadata.uploaded_at, Some("2015-08-05T12:34:56".parse().unwrap()));
+        assert_eq!(metadata.duration, Some(102));
+
+        // If the API does not know the video, we fall back to what the oEmbed endpoint told us
+        let verifier = OEmbedVerifier {
+            client: Box::new(YouTubeStubClient(r#"{"items": []}"#)),
+            youtube_api_key: Some("key".to_string()),
+        };
+        let (result, metadata) = verifier.verify(YOUTUBE).await;
+
+        assert_eq!(result, VideoCheckResult::Available);
+        assert_eq!(metadata.title.as_deref(), Some("Bloodbath 100%"));
+        assert_eq!(metadata.uploaded_at, None);
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("PT42S"), Some(42));
+        assert_eq!(parse_duration("PT4M13S"), Some(253));
+        assert_eq!(parse_duration("PT1H"), Some(3600));
+        assert_eq!(parse_duration("P1DT2H3M4S"), Some(93784));
+        assert_eq!(parse_duration("P0D"), Some(0));
+
+        assert_eq!(parse_duration("4M13S"), None);
+        assert_eq!(parse_duration("PT4M13"), None);
+        assert_eq!(parse_duration("P4M"), None);
+        assert_eq!(parse_duration("PT1.5S"), None);
+    }
+}
@@ -93,6 +93,28 @@ impl AccountPageTab for ListIntegrationTab {
                         }
                     }
                     @if let Some(ref claim) = player_claim {
+                        @if !claim.verified {
+                            div.overlined.pad #claims-gd-verification {
+                                p.info-red.output style = "margin: 10px 0" {}
+                                p.info-green.output style = "margin: 10px 0" {}
+                                div.flex.no-stretch style="justify-content: space-between; align-items: center" {
+                                    b {
+                                        (tr("claim-gd-verification"))
+                                    }
+                                    span {
+                                        a.button.blue.hover #claims-gd-request-code style = "margin-right: 5px" {
+                                            (tr("claim-gd-verification.request-code"))
+                                        }
+                                        a.button.blue.hover #claims-gd-verify {
+                                            (tr("claim-gd-verification.submit"))
+                                        }
+                                    }
+                                }
+                                p {
+                                    (tr("claim-gd-verification.info"))
+                                }
+                            }
+                        }
                         @if claim.verified {
                             div.overlined.pad.js-collapse-content #claims-claim-panel style="display:none" {
                                 // It'd be neat to eliminate this feature and instead have this tied to the presence of a Box<dyn GeolocationProvider> state, but
@@ -127,7 +149,37 @@ impl AccountPageTab for ListIntegrationTab {
                                 p {
                                     (tr("claim-lock-submissions.info"))
                                 }
+                                div.cb-container.flex.no-stretch style="justify-content: space-between; align-items: center" {
+                                    b {
+                                        (tr("claim-third-party-approval"))
+                                    }
+                                    @if claim.third_party_approval {
+                                        input #third-party-approval-checkbox type = "checkbox" name = "third_party_approval" checked = "";
+                                    }
+                                    @else {
+                                        input #third-party-approval-checkbox type = "checkbox" name = "third_party_approval";
+                                    }
+                                    span.checkmark {}
+                                }
+                                p {
+                                    (tr("claim-third-party-approval.info"))
+                                }
+                            }
+                        }
+                    }
+                }
+                @if let Some(ref claim) = player_claim {
+                    @if claim.verified && claim.third_party_approval {
+                        div.panel.fade #claims-pending-panel {
+                            h2.pad.underlined {
+                                (tr("claim-pending"))
+                            }
+                            p {
+                                (tr("claim-pending.info"))
                             }
+                            p.info-red.output style = "margin: 10px 0" {}
+                            p.info-green.output style = "margin: 10px 0" {}
+                            ul #claims-pending-list {}
                         }
                     }
                 }
@@ -156,6 +208,52 @@ impl AccountPageTab for ListIntegrationTab {
                             }
                             (paginator("claims-record-pagination", "/api/v1/records/"))
                         }
+                        div.panel.fade {
+                            h2.pad.underlined {
+                                (tr("claim-profile"))
+                            }
+                            p {
+                                (tr("claim-profile.info"))
+                            }
+                            form.flex.col #claim-profile-form novalidate = "" {
+                                p.info-red.output {}
+                                p.info-green.output {}
+                                i #claim-profile-pending style = "display: none" {
+                                    (tr("claim-profile.pending"))
+                                }
+                                span.form-input #claim-profile-display-name {
+                                    label for = "display_name" { (tr("claim-profile.display-name")) }
+                                    input type = "text" name = "display_name" maxlength = "50";
+                                    p.error {}
+                                }
+                                span.form-input #claim-profile-bio {
+                                    label for = "bio" { (tr("claim-profile.bio")) }
+                                    textarea name = "bio" maxlength = "1000" rows = "4" {}
+                                    p.error {}
+                                }
+                                span.form-input #claim-profile-youtube {
+                                    label for = "youtube" { (tr("claim-profile.youtube")) }
+                                    input type = "url" name = "youtube" placeholder = "https://www.youtube.com/@...";
+                                    p.error {}
+                                }
+                                span.form-input #claim-profile-twitch {
+                                    label for = "twitch" { (tr("claim-profile.twitch")) }
+                                    input type = "text" name = "twitch" maxlength = "25";
+                                    p.error {}
+                                }
+                                span.form-input #claim-profile-discord {
+                                    label for = "discord" { (tr("claim-profile.discord")) }
+                                    input type = "text" name = "discord" maxlength = "32";
+                                    p.error {}
+                                }
+                                span.form-input #claim-profile-gd-account {
+                                    label for = "gd_account" { (tr("claim-profile.gd-account")) }
+                                    input type = "text" name = "gd_account" maxlength = "15";
+                                    p.error {}
+                                }
+                                input.button.blue.hover type = "submit" style = "margin: 15px auto 0px;" value = (tr("claim-profile.submit"));
+                            }
+                        }
                     }
                 }
                 @if is_moderator {
@@ -174,6 +272,27 @@ impl AccountPageTab for ListIntegrationTab {
                         }
                         (filtered_paginator("claim-pagination", "/api/v1/players/claims/"))
                     }
+                    div.panel.fade #claim-profile-manager {
+                        h2.pad.underlined {
+                            (tr("claim-profile-manager"))
+                        }
+                        p {
+                            (tr("claim-profile-manager.info"))
+                        }
+                        p.info-red.output style = "margin: 10px 0" {}
+                        p.info-green.output style = "margin: 10px 0" {}
+                        ul #claim-profile-manager-list {}
+                        form.flex.col.overlined.pad #claim-profile-remove-form novalidate = "" {
+                            p.info-red.output {}
+                            p.info-green.output {}
+                            span.form-input #claim-profile-remove-player-id {
+                                label for = "player_id" { (tr("claim-profile-manager.remove-player-id")) }
+                                input required = "" type = "number" name = "player_id" min = "0";
+                                p.error {}
+                            }
+                            input.button.red.hover type = "submit" style = "margin: 15px auto 0px;" value = (tr("claim-profile-manager.remove-submit"));
+                        }
+                    }
                 }
             }
             div.right {
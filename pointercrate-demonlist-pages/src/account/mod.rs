@@ -2,6 +2,7 @@
 
 pub mod demons;
 pub mod list_integration;
+pub mod moderation;
 pub mod players;
 pub mod records;
 pub mod submitters;
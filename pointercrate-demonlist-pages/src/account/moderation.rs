@@ -0,0 +1,117 @@
+use maud::{html, Markup, PreEscaped};
+use pointercrate_core::{localization::tr, permission::PermissionsManager};
+use pointercrate_demonlist::LIST_ADMINISTRATOR;
+use pointercrate_user::auth::{AuthenticatedUser, NonMutating};
+use pointercrate_user_pages::account::AccountPageTab;
+use sqlx::PgConnection;
+
+pub struct ModerationStatsTab;
+
+#[async_trait::async_trait]
+impl AccountPageTab for ModerationStatsTab {
+    fn should_display_for(&self, permissions_we_have: u16, permissions: &PermissionsManager) -> bool {
+        permissions.require_permission(permissions_we_have, LIST_ADMINISTRATOR).is_ok()
+    }
+
+    fn initialization_script(&self) -> String {
+        "/static/demonlist/js/account/moderation.js".into()
+    }
+
+    fn tab_id(&self) -> u8 {
+        8
+    }
+
+    fn tab(&self) -> Markup {
+        html! {
+            b {
+                (tr("moderation-stats"))
+            }
+            (PreEscaped("&nbsp;&nbsp;"))
+            i class = "fa fa-bar-chart fa-2x" aria-hidden="true" {}
+        }
+    }
+
+    async fn content(
+        &self, _user: &AuthenticatedUser<NonMutating>, _permissions: &PermissionsManager, _connection: &mut PgConnection,
+    ) -> Markup {
+        html! {
+            div.left {
+                div.panel.fade #moderation-stats {
+                    h2.underlined.pad {
+                        (tr("moderation-stats-panel"))
+                    }
+                    p {
+                        (tr("moderation-stats-panel.info"))
+                    }
+                    p.info-red.output {}
+                    span.form-input {
+                        label {
+                            (tr("moderation-stats-panel.window"))
+                        }
+                        div.dropdown-menu #moderation-stats-window {
+                            div {
+                                input type="text" data-default="30" style = "font-weight: bold;";
+                            }
+                            div.menu {
+                                ul {
+                                    li.white.hover data-value="7" {(tr("moderation-stats-panel.window-7"))}
+                                    li.white.hover data-value="30" {(tr("moderation-stats-panel.window-30"))}
+                                    li.white.hover data-value="90" {(tr("moderation-stats-panel.window-90"))}
+                                    li.white.hover data-value="365" {(tr("moderation-stats-panel.window-365"))}
+                                }
+                            }
+                        }
+                    }
+                    table style = "margin-top: 20px" {
+                        thead {
+                            tr {
+                                th.blue {
+                                    (tr("moderation-stats-reviewer"))
+                                }
+                                th.blue {
+                                    (tr("record-approved"))
+                                }
+                                th.blue {
+                                    (tr("record-rejected"))
+                                }
+                                th.blue {
+                                    (tr("record-underconsideration"))
+                                }
+                                th.blue {
+                                    (tr("moderation-stats-median"))
+                                }
+                            }
+                        }
+                        tbody #moderation-stats-reviewers {}
+                    }
+                }
+            }
+            div.right {
+                div.panel.fade #review-queue {
+                    h2.underlined.pad {
+                        (tr("review-queue"))
+                    }
+                    p {
+                        (tr("review-queue.info"))
+                    }
+                    table {
+                        thead {
+                            tr {
+                                th.blue {
+                                    (tr("review-queue-tier"))
+                                }
+                                th.blue {
+                                    (tr("record-submitted"))
+                                }
+                                th.blue {
+                                    (tr("record-underconsideration"))
+                                }
+                            }
+                        }
+                        tbody #review-queue-tiers {}
+                    }
+                }
+            }
+        }
+    }
+}
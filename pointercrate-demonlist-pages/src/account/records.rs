@@ -10,6 +10,7 @@ use pointercrate_core_pages::{
 };
 use pointercrate_demonlist::{
     demon::{current_list, Demon},
+    list::List,
     LIST_HELPER,
 };
 use pointercrate_user::auth::{AuthenticatedUser, NonMutating};
@@ -45,8 +46,16 @@ impl AccountPageTab for RecordsPage {
     async fn content(
         &self, _user: &AuthenticatedUser<NonMutating>, _permissions: &PermissionsManager, connection: &mut PgConnection,
     ) -> Markup {
-        let demons = match current_list(connection).await {
-            Ok(demons) => demons,
+        let tiers_and_demons = async {
+            let list = List::default_list(&mut *connection).await?;
+            let tiers = list.tiers(&mut *connection).await?;
+            let demons = current_list(&list.id, &mut *connection).await?;
+
+            Ok::<_, pointercrate_demonlist::error::DemonlistError>((tiers, demons))
+        };
+
+        let (tiers, demons) = match tiers_and_demons.await {
+            Ok(tiers_and_demons) => tiers_and_demons,
             Err(err) => {
                 return ErrorFragment {
                     status: err.status_code(),
@@ -59,9 +68,10 @@ impl AccountPageTab for RecordsPage {
 
         html! {
             div.left {
-                (RecordSubmitter::new(false, &demons[..]))
+                (RecordSubmitter::new(false, &tiers, &demons[..]))
                 (record_manager(&demons[..]))
                 (note_adder())
+                (evidence_adder())
                 div.panel.fade #record-notes-container style = "display:none" {
                     div.white.hover.clickable #add-record-note-open {
                         b {(tr("record-note"))}
@@ -131,13 +141,17 @@ fn record_manager(demons: &[Demon]) -> Markup {
                                 }
                                 br;
                                 a.link #record-video-link target = "_blank" {}
+                                br;
+                                i #record-video-check style = "font-size: 80%" {}
                             }
                         }
                         div.stats-container.flex.space {
-                            span {
-                                b { (tr("record-rawfootage"))  }
+                            span style = "width: 100%" {
+                                b {
+                                    i.fa.fa-plus.clickable #add-record-evidence-open aria-hidden = "true" {} " " (tr("record-evidence"))
+                                }
                                 br;
-                                a.link #record-raw-footage-link target = "_blank" {}
+                                div #record-evidence {} // populated by javascript when a record is clicked
                             }
                         }
                         div.stats-container.flex.space {
@@ -326,6 +340,40 @@ fn note_adder() -> Markup {
     }
 }
 
+fn evidence_adder() -> Markup {
+    html! {
+        div.panel.fade.closable #add-record-evidence style = "display: none" {
+            span.plus.cross.hover {}
+            div style="display: flex;align-items: center;justify-content: space-between;" {
+                div.button.blue.hover.small style = "width: 100px; margin-bottom: 10px"{
+                    (tr("record-evidence-add.submit"))
+                }
+                div.cb-container.flex.no-stretch style="justify-content: space-between; align-items: center" {
+                    b {
+                        (tr("record-evidence-add.public-checkbox"))
+                    }
+                    input #add-evidence-is-public-checkbox type = "checkbox" name = "is_public";
+                    span.checkmark {}
+                }
+            }
+            p.info-red.output {}
+            div.dropdown-menu #add-evidence-kind {
+                div {
+                    input type="text" data-default="raw_footage" style = "font-weight: bold;";
+                }
+                div.menu {
+                    ul {
+                        li.white.hover data-value="raw_footage" {(tr("record-evidence-kind.raw-footage"))}
+                        li.white.hover data-value="click_audio" {(tr("record-evidence-kind.click-audio"))}
+                        li.white.hover data-value="second_angle" {(tr("record-evidence-kind.second-angle"))}
+                    }
+                }
+            }
+            input #add-evidence-url type = "url" style = "width: 100%; margin-top: 10px" placeholder = (tr("record-evidence-add.placeholder"));
+        }
+    }
+}
+
 fn change_progress_dialog() -> Markup {
     html! {
         div.overlay.closable {
@@ -78,6 +78,20 @@ impl AccountPageTab for SubmittersPage {
                                             }
                                         }
                                     }
+                                    span {
+                                        b {
+                                            (tr("submitter-member"))
+                                        }
+                                        br;
+                                        span #submitter-member {}
+                                    }
+                                    span {
+                                        b {
+                                            (tr("submitter-statistics"))
+                                        }
+                                        br;
+                                        span #submitter-statistics {}
+                                    }
                                 }
                                 span.button.blue.hover #submitter-list-records style = "margin: 15px auto 0px" {(tr("submitter-viewer.records-redirect"))};
                             }
@@ -88,6 +102,7 @@ impl AccountPageTab for SubmittersPage {
             }
             div.right {
                 (submitter_selector())
+                (submission_bans())
             }
         }
     }
@@ -114,3 +129,27 @@ fn submitter_selector() -> Markup {
         }
     }
 }
+
+fn submission_bans() -> Markup {
+    html! {
+        div.panel.fade #submission-bans {
+            h2.underlined.pad {
+                (tr("submission-bans"))
+            }
+            p {
+                (tr("submission-bans.info"))
+            }
+            ul #submission-bans-list {}
+            form.flex.col #submission-ban-form novalidate = "" {
+                p.info-red.output {}
+                p.info-green.output {}
+                span.form-input #submission-ban-target {
+                    label for = "target" {(tr("submission-bans.target")) }
+                    input required = "" type = "text" name = "target" style="width:93%";
+                    p.error {}
+                }
+                input.button.red.hover type = "submit" style = "margin: 15px auto 0px;" value=(tr("submission-bans.submit"));
+            }
+        }
+    }
+}
@@ -2,17 +2,22 @@ use crate::components::{demon_dropdown, player_selection_dropdown};
 use maud::{html, Markup, Render};
 use pointercrate_core::{localization::tr, trp};
 use pointercrate_core_pages::trp_html;
-use pointercrate_demonlist::{config, demon::Demon};
+use pointercrate_demonlist::{
+    demon::Demon,
+    list::{TierSubmissions, Tiers},
+};
 
 pub struct RecordSubmitter<'a> {
     initially_visible: bool,
+    tiers: &'a Tiers,
     demons: &'a [Demon],
 }
 
 impl<'a> RecordSubmitter<'a> {
-    pub fn new(visible: bool, demons: &'a [Demon]) -> RecordSubmitter<'a> {
+    pub fn new(visible: bool, tiers: &'a Tiers, demons: &'a [Demon]) -> RecordSubmitter<'a> {
         RecordSubmitter {
             initially_visible: visible,
+            tiers,
             demons,
         }
     }
@@ -32,11 +37,13 @@ impl Render for RecordSubmitter<'_> {
                     h3 {
                         (tr("record-submission.demon"))
                     }
-                    p {
-                        (trp!("record-submission.demon-info", "list-size" = config::extended_list_size()))
+                    @if let Some(last_open_position) = self.tiers.last_open_position() {
+                        p {
+                            (trp!("record-submission.demon-info", "list-size" = last_open_position))
+                        }
                     }
                     span.form-input data-type = "dropdown" {
-                        (demon_dropdown("id_demon", self.demons.iter().filter(|demon| demon.base.position <= config::extended_list_size())))
+                        (demon_dropdown("id_demon", self.demons.iter().filter(|demon| self.tiers.submissions(demon.base.position) != TierSubmissions::Closed)))
                         p.error {}
                     }
                     h3 {
@@ -11,11 +11,12 @@ use maud::{html, Markup, PreEscaped};
 use pointercrate_core::{localization::tr, trp};
 use pointercrate_core_pages::{head::HeadLike, trp_html, PageFragment};
 use pointercrate_demonlist::{
-    config::{self as list_config, extended_list_size},
+    creator::DemonCreator,
     demon::{Demon, FullDemon},
+    list::{List, Tiers},
+    video::Video,
 };
 use pointercrate_integrate::gd::{DemonRating, IntegrationLevel, LevelRating, Thunk};
-use url::Url;
 
 #[derive(Debug)]
 pub struct DemonMovement {
@@ -25,6 +26,8 @@ pub struct DemonMovement {
 
 pub struct DemonPage {
     pub team: Team,
+    pub list: List,
+    pub tiers: Tiers,
     pub demonlist: Vec<Demon>,
     pub data: FullDemon,
     pub movements: Vec<DemonMovement>,
@@ -53,7 +56,7 @@ impl DemonPage {
             self.data.demon.base.name // FIXME: flatten the structs, holy shit
         );
 
-        if self.data.demon.base.position <= extended_list_size() {
+        if self.data.demon.base.position <= self.list.extended_list_size {
             title = format!("#{} - {}", self.data.demon.base.position, title);
         }
 
@@ -112,13 +115,14 @@ impl DemonPage {
                     window.list_length = {0};
                     window.extended_list_length = {1};
                     window.demon_id = {2};
-                </script>", list_config::list_size(), list_config::extended_list_size(), self.data.demon.base.id
+                </script>", self.list.list_size, self.list.extended_list_size, self.data.demon.base.id
             )))
+            link rel = "alternate" type = "application/atom+xml" href = (format!("/demonlist/feeds/demons/{}/records/", self.data.demon.base.id));
         }
     }
 
     fn body(&self) -> Markup {
-        let dropdowns = super::dropdowns(&self.demonlist.iter().collect::<Vec<_>>()[..], Some(&self.data.demon));
+        let dropdowns = super::dropdowns(&self.tiers, &self.demonlist.iter().collect::<Vec<_>>()[..], Some(&self.data.demon));
 
         let mut labels = Vec::new();
 
@@ -150,7 +154,7 @@ impl DemonPage {
 
             div.flex.m-center.container {
                 main.left {
-                    (RecordSubmitter::new(false, &self.demonlist))
+                    (RecordSubmitter::new(false, &self.tiers, &self.demonlist))
                     (self.demon_panel())
                     div.panel.fade.js-scroll-anim.js-collapse data-anim = "fade" {
                         h2.underlined.pad {
@@ -251,7 +255,7 @@ impl DemonPage {
                                 "demon-headline.no-creators",
                                 "verified-and-published" = verified_and_published
                             )) },
-                            [creator] => {
+                            [DemonCreator { player: creator, .. }] => {
                                 @if creator == &self.data.demon.publisher && creator == &self.data.demon.verifier {
                                     (trp_html!("demon-headline-by", "creator" = html!{(P(creator, None))}))
                                 }
@@ -277,7 +281,7 @@ impl DemonPage {
                                     ))
                                 }
                             },
-                            [creator1, creator2] => {
+                            [DemonCreator { player: creator1, .. }, DemonCreator { player: creator2, .. }] => {
                                 (trp_html!(
                                     "demon-headline.two-creators",
                                     "creator1" = html!{(P(creator1, None))},
@@ -285,7 +289,7 @@ impl DemonPage {
                                     "verified-and-published" = verified_and_published
                                 ))
                             },
-                            [creator1, rest @ ..] => {
+                            [DemonCreator { player: creator1, .. }, rest @ ..] => {
                                 (trp_html!(
                                     "demon-headline.more-creators",
                                     "creator" = html!{(P(creator1, None))},
@@ -293,7 +297,7 @@ impl DemonPage {
                                       div.tooltip.underdotted {
                                             (tr("demon-headline.more-creators-tooltip"))
                                             div.tooltiptext.fade {
-                                                (rest.iter().map(|player| player.name.as_ref()).collect::<Vec<_>>().join(", "))
+                                                (rest.iter().map(|creator| creator.player.name.as_ref()).collect::<Vec<_>>().join(", "))
                                             }
                                         }
                                     },
@@ -302,6 +306,15 @@ impl DemonPage {
                             }
                         }
                     }
+                    @if !self.data.tags.is_empty() {
+                        div.flex.wrap #demon-tags {
+                            @for tag in &self.data.tags {
+                                span.tag title = (tag.id) {
+                                    (tag.localized_name())
+                                }
+                            }
+                        }
+                    }
                 }
                 @if let Some(ref level) = self.integration {
                     @if let Some(Thunk::Processed(ref description)) = level.description {
@@ -398,7 +411,7 @@ impl DemonPage {
                             }
                         }
                     }
-                    @if position <= list_config::extended_list_size() {
+                    @if position <= self.list.extended_list_size {
                         span {
                             b {
                                 (trp!("demon-score", "percent" = 100.0))
@@ -407,7 +420,7 @@ impl DemonPage {
                             (format!("{:.2}", score100))
                         }
                     }
-                    @if position <= list_config::list_size(){
+                    @if position <= self.list.list_size{
                         span {
                             b {
                                 (trp!("demon-score", "percent" = self.data.demon.requirement))
@@ -426,18 +439,18 @@ impl DemonPage {
         let _name = &self.data.demon.base.name;
 
         html! {
-            @if !self.data.records.is_empty() || position <= list_config::extended_list_size() {
+            @if !self.data.records.is_empty() || position <= self.list.extended_list_size {
                 section.records.panel.fade.js-scroll-anim data-anim = "fade" {
                     div.underlined.pad {
                         h2 {
                             (tr("demon-records"))
                         }
-                        @if position <= list_config::list_size() {
+                        @if position <= self.list.list_size {
                             h3 {
                                 (trp!("demon-records-qualify", "percent" = self.data.demon.requirement))
                             }
                         }
-                        @else if position <= list_config::extended_list_size() {
+                        @else if position <= self.list.extended_list_size {
                             h3 {
                                 (trp!("demon-records-qualify", "percent" = 100.0))
                             }
@@ -451,7 +464,7 @@ impl DemonPage {
                     }
                     @if self.data.records.is_empty() {
                         h3 {
-                            @if position > list_config::extended_list_size() {
+                            @if position > self.list.extended_list_size {
                                 (tr("demon-records.none"))
                             }
                             @else {
@@ -511,37 +524,10 @@ impl DemonPage {
     }
 }
 
-fn host(video: &str) -> &str {
-    match Url::parse(video).unwrap().domain().unwrap() {
-        "www.youtube.com" => "YouTube",
-        "www.twitch.tv" => "Twitch",
-        "everyplay.com" => "Everyplay",
-        "www.bilibili.com" => "Bilibili",
-        "vimeo.com" => "Vimeo",
-        host => panic!("{}", host),
-    }
+fn host(video: &str) -> &'static str {
+    Video::parse(video).map(|video| video.host()).unwrap_or("Video")
 }
 
 fn embed(video: &str) -> Option<String> {
-    // Video URLs need to be wellformed once we get here!
-    let url = Url::parse(video).unwrap();
-
-    match url.domain()? {
-        "www.youtube.com" => {
-            let video_id = url
-                .query_pairs()
-                .find_map(|(key, value)| if key == "v" { Some(value) } else { None })?;
-
-            Some(format!("https://www.youtube.com/embed/{}", video_id))
-        },
-        "www.twitch.tv" => {
-            // per validation always of the form 'https://www.twitch.tv/videos/[video id]/'
-            let mut url_segment = url.path_segments()?;
-            url_segment.next()?;
-            let video_id = url_segment.next()?;
-
-            Some(format!("https://player.twitch.tv/?video={}&autoplay=false", video_id))
-        },
-        _ => None,
-    }
+    Video::parse(video).ok()?.embed_url()
 }
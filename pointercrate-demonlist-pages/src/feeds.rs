@@ -0,0 +1,162 @@
+//! Module containing Atom feeds that allow following changes to the demonlist in feed readers
+//!
+//! Since pointercrate does not know the domain it is deployed under, all links in the generated
+//! feeds are relative (and thus resolved against the URL the feed was retrieved from), and entry
+//! IDs are URNs.
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use maud::{html, Markup, PreEscaped, Render};
+use pointercrate_core::{localization::tr, trp};
+use pointercrate_demonlist::{
+    demon::{audit::ListMovement, MinimalDemon},
+    player::DatabasePlayer,
+    record::ApprovedRecord,
+};
+
+pub struct AtomFeed {
+    id: String,
+    title: String,
+
+    /// The URL under which this feed is served
+    self_link: String,
+
+    /// The URL of the HTML page whose content this feed describes
+    alternate_link: String,
+    entries: Vec<AtomEntry>,
+}
+
+struct AtomEntry {
+    id: String,
+    title: String,
+    updated: NaiveDateTime,
+    link: String,
+    content: Option<String>,
+}
+
+impl AtomFeed {
+    /// The time of the most recent update to this feed, or the unix epoch if the feed is empty
+    pub fn updated(&self) -> NaiveDateTime {
+        self.entries
+            .iter()
+            .map(|entry| entry.updated)
+            .max()
+            .unwrap_or(DateTime::<Utc>::UNIX_EPOCH.naive_utc())
+    }
+}
+
+/// Formats the given (UTC) time as specified by RFC 3339, as required by the Atom specification
+fn atom_time(time: &NaiveDateTime) -> String {
+    time.and_utc().to_rfc3339()
+}
+
+impl Render for AtomFeed {
+    fn render(&self) -> Markup {
+        html! {
+            (PreEscaped(r#"<?xml version="1.0" encoding="utf-8"?>"#))
+            feed xmlns="http://www.w3.org/2005/Atom" {
+                id { (self.id) }
+                title { (self.title) }
+                updated { (atom_time(&self.updated())) }
+                link rel="self" href=(self.self_link) {}
+                link rel="alternate" type="text/html" href=(self.alternate_link) {}
+                @for entry in &self.entries {
+                    entry {
+                        id { (entry.id) }
+                        title { (entry.title) }
+                        updated { (atom_time(&entry.updated)) }
+                        link rel="alternate" href=(entry.link) {}
+                        @if let Some(ref content) = entry.content {
+                            content type="text" { (content) }
+                        }
+                        // Atom requires an author for each entry if the feed itself does not have one
+                        author { name { (tr("feed-author")) } }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn permalink(demon: &MinimalDemon) -> String {
+    format!("/demonlist/permalink/{}/", demon.id)
+}
+
+pub fn list_movements_feed(movements: Vec<ListMovement>) -> AtomFeed {
+    AtomFeed {
+        id: "urn:pointercrate:feed:movements".to_string(),
+        title: tr("feed-movements"),
+        self_link: "/demonlist/feeds/movements/".to_string(),
+        alternate_link: "/demonlist/".to_string(),
+        entries: movements
+            .into_iter()
+            .map(|movement| {
+                let title = match movement.old_position {
+                    None => trp!(
+                        "feed-movements.added",
+                        "demon" = movement.demon.name,
+                        "position" = movement.new_position
+                    ),
+                    Some(old_position) if old_position > movement.new_position => trp!(
+                        "feed-movements.moved-up",
+                        "demon" = movement.demon.name,
+                        "old-position" = old_position,
+                        "new-position" = movement.new_position
+                    ),
+                    Some(old_position) => trp!(
+                        "feed-movements.moved-down",
+                        "demon" = movement.demon.name,
+                        "old-position" = old_position,
+                        "new-position" = movement.new_position
+                    ),
+                };
+
+                AtomEntry {
+                    id: format!(
+                        "urn:pointercrate:movement:{}:{}",
+                        movement.demon.id,
+                        movement.time.and_utc().timestamp_micros()
+                    ),
+                    title,
+                    updated: movement.time,
+                    link: permalink(&movement.demon),
+                    content: None,
+                }
+            })
+            .collect(),
+    }
+}
+
+fn record_entry(record: ApprovedRecord) -> AtomEntry {
+    AtomEntry {
+        id: format!("urn:pointercrate:record:{}", record.record.id),
+        title: trp!(
+            "feed-record",
+            "player" = record.record.player.name,
+            "demon" = record.record.demon.name,
+            "progress" = record.record.progress
+        ),
+        updated: record.approved_at,
+        link: permalink(&record.record.demon),
+        content: record.record.video,
+    }
+}
+
+pub fn demon_records_feed(demon: &MinimalDemon, records: Vec<ApprovedRecord>) -> AtomFeed {
+    AtomFeed {
+        id: format!("urn:pointercrate:feed:demon:{}:records", demon.id),
+        title: trp!("feed-demon-records", "demon" = demon.name),
+        self_link: format!("/demonlist/feeds/demons/{}/records/", demon.id),
+        alternate_link: permalink(demon),
+        entries: records.into_iter().map(record_entry).collect(),
+    }
+}
+
+pub fn player_records_feed(player: &DatabasePlayer, records: Vec<ApprovedRecord>) -> AtomFeed {
+    AtomFeed {
+        id: format!("urn:pointercrate:feed:player:{}:records", player.id),
+        title: trp!("feed-player-records", "player" = player.name),
+        self_link: format!("/demonlist/feeds/players/{}/records/", player.id),
+        alternate_link: format!("/demonlist/statsviewer/?player={}", player.id),
+        entries: records.into_iter().map(record_entry).collect(),
+    }
+}
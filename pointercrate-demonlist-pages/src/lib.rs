@@ -1,45 +1,37 @@
 use maud::{html, Markup};
 
-use pointercrate_core::localization::tr;
-use pointercrate_demonlist::{config, demon::Demon};
+use pointercrate_core::localization::try_tr;
+use pointercrate_demonlist::{demon::Demon, list::Tiers};
 
 pub mod account;
 pub mod components;
 pub mod demon_page;
+pub mod feeds;
 pub mod overview;
 pub mod statsviewer;
 
-struct ListSection {
+struct ListSection<'a> {
     name: String,
     description: String,
-    id: &'static str,
+    id: &'a str,
     numbered: bool,
 }
 
-fn dropdowns(all_demons: &[&Demon], current: Option<&Demon>) -> Markup {
-    let (main, extended, legacy) = if all_demons.len() < config::list_size() as usize {
-        (all_demons, Default::default(), Default::default())
-    } else {
-        let (extended, legacy) = if all_demons.len() < config::extended_list_size() as usize {
-            (&all_demons[config::list_size() as usize..], Default::default())
-        } else {
-            (
-                &all_demons[config::list_size() as usize..config::extended_list_size() as usize],
-                &all_demons[config::extended_list_size() as usize..],
-            )
-        };
-
-        (&all_demons[..config::list_size() as usize], extended, legacy)
-    };
-
+fn dropdowns(tiers: &Tiers, all_demons: &[&Demon], current: Option<&Demon>) -> Markup {
     html! {
         nav.flex.wrap.m-center.fade #lists style="text-align: center;" {
-            // The drop down for the main list:
-            (dropdown(&ListSection { name: tr("main-list"), description: tr("main-list.info"), id: "mainlist", numbered: true }, main, current))
-            // The drop down for the extended list:
-            (dropdown(&ListSection { name: tr("extended-list"), description: tr("extended-list.info"), id: "extended", numbered: true }, extended, current))
-            // The drop down for the legacy list:
-            (dropdown(&ListSection { name: tr("legacy-list"), description: tr("legacy-list.info"), id: "legacy", numbered: false }, legacy, current))
+            // One drop down per tier. The final, unbounded tier (e.g. the legacy list) is not ordered in any meaningful way
+            @for tier in &tiers.0 {
+                @let section = ListSection {
+                    name: try_tr(&tier.label).unwrap_or_else(|| tier.label.clone()),
+                    description: try_tr(&format!("{}.info", tier.label)).unwrap_or_default(),
+                    id: &tier.id,
+                    numbered: tier.last_position.is_some() || tiers.0.len() == 1,
+                };
+                @let demons = all_demons.iter().copied().filter(|demon| tier.contains(demon.base.position)).collect::<Vec<_>>();
+
+                (dropdown(&section, &demons, current))
+            }
         }
     }
 }
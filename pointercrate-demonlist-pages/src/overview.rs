@@ -12,12 +12,14 @@ use pointercrate_core::{localization::tr, trp};
 use pointercrate_core_pages::{head::HeadLike, trp_html, PageFragment};
 use pointercrate_demonlist::player::FullPlayer;
 use pointercrate_demonlist::{
-    config as list_config, config,
     demon::{Demon, TimeShiftedDemon},
+    list::{List, Tiers},
 };
 
 pub struct OverviewPage {
     pub team: Team,
+    pub list: List,
+    pub tiers: Tiers,
     pub demonlist: Vec<Demon>,
     pub time_machine: Tardis,
     pub submitter_initially_visible: bool,
@@ -76,10 +78,11 @@ impl OverviewPage {
                 <script>
                     window.list_length = {0};
                     window.extended_list_length = {1}
-                </script>", list_config::list_size(), list_config::extended_list_size())
+                </script>", self.list.list_size, self.list.extended_list_size)
             ))
             // FIXME: abstract away
             link ref = "canonical" href = "https://pointercrate.com/demonlist/";
+            link rel = "alternate" type = "application/atom+xml" href = "/demonlist/feeds/movements/";
         }
     }
 
@@ -89,7 +92,7 @@ impl OverviewPage {
             _ => self.demonlist.iter().collect(),
         };
 
-        let dropdowns = super::dropdowns(&demons_for_dropdown[..], None);
+        let dropdowns = super::dropdowns(&self.tiers, &demons_for_dropdown[..], None);
 
         html! {
             (dropdowns)
@@ -97,19 +100,19 @@ impl OverviewPage {
             div.flex.m-center.container {
                 main.left {
                     (self.time_machine)
-                    (RecordSubmitter::new(self.submitter_initially_visible, &self.demonlist))
+                    (RecordSubmitter::new(self.submitter_initially_visible, &self.tiers, &self.demonlist))
 
                     @match &self.time_machine {
                         Tardis::Activated { demons, ..} => {
                             @for TimeShiftedDemon {current_demon, position_now} in demons {
-                                @if current_demon.base.position <= list_config::extended_list_size() {
+                                @if current_demon.base.position <= self.list.extended_list_size {
                                     (self.demon_panel(current_demon, Some(*position_now)))
                                 }
                             }
                         },
                         _ => {
                             @for demon in &self.demonlist {
-                                @if demon.base.position <= list_config::extended_list_size() {
+                                @if demon.base.position <= self.list.extended_list_size {
                                     (self.demon_panel(demon, None))
                                 }
                             }
@@ -165,7 +168,7 @@ impl OverviewPage {
                          }
                          div style="text-align: left; font-size: 0.8em" {
                             @if let Some(current_position) = current_position {
-                                 @if current_position > list_config::extended_list_size() {
+                                 @if current_position > self.list.extended_list_size {
                                      (tr("time-machine.active-position-legacy"))
                                  }
                                  @else {
@@ -176,7 +179,7 @@ impl OverviewPage {
                                  }
                             }
                             @else {
-                                @if demon.base.position > config::list_size() {
+                                @if demon.base.position > self.list.list_size {
                                     (trp!(
                                         "demon-info.score-short",
                                         "score" = total_score
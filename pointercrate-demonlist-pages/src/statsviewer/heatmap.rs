@@ -0,0 +1,119 @@
+//! Module for rendering [`Heatmap`]s onto the world map used by the stats viewers
+//!
+//! The stats viewers embed the map as an interactive SVG and load the heatmap as an external
+//! stylesheet. For embedding the map elsewhere (e.g. in Discord), this module can also produce a
+//! self-contained, non-interactive SVG and rasterize it to PNG.
+
+use pointercrate_demonlist::nationality::{Heatmap, HeatmapEntry};
+use resvg::{tiny_skia, usvg};
+
+const WORLD_MAP: &str = include_str!("../../static/images/world.svg");
+
+/// Replaces the interactive styles of the world map in the self-contained SVG
+const STATIC_MAP_STYLE: &str = "
+.land path, .island path, .land-with-states path { stroke: #ffffff; fill: #dadce0; }
+.land path, .land-with-states path { stroke-width: 5; }
+#gb path, #dk path { stroke-width: 2; }
+.island path, .land-with-states .state { stroke: none; }
+.circle { opacity: 0; }
+";
+
+/// Computes the color of a nation/subdivision on the heatmap, as RGB components
+fn heatmap_color(entry: &HeatmapEntry) -> (f64, f64, f64) {
+    // Artificially adjust the highest value so that the normalized value is never 1. If it were 1, the resulting
+    // color will be equal to the "hover"/"selected" color, which looks bad.
+    let factor = entry.normalized / 1.5;
+
+    (
+        0xda as f64 + (0x08 - 0xda) as f64 * factor,
+        0xdc as f64 + (0x81 - 0xdc) as f64 * factor,
+        0xe0 as f64 + (0xc6 - 0xe0) as f64 * factor,
+    )
+}
+
+/// The ID of the element representing the given nation/subdivision on the world map
+fn map_id(entry: &HeatmapEntry) -> String {
+    match entry.subdivision_code {
+        Some(ref subdivision) => format!("{}-{}", entry.country_code, subdivision),
+        None => entry.country_code.to_lowercase(),
+    }
+}
+
+/// The stylesheet applying the given heatmap to the interactive world map of the stats viewers
+pub fn heatmap_css(heatmap: &Heatmap) -> String {
+    let mut css = String::new();
+
+    for entry in heatmap.nations.iter().chain(&heatmap.subdivisions) {
+        let (r, g, b) = heatmap_color(entry);
+
+        css.push_str(&format!(
+            ".heatmapped #{0}, .heatmapped #{0} > path {{ fill: rgb({1}, {2}, {3}); }}",
+            map_id(entry),
+            r,
+            g,
+            b
+        ));
+    }
+
+    css
+}
+
+/// Renders the given heatmap onto a self-contained copy of the world map
+///
+/// Only nations are colored, as subdivisions are not shown on the map unless selected.
+pub fn world_map_svg(heatmap: &Heatmap) -> String {
+    let mut style = String::from(STATIC_MAP_STYLE);
+
+    for entry in &heatmap.nations {
+        let (r, g, b) = heatmap_color(entry);
+
+        style.push_str(&format!(
+            "#{0}, #{0} > path {{ fill: #{1:02x}{2:02x}{3:02x}; }}\n",
+            map_id(entry),
+            r.round() as u8,
+            g.round() as u8,
+            b.round() as u8
+        ));
+    }
+
+    // Drop the link to the external heatmap stylesheet, and replace the map's own (interactive) stylesheet
+    let without_link = remove_element(WORLD_MAP, "<xhtml:link", "/>");
+    let (before_style, after_style) = split_around_element(&without_link, "<style", "</style>");
+
+    format!("{}<style type=\"text/css\">{}</style>{}", before_style, style, after_style)
+}
+
+/// Rasterizes [`world_map_svg`] to a PNG image of the given width
+///
+/// Returns `None` if the width is zero or the image could not be encoded.
+pub fn world_map_png(heatmap: &Heatmap, width: u32) -> Option<Vec<u8>> {
+    let tree = usvg::Tree::from_str(&world_map_svg(heatmap), &usvg::Options::default()).ok()?;
+
+    let scale = width as f32 / tree.size().width();
+    let height = (tree.size().height() * scale).ceil() as u32;
+
+    let mut pixmap = tiny_skia::Pixmap::new(width, height)?;
+
+    resvg::render(&tree, tiny_skia::Transform::from_scale(scale, scale), &mut pixmap.as_mut());
+
+    pixmap.encode_png().ok()
+}
+
+fn remove_element(svg: &str, start: &str, end: &str) -> String {
+    let (before, after) = split_around_element(svg, start, end);
+
+    format!("{}{}", before, after)
+}
+
+/// Splits the given SVG into the parts before and after the first element starting with `start`
+/// and ending with `end`. If no such element exists, the second part is empty.
+fn split_around_element<'a>(svg: &'a str, start: &str, end: &str) -> (&'a str, &'a str) {
+    let Some(element_start) = svg.find(start) else {
+        return (svg, "");
+    };
+
+    match svg[element_start..].find(end) {
+        Some(element_length) => (&svg[..element_start], &svg[element_start + element_length + end.len()..]),
+        None => (svg, ""),
+    }
+}
@@ -3,6 +3,7 @@ use pointercrate_core::localization::tr;
 use pointercrate_core_pages::util::{dropdown, filtered_paginator, simple_dropdown};
 use pointercrate_demonlist::nationality::Nationality;
 
+pub mod heatmap;
 pub mod individual;
 pub mod national;
 
@@ -147,6 +148,10 @@ fn stats_viewer_html(nations: Option<&[Nationality]>, rows: Vec<StatsViewerRow>,
                     div {
                         div.flex.col {
                             h3 #player-name style = "font-size:1.4em; overflow: hidden" {}
+                            @if !is_nation_stats_viewer {
+                                i #player-previous-names style = "font-size: 90%; text-align: center; display: none" {}
+                                div #player-profile style = "font-size: 90%; text-align: center; display: none" {}
+                            }
                             @for row in rows {
                                 div.stats-container.flex.space {
                                     @for column in row.0 {
@@ -2,10 +2,13 @@ error-demonlist-geolocationfailed = Geolocation failed!
 error-demonlist-malformedvideourl = Malformed video URL
 error-demonlist-bannedfromsubmissions = You are banned from submitting records to the demonlist!
 error-demonlist-claimunverified = Your claim on this player is unverified
-error-demonlist-vpsdetected = IP geolocation attempt through VPS detected
+error-demonlist-vpsdetected = Your request seems to originate from a VPN, proxy or datacenter. Please disable it and try again
 error-demonlist-nothirdpartysubmissions = This player has requested that only they themselves can submit their records
 error-demonlist-submitternotfound = No submitter with id { $id } found
+error-demonlist-submissionbannotfound = No submission ban with id { $id } found
+error-demonlist-pendingsubmissionnotfound = No pending submission with id { $id } found
 error-demonlist-notenotfound = No note with id { $note-id } found on record with id { $record-id }
+error-demonlist-evidencenotfound = No evidence with id { $evidence-id } found on record with id { $record-id }
 error-demonlist-creatornotfound = Player with id { $player-id } is no creator of demon with id { demon-id }
 error-demonlist-nationalitynotfound = No nationality with iso code { $iso-code } found
 error-demonlist-subdivisionnotfound = No subdivision with code { $subdivision-code } found in nation { $nation-code }
@@ -16,10 +19,18 @@ error-demonlist-demonnotfoundname = No demon with name { $demon-name } found
 error-demonlist-demonnotfoundposition = No demon at position { $demon-position } found
 error-demonlist-recordnotfound = No record with id { $record-id } found
 error-demonlist-claimnotfound = No claim by user { $member-id } on player { $player-id } found
+error-demonlist-listnotfound = No list with id { $list-id } found
+error-demonlist-mergenotfound = No player merge with id { $id } found
+error-demonlist-profilenotfound = No profile found for player with id { $player-id }
 error-demonlist-creatorexists = This player is already registered as a creator on this demon
 error-demonlist-duplicatevideo = This video is already used by record #{ $record-id }
 error-demonlist-nonationset = Attempt to set subdivision without nation
 error-demonlist-conflictingclaims = The players '{ $player-1 }' and '{ $player-2 }' have verified claims by different pointercrate users
+error-demonlist-listexists = A list with this ID already exists
+error-demonlist-recordlocked = This record is currently locked for review by { $reviewer }
+error-demonlist-mergeundone = This merge has already been undone
+error-demonlist-mergeexpired = This merge is too old to be undone
+error-demonlist-mergesuperseded = This merge cannot be undone while a later merge involving these players is in place, or while a different player uses the merged player's name
 error-demonlist-invalidrequirement = Record requirement needs to be greater than -1 and smaller than 101
 error-demonlist-invalidposition = Demon position needs to be greater than or equal to 1 and smaller than or equal to { $maximal }
 error-demonlist-invalidprogress = Record progress must lie between { $requirement } and 100%!
@@ -34,8 +45,32 @@ error-demonlist-alreadyclaimed = This player already has a verified claim associ
 error-demonlist-rawrequired = Raw footage much be provided to submit this record
 error-demonlist-malformedrawurl = Raw footage needs to be a valid URL
 error-demonlist-invalidlevelid = Level ID needs to be positive
+error-demonlist-invalidlistid = List IDs may only consist of lowercase letters, digits and single dashes, and can be at most 32 characters long
+error-demonlist-invalidlistsize = The main list needs to contain at least one demon, and cannot be larger than the extended list
+error-demonlist-invalidtiers = Tiers need to cover every position exactly once, with only the last tier being unbounded. Each tier needs a unique ID, a label and one of the list permissions as edit permission, and the list's sizes need to be the last position of some tier
+error-demonlist-malformedevidenceurl = Evidence links need to be valid URLs
+error-demonlist-invalidbantarget = A ban needs to target either a user account or an IP address/network (e.g. 192.0.2.1 or 2001:db8::/64)
+error-demonlist-noverificationcode = No verification code has been issued for this claim, or it has expired. Please request a new one
+error-demonlist-gdaccountinuse = This Geometry Dash account is already linked to a different player
+error-demonlist-gdaccountnotfound = No Geometry Dash account named "{$player-name}" could be found
+error-demonlist-claimverificationfailed = The verification code could not be found in the comments or the profile of the Geometry Dash account. Note that it can take a few minutes for changes to show up
+error-demonlist-invalidprofilefield = Invalid value for profile field '{ $field }'. Display names can be at most 50 and bios at most 1000 characters long, YouTube links need to point to a channel, and Twitch, Discord and Geometry Dash names need to be valid usernames
+error-demonlist-nationalityexists = A nationality with this country code or name already exists
+error-demonlist-subdivisionexists = A subdivision with this code or name already exists
+error-demonlist-invalidcountrycode = Country codes need to be officially assigned ISO 3166-1 alpha-2 codes (or XK for Kosovo)
+error-demonlist-invalidsubdivisioncode = Subdivision codes need to be the part of an ISO 3166-2 code following the country code, consisting of one to three letters and digits
+error-demonlist-invalidcreatorpart = A creator's part must start before it ends, and lie between 0% and 100%
+error-demonlist-tagnotfound = No tag with id { $tag-id } found
+error-demonlist-demontagnotfound = Demon with id { $demon-id } is not tagged with { $tag-id }
+error-demonlist-tagexists = A tag with this ID already exists
+error-demonlist-demontagexists = This demon already has this tag
+error-demonlist-invalidtagid = Tag IDs may only consist of lowercase letters, digits and single dashes, and can be at most 32 characters long
+error-demonlist-invalidtagname = Tags need at least one name. Names need to be keyed by a language code (e.g. "en") and can be at most 32 characters long
+error-demonlist-geometrydashunavailable = The Geometry Dash servers could not be reached. Please try again later
 
 error-demonlist-ratelimit-record-submit = You're submitting too many records too fast!
 error-demonlist-ratelimit-record-submit-global = Too many records are being submitted right now!
 error-demonlist-ratelimit-new-submitters = DDoS protection ratelimit
-error-demonlist-ratelimit-add-demon = Please don't spam the button, rSteel
\ No newline at end of file
+error-demonlist-ratelimit-add-demon = Please don't spam the button, rSteel
+error-demonlist-ratelimit-claim-verification = Please wait a few minutes before trying to verify your claim again
+error-demonlist-ratelimit-heatmap-png = You are requesting heatmap images too quickly. Please wait a minute before trying again
\ No newline at end of file
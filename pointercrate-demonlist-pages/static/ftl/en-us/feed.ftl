@@ -0,0 +1,13 @@
+## Atom feeds
+feed-author = Demonlist Team
+
+feed-movements = Demonlist Changes
+    .added = { $demon } has been added to the list at #{ $position }
+    .moved-up = { $demon } has been moved up from #{ $old-position } to #{ $new-position }
+    .moved-down = { $demon } has been moved down from #{ $old-position } to #{ $new-position }
+
+feed-demon-records = New Records on { $demon }
+
+feed-player-records = New Records by { $player }
+
+feed-record = { $player } achieved { $progress }% on { $demon }
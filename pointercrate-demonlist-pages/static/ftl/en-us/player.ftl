@@ -53,17 +53,61 @@ claim-geolocate = Geolocate statsviewer flag
     .edit-success = Set nationality to { $nationality }
     .edit-success-subdivision = Set nationality to { $nationality }/{ $subdivision }
 
+claim-gd-verification = Verify through Geometry Dash
+    .info = Verify your claim by proving that you own your player's Geometry Dash account: request a code, then post it as a comment on that account's profile, or put it into the YouTube, Twitter or Twitch field of that account's profile. Codes are valid for 24 hours.
+    .code = Your code: { $code }
+    .request-code = Get code
+    .submit = Verify
+
+    .submit-success = Your claim was verified!
+
 claim-lock-submissions = Lock submissions
     .info = Whether submissions for your claimed player should be locked, meaning only you will be able to submit records for your claimed player (and only while logged in to this account holding the verified claim)
 
     .edit-success = Successfully applied change
 
+claim-third-party-approval = Approve third-party submissions
+    .info = Only takes effect while submissions are locked. Instead of rejecting submissions for your claimed player made by others, hold them for your approval. The list team only gets to see submissions you accepted.
+
+claim-pending = Submissions awaiting your approval
+    .info = Submissions for your claimed player made by others. Accepting a submission sends it to the list team for review as usual, declining it discards it.
+    .none = No submissions are awaiting your approval!
+
+    .accept-success = Submission accepted
+    .decline-success = Submission declined
+
 claim-records = Your claimed player's records
     .info = A list of your claimed player's records, including all under consideration and rejected records and all submissions. Use this to track the status of your submissions. Clicking on a record will pull up any public notes a list mod left on the given record. The background color of each record tells you whether the record is { $record-approved-styled }, { $record-submitted-styled }, { $record-rejected-styled } or { $record-underconsideration-styled }.
 
     .record-notes = Notes for record { $record-id }:
     .record-notes-none = No public notes on this record!
 
+claim-profile = Your claimed player's profile
+    .info = Set up a profile that is shown on your claimed player's stats viewer entry. Changes are reviewed by a moderator before they become visible. Leave a field empty to remove it from your profile.
+    .pending = Your latest changes are awaiting a moderator's approval.
+
+    .display-name = Display name:
+    .bio = Bio:
+    .youtube = YouTube channel:
+    .twitch = Twitch username:
+    .discord = Discord handle:
+    .gd-account = Geometry Dash account name:
+
+    .submit = Submit for review
+    .submit-success = Your changes were submitted for review!
+
+claim-profile-manager = Review Profiles
+    .info = Profile changes made by claimants are listed below. Approved changes become visible on the player's stats viewer entry immediately. Use the form at the bottom to remove a player's live profile, e.g. if it was reported for abuse.
+    .none = No profile changes are awaiting review!
+
+    .remove-player-id = Player ID:
+    .remove-player-id-validator-valuemissing = Please enter a player ID
+    .remove-submit = Remove profile
+
+    .approve-success = Profile changes approved
+    .reject-success = Profile changes rejected
+    .delete-success = Profile removed
+
 claim-manager = Manage Claims
     .info-a = Manage claims using the interface below. The list can be filtered by player and user using the panels on the right. Invalid claims should be deleted using the trash icon.
     .info-b = To verify a claim, click the checkmark. Only verify claims you have verified to be correct (this will probably mean talking to the player that's being claimed, and asking if they initiated the claim themselves, or if the claim is malicious).
@@ -6,7 +6,6 @@ record-rejected = Rejected
 
 record-videolink = Video Link
 record-videoproof = Video Proof
-record-rawfootage = Raw Footage
 record-demon = Demon
 record-holder = Record Holder
 record-progress = Progress
@@ -40,10 +39,36 @@ record-note-listed = Record Note #{ $note-id }
 
     .author = This note was left by { $author }.
     .author-submitter = This note was left as a comment by the submitter.
+    .author-automatic = This note was left automatically, because the submission looked suspicious.
     .editors = This note was subsequently modified by: { $editors }.
     .transferred = This note was not originally left on this record.
     .public = This note is public.
 
+record-video-check = Video Check
+    .available = The video was confirmed to exist on { $checked-at }.
+    .missing = The video was reported missing on { $checked-at }.
+    .inconclusive = The video could not be checked automatically on { $checked-at } and needs to be checked manually.
+    .metadata = The video is titled '{ $title }' and was uploaded by { $channel }.
+
+record-evidence = Evidence
+
+record-evidence-add = Add
+    .placeholder = Link to the evidence
+    .public-checkbox = Public link
+
+    .submit = Add
+
+record-evidence-kind = Evidence
+    .raw-footage = Raw Footage
+    .click-audio = Click Audio
+    .second-angle = Second Angle
+
+record-evidence-listed = Evidence
+    .confirm-delete = This action will irrevocably remove this evidence link. Proceed?
+
+    .transferred = (transferred from another record)
+    .public = (public)
+
 record-status-filter-panel = Filter
     .info = Filter by record status
 
@@ -120,4 +145,25 @@ record-manager-help = Manage Records
 
     .note-a = If a player is banned, they cannot have { record-approved }/{ record-submitted } records on the list. All records marked as { record-submitted } are deleted, all others are changed to { record-rejected }.
 
-    .note-b = Banning a submitter will delete all their submissions that still have the status { record-submitted }. Records submitted by them that were already { record-approved }/{ record-rejected } will not be affected.
\ No newline at end of file
+    .note-b = Banning a submitter will delete all their submissions that still have the status { record-submitted }. Records submitted by them that were already { record-approved }/{ record-rejected } will not be affected.
+
+## Moderation statistics tab (user area)
+moderation-stats = Moderation
+moderation-stats-panel = Moderation Statistics
+    .info = The number of records each list team member changed the status of in the selected time frame. The median decision time is measured from a record's submission until it got approved or rejected.
+    .window = Time frame:
+    .window-7 = Last 7 days
+    .window-30 = Last 30 days
+    .window-90 = Last 90 days
+    .window-365 = Last year
+    .none = No record changed status in the selected time frame
+
+moderation-stats-reviewer = Team Member
+moderation-stats-median = Median Decision Time
+moderation-stats-hours = { $hours } hours
+moderation-stats-days = { $days } days
+
+review-queue = Review Queue
+    .info = The number of records currently waiting for review in each tier of each list
+
+review-queue-tier = Tier
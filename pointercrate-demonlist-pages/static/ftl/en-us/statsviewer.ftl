@@ -1,6 +1,7 @@
 statsviewer = Stats Viewer
     .rank = Demonlist rank
     .score = Demonlist score
+    .rank-delta = Change in rank over the last 30 days
     .stats = Demonlist stats
     .hardest = Hardest demon
 
@@ -19,6 +20,10 @@ statsviewer = Stats Viewer
 
 statsviewer-individual = Individual
     .welcome = Click on a player's name on the left to get started!
+    .previous-names = Previously known as { $names }
+    .profile-display-name = Goes by { $display-name }
+    .profile-discord = Discord: { $handle }
+    .profile-gd-account = Geometry Dash: { $name }
 
     .option-international = International
 
@@ -2,6 +2,11 @@ submitter-banned = Banned
     .yes = Yes
     .no = No
 
+submitter-member = User account
+
+submitter-statistics = Submissions
+    .summary = { $submitted } submitted, { $approved } approved, { $rejected } rejected, { $deleted } deleted by video check
+
 ## Record submitter
 record-submission-panel = Submit Records
     .info = Note: Please do not submit nonsense, it only makes it harder for us all and will get you banned. Also note that the form rejects duplicate submissions.
@@ -86,4 +91,15 @@ submitter-idsearch-panel = Search submitter by ID
 
     .submit = Find by ID
 
-    .id-validator-valuemissing = Submitter ID required
\ No newline at end of file
+    .id-validator-valuemissing = Submitter ID required
+
+submission-bans = Submission Bans
+    .info = Bans prevent a user account, an IP address or a whole network (e.g. 2001:db8::/64) from submitting records, regardless of which submitters exist for them. Issuing a ban deletes all submissions covered by it that are still in the 'submitted' state.
+    .target = User ID, IP address or network:
+    .target-validator-valuemissing = Please specify what to ban
+    .submit = Ban
+    .submit-success = Ban issued!
+    .confirm-delete = Lift this ban?
+
+submission-ban-member = User #{ $member-id }
+submission-ban-since = (since { $banned-at })
@@ -1,10 +1,13 @@
 error-demonlist-malformedvideourl = Неправильная ссылка на видео
 error-demonlist-bannedfromsubmissions = Вы забанены в демонлисте!
 error-demonlist-claimunverified = Ваш запрос на присвоение профиля не подтвержден
-error-demonlist-vpsdetected = Была обнаружена попытка IP-геолокации через VPS
+error-demonlist-vpsdetected = Похоже, ваш запрос отправлен через VPN, прокси или дата-центр. Пожалуйста, отключите его и попробуйте снова
 error-demonlist-nothirdpartysubmissions = Этот игрок указал, что только он сам может отправлять свои рекорды
 error-demonlist-submitternotfound = Отправитель с id { $id } не был найден
+error-demonlist-submissionbannotfound = Бан отправок с id { $id } не был найден
+error-demonlist-pendingsubmissionnotfound = Ожидающая отправка с id { $id } не была найдена
 error-demonlist-notenotfound = Записка с id { $note-id } не была найдена на рекорде с id { $record-id }
+error-demonlist-evidencenotfound = Доказательство с id { $evidence-id } не было найдено на рекорде с id { $record-id }
 error-demonlist-creatornotfound = Игрок с id { $player-id } не является креатором демона с id { demon-id }
 error-demonlist-nationalitynotfound = Страна с iso-кодом { $iso-code } не была найдена
 error-demonlist-subdivisionnotfound = Регион с кодом { $subdivision-code } не был найден в стране { $nation-code }
@@ -15,10 +18,18 @@ error-demonlist-demonnotfoundname = Демон с именем { $demon-name } 
 error-demonlist-demonnotfoundposition = Демон на позиции { $demon-position } не был найден
 error-demonlist-recordnotfound = Рекорд с id { $record-id } не был найден
 error-demonlist-claimnotfound = Запрос пользователем { $member-id } на присвоение профиля { $player-id } не был найден
+error-demonlist-listnotfound = Список с id { $list-id } не был найден
+error-demonlist-mergenotfound = Слияние игроков с id { $id } не было найдено
+error-demonlist-profilenotfound = Профиль игрока с id { $player-id } не был найден
 error-demonlist-creatorexists = Этот игрок уже указан как креатор на этом демоне
 error-demonlist-duplicatevideo = Это видео уже используется рекордом #{ $record-id }
 error-demonlist-nonationset = Попытка установить регион без страны
 error-demonlist-conflictingclaims = Игроки '{ $player-1 }' и '{ $player-2 }' имеют подтвержденные присвоения разными пользователями pointercrate
+error-demonlist-listexists = Список с таким ID уже существует
+error-demonlist-recordlocked = Этот рекорд сейчас заблокирован для проверки пользователем { $reviewer }
+error-demonlist-mergeundone = Это слияние уже было отменено
+error-demonlist-mergeexpired = Это слияние слишком старое, чтобы его можно было отменить
+error-demonlist-mergesuperseded = Это слияние нельзя отменить, пока действует более позднее слияние этих игроков или пока имя объединённого игрока использует другой игрок
 error-demonlist-invalidrequirement = Требование к рекорду должно быть больше -1 и меньше 101
 error-demonlist-invalidposition = Позиция демона должна быть между 1 и { $maximal }
 error-demonlist-invalidprogress = Прогресс на рекорде должен находиться между { $requirement } и 100%!
@@ -33,8 +44,32 @@ error-demonlist-alreadyclaimed = Этот игрок уже имеет связ
 error-demonlist-rawrequired = Для отправки рекорда необходимо предоставить необработанную запись
 error-demonlist-malformedrawurl = Необработанная запись должна быть в виде правильно оформленной ссылки
 error-demonlist-invalidlevelid = ID уровня должен быть положительным
+error-demonlist-invalidlistid = ID списка может содержать только строчные буквы, цифры и одиночные дефисы, и быть не длиннее 32 символов
+error-demonlist-invalidlistsize = Основной список должен содержать хотя бы одного демона и не может быть больше расширенного списка
+error-demonlist-invalidtiers = Уровни должны покрывать каждую позицию ровно один раз, и только последний уровень может быть неограниченным. Каждому уровню нужны уникальный ID, название и одно из прав списка в качестве права на редактирование, а размеры списка должны совпадать с последней позицией какого-либо уровня
+error-demonlist-malformedevidenceurl = Ссылки на доказательства должны быть правильно оформленными ссылками
+error-demonlist-invalidbantarget = Бан должен быть направлен либо на аккаунт, либо на IP-адрес/сеть (например, 192.0.2.1 или 2001:db8::/64)
+error-demonlist-noverificationcode = Для этого присвоения не был выдан код подтверждения, или срок его действия истек. Пожалуйста, запросите новый код
+error-demonlist-gdaccountinuse = Этот аккаунт Geometry Dash уже связан с другим игроком
+error-demonlist-gdaccountnotfound = Не удалось найти аккаунт Geometry Dash с именем "{$player-name}"
+error-demonlist-claimverificationfailed = Код подтверждения не найден ни в комментариях, ни в профиле аккаунта Geometry Dash. Обратите внимание, что изменения могут появиться только через несколько минут
+error-demonlist-invalidprofilefield = Недопустимое значение поля профиля '{ $field }'. Отображаемое имя может содержать не более 50, а описание не более 1000 символов, ссылки на YouTube должны вести на канал, а имена в Twitch, Discord и Geometry Dash должны быть допустимыми именами пользователей
+error-demonlist-nationalityexists = Страна с таким кодом или названием уже существует
+error-demonlist-subdivisionexists = Регион с таким кодом или названием уже существует
+error-demonlist-invalidcountrycode = Код страны должен быть официально присвоенным кодом ISO 3166-1 alpha-2 (или XK для Косово)
+error-demonlist-invalidsubdivisioncode = Код региона должен быть частью кода ISO 3166-2 после кода страны и состоять из одного-трёх букв и цифр
+error-demonlist-invalidcreatorpart = Часть создателя должна начинаться раньше, чем заканчивается, и находиться в пределах от 0% до 100%
+error-demonlist-tagnotfound = Тег с id { $tag-id } не был найден
+error-demonlist-demontagnotfound = У демона с id { $demon-id } нет тега { $tag-id }
+error-demonlist-tagexists = Тег с таким ID уже существует
+error-demonlist-demontagexists = У этого демона уже есть этот тег
+error-demonlist-invalidtagid = ID тега может состоять только из строчных букв, цифр и одиночных дефисов, и быть не длиннее 32 символов
+error-demonlist-invalidtagname = У тега должно быть хотя бы одно название. Названия должны быть привязаны к коду языка (например, "ru") и быть не длиннее 32 символов
+error-demonlist-geometrydashunavailable = Не удалось связаться с серверами Geometry Dash. Пожалуйста, попробуйте позже
 
 error-demonlist-ratelimit-record-submit = Вы отправляете слишком много рекордов слишком часто!
 error-demonlist-ratelimit-record-submit-global = Слишком много рекордов отправляется на данный момент!
 error-demonlist-ratelimit-new-submitters = Ограничение запросов для DDoS-защиты
-error-demonlist-ratelimit-add-demon = Поаккуратнее с кнопкой бро
\ No newline at end of file
+error-demonlist-ratelimit-add-demon = Поаккуратнее с кнопкой бро
+error-demonlist-ratelimit-claim-verification = Пожалуйста, подождите несколько минут, прежде чем снова пытаться подтвердить присвоение
+error-demonlist-ratelimit-heatmap-png = Вы слишком часто запрашиваете изображения тепловой карты. Пожалуйста, подождите минуту, прежде чем пытаться снова
\ No newline at end of file
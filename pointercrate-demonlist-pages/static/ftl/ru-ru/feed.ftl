@@ -0,0 +1,13 @@
+## Atom feeds
+feed-author = Команда демонлиста
+
+feed-movements = Изменения в демонлисте
+    .added = { $demon } был добавлен в лист на #{ $position }
+    .moved-up = { $demon } был перемещён вверх с #{ $old-position } на #{ $new-position }
+    .moved-down = { $demon } был перемещён вниз с #{ $old-position } на #{ $new-position }
+
+feed-demon-records = Новые рекорды на { $demon }
+
+feed-player-records = Новые рекорды игрока { $player }
+
+feed-record = { $player } прошёл { $demon } на { $progress }%
@@ -51,17 +51,61 @@ claim-geolocate = Указать флаг для панели статистик
     .edit-success = Страна указана как { $nationality }
     .edit-success-subdivision = Страна указана как { $nationality }/{ $subdivision }
 
+claim-gd-verification = Подтвердить через Geometry Dash
+    .info = Подтвердите присвоение, доказав, что аккаунт Geometry Dash вашего профиля принадлежит вам: запросите код, затем опубликуйте его в комментарии на профиле этого аккаунта или укажите его в поле YouTube, Twitter или Twitch этого профиля. Коды действительны в течение 24 часов.
+    .code = Ваш код: { $code }
+    .request-code = Получить код
+    .submit = Подтвердить
+
+    .submit-success = Присвоение подтверждено!
+
 claim-lock-submissions = Ограничить отправку рекордов
     .info = Ограничивает отправку ваших рекордов другими игроками, что означает возможность отправки рекордов только при условии входа в аккаунт с подтвержденным присвоением профиля нужного игрока.
 
     .edit-success = Изменение успешно применено
 
+claim-third-party-approval = Одобрять рекорды от других игроков
+    .info = Действует только при ограничении отправки рекордов. Вместо отклонения рекордов, отправленных на ваш профиль другими игроками, они будут ожидать вашего одобрения. Команда листа видит только одобренные вами рекорды.
+
+claim-pending = Рекорды, ожидающие вашего одобрения
+    .info = Рекорды на вашем профиле, отправленные другими игроками. Одобренный рекорд отправляется на проверку команде листа как обычно, отклоненный рекорд удаляется.
+    .none = Нет рекордов, ожидающих вашего одобрения!
+
+    .accept-success = Рекорд одобрен
+    .decline-success = Рекорд отклонен
+
 claim-records = Рекорды на вашем профиле
     .info = Список рекордов на вашем присвоенном профиле, включая все возможные их статусы. Используйте этот список для отслеживания статуса ваших рекордов. Нажатие на рекорд покажет все публичные заметки, которые модераторы листа оставили к этому рекорду. Цвет заднего фона на каждом рекорде показывает, является ли рекорд { $record-approved-styled }, { $record-submitted-styled }, { $record-rejected-styled } или { $record-underconsideration-styled }.
 
     .record-notes = Заметки для рекорда { $record-id }:
     .record-notes-none = На данном рекорде отсутствуют публичные заметки!
 
+claim-profile = Профиль вашего присвоенного игрока
+    .info = Настройте профиль, который будет показан в статистике вашего присвоенного игрока. Изменения становятся видимыми только после проверки модератором. Оставьте поле пустым, чтобы удалить его из профиля.
+    .pending = Ваши последние изменения ожидают одобрения модератора.
+
+    .display-name = Отображаемое имя:
+    .bio = Описание:
+    .youtube = Канал на YouTube:
+    .twitch = Имя пользователя Twitch:
+    .discord = Имя пользователя Discord:
+    .gd-account = Имя аккаунта Geometry Dash:
+
+    .submit = Отправить на проверку
+    .submit-success = Ваши изменения отправлены на проверку!
+
+claim-profile-manager = Проверка профилей
+    .info = Ниже перечислены изменения профилей, внесенные владельцами присвоенных профилей. Одобренные изменения сразу становятся видимыми в статистике игрока. Используйте форму внизу, чтобы удалить профиль игрока, например, если на него поступила жалоба.
+    .none = Нет изменений профилей, ожидающих проверки!
+
+    .remove-player-id = ID игрока:
+    .remove-player-id-validator-valuemissing = Пожалуйста, введите ID игрока
+    .remove-submit = Удалить профиль
+
+    .approve-success = Изменения профиля одобрены
+    .reject-success = Изменения профиля отклонены
+    .delete-success = Профиль удален
+
 claim-manager = Менеджер присвоения
     .info-a = Здесь проходит работа с присвоением профилей через интерфейс ниже. Список сортируется по профилям и пользователям через панели справа. Неправильные запросы на присвоение должны удаляться через кнопку с мусоркой.
     .info-b = Для подтверждения присвоения нажмите на галочку. Удостоверьтесь, что запрос на присвоение правильный (для этого придется поговорить с игроком, профиль которого присваивается, и уточнить, сами ли они отправили запрос, либо же он принадлежит злоумышленнику).
@@ -6,7 +6,6 @@ record-rejected = Отклонен
 
 record-videolink = Ссылка на видео
 record-videoproof = Видео-доказательства
-record-rawfootage = Необработанная запись
 record-demon = Демон
 record-holder = Владелец рекорда
 record-progress = Прогресс
@@ -40,10 +39,36 @@ record-note-listed = Заметка #{ $note-id }
 
     .author = Эта заметка была оставлена { $author }.
     .author-submitter = Эта заметка была оставлена как комментарий от отправителя.
+    .author-automatic = Эта заметка была оставлена автоматически, так как отправка показалась подозрительной.
     .editors = Эту заметка позже отредактировали: { $editors }.
     .transferred = Эта заметка изначально не принадлежит этому рекорду.
     .public = Эта заметка является публичной.
 
+record-video-check = Проверка видео
+    .available = Существование видео было подтверждено { $checked-at }.
+    .missing = Видео было отмечено как отсутствующее { $checked-at }.
+    .inconclusive = Видео не удалось проверить автоматически { $checked-at }, его нужно проверить вручную.
+    .metadata = Видео называется '{ $title }' и было загружено { $channel }.
+
+record-evidence = Доказательства
+
+record-evidence-add = Добавить
+    .placeholder = Ссылка на доказательство
+    .public-checkbox = Публичная ссылка
+
+    .submit = Добавить
+
+record-evidence-kind = Доказательства
+    .raw-footage = Необработанная запись
+    .click-audio = Звук кликов
+    .second-angle = Второй ракурс
+
+record-evidence-listed = Доказательства
+    .confirm-delete = Это действие невозвратно удалит эту ссылку на доказательство. Продолжить?
+
+    .transferred = (перенесено с другого рекорда)
+    .public = (публичная)
+
 record-status-filter-panel = Фильтрация
     .info = Фильтрация по статусу рекордов
 
@@ -120,4 +145,25 @@ record-manager-help = Работа с рекордами
 
     .note-a = Если игрок забанен, им запрещено иметь рекорды со статусом { record-approved } либо { record-submitted } в листе. Все рекорды, помеченные как '{ record-submitted }' будут удалены, все остальные поменяют статус на '{ record-rejected }'.
 
-    .note-b = Бан отправителя приведет к удалению всех их рекордов со статусом '{ record-submitted }'. Отправленные ими рекорды, которые уже поменяли статус на { record-approved } либо { record-rejected } не будут затронуты.
\ No newline at end of file
+    .note-b = Бан отправителя приведет к удалению всех их рекордов со статусом '{ record-submitted }'. Отправленные ими рекорды, которые уже поменяли статус на { record-approved } либо { record-rejected } не будут затронуты.
+
+## Moderation statistics tab (user area)
+moderation-stats = Модерация
+moderation-stats-panel = Статистика модерации
+    .info = Количество рекордов, статус которых каждый участник команды списка изменил за выбранный период. Медианное время решения отсчитывается от отправки рекорда до его принятия или отклонения.
+    .window = Период:
+    .window-7 = Последние 7 дней
+    .window-30 = Последние 30 дней
+    .window-90 = Последние 90 дней
+    .window-365 = Последний год
+    .none = За выбранный период ни один рекорд не изменил статус
+
+moderation-stats-reviewer = Участник команды
+moderation-stats-median = Медианное время решения
+moderation-stats-hours = { $hours } ч.
+moderation-stats-days = { $days } дн.
+
+review-queue = Очередь проверки
+    .info = Количество рекордов, ожидающих проверки, в каждом уровне каждого списка
+
+review-queue-tier = Уровень
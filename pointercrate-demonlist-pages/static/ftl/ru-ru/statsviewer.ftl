@@ -1,6 +1,7 @@
 statsviewer = Панель статистики
     .rank = Позиция в демонлисте
     .score = Очки демонлиста
+    .rank-delta = Изменение позиции за последние 30 дней
     .stats = Статистика в демонлисте
     .hardest = Сложнейший демон
 
@@ -19,6 +20,10 @@ statsviewer = Панель статистики
 
 statsviewer-individual = Игроки
     .welcome = Нажмите на имя игрока слева для начала работы!
+    .previous-names = Прежние имена: { $names }
+    .profile-display-name = Предпочитает имя { $display-name }
+    .profile-discord = Discord: { $handle }
+    .profile-gd-account = Geometry Dash: { $name }
 
     .option-international = Международная
 
@@ -2,6 +2,11 @@ submitter-banned = Забанен
     .yes = Да
     .no = Нет
 
+submitter-member = Аккаунт
+
+submitter-statistics = Отправки
+    .summary = { $submitted } отправлено, { $approved } принято, { $rejected } отклонено, { $deleted } удалено проверкой видео
+
 ## Record submitter
 record-submission-panel = Отправление рекордов
     .info = П/С: Пожалуйста, не отправляйте всякую чушь, это лишь усложняет нам работу и приведет к бану. Также учтите, что форма отклоняет дубликаты уже существующих рекордов.
@@ -86,4 +91,15 @@ submitter-idsearch-panel = Найти отправителя по ID
 
     .submit = Найти по ID
 
-    .id-validator-valuemissing = Требуется ID отправителя
\ No newline at end of file
+    .id-validator-valuemissing = Требуется ID отправителя
+
+submission-bans = Баны отправок
+    .info = Баны запрещают аккаунту, IP-адресу или целой сети (например, 2001:db8::/64) отправлять рекорды, независимо от того, какие отправители для них существуют. Бан удаляет все попадающие под него отправки, которые всё ещё находятся в состоянии 'отправлено'.
+    .target = ID пользователя, IP-адрес или сеть:
+    .target-validator-valuemissing = Пожалуйста, укажите, что забанить
+    .submit = Забанить
+    .submit-success = Бан выдан!
+    .confirm-delete = Снять этот бан?
+
+submission-ban-member = Пользователь #{ $member-id }
+submission-ban-since = (с { $banned-at })
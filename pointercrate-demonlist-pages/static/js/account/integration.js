@@ -2,11 +2,13 @@ import {
   del,
   displayError,
   FilteredPaginator,
+  Form,
   Output,
   patch,
   post,
   put,
   get,
+  valueMissing,
 } from "/static/core/js/modules/form.js";
 import {
   embedVideo,
@@ -228,6 +230,45 @@ export function initialize() {
     playerPaginator.html.parentElement.style.display = "block";
   });
 
+  let verificationPanel = document.getElementById("claims-gd-verification");
+
+  if (verificationPanel) {
+    let output = new Output(verificationPanel);
+    let claimUrl =
+      "/api/v1/players/" +
+      claimedPlayer.dataset.id +
+      "/claims/" +
+      window.userId +
+      "/";
+
+    document
+      .getElementById("claims-gd-request-code")
+      .addEventListener("click", () => {
+        post(claimUrl + "verification-code/")
+          .then((response) => {
+            output.setSuccess(
+              trp("demonlist", "player", "claim-gd-verification.code", {
+                ["code"]: response.data.code,
+              })
+            );
+          })
+          .catch(displayError(output));
+      });
+
+    document
+      .getElementById("claims-gd-verify")
+      .addEventListener("click", () => {
+        post(claimUrl + "verify/")
+          .then(() => {
+            output.setSuccess(
+              tr("demonlist", "player", "claim-gd-verification.submit-success")
+            );
+            window.location.reload();
+          })
+          .catch(displayError(output));
+      });
+  }
+
   let claimPanel = document.getElementById("claims-claim-panel");
 
   if (claimPanel) {
@@ -284,7 +325,277 @@ export function initialize() {
         .catch(displayError(output));
     });
 
+    let thirdPartyApprovalCheckbox = document.getElementById(
+      "third-party-approval-checkbox"
+    );
+    thirdPartyApprovalCheckbox.addEventListener("change", () => {
+      patch(
+        "/api/v1/players/" + playerId + "/claims/" + window.userId + "/",
+        {},
+        { third_party_approval: thirdPartyApprovalCheckbox.checked }
+      )
+        .then((_) => {
+          output.setSuccess(
+            tr("demonlist", "player", "claim-lock-submissions.edit-success")
+          );
+        })
+        .catch(displayError(output));
+    });
+
     let recordPaginator = new ClaimedPlayerRecordPaginator(playerId);
     recordPaginator.initialize();
   }
+
+  let pendingPanel = document.getElementById("claims-pending-panel");
+
+  if (pendingPanel) {
+    let output = new Output(pendingPanel);
+    let list = document.getElementById("claims-pending-list");
+
+    let refresh = () =>
+      get("/api/v1/records/pending/")
+        .then((response) => {
+          list.replaceChildren(
+            ...response.data.map((submission) =>
+              generatePendingSubmission(submission, output, refresh)
+            )
+          );
+
+          if (response.data.length === 0) {
+            let li = document.createElement("li");
+            li.innerText = tr("demonlist", "player", "claim-pending.none");
+            list.appendChild(li);
+          }
+        })
+        .catch(displayError(output));
+
+    refresh();
+  }
+
+  if (document.getElementById("claim-profile-form")) {
+    setupProfileForm();
+  }
+
+  if (document.getElementById("claim-profile-manager")) {
+    setupProfileManager();
+  }
+}
+
+const PROFILE_FIELDS = {
+  "claim-profile-display-name": "display_name",
+  "claim-profile-bio": "bio",
+  "claim-profile-youtube": "youtube",
+  "claim-profile-twitch": "twitch",
+  "claim-profile-discord": "discord",
+  "claim-profile-gd-account": "gd_account",
+};
+
+function setupProfileForm() {
+  let form = new Form(document.getElementById("claim-profile-form"));
+  let pendingNotice = document.getElementById("claim-profile-pending");
+
+  let fill = (profile) => {
+    for (let [inputId, field] of Object.entries(PROFILE_FIELDS)) {
+      form.input(inputId).value = (profile && profile[field]) || "";
+    }
+  };
+
+  get("/api/v1/players/me/profile/")
+    .then((response) => {
+      let pending = response.data.pending;
+
+      fill(pending ? pending.profile : response.data.profile);
+      pendingNotice.style.display = pending ? "block" : "none";
+    })
+    .catch(displayError(form));
+
+  form.onSubmit(() => {
+    patch("/api/v1/players/me/profile/", {}, form.serialize())
+      .then((response) => {
+        fill(response.data.profile);
+        pendingNotice.style.display = "block";
+        form.setSuccess(
+          tr("demonlist", "player", "claim-profile.submit-success")
+        );
+      })
+      .catch(displayError(form));
+  });
+}
+
+function setupProfileManager() {
+  let output = new Output(document.getElementById("claim-profile-manager"));
+  let list = document.getElementById("claim-profile-manager-list");
+
+  let refresh = () =>
+    get("/api/v1/players/profiles/pending/")
+      .then((response) => {
+        list.replaceChildren(
+          ...response.data.map((pending) =>
+            generatePendingProfile(pending, output, refresh)
+          )
+        );
+
+        if (response.data.length === 0) {
+          let li = document.createElement("li");
+          li.innerText = tr(
+            "demonlist",
+            "player",
+            "claim-profile-manager.none"
+          );
+          list.appendChild(li);
+        }
+      })
+      .catch(displayError(output));
+
+  refresh();
+
+  let removeForm = new Form(
+    document.getElementById("claim-profile-remove-form")
+  );
+  let playerId = removeForm.input("claim-profile-remove-player-id");
+
+  playerId.addValidator(
+    valueMissing,
+    tr(
+      "demonlist",
+      "player",
+      "claim-profile-manager.remove-player-id-validator-valuemissing"
+    )
+  );
+
+  removeForm.onSubmit(() => {
+    del("/api/v1/players/" + playerId.value + "/profile/")
+      .then(() => {
+        removeForm.setSuccess(
+          tr("demonlist", "player", "claim-profile-manager.delete-success")
+        );
+      })
+      .catch(displayError(removeForm));
+  });
+}
+
+function generatePendingProfile(pending, output, refresh) {
+  let li = document.createElement("li");
+
+  li.classList.add("flex", "no-stretch");
+  li.style.justifyContent = "space-between";
+
+  let leftDiv = document.createElement("div");
+  let title = document.createElement("b");
+
+  title.innerText = pending.player.name + " (" + pending.player.id + ")";
+  leftDiv.appendChild(title);
+
+  for (let field of Object.values(PROFILE_FIELDS)) {
+    let value = pending.profile[field];
+
+    if (value) {
+      let line = document.createElement("span");
+      line.style.whiteSpace = "pre-line";
+      line.innerText = field + ": " + value;
+
+      leftDiv.appendChild(document.createElement("br"));
+      leftDiv.appendChild(line);
+    }
+  }
+
+  li.appendChild(leftDiv);
+
+  let rightDiv = document.createElement("div");
+  rightDiv.classList.add("flex");
+
+  let approveButton = makeButton("check");
+  approveButton.style.marginRight = "5px";
+
+  approveButton.addEventListener("click", () => {
+    post("/api/v1/players/" + pending.player.id + "/profile/approve/")
+      .then(() => {
+        output.setSuccess(
+          tr("demonlist", "player", "claim-profile-manager.approve-success")
+        );
+        refresh();
+      })
+      .catch(displayError(output));
+  });
+
+  let rejectButton = makeButton("trash-alt");
+
+  rejectButton.addEventListener("click", () => {
+    post("/api/v1/players/" + pending.player.id + "/profile/reject/")
+      .then(() => {
+        output.setSuccess(
+          tr("demonlist", "player", "claim-profile-manager.reject-success")
+        );
+        refresh();
+      })
+      .catch(displayError(output));
+  });
+
+  rightDiv.appendChild(approveButton);
+  rightDiv.appendChild(rejectButton);
+  li.appendChild(rightDiv);
+
+  return li;
+}
+
+function generatePendingSubmission(submission, output, refresh) {
+  let li = document.createElement("li");
+
+  li.classList.add("flex", "no-stretch");
+  li.style.justifyContent = "space-between";
+
+  let leftDiv = document.createElement("div");
+  let title = document.createElement("b");
+
+  title.innerText = submission.demon.name + " - " + submission.progress + "%";
+  leftDiv.appendChild(title);
+
+  if (submission.video) {
+    let video = document.createElement("a");
+
+    video.href = submission.video;
+    video.target = "_blank";
+    video.innerText = submission.video;
+
+    leftDiv.appendChild(document.createElement("br"));
+    leftDiv.appendChild(video);
+  }
+
+  li.appendChild(leftDiv);
+
+  let rightDiv = document.createElement("div");
+  rightDiv.classList.add("flex");
+
+  let acceptButton = makeButton("check");
+  acceptButton.style.marginRight = "5px";
+
+  acceptButton.addEventListener("click", () => {
+    post("/api/v1/records/pending/" + submission.id + "/accept/")
+      .then(() => {
+        output.setSuccess(
+          tr("demonlist", "player", "claim-pending.accept-success")
+        );
+        refresh();
+      })
+      .catch(displayError(output));
+  });
+
+  let declineButton = makeButton("trash-alt");
+
+  declineButton.addEventListener("click", () => {
+    post("/api/v1/records/pending/" + submission.id + "/decline/")
+      .then(() => {
+        output.setSuccess(
+          tr("demonlist", "player", "claim-pending.decline-success")
+        );
+        refresh();
+      })
+      .catch(displayError(output));
+  });
+
+  rightDiv.appendChild(acceptButton);
+  rightDiv.appendChild(declineButton);
+  li.appendChild(rightDiv);
+
+  return li;
 }
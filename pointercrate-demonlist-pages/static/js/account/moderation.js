@@ -0,0 +1,104 @@
+import {
+  displayError,
+  Dropdown,
+  get,
+  Output,
+} from "/static/core/js/modules/form.js";
+import { tr, trp } from "/static/core/js/modules/localization.js";
+
+function formatDuration(seconds) {
+  if (seconds === null || seconds === undefined) {
+    return "-";
+  }
+
+  let hours = seconds / 3600;
+
+  if (hours < 48) {
+    return trp("demonlist", "record", "moderation-stats-hours", {
+      ["hours"]: hours.toFixed(1),
+    });
+  }
+
+  return trp("demonlist", "record", "moderation-stats-days", {
+    ["days"]: (hours / 24).toFixed(1),
+  });
+}
+
+function generateRow(cells) {
+  let row = document.createElement("tr");
+
+  for (let cell of cells) {
+    let td = document.createElement("td");
+    td.innerText = cell;
+    row.appendChild(td);
+  }
+
+  return row;
+}
+
+class ModerationStatsViewer extends Output {
+  constructor() {
+    super(document.getElementById("moderation-stats"));
+
+    this.reviewers = document.getElementById("moderation-stats-reviewers");
+    this.queue = document.getElementById("review-queue-tiers");
+
+    this.window = new Dropdown(
+      document.getElementById("moderation-stats-window")
+    );
+    this.window.addEventListener((days) => this.load(days));
+  }
+
+  load(days) {
+    let after = new Date(Date.now() - days * 24 * 60 * 60 * 1000);
+
+    // The API expects naive UTC timestamps
+    let query = after.toISOString().slice(0, 19);
+
+    return get("/api/v1/records/stats/?after=" + query)
+      .then((response) => {
+        this.setError(null);
+        this.render(response.data);
+      })
+      .catch(displayError(this));
+  }
+
+  render(stats) {
+    this.reviewers.innerHTML = "";
+    this.queue.innerHTML = "";
+
+    if (stats.reviewers.length === 0) {
+      let row = generateRow([
+        tr("demonlist", "record", "moderation-stats-panel.none"),
+      ]);
+      row.firstChild.colSpan = 5;
+      this.reviewers.appendChild(row);
+    }
+
+    for (let reviewer of stats.reviewers) {
+      this.reviewers.appendChild(
+        generateRow([
+          reviewer.user.name || "#" + reviewer.user.id,
+          reviewer.approvals,
+          reviewer.rejections,
+          reviewer.under_consideration,
+          formatDuration(reviewer.median_decision_seconds),
+        ])
+      );
+    }
+
+    for (let tier of stats.queue) {
+      this.queue.appendChild(
+        generateRow([
+          tier.list + " / " + tier.tier,
+          tier.submitted,
+          tier.under_consideration,
+        ])
+      );
+    }
+  }
+}
+
+export function initialize() {
+  new ModerationStatsViewer().load(30);
+}
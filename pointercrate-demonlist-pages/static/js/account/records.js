@@ -43,7 +43,8 @@ class RecordManager extends Paginator {
 
     this._video = document.getElementById("record-video");
     this._video_link = document.getElementById("record-video-link");
-    this._raw_footage_link = document.getElementById("record-raw-footage-link");
+    this._evidence = document.getElementById("record-evidence");
+    this._video_check = document.getElementById("record-video-check");
     this._id = document.getElementById("record-id");
     this._demon = document.getElementById("record-demon");
     this._holder = document.getElementById("record-holder");
@@ -207,12 +208,37 @@ class RecordManager extends Paginator {
       this._video_link.style.display = "none";
     }
 
-    if (this.currentObject.raw_footage !== undefined) {
-      this._raw_footage_link.href = this.currentObject.raw_footage;
-      this._raw_footage_link.innerText = this.currentObject.raw_footage;
-      this._raw_footage_link.style.display = "initial";
+    if (this.currentObject.video_check) {
+      this._video_check.innerText = trp(
+        "demonlist",
+        "record",
+        "record-video-check." + this.currentObject.video_check.result,
+        {
+          ["checked-at"]: this.currentObject.video_check.checked_at,
+        }
+      );
+
+      let check = this.currentObject.video_check;
+
+      if (check.title !== null && check.channel !== null) {
+        this._video_check.innerText +=
+          " " +
+          trp("demonlist", "record", "record-video-check.metadata", {
+            ["title"]: check.title,
+            ["channel"]: check.channel,
+          });
+      }
     } else {
-      this._raw_footage_link.style.display = "none";
+      this._video_check.innerText = "";
+    }
+
+    // clear evidence
+    while (this._evidence.firstChild) {
+      this._evidence.removeChild(this._evidence.firstChild);
+    }
+
+    for (let evidence of this.currentObject.evidence || []) {
+      this._evidence.appendChild(createEvidenceHtml(evidence));
     }
 
     this._id.innerText = this.currentObject.id;
@@ -225,7 +251,17 @@ class RecordManager extends Paginator {
       ")";
     this._status.selectSilently(this.currentObject.status);
     this._progress.innerText = this.currentObject.progress + "%";
-    this._submitter.innerText = this.currentObject.submitter.id;
+    this._submitter.innerText =
+      this.currentObject.submitter.id +
+      " (" +
+      trp("demonlist", "submitter", "submitter-statistics.summary", {
+        ["submitted"]: this.currentObject.submitter.statistics.submitted,
+        ["approved"]: this.currentObject.submitter.statistics.approved,
+        ["rejected"]: this.currentObject.submitter.statistics.rejected,
+        ["deleted"]:
+          this.currentObject.submitter.statistics.deleted_by_video_check,
+      }) +
+      ")";
 
     // this is introducing race conditions. Oh well.
     return get("/api/v1/records/" + this.currentObject.id + "/notes/").then(
@@ -290,7 +326,13 @@ function createNoteHtml(note) {
   furtherInfo.style.fontSize = "80%";
   furtherInfo.style.textAlign = "right";
 
-  if (note.author === null) {
+  if (note.is_automatic) {
+    furtherInfo.innerText = tr(
+      "demonlist",
+      "record",
+      "record-note-listed.author-automatic"
+    );
+  } else if (note.author === null) {
     furtherInfo.innerText = tr(
       "demonlist",
       "record",
@@ -333,6 +375,98 @@ function createNoteHtml(note) {
   return noteDiv;
 }
 
+function createEvidenceHtml(evidence) {
+  let evidenceDiv = document.createElement("div");
+
+  let closeX = document.createElement("span");
+  closeX.classList.add("hover");
+  closeX.classList.add("plus");
+  closeX.classList.add("cross");
+
+  closeX.style.transform = "scale(0.75)";
+
+  closeX.addEventListener("click", () => {
+    if (
+      confirm(tr("demonlist", "record", "record-evidence-listed.confirm-delete"))
+    ) {
+      del(
+        "/api/v1/records/" +
+          recordManager.currentObject.id +
+          "/evidence/" +
+          evidence.id +
+          "/"
+      ).then(() => evidenceDiv.parentElement.removeChild(evidenceDiv));
+    }
+  });
+
+  let kind = document.createElement("b");
+  kind.innerText =
+    tr(
+      "demonlist",
+      "record",
+      "record-evidence-kind." + evidence.kind.replace("_", "-")
+    ) + ": ";
+
+  let link = document.createElement("a");
+  link.classList.add("link");
+  link.target = "_blank";
+  link.href = evidence.url;
+  link.innerText = evidence.url;
+
+  let furtherInfo = document.createElement("i");
+  furtherInfo.style.fontSize = "80%";
+  furtherInfo.innerText = " ";
+
+  if (evidence.transferred) {
+    furtherInfo.innerText +=
+      tr("demonlist", "record", "record-evidence-listed.transferred") + " ";
+  }
+
+  if (evidence.is_public) {
+    furtherInfo.innerText +=
+      tr("demonlist", "record", "record-evidence-listed.public") + " ";
+  }
+
+  evidenceDiv.appendChild(closeX);
+  evidenceDiv.appendChild(kind);
+  evidenceDiv.appendChild(link);
+  evidenceDiv.appendChild(furtherInfo);
+
+  return evidenceDiv;
+}
+
+function setupAddEvidence() {
+  let adder = document.getElementById("add-record-evidence");
+  let output = new Output(adder);
+  let kind = new Dropdown(document.getElementById("add-evidence-kind"));
+  let url = document.getElementById("add-evidence-url");
+  let add = adder.getElementsByClassName("button")[0];
+  let isPublic = document.getElementById("add-evidence-is-public-checkbox");
+
+  add.addEventListener("click", () => {
+    post(
+      "/api/v1/records/" + recordManager.currentObject.id + "/evidence/",
+      {},
+      { kind: kind.selected, url: url.value, is_public: isPublic.checked }
+    )
+      .then((response) => {
+        recordManager._evidence.appendChild(
+          createEvidenceHtml(response.data.data)
+        );
+
+        $(adder).hide(100);
+        url.value = "";
+      })
+      .catch(displayError(output));
+  });
+
+  document
+    .getElementById("add-record-evidence-open")
+    .addEventListener("click", () => {
+      $(adder).show(300);
+    });
+}
+
 function setupAddNote() {
   let adder = document.getElementById("add-record-note");
   let output = new Output(adder);
@@ -445,6 +579,7 @@ export function initialize() {
   setupRecordFilterPlayerIdForm();
   setupRecordFilterPlayerNameForm();
   setupAddNote();
+  setupAddEvidence();
   setupEditRecordForm();
   setupRecordSearchRecordIdForm();
 
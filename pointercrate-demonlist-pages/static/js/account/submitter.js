@@ -1,6 +1,9 @@
 import {
+  del,
   displayError,
   Form,
+  get,
+  post,
   Viewer,
   valueMissing,
   Paginator,
@@ -32,6 +35,15 @@ function generateSubmitter(submitter) {
   return li;
 }
 
+function formatStatistics(statistics) {
+  return trp("demonlist", "submitter", "submitter-statistics.summary", {
+    ["submitted"]: statistics.submitted,
+    ["approved"]: statistics.approved,
+    ["rejected"]: statistics.rejected,
+    ["deleted"]: statistics.deleted_by_video_check,
+  });
+}
+
 class SubmitterManager extends Paginator {
   constructor() {
     super("submitter-pagination", {}, generateSubmitter);
@@ -42,6 +54,8 @@ class SubmitterManager extends Paginator {
     );
 
     this._id = document.getElementById("submitter-submitter-id");
+    this._member = document.getElementById("submitter-member");
+    this._statistics = document.getElementById("submitter-statistics");
     this._banned = setupDropdownEditor(
       new PaginatorEditorBackend(this, true),
       "edit-submitter-banned",
@@ -60,6 +74,13 @@ class SubmitterManager extends Paginator {
 
     this._id.innerText = this.currentObject.id;
     this._banned.selectSilently(this.currentObject.banned.toString());
+    this._member.innerText =
+      this.currentObject.member_id === null
+        ? "-"
+        : this.currentObject.member_id;
+    this._statistics.innerText = formatStatistics(
+      this.currentObject.statistics
+    );
   }
 }
 
@@ -86,8 +107,88 @@ function setupSubmitterSearchSubmitterIdForm() {
   });
 }
 
+function createBanHtml(ban) {
+  let li = document.createElement("li");
+  li.classList.add("white");
+
+  let closeX = document.createElement("span");
+  closeX.classList.add("hover");
+  closeX.classList.add("plus");
+  closeX.classList.add("cross");
+  closeX.style.transform = "scale(0.75)";
+
+  closeX.addEventListener("click", () => {
+    if (
+      confirm(tr("demonlist", "submitter", "submission-bans.confirm-delete"))
+    ) {
+      del("/api/v1/submitters/bans/" + ban.id + "/").then(() =>
+        li.parentElement.removeChild(li)
+      );
+    }
+  });
+
+  let b = document.createElement("b");
+
+  if (ban.member_id !== null) {
+    b.innerText = trp("demonlist", "submitter", "submission-ban-member", {
+      ["member-id"]: ban.member_id,
+    });
+  } else {
+    b.innerText = ban.network;
+  }
+
+  let i = document.createElement("i");
+  i.innerText =
+    " " +
+    trp("demonlist", "submitter", "submission-ban-since", {
+      ["banned-at"]: ban.banned_at,
+    });
+
+  li.appendChild(closeX);
+  li.appendChild(b);
+  li.appendChild(i);
+
+  return li;
+}
+
+function setupSubmissionBans() {
+  let list = document.getElementById("submission-bans-list");
+  let banForm = new Form(document.getElementById("submission-ban-form"));
+  let target = banForm.input("submission-ban-target");
+
+  target.addValidator(
+    valueMissing,
+    tr("demonlist", "submitter", "submission-bans.target-validator-valuemissing")
+  );
+
+  get("/api/v1/submitters/bans/")
+    .then((response) => {
+      for (let ban of response.data) {
+        list.appendChild(createBanHtml(ban));
+      }
+    })
+    .catch(displayError(banForm));
+
+  banForm.onSubmit(() => {
+    // Plain numbers are user ids, everything else is an IP address or network
+    let data = /^[0-9]+$/.test(target.value)
+      ? { member_id: parseInt(target.value) }
+      : { network: target.value };
+
+    post("/api/v1/submitters/bans/", {}, data)
+      .then((response) => {
+        list.appendChild(createBanHtml(response.data.data));
+        banForm.setSuccess(
+          tr("demonlist", "submitter", "submission-bans.submit-success")
+        );
+      })
+      .catch(displayError(banForm));
+  });
+}
+
 export function initialize(tabber) {
   setupSubmitterSearchSubmitterIdForm();
+  setupSubmissionBans();
 
   submitterManager = new SubmitterManager();
   submitterManager.initialize();
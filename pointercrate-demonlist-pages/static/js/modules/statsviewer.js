@@ -135,6 +135,42 @@ export class StatsViewer extends FilteredPaginator {
     }
   }
 
+  /**
+   * Appends the change in rank over the last 30 days to the rank display
+   *
+   * @param {string} historyEndpoint The endpoint from which to retrieve the score history of the currently selected entry
+   * @param {number|null} currentRank The current rank of the currently selected entry
+   */
+  setRankDelta(historyEndpoint, currentRank) {
+    // Make sure we do not display the delta of some previously selected entry whose request took longer
+    let request = (this._rankDeltaRequest = (this._rankDeltaRequest || 0) + 1);
+
+    get(historyEndpoint).then((response) => {
+      if (request !== this._rankDeltaRequest || !currentRank) return;
+
+      let monthAgo = new Date();
+      monthAgo.setDate(monthAgo.getDate() - 30);
+
+      // Snapshots are only taken when something changes, so the rank 30 days ago is the one from the last snapshot before then.
+      // Timestamps are UTC.
+      let past = response.data
+        .filter((snapshot) => new Date(snapshot.time + "Z") < monthAgo)
+        .pop();
+
+      if (past === undefined || past.rank === null || past.rank === currentRank)
+        return;
+
+      let delta = past.rank - currentRank;
+      let span = document.createElement("span");
+
+      span.title = tr("demonlist", "statsviewer", "statsviewer.rank-delta");
+      span.style.color = delta > 0 ? "green" : "red";
+      span.innerText = (delta > 0 ? " ▲" : " ▼") + Math.abs(delta);
+
+      this._rank.appendChild(span);
+    });
+  }
+
   setHardest(hardest) {
     if (this._hardest.lastChild)
       this._hardest.removeChild(this._hardest.lastChild);
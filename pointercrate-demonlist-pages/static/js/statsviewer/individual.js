@@ -1,4 +1,4 @@
-import { tr } from "/static/core/js/modules/localization.js";
+import { tr, trp } from "/static/core/js/modules/localization.js";
 import { displayError, Dropdown, get } from "/static/core/js/modules/form.js";
 import {
   getCountryFlag,
@@ -25,9 +25,15 @@ class IndividualStatsViewer extends StatsViewer {
     var playerData = response.data.data;
 
     this._rank.innerText = playerData.rank || "-";
+    this.setRankDelta(
+      "/api/v1/players/" + playerData.id + "/history/",
+      playerData.rank
+    );
     this._score.innerText = playerData.score.toFixed(2);
 
     this.setName(playerData.name, playerData.nationality);
+    this.setPreviousNames(playerData.previous_names);
+    this.setProfile(playerData.profile);
 
     const selectedSort = this.demonSortingModeDropdown.selected;
 
@@ -127,6 +133,114 @@ class IndividualStatsViewer extends StatsViewer {
     });
   }
 
+  setPreviousNames(previousNames) {
+    let element = document.getElementById("player-previous-names");
+
+    if (previousNames.length === 0) {
+      element.style.display = "none";
+    } else {
+      element.textContent = trp(
+        "demonlist",
+        "statsviewer",
+        "statsviewer-individual.previous-names",
+        {
+          ["names"]: previousNames.join(", "),
+        }
+      );
+      element.style.display = "";
+    }
+  }
+
+  setProfile(profile) {
+    let element = document.getElementById("player-profile");
+
+    while (element.lastChild) {
+      element.removeChild(element.lastChild);
+    }
+
+    if (!profile) {
+      element.style.display = "none";
+      return;
+    }
+
+    if (profile.display_name) {
+      let displayName = document.createElement("i");
+      displayName.textContent = trp(
+        "demonlist",
+        "statsviewer",
+        "statsviewer-individual.profile-display-name",
+        {
+          ["display-name"]: profile.display_name,
+        }
+      );
+      element.appendChild(displayName);
+    }
+
+    if (profile.bio) {
+      let bio = document.createElement("p");
+      bio.style.whiteSpace = "pre-line";
+      bio.textContent = profile.bio;
+      element.appendChild(bio);
+    }
+
+    let links = [];
+
+    if (profile.youtube) {
+      links.push(createLink("YouTube", profile.youtube));
+    }
+
+    if (profile.twitch) {
+      links.push(
+        createLink("Twitch", "https://www.twitch.tv/" + profile.twitch)
+      );
+    }
+
+    if (profile.discord) {
+      links.push(
+        document.createTextNode(
+          trp(
+            "demonlist",
+            "statsviewer",
+            "statsviewer-individual.profile-discord",
+            {
+              ["handle"]: profile.discord,
+            }
+          )
+        )
+      );
+    }
+
+    if (profile.gd_account) {
+      links.push(
+        document.createTextNode(
+          trp(
+            "demonlist",
+            "statsviewer",
+            "statsviewer-individual.profile-gd-account",
+            {
+              ["name"]: profile.gd_account,
+            }
+          )
+        )
+      );
+    }
+
+    if (links.length !== 0) {
+      let linkContainer = document.createElement("div");
+
+      links.forEach((link, index) => {
+        if (index !== 0) {
+          linkContainer.appendChild(document.createTextNode(" | "));
+        }
+        linkContainer.appendChild(link);
+      });
+
+      element.appendChild(linkContainer);
+    }
+
+    element.style.display = "";
+  }
+
   formatDemonsInto(element, demons) {
     formatInto(
       element,
@@ -285,3 +399,15 @@ function generateStatsViewerPlayer(player) {
 
   return li;
 }
+
+function createLink(text, href) {
+  let a = document.createElement("a");
+
+  a.className = "link";
+  a.href = href;
+  a.target = "_blank";
+  a.rel = "noopener noreferrer";
+  a.textContent = text;
+
+  return a;
+}
@@ -23,6 +23,10 @@ class NationStatsViewer extends StatsViewer {
     super.onReceive(response);
 
     this._rank.innerText = this.currentlySelected.dataset.rank;
+    this.setRankDelta(
+      "/api/v1/nationalities/" + this.currentlySelected.dataset.id + "/history/",
+      parseInt(this.currentlySelected.dataset.rank)
+    );
     this._score.innerHTML =
       this.currentlySelected.getElementsByTagName("i")[0].innerHTML;
 
@@ -0,0 +1,34 @@
+-- One row per nation (with subdivision NULL) and one row per subdivision that has a non-zero value for the given metric ($2) on the given list ($1)
+SELECT nation AS "nation!", subdivision, value AS "value!"
+FROM (
+    SELECT nation, NULL::VARCHAR AS subdivision, score AS value
+    FROM nation_scores
+    WHERE list = $1 AND $2 = 'score'
+
+    UNION ALL
+
+    SELECT nation, subdivision, score
+    FROM subdivision_scores
+    WHERE list = $1 AND $2 = 'score'
+
+    UNION ALL
+
+    SELECT players.nationality, players.subdivision, COUNT(*)::FLOAT8
+    FROM player_scores
+    INNER JOIN players ON players.id = player_scores.player
+    WHERE player_scores.list = $1 AND $2 = 'players' AND player_scores.score > 0 AND NOT players.banned AND players.nationality IS NOT NULL
+    GROUP BY GROUPING SETS ((players.nationality), (players.nationality, players.subdivision))
+    HAVING GROUPING(players.subdivision) = 1 OR players.subdivision IS NOT NULL
+
+    UNION ALL
+
+    SELECT players.nationality, players.subdivision, COUNT(*)::FLOAT8
+    FROM records
+    INNER JOIN players ON players.id = records.player
+    INNER JOIN demons ON demons.id = records.demon
+    WHERE demons.list = $1 AND $2 = 'records' AND records.status_ = 'APPROVED' AND NOT players.banned AND players.nationality IS NOT NULL
+    GROUP BY GROUPING SETS ((players.nationality), (players.nationality, players.subdivision))
+    HAVING GROUPING(players.subdivision) = 1 OR players.subdivision IS NOT NULL
+) heatmap
+WHERE value > 0
+ORDER BY value DESC
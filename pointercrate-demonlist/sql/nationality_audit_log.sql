@@ -0,0 +1,26 @@
+SELECT entries.time AS "time!",
+       entries.audit_id AS "audit_id!",
+       entries.userid AS "userid!",
+       members.name AS "username?",
+       entries.subdivision,
+       entries.kind AS "kind!",
+       entries.name,
+       entries.continent
+FROM (
+    SELECT time, audit_id, userid, NULL::TEXT AS subdivision, 'addition' AS kind, NULL::TEXT AS name, NULL::TEXT AS continent
+    FROM nationality_additions WHERE iso_country_code = $1
+    UNION ALL
+    SELECT time, audit_id, userid, NULL, 'modification', nation::TEXT, continent::TEXT
+    FROM nationality_modifications WHERE iso_country_code = $1
+    UNION ALL
+    SELECT time, audit_id, userid, iso_code::TEXT, 'addition', NULL, NULL
+    FROM subdivision_additions WHERE nation = $1
+    UNION ALL
+    SELECT time, audit_id, userid, iso_code::TEXT, 'modification', name::TEXT, NULL
+    FROM subdivision_modifications WHERE nation = $1
+    UNION ALL
+    SELECT time, audit_id, userid, iso_code::TEXT, 'deletion', NULL, NULL
+    FROM subdivision_deletions WHERE nation = $1
+) AS entries
+LEFT OUTER JOIN members ON members.member_id = entries.userid
+ORDER BY entries.time, entries.audit_id
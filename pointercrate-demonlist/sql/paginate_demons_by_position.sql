@@ -1,4 +1,4 @@
-SELECT demons.id AS demon_id, demons.name::text AS demon_name, demons.position, demons.requirement, demons.level_id, CASE WHEN verifiers.link_banned THEN NULL ElSE demons.video::text END,demons.thumbnail,
+SELECT demons.id AS demon_id, demons.name::text AS demon_name, demons.position, demons.list, demons.requirement, demons.level_id, CASE WHEN verifiers.link_banned THEN NULL ElSE demons.video::text END,demons.thumbnail,
        verifiers.id AS verifier_id, verifiers.name::text AS verifier_name, verifiers.banned AS verifier_banned,
        publishers.id AS publisher_id, publishers.name::text AS publisher_name, publishers.banned AS publisher_banned
 FROM demons
@@ -16,6 +16,8 @@ WHERE (demons.position < $1 OR $1 IS NULL)
   AND (publishers.name::CITEXT = $10 OR $10 IS NULL)
   AND (STRPOS(demons.name, $11::CITEXT) > 0 OR $11 is NULL)
   AND (demons.level_id = $12 OR $12 IS NULL)
+  AND demons.list = $14
+  AND ($15::TEXT IS NULL OR EXISTS (SELECT 1 FROM demon_tags WHERE demon_tags.demon = demons.id AND demon_tags.tag = $15))
   AND demons.position IS NOT NULL
 ORDER BY demons.position {}
 LIMIT $13
\ No newline at end of file
@@ -6,5 +6,6 @@ WHERE (index < $1 OR $1 IS NULL)
   AND (nation = $4 OR iso_country_code = $4 OR (nation IS NULL AND $5) OR ($4 IS NULL AND NOT $5))
   AND (continent = CAST($6::TEXT AS continent) OR $6 IS NULL)
   AND (subdivision = $7 OR $7 IS NULL)
+  AND list = $9
 ORDER BY rank {}, id
 LIMIT $8
\ No newline at end of file
@@ -1,9 +1,11 @@
 SELECT records.id, progress, CASE WHEN players.link_banned THEN NULL ELSE records.video::text END, status_::text AS status,
        players.id AS player_id, players.name::text AS player_name, players.banned AS player_banned,
-       demons.id AS demon_id, demons.name::text AS demon_name, demons.position
+       demons.id AS demon_id, demons.name::text AS demon_name, demons.position, demons.list
 FROM records
 INNER JOIN players ON records.player = players.id
 INNER JOIN demons ON records.demon = demons.id
+LEFT OUTER JOIN record_locks ON record_locks.record = records.id AND record_locks.locked_until > (NOW() AT TIME ZONE 'utc')
+LEFT OUTER JOIN record_video_checks ON record_video_checks.record = records.id AND record_video_checks.video = records.video
 WHERE (records.id < $1 OR $1 IS NULL)
   AND (records.id > $2 OR $2 IS NULL)
   AND (progress = $3 OR $3 IS NULL)
@@ -18,5 +20,9 @@ WHERE (records.id < $1 OR $1 IS NULL)
   AND (records.video = $12 OR (records.video IS NULL AND $13) OR ($12 IS NULL AND NOT $13))
   AND (players.id = $14 OR $14 IS NULL)
   AND (records.submitter = $15 OR $15 IS NULL)
+  AND (record_locks.reviewer = $16 OR $16 IS NULL)
+  AND (record_locks.record IS NULL OR NOT $17)
+  AND (record_video_checks.result = CAST($18::TEXT AS VIDEO_CHECK_RESULT) OR $18 IS NULL)
+  AND (demons.list = $19 OR $19 IS NULL)
 ORDER BY id {}
-LIMIT $16
+LIMIT $20
@@ -0,0 +1,9 @@
+SELECT index, rank, score, iso_code::TEXT, name::TEXT
+FROM ranked_subdivisions
+WHERE (index < $1 OR $1 IS NULL)
+  AND (index > $2 OR $2 IS NULL)
+  AND (STRPOS(name, $3::CITEXT) > 0 OR $3 is NULL)
+  AND nation = $4
+  AND list = $6
+ORDER BY index {}
+LIMIT $5
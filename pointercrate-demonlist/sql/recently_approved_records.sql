@@ -0,0 +1,19 @@
+SELECT records.id, records.progress, CASE WHEN players.link_banned THEN NULL ELSE records.video::text END, demons.id AS demon_id, demons.name AS "demon_name: String", demons.position, demons.list,
+       players.id AS player_id, players.name AS "player_name: String", players.banned AS player_banned, approvals.time AS "approved_at!"
+FROM records
+INNER JOIN demons ON records.demon = demons.id
+INNER JOIN players ON records.player = players.id
+-- The audit log stores the old status whenever the status of a record changes, meaning the most recent status change
+-- of an approved record is the time at which it got approved. Records that never changed status have been added as approved.
+INNER JOIN LATERAL (
+    SELECT COALESCE(
+        (SELECT MAX(time) FROM record_modifications WHERE record_modifications.id = records.id AND record_modifications.status_ IS NOT NULL),
+        (SELECT MIN(time) FROM record_additions WHERE record_additions.id = records.id)
+    ) AS time
+) AS approvals ON approvals.time IS NOT NULL
+WHERE records.status_ = 'APPROVED'
+  AND NOT players.banned
+  AND ($1::INTEGER IS NULL OR records.demon = $1)
+  AND ($2::INTEGER IS NULL OR records.player = $2)
+ORDER BY approvals.time DESC, records.id DESC
+LIMIT $3
@@ -1,12 +1,14 @@
 SELECT progress,
        CASE WHEN players.link_banned THEN NULL ELSE records.video::text END,
-       CASE WHEN players.link_banned THEN NULL ELSE records.raw_footage::text END,
        status_::text AS "status!: String" ,
        players.id AS player_id, players.name AS "player_name: String", players.banned AS player_banned,
-       demons.id AS demon_id, demons.name AS "demon_name: String", demons.position,
-       submitters.submitter_id AS submitter_id, submitters.banned AS submitter_banned
+       demons.id AS demon_id, demons.name AS "demon_name: String", demons.position, demons.list,
+       submitters.submitter_id AS submitter_id, submitters.banned AS submitter_banned, submitters.member AS submitter_member,
+       submitter_statistics.submitted AS "submitter_submitted!", submitter_statistics.approved AS "submitter_approved!",
+       submitter_statistics.rejected AS "submitter_rejected!", submitter_statistics.deleted_by_video_check AS "submitter_deleted_by_video_check!"
 FROM records
 INNER JOIN players ON records.player = players.id
 INNER JOIN demons ON records.demon = demons.id
 INNER JOIN submitters ON records.submitter = submitters.submitter_id
+INNER JOIN submitter_statistics ON submitters.submitter_id = submitter_statistics.submitter
 WHERE records.id = $1
@@ -0,0 +1,15 @@
+-- A tier covers all positions after the previous tier's last position, up to and including its own
+WITH tiers AS (
+    SELECT list, id, last_position,
+           COALESCE(LAG(last_position) OVER (PARTITION BY list ORDER BY last_position NULLS LAST), 0) AS previous_last
+    FROM list_tiers
+)
+SELECT tiers.list, tiers.id AS tier,
+       COUNT(records.id) FILTER (WHERE records.status_ = 'SUBMITTED') AS "submitted!",
+       COUNT(records.id) FILTER (WHERE records.status_ = 'UNDER_CONSIDERATION') AS "under_consideration!"
+FROM tiers
+LEFT OUTER JOIN demons ON demons.list = tiers.list AND demons.position > tiers.previous_last
+                      AND (tiers.last_position IS NULL OR demons.position <= tiers.last_position)
+LEFT OUTER JOIN records ON records.demon = demons.id
+GROUP BY tiers.list, tiers.id, tiers.previous_last
+ORDER BY tiers.list, tiers.previous_last
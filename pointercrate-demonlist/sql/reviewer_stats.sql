@@ -0,0 +1,37 @@
+-- The audit log stores the old status whenever the status of a record changes. The status a record was changed to is
+-- therefore the old status stored in the next status change, or the record's current status if no further change
+-- happened. The copy of a record written to the audit log right before its deletion counts as such a "next change", but is
+-- not a status change itself.
+WITH status_changes AS (
+    SELECT modifications.userid, modifications.time, modifications.id AS record, modifications.status_ AS old_status,
+           COALESCE(
+               (SELECT next.status_ FROM record_modifications AS next
+                WHERE next.id = modifications.id AND next.audit_id > modifications.audit_id AND next.status_ IS NOT NULL
+                ORDER BY next.audit_id LIMIT 1),
+               (SELECT records.status_ FROM records WHERE records.id = modifications.id)
+           ) AS new_status
+    FROM record_modifications AS modifications
+    WHERE modifications.status_ IS NOT NULL
+      AND modifications.time >= $1
+      AND modifications.time < $2
+      AND NOT EXISTS (
+          SELECT 1 FROM record_deletions
+          WHERE record_deletions.id = modifications.id AND record_deletions.time = modifications.time
+            AND modifications.progress IS NOT NULL AND modifications.player IS NOT NULL
+      )
+)
+SELECT status_changes.userid AS "id!", members.name AS "name?",
+       COUNT(*) FILTER (WHERE new_status = 'APPROVED') AS "approvals!",
+       COUNT(*) FILTER (WHERE new_status = 'REJECTED') AS "rejections!",
+       COUNT(*) FILTER (WHERE new_status = 'UNDER_CONSIDERATION') AS "under_consideration!",
+       -- Records added with a status other than 'submitted' are added and then immediately changed in the same
+       -- transaction. These were never waiting in the queue, and would skew the median.
+       PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY EXTRACT(EPOCH FROM status_changes.time - additions.time))
+           FILTER (WHERE new_status IN ('APPROVED', 'REJECTED') AND old_status IN ('SUBMITTED', 'UNDER_CONSIDERATION')
+                   AND additions.time < status_changes.time) AS median_decision_seconds
+FROM status_changes
+LEFT OUTER JOIN record_additions AS additions ON additions.id = status_changes.record
+LEFT OUTER JOIN members ON members.member_id = status_changes.userid
+WHERE new_status IS NOT NULL
+GROUP BY status_changes.userid, members.name
+ORDER BY COUNT(*) DESC, status_changes.userid
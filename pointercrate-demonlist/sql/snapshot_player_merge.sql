@@ -0,0 +1,26 @@
+-- Records a merge of player $2 into player $1, together with the state of every row the merge is going to touch
+WITH affected_records AS (
+    -- A merge only ever touches the records of the merged player, and the records of the remaining player on the same
+    -- demons (these are the ones that might get deleted as duplicates)
+    SELECT id FROM records WHERE player = $2 OR (player = $1 AND demon IN (SELECT demon FROM records WHERE player = $2))
+)
+INSERT INTO player_merges (player, merged_id, merged_name, merged_by, snapshot)
+SELECT $1, players.id, players.name, (SELECT id FROM active_user LIMIT 1), jsonb_build_object(
+    'player', to_jsonb(players),
+    'records', (SELECT COALESCE(jsonb_agg(records), '[]') FROM records WHERE id IN (SELECT id FROM affected_records)),
+    'record_notes', (SELECT COALESCE(jsonb_agg(record_notes), '[]') FROM record_notes WHERE record IN (SELECT id FROM affected_records)),
+    'record_evidence', (SELECT COALESCE(jsonb_agg(record_evidence), '[]') FROM record_evidence WHERE record IN (SELECT id FROM affected_records)),
+    'record_video_checks', (SELECT COALESCE(jsonb_agg(record_video_checks), '[]') FROM record_video_checks WHERE record IN (SELECT id FROM affected_records)),
+    'creators', (SELECT COALESCE(jsonb_agg(creators), '[]') FROM creators WHERE creator IN ($1, $2)),
+    'verified', (SELECT COALESCE(jsonb_agg(id), '[]') FROM demons WHERE verifier = $2),
+    'published', (SELECT COALESCE(jsonb_agg(id), '[]') FROM demons WHERE publisher = $2),
+    'claims', (SELECT COALESCE(jsonb_agg(player_claims), '[]') FROM player_claims WHERE player_id IN ($1, $2)),
+    'profiles', (SELECT COALESCE(jsonb_agg(player_profiles), '[]') FROM player_profiles WHERE player IN ($1, $2)),
+    'pending_profiles', (SELECT COALESCE(jsonb_agg(pending_player_profiles), '[]') FROM pending_player_profiles WHERE player IN ($1, $2)),
+    'pending_submissions', (SELECT COALESCE(jsonb_agg(id), '[]') FROM pending_submissions WHERE player = $2),
+    'aliases', (SELECT COALESCE(jsonb_agg(id), '[]') FROM player_aliases WHERE player = $2),
+    'score_history', (SELECT COALESCE(jsonb_agg(player_score_history), '[]') FROM player_score_history WHERE player = $2)
+)
+FROM players
+WHERE players.id = $2
+RETURNING id
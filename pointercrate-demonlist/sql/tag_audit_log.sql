@@ -0,0 +1,28 @@
+SELECT entries.time AS "time!",
+       entries.audit_id AS "audit_id!",
+       entries.userid AS "userid!",
+       members.name AS "username?",
+       entries.demon,
+       demons.name::TEXT AS demon_name,
+       entries.kind AS "kind!",
+       entries.language,
+       entries.name
+FROM (
+    SELECT time, audit_id, userid, NULL::INTEGER AS demon, 'addition' AS kind, NULL::TEXT AS language, NULL::TEXT AS name
+    FROM tag_additions WHERE tag = $1
+    UNION ALL
+    SELECT time, audit_id, userid, NULL, 'modification', language::TEXT, name
+    FROM tag_name_modifications WHERE tag = $1
+    UNION ALL
+    SELECT time, audit_id, userid, NULL, 'deletion', NULL, NULL
+    FROM tag_deletions WHERE tag = $1
+    UNION ALL
+    SELECT time, audit_id, userid, demon, 'addition', NULL, NULL
+    FROM demon_tag_additions WHERE tag = $1
+    UNION ALL
+    SELECT time, audit_id, userid, demon, 'deletion', NULL, NULL
+    FROM demon_tag_deletions WHERE tag = $1
+) AS entries
+LEFT OUTER JOIN members ON members.member_id = entries.userid
+LEFT OUTER JOIN demons ON demons.id = entries.demon
+ORDER BY entries.time, entries.audit_id
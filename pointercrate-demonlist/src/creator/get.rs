@@ -1,5 +1,5 @@
 use crate::{
-    creator::Creator,
+    creator::{CreatedDemon, Creator, CreatorRole, DemonCreator, Part},
     demon::MinimalDemon,
     error::{DemonlistError, Result},
     player::DatabasePlayer,
@@ -9,56 +9,79 @@ use sqlx::PgConnection;
 
 impl Creator {
     pub async fn get(demon: &MinimalDemon, player: &DatabasePlayer, connection: &mut PgConnection) -> Result<Creator> {
-        let exists = sqlx::query!(
-            r#"SELECT EXISTS (SELECT FROM creators WHERE creator=$1 AND demon = $2) AS "result!: bool""#,
+        let row = sqlx::query!(
+            r#"SELECT roles::TEXT[] AS "roles!", part_start, part_end FROM creators WHERE creator = $1 AND demon = $2"#,
             player.id,
             demon.id
         )
         .fetch_one(connection)
-        .await?
-        .result;
+        .await;
 
-        if exists {
-            Ok(Creator {
+        match row {
+            Ok(row) => Ok(Creator {
                 demon: demon.id,
                 creator: player.id,
-            })
-        } else {
-            Err(DemonlistError::CreatorNotFound {
+                roles: row.roles.iter().map(|role| CreatorRole::from_sql(role)).collect::<Result<_>>()?,
+                part: Part::from_sql(row.part_start, row.part_end),
+            }),
+            Err(sqlx::Error::RowNotFound) => Err(DemonlistError::CreatorNotFound {
                 player_id: player.id,
                 demon_id: demon.id,
-            })
+            }),
+            Err(err) => Err(err.into()),
         }
     }
 }
 
-pub async fn creators_of(demon: &MinimalDemon, connection: &mut PgConnection) -> Result<Vec<DatabasePlayer>> {
+pub async fn creators_of(demon: &MinimalDemon, connection: &mut PgConnection) -> Result<Vec<DemonCreator>> {
     let mut stream = sqlx::query!(
-        r#"SELECT players.id, players.name, players.banned FROM players INNER JOIN creators ON players.id = creators.creator WHERE 
-         creators.demon = $1"#,
+        r#"SELECT players.id, players.name, players.banned, creators.roles::TEXT[] AS "roles!", creators.part_start, creators.part_end 
+         FROM players INNER JOIN creators ON players.id = creators.creator WHERE creators.demon = $1"#,
         demon.id
     )
     .fetch(connection);
-    let mut players = Vec::new();
+    let mut creators = Vec::new();
 
     while let Some(row) = stream.next().await {
         let row = row?;
 
-        players.push(DatabasePlayer {
-            id: row.id,
-            name: row.name,
-            banned: row.banned,
+        creators.push(DemonCreator {
+            player: DatabasePlayer {
+                id: row.id,
+                name: row.name,
+                banned: row.banned,
+            },
+            roles: row.roles.iter().map(|role| CreatorRole::from_sql(role)).collect::<Result<_>>()?,
+            part: Part::from_sql(row.part_start, row.part_end),
         })
     }
 
-    Ok(players)
+    Ok(creators)
 }
 
-pub async fn created_by(player_id: i32, connection: &mut PgConnection) -> Result<Vec<MinimalDemon>> {
-    query_many_demons!(
-        connection,
-        r#"SELECT demons.id, demons.name, demons.position FROM demons INNER JOIN creators ON demons.id = creators.demon WHERE
-         creators.creator=$1"#,
+pub async fn created_by(player_id: i32, connection: &mut PgConnection) -> Result<Vec<CreatedDemon>> {
+    let mut stream = sqlx::query!(
+        r#"SELECT demons.id, demons.name, demons.position, demons.list, creators.roles::TEXT[] AS "roles!", creators.part_start, 
+         creators.part_end FROM demons INNER JOIN creators ON demons.id = creators.demon WHERE creators.creator = $1"#,
         player_id
     )
+    .fetch(connection);
+    let mut demons = Vec::new();
+
+    while let Some(row) = stream.next().await {
+        let row = row?;
+
+        demons.push(CreatedDemon {
+            demon: MinimalDemon {
+                id: row.id,
+                position: row.position,
+                name: row.name,
+                list: row.list,
+            },
+            roles: row.roles.iter().map(|role| CreatorRole::from_sql(role)).collect::<Result<_>>()?,
+            part: Part::from_sql(row.part_start, row.part_end),
+        })
+    }
+
+    Ok(demons)
 }
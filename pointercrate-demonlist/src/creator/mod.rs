@@ -1,7 +1,10 @@
 // pub use self::post::PostCreator;
 pub use self::get::{created_by, creators_of};
+use crate::{demon::MinimalDemon, error::Result, player::DatabasePlayer};
 use derive_more::Display;
+use pointercrate_core::error::CoreError;
 pub use post::PostCreator;
+use serde::{Deserialize, Serialize};
 
 mod delete;
 mod get;
@@ -12,4 +15,78 @@ mod post;
 pub struct Creator {
     demon: i32,
     creator: i32,
+    pub roles: Vec<CreatorRole>,
+    pub part: Option<Part>,
+}
+
+/// What a creator contributed to a level
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[serde(rename_all = "snake_case")]
+pub enum CreatorRole {
+    Host,
+    Gameplay,
+    Decoration,
+    Effects,
+
+    /// The creator verified (i.e. played through) their own part of the level, but not
+    /// necessarily the whole level
+    VerifierOfPart,
+}
+
+impl CreatorRole {
+    pub fn to_sql(&self) -> String {
+        match self {
+            CreatorRole::Host => "host",
+            CreatorRole::Gameplay => "gameplay",
+            CreatorRole::Decoration => "decoration",
+            CreatorRole::Effects => "effects",
+            CreatorRole::VerifierOfPart => "verifier_of_part",
+        }
+        .to_owned()
+    }
+
+    fn from_sql(sql: &str) -> Result<Self> {
+        match sql {
+            "host" => Ok(CreatorRole::Host),
+            "gameplay" => Ok(CreatorRole::Gameplay),
+            "decoration" => Ok(CreatorRole::Decoration),
+            "effects" => Ok(CreatorRole::Effects),
+            "verifier_of_part" => Ok(CreatorRole::VerifierOfPart),
+            _ => Err(CoreError::internal_server_error(format!("invalid creator role: {}", sql)).into()),
+        }
+    }
+}
+
+/// The part of a level a creator worked on, as a range of percentages
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Part {
+    pub start: i16,
+    pub end: i16,
+}
+
+impl Part {
+    fn from_sql(start: Option<i16>, end: Option<i16>) -> Option<Part> {
+        match (start, end) {
+            (Some(start), Some(end)) => Some(Part { start, end }),
+            _ => None,
+        }
+    }
+}
+
+/// A creator of some demon, together with their contributions to it
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Hash)]
+pub struct DemonCreator {
+    #[serde(flatten)]
+    pub player: DatabasePlayer,
+    pub roles: Vec<CreatorRole>,
+    pub part: Option<Part>,
+}
+
+/// A demon some player created, together with their contributions to it
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Hash)]
+pub struct CreatedDemon {
+    #[serde(flatten)]
+    pub demon: MinimalDemon,
+    pub roles: Vec<CreatorRole>,
+    pub part: Option<Part>,
 }
@@ -1,5 +1,5 @@
 use crate::{
-    creator::Creator,
+    creator::{Creator, CreatorRole, Part},
     demon::MinimalDemon,
     error::{DemonlistError, Result},
     player::DatabasePlayer,
@@ -10,23 +10,73 @@ use sqlx::PgConnection;
 #[derive(Debug, Deserialize)]
 pub struct PostCreator {
     pub creator: String,
+
+    #[serde(default)]
+    pub roles: Vec<CreatorRole>,
+
+    #[serde(default)]
+    pub part: Option<Part>,
+}
+
+impl Part {
+    pub fn validate(&self) -> Result<()> {
+        if self.start < 0 || self.start >= self.end || self.end > 100 {
+            return Err(DemonlistError::InvalidCreatorPart);
+        }
+
+        Ok(())
+    }
 }
 
 impl Creator {
-    pub async fn insert(demon: &MinimalDemon, player: &DatabasePlayer, connection: &mut PgConnection) -> Result<Creator> {
+    pub async fn insert(
+        demon: &MinimalDemon, player: &DatabasePlayer, mut roles: Vec<CreatorRole>, part: Option<Part>, connection: &mut PgConnection,
+    ) -> Result<Creator> {
+        if let Some(part) = part {
+            part.validate()?;
+        }
+
+        roles.sort();
+        roles.dedup();
+
         match Creator::get(demon, player, connection).await {
             Ok(_) => return Err(DemonlistError::CreatorExists),
             Err(DemonlistError::CreatorNotFound { .. }) => (),
             Err(err) => return Err(err),
         }
 
-        let _ = sqlx::query!("INSERT INTO creators (creator, demon) VALUES ($1, $2)", player.id, demon.id)
-            .execute(connection)
-            .await?;
+        let _ = sqlx::query!(
+            "INSERT INTO creators (creator, demon, roles, part_start, part_end) VALUES ($1, $2, CAST($3::TEXT[] AS creator_role[]), $4, $5)",
+            player.id,
+            demon.id,
+            &roles.iter().map(CreatorRole::to_sql).collect::<Vec<_>>(),
+            part.map(|part| part.start),
+            part.map(|part| part.end)
+        )
+        .execute(connection)
+        .await?;
 
         Ok(Creator {
             demon: demon.id,
             creator: player.id,
+            roles,
+            part,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{creator::Part, error::DemonlistError};
+
+    #[test]
+    fn test_validate_part() {
+        assert_eq!(Part { start: 0, end: 100 }.validate(), Ok(()));
+        assert_eq!(Part { start: 34, end: 52 }.validate(), Ok(()));
+
+        assert_eq!(Part { start: -1, end: 20 }.validate(), Err(DemonlistError::InvalidCreatorPart));
+        assert_eq!(Part { start: 20, end: 20 }.validate(), Err(DemonlistError::InvalidCreatorPart));
+        assert_eq!(Part { start: 50, end: 20 }.validate(), Err(DemonlistError::InvalidCreatorPart));
+        assert_eq!(Part { start: 90, end: 101 }.validate(), Err(DemonlistError::InvalidCreatorPart));
+    }
+}
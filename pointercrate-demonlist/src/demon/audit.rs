@@ -16,6 +16,12 @@ pub struct DemonModificationData {
     pub video: Option<String>,
     pub verifier: Option<NamedId>,
     pub publisher: Option<NamedId>,
+
+    /// The tag that was assigned to the demon, if this entry is about a tag being assigned
+    pub added_tag: Option<String>,
+
+    /// The tag that was removed from the demon, if this entry is about a tag being removed
+    pub removed_tag: Option<String>,
 }
 
 #[derive(Serialize, Debug)]
@@ -36,45 +42,142 @@ pub struct MovementLogEntry {
     new_position: Option<i16>,
 }
 
-pub async fn movement_log_for_demon(demon_id: i32, connection: &mut PgConnection) -> Result<Vec<MovementLogEntry>> {
-    let audit_log = audit_log_for_demon(demon_id, connection).await?;
+/// A change to the list caused directly by a demon being added or moved (as opposed to the shifts
+/// these changes induce on other demons)
+#[derive(Serialize, Debug)]
+pub struct ListMovement {
+    pub time: NaiveDateTime,
+    pub demon: MinimalDemon,
 
-    let mut movement_log = Vec::new();
+    /// The position the demon was moved from. `None` if the demon was newly added.
+    pub old_position: Option<i16>,
+
+    /// The position the demon was added at/moved to
+    pub new_position: i16,
+}
+
+/// Retrieves the `limit` most recent additions and movements of demons on the given list, newest
+/// first
+///
+/// Demons that have since been deleted are not included.
+pub async fn recent_list_movements(list_id: &str, limit: i64, connection: &mut PgConnection) -> Result<Vec<ListMovement>> {
+    let mut movements = Vec::new();
+
+    // The position a demon ended up at after some change is the old position stored in the next modification entry that
+    // changed its position, or its current position if no such entry exists
+    let mut addition_stream = sqlx::query!(
+        r#"SELECT demon_additions.time, demons.id, demons.name AS "name: String", demons.position, demons.list,
+                  (SELECT position FROM demon_modifications WHERE demon_modifications.id = demons.id AND demon_modifications.time > demon_additions.time 
+                      AND demon_modifications.position IS NOT NULL AND demon_modifications.position != -1 ORDER BY demon_modifications.time LIMIT 1) AS position_after
+           FROM demon_additions INNER JOIN demons ON demons.id = demon_additions.id
+           WHERE demons.list = $2
+           ORDER BY demon_additions.time DESC LIMIT $1"#,
+        limit,
+        list_id
+    )
+    .fetch(&mut *connection);
+
+    while let Some(row) = addition_stream.next().await {
+        let row = row?;
+
+        movements.push(ListMovement {
+            time: row.time,
+            demon: MinimalDemon {
+                id: row.id,
+                position: row.position,
+                name: row.name,
+                list: row.list,
+            },
+            old_position: None,
+            new_position: row.position_after.unwrap_or(row.position),
+        })
+    }
+
+    drop(addition_stream);
+
+    // Moving a demon first sets its position to -1 (logging its old position), and then to its new position (logging -1),
+    // all within the same transaction
+    let mut move_stream = sqlx::query!(
+        r#"SELECT moves.time, demons.id, demons.name AS "name: String", demons.position, demons.list,
+                  (SELECT position FROM demon_modifications WHERE demon_modifications.id = demons.id AND demon_modifications.time = moves.time 
+                      AND demon_modifications.position != -1 LIMIT 1) AS position_before,
+                  (SELECT position FROM demon_modifications WHERE demon_modifications.id = demons.id AND demon_modifications.time > moves.time 
+                      AND demon_modifications.position IS NOT NULL AND demon_modifications.position != -1 ORDER BY demon_modifications.time LIMIT 1) AS position_after
+           FROM demon_modifications AS moves INNER JOIN demons ON demons.id = moves.id
+           WHERE moves.position = -1 AND demons.list = $2
+           ORDER BY moves.time DESC LIMIT $1"#,
+        limit,
+        list_id
+    )
+    .fetch(connection);
+
+    while let Some(row) = move_stream.next().await {
+        let row = row?;
+
+        movements.push(ListMovement {
+            time: row.time,
+            demon: MinimalDemon {
+                id: row.id,
+                position: row.position,
+                name: row.name,
+                list: row.list,
+            },
+            old_position: row.position_before,
+            new_position: row.position_after.unwrap_or(row.position),
+        })
+    }
+
+    movements.sort_by_key(|movement| std::cmp::Reverse(movement.time));
+    movements.truncate(limit as usize);
+
+    Ok(movements)
+}
+
+/// All demon additions and movements, keyed by the time at which they happened
+///
+/// Used to figure out the reason for a given position change of some demon, as each change in
+/// position caused by the addition/movement of a demon happens in the same transaction (and thus
+/// has the same timestamp) as the addition/movement itself.
+pub(crate) struct MovementEvents {
     // map time -> NamedId keeping track of all additions
-    let mut additions = HashMap::new();
+    pub(crate) additions: HashMap<NaiveDateTime, NamedId>,
     // map time -> NamedId keeping track when movements to -1 happened
-    let mut all_moves = HashMap::new();
+    pub(crate) moves: HashMap<NaiveDateTime, NamedId>,
+}
 
-    {
-        // non-lexical lifetimes working amazingly I see >.>
-        let mut addition_stream = sqlx::query!(
-            r#"SELECT time AS "time!", demon_additions.id AS "id!", demons.name::text FROM demon_additions LEFT OUTER JOIN demons ON demons.id = demon_additions.id"#
-        )
-        .fetch(&mut *connection);
+impl MovementEvents {
+    pub(crate) async fn load(connection: &mut PgConnection) -> Result<Self> {
+        let mut additions = HashMap::new();
+        let mut moves = HashMap::new();
 
-        while let Some(row) = addition_stream.next().await {
-            let row = row?;
-            additions.insert(
-                row.time,
-                NamedId {
-                    id: row.id,
-                    name: row.name,
-                },
-            );
+        {
+            // non-lexical lifetimes working amazingly I see >.>
+            let mut addition_stream = sqlx::query!(
+                r#"SELECT time AS "time!", demon_additions.id AS "id!", demons.name::text FROM demon_additions LEFT OUTER JOIN demons ON demons.id = demon_additions.id"#
+            )
+            .fetch(&mut *connection);
+
+            while let Some(row) = addition_stream.next().await {
+                let row = row?;
+                additions.insert(
+                    row.time,
+                    NamedId {
+                        id: row.id,
+                        name: row.name,
+                    },
+                );
+            }
         }
-    }
 
-    {
-        // non-lexical lifetimes working amazingly I see >.>
         let mut move_stream = sqlx::query!(
             "SELECT time, demon_modifications.id, demons.name::TEXT FROM demon_modifications LEFT OUTER JOIN demons ON demons.id = \
              demon_modifications.id WHERE demon_modifications.position = -1"
         )
-        .fetch(&mut *connection);
+        .fetch(connection);
 
         while let Some(row) = move_stream.next().await {
             let row = row?;
-            all_moves.insert(
+            moves.insert(
                 row.time,
                 NamedId {
                     id: row.id,
@@ -82,8 +185,44 @@ pub async fn movement_log_for_demon(demon_id: i32, connection: &mut PgConnection
                 },
             );
         }
+
+        Ok(MovementEvents { additions, moves })
+    }
+}
+
+/// Determines why the demon with the given id changed position at the given time
+///
+/// If there exists an entry with position = -1 for the same timestamp, then some demon was moved in
+/// this transaction. If it was this demon, this demon was moved itself, otherwise this change is the
+/// shift induced by that other demon being moved. If there exists an addition entry for another
+/// demon with the same timestamp, then this movement is the shift induced by that addition.
+/// Otherwise, we do not know (the log entry is from before we kept track of audit logs
+/// accurately) :(
+pub(crate) fn movement_reason(demon_id: i32, time: NaiveDateTime, events: &MovementEvents) -> MovementReason {
+    // if the time part of the datetime object is just zeros, the log entry was generated from deltas,
+    // meaning we can't figure out reasons accurately
+    if time.time() == NaiveTime::from_hms_opt(12, 0, 0).unwrap() {
+        return MovementReason::Unknown;
     }
 
+    match events.moves.get(&time) {
+        Some(id) if id.id == demon_id => MovementReason::Moved,
+        Some(id) => MovementReason::OtherMoved { other: id.clone() },
+        None => match events.additions.get(&time) {
+            Some(added_demon) => MovementReason::OtherAddedAbove {
+                other: added_demon.clone(),
+            },
+            None => MovementReason::Unknown,
+        },
+    }
+}
+
+pub async fn movement_log_for_demon(demon_id: i32, connection: &mut PgConnection) -> Result<Vec<MovementLogEntry>> {
+    let audit_log = audit_log_for_demon(demon_id, connection).await?;
+    let events = MovementEvents::load(&mut *connection).await?;
+
+    let mut movement_log = Vec::new();
+
     for log_entry in audit_log {
         let time = log_entry.time;
 
@@ -108,61 +247,17 @@ pub async fn movement_log_for_demon(demon_id: i32, connection: &mut PgConnection
                         entry.new_position = Some(old_position);
                     }
 
-                    // if the time part of the datetime object is just zeros, the log entry was generated from deltas,
-                    // meaning we can't figure out reasons accurately
-                    if time.time() == NaiveTime::from_hms_opt(12, 0, 0).unwrap() {
-                        movement_log.push(MovementLogEntry {
-                            reason: MovementReason::Unknown,
-                            time,
-                            new_position: None,
-                        });
-
-                        continue;
-                    }
-
-                    let moved = all_moves.get(&time);
-
-                    match moved {
-                        Some(id) if id.id == demon_id => movement_log.push(MovementLogEntry {
-                            reason: MovementReason::Moved,
-                            time,
-                            new_position: None,
-                        }),
-                        Some(id) => movement_log.push(MovementLogEntry {
-                            reason: MovementReason::OtherMoved { other: id.clone() },
-                            new_position: Some(old_position),
-                            time,
-                        }),
-                        None => {
-                            let added_demon = additions.get(&time);
-
-                            match added_demon {
-                                Some(added_demon) => movement_log.push(MovementLogEntry {
-                                    reason: MovementReason::OtherAddedAbove {
-                                        other: added_demon.clone(),
-                                    },
-                                    new_position: None,
-                                    time,
-                                }),
-                                None => movement_log.push(MovementLogEntry {
-                                    reason: MovementReason::Unknown,
-                                    new_position: None,
-                                    time,
-                                }),
-                            }
-                        },
-                    }
-
-                    // if old position = -1 there exists another modification entry with the same
-                    // timestamp (due to transactional nature of movements). Means that _this_ demon
-                    // was moved
+                    let reason = movement_reason(demon_id, time, &events);
+                    let new_position = match reason {
+                        MovementReason::OtherMoved { .. } => Some(old_position),
+                        _ => None,
+                    };
 
-                    // if there exists an entry with position = -1 from another demon, then this
-                    // movement is the shift induced by that other demon being moved
-                    // if there exists an addition entry for another demon with the same timestamp,
-                    // then this movement is the shift induced by that addition
-                    // otherwise, we do not know (the log entry is from before we kept track of
-                    // audit logs accurately) :(
+                    movement_log.push(MovementLogEntry {
+                        reason,
+                        time,
+                        new_position,
+                    });
                 }
             },
             AuditLogEntryType::Deletion => unreachable!(),
@@ -205,6 +300,47 @@ pub async fn audit_log_for_demon(demon_id: i32, connection: &mut PgConnection) -
         });
     }
 
+    let tag_changes = sqlx::query!(
+        r#"SELECT changes.time AS "time!", changes.audit_id AS "audit_id!", changes.userid AS "userid!", members.name AS "username?",
+                  changes.tag AS "tag!", changes.added AS "added!"
+           FROM (
+               SELECT time, audit_id, userid, tag, TRUE AS added FROM demon_tag_additions WHERE demon = $1
+               UNION ALL
+               SELECT time, audit_id, userid, tag, FALSE FROM demon_tag_deletions WHERE demon = $1
+           ) AS changes
+           LEFT OUTER JOIN members ON members.member_id = changes.userid"#,
+        demon_id
+    )
+    .fetch_all(&mut *connection)
+    .await?;
+
+    for change in tag_changes {
+        let (added_tag, removed_tag) = match change.added {
+            true => (Some(change.tag), None),
+            false => (None, Some(change.tag)),
+        };
+
+        entries.push(AuditLogEntry {
+            time: change.time,
+            entry_id: change.audit_id,
+            id: demon_id,
+            r#type: AuditLogEntryType::Modification(DemonModificationData {
+                name: None,
+                position: None,
+                requirement: None,
+                video: None,
+                verifier: None,
+                publisher: None,
+                added_tag,
+                removed_tag,
+            }),
+            user: NamedId {
+                name: change.username,
+                id: change.userid,
+            },
+        })
+    }
+
     let mut modification_stream = sqlx::query!(
         r#"SELECT time,
                 audit_id,
@@ -255,6 +391,8 @@ pub async fn audit_log_for_demon(demon_id: i32, connection: &mut PgConnection) -
                     }),
                     None => None,
                 },
+                added_tag: None,
+                removed_tag: None,
             }),
             user: NamedId {
                 name: row.username,
@@ -263,5 +401,8 @@ pub async fn audit_log_for_demon(demon_id: i32, connection: &mut PgConnection) -
         })
     }
 
+    // Tag changes are logged separately from all other modifications
+    entries.sort_by_key(|entry| (entry.time, entry.entry_id));
+
     Ok(entries)
 }
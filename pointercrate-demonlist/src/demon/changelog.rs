@@ -0,0 +1,183 @@
+//! Module for reconstructing what happened on the list between two points in time
+//!
+//! The changelog is computed by diffing the result of [`list_at`] at the two given times. Reasons
+//! for each change are reconstructed from the demon audit log in the same way
+//! [`movement_log_for_demon`](super::audit::movement_log_for_demon) does it.
+
+use crate::{
+    demon::{
+        audit::{movement_reason, MovementEvents, MovementReason},
+        list_at, TimeShiftedDemon,
+    },
+    error::Result,
+    list::{List, DEFAULT_LIST},
+};
+use chrono::NaiveDateTime;
+use futures::StreamExt;
+use pointercrate_core::{audit::NamedId, error::CoreError};
+use serde::{Deserialize, Serialize};
+use sqlx::PgConnection;
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize)]
+pub struct ChangelogQuery {
+    /// The point in time from which on changes should be reported
+    pub after: NaiveDateTime,
+
+    /// The point in time until which changes should be reported. Defaults to the current time.
+    #[serde(default)]
+    pub before: Option<NaiveDateTime>,
+
+    /// The list whose changelog should be computed. Not part of the query string, but instead set
+    /// from the request path. Defaults to the [default list](DEFAULT_LIST).
+    #[serde(skip)]
+    pub list: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ListChangelog {
+    pub after: NaiveDateTime,
+    pub before: NaiveDateTime,
+
+    /// Demons that were added to the list in the given time frame
+    pub additions: Vec<ChangelogEntry>,
+
+    /// Demons that were part of the main or extended list at the start of the time frame, but are
+    /// either part of the legacy list or no longer exist at its end
+    pub removals: Vec<ChangelogEntry>,
+
+    /// Demons that changed position in the given time frame while being part of the main or
+    /// extended list at either its start or its end (and that were not removed from it)
+    pub moves: Vec<ChangelogEntry>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChangelogEntry {
+    pub demon: NamedId,
+
+    /// The demon's position at the start of the time frame. `None` for demons that were added
+    /// during the time frame
+    pub old_position: Option<i16>,
+
+    /// The demon's position at the end of the time frame. `None` for demons that no longer
+    /// exist.
+    pub new_position: Option<i16>,
+
+    /// Reasons for each individual change in position this demon underwent during the time frame,
+    /// in chronological order
+    pub reasons: Vec<MovementReason>,
+}
+
+impl ChangelogQuery {
+    pub async fn changelog(&self, connection: &mut PgConnection) -> Result<ListChangelog> {
+        let before = self.before.unwrap_or_else(|| chrono::Utc::now().naive_utc());
+
+        if before <= self.after {
+            return Err(CoreError::AfterSmallerBefore.into());
+        }
+
+        let list = List::by_id(self.list.as_deref().unwrap_or(DEFAULT_LIST), &mut *connection).await?;
+
+        let old_list = list_at(&list.id, &mut *connection, self.after).await?;
+        let new_list = list_at(&list.id, &mut *connection, before).await?;
+
+        let mut reasons = movement_reasons_between(self.after, before, connection).await?;
+
+        let old_positions: HashMap<i32, i16> = old_list
+            .iter()
+            .map(|demon| (demon.current_demon.base.id, demon.current_demon.base.position))
+            .collect();
+
+        let extended_list_size = list.extended_list_size;
+        let mut changelog = ListChangelog {
+            after: self.after,
+            before,
+            additions: Vec::new(),
+            removals: Vec::new(),
+            moves: Vec::new(),
+        };
+
+        for demon in &new_list {
+            let id = demon.current_demon.base.id;
+            let new_position = demon.current_demon.base.position;
+
+            let entry = |reasons: &mut HashMap<i32, Vec<MovementReason>>, old_position| ChangelogEntry {
+                demon: named_id(demon),
+                old_position,
+                new_position: Some(new_position),
+                reasons: reasons.remove(&id).unwrap_or_default(),
+            };
+
+            match old_positions.get(&id) {
+                None => changelog.additions.push(entry(&mut reasons, None)),
+                Some(&old_position) if old_position == new_position => (),
+                Some(&old_position) if old_position <= extended_list_size && new_position > extended_list_size => {
+                    changelog.removals.push(entry(&mut reasons, Some(old_position)))
+                },
+                Some(&old_position) if old_position <= extended_list_size || new_position <= extended_list_size => {
+                    changelog.moves.push(entry(&mut reasons, Some(old_position)))
+                },
+                Some(_) => (),
+            }
+        }
+
+        // Demons that have been deleted do not show up in `new_list` at all
+        for demon in old_list {
+            if demon.current_demon.base.position <= extended_list_size
+                && !new_list.iter().any(|new| new.current_demon.base.id == demon.current_demon.base.id)
+            {
+                changelog.removals.push(ChangelogEntry {
+                    demon: named_id(&demon),
+                    old_position: Some(demon.current_demon.base.position),
+                    new_position: None,
+                    reasons: Vec::new(),
+                });
+            }
+        }
+
+        changelog.removals.sort_by_key(|entry| entry.old_position);
+
+        Ok(changelog)
+    }
+}
+
+fn named_id(demon: &TimeShiftedDemon) -> NamedId {
+    NamedId {
+        id: demon.current_demon.base.id,
+        name: Some(demon.current_demon.base.name.clone()),
+    }
+}
+
+/// Reconstructs the reasons for all position changes that happened within the given time frame,
+/// grouped by demon
+///
+/// Note that [`list_at`] reconstructs the list as it was right _before_ the given point in time,
+/// so the time frame is inclusive at its start and exclusive at its end
+async fn movement_reasons_between(
+    after: NaiveDateTime, before: NaiveDateTime, connection: &mut PgConnection,
+) -> Result<HashMap<i32, Vec<MovementReason>>> {
+    let events = MovementEvents::load(&mut *connection).await?;
+    let mut reasons: HashMap<i32, Vec<MovementReason>> = HashMap::new();
+
+    for (time, added) in &events.additions {
+        if *time >= after && *time < before {
+            reasons.entry(added.id).or_default().push(MovementReason::Added);
+        }
+    }
+
+    let mut modification_stream = sqlx::query!(
+        "SELECT time, id FROM demon_modifications WHERE time >= $1 AND time < $2 AND position IS NOT NULL AND position != -1 ORDER BY \
+         time",
+        after,
+        before
+    )
+    .fetch(connection);
+
+    while let Some(row) = modification_stream.next().await {
+        let row = row?;
+
+        reasons.entry(row.id).or_default().push(movement_reason(row.id, row.time, &events));
+    }
+
+    Ok(reasons)
+}
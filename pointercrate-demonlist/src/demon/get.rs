@@ -4,6 +4,7 @@ use crate::{
     error::{DemonlistError, Result},
     player::DatabasePlayer,
     record::approved_records_on,
+    tag::tags_of,
 };
 use chrono::NaiveDateTime;
 use futures::StreamExt;
@@ -11,7 +12,7 @@ use sqlx::{Error, PgConnection};
 
 impl MinimalDemon {
     pub async fn by_id(id: i32, connection: &mut PgConnection) -> Result<MinimalDemon> {
-        sqlx::query_as!(MinimalDemon, r#"SELECT id, name, position FROM demons WHERE id = $1"#, id)
+        sqlx::query_as!(MinimalDemon, r#"SELECT id, name, position, list FROM demons WHERE id = $1"#, id)
             .fetch_one(connection)
             .await
             .map_err(|err| match err {
@@ -21,7 +22,7 @@ impl MinimalDemon {
     }
 
     pub async fn by_name(name: &str, connection: &mut PgConnection) -> Result<MinimalDemon> {
-        let mut stream = sqlx::query!(r#"SELECT id, name, position FROM demons WHERE name = $1"#, name.to_string()).fetch(connection);
+        let mut stream = sqlx::query!(r#"SELECT id, name, position, list FROM demons WHERE name = $1"#, name.to_string()).fetch(connection);
 
         let mut demon = None;
         let mut further_demons = Vec::new();
@@ -33,6 +34,7 @@ impl MinimalDemon {
                 id: row.id,
                 position: row.position,
                 name: row.name,
+                list: row.list,
             };
 
             if demon.is_none() {
@@ -62,8 +64,8 @@ impl FullDemon {
         Demon::by_id(id, connection).await?.upgrade(connection).await
     }
 
-    pub async fn by_position(position: i16, connection: &mut PgConnection) -> Result<FullDemon> {
-        Demon::by_position(position, connection).await?.upgrade(connection).await
+    pub async fn by_position(list_id: &str, position: i16, connection: &mut PgConnection) -> Result<FullDemon> {
+        Demon::by_position(list_id, position, connection).await?.upgrade(connection).await
     }
 }
 
@@ -72,11 +74,13 @@ impl Demon {
     async fn upgrade(self, connection: &mut PgConnection) -> Result<FullDemon> {
         let creators = creators_of(&self.base, connection).await?;
         let records = approved_records_on(&self.base, connection).await?;
+        let tags = tags_of(&self.base, connection).await?;
 
         Ok(FullDemon {
             demon: self,
             creators,
             records,
+            tags,
         })
     }
 
@@ -91,8 +95,8 @@ impl Demon {
             })
     }
 
-    pub async fn by_position(position: i16, connection: &mut PgConnection) -> Result<Demon> {
-        sqlx::query_file_as!(FetchedDemon, "sql/demon_by_position.sql", position)
+    pub async fn by_position(list_id: &str, position: i16, connection: &mut PgConnection) -> Result<Demon> {
+        sqlx::query_file_as!(FetchedDemon, "sql/demon_by_position.sql", list_id, position)
             .fetch_one(connection)
             .await
             .map(Into::into)
@@ -119,7 +123,7 @@ macro_rules! query_many_demons {
 pub async fn published_by(player: &DatabasePlayer, connection: &mut PgConnection) -> Result<Vec<MinimalDemon>> {
     query_many_demons!(
         connection,
-        r#"SELECT id, name, position FROM demons WHERE publisher = $1"#,
+        r#"SELECT id, name, position, list FROM demons WHERE publisher = $1"#,
         player.id
     )
 }
@@ -127,7 +131,7 @@ pub async fn published_by(player: &DatabasePlayer, connection: &mut PgConnection
 pub async fn verified_by(player: &DatabasePlayer, connection: &mut PgConnection) -> Result<Vec<MinimalDemon>> {
     query_many_demons!(
         connection,
-        r#"SELECT id, name, position FROM demons WHERE verifier = $1"#,
+        r#"SELECT id, name, position, list FROM demons WHERE verifier = $1"#,
         player.id
     )
 }
@@ -136,6 +140,7 @@ struct FetchedDemon {
     demon_id: i32,
     demon_name: String,
     position: i16,
+    list: String,
     requirement: i16,
     video: Option<String>,
     thumbnail: String,
@@ -155,6 +160,7 @@ impl From<FetchedDemon> for Demon {
                 id: fetched.demon_id,
                 name: fetched.demon_name,
                 position: fetched.position,
+                list: fetched.list,
             },
             requirement: fetched.requirement,
             video: fetched.video,
@@ -174,8 +180,8 @@ impl From<FetchedDemon> for Demon {
     }
 }
 
-pub async fn current_list(connection: &mut PgConnection) -> Result<Vec<Demon>> {
-    Ok(sqlx::query_file_as!(FetchedDemon, "sql/all_demons.sql")
+pub async fn current_list(list_id: &str, connection: &mut PgConnection) -> Result<Vec<Demon>> {
+    Ok(sqlx::query_file_as!(FetchedDemon, "sql/all_demons.sql", list_id)
         .fetch_all(connection)
         .await?
         .into_iter()
@@ -183,8 +189,8 @@ pub async fn current_list(connection: &mut PgConnection) -> Result<Vec<Demon>> {
         .collect())
 }
 
-pub async fn list_at(connection: &mut PgConnection, at: NaiveDateTime) -> Result<Vec<TimeShiftedDemon>> {
-    let mut stream = sqlx::query_file!("sql/all_demons_at.sql", at).fetch(connection);
+pub async fn list_at(list_id: &str, connection: &mut PgConnection, at: NaiveDateTime) -> Result<Vec<TimeShiftedDemon>> {
+    let mut stream = sqlx::query_file!("sql/all_demons_at.sql", list_id, at).fetch(connection);
     let mut demons = Vec::new();
 
     while let Some(row) = stream.next().await {
@@ -196,6 +202,7 @@ pub async fn list_at(connection: &mut PgConnection, at: NaiveDateTime) -> Result
                     id: row.demon_id,
                     position: row.position,
                     name: row.demon_name,
+                    list: list_id.to_string(),
                 },
                 requirement: row.requirement,
                 video: row.video,
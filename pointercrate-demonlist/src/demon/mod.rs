@@ -1,13 +1,16 @@
 pub use self::{
+    changelog::{ChangelogEntry, ChangelogQuery, ListChangelog},
     get::{current_list, list_at, published_by, verified_by},
     paginate::{DemonIdPagination, DemonPositionPagination},
     patch::PatchDemon,
     post::PostDemon,
 };
 use crate::{
+    creator::DemonCreator,
     error::{DemonlistError, Result},
     player::DatabasePlayer,
     record::MinimalRecordP,
+    tag::Tag,
 };
 use derive_more::Display;
 use log::info;
@@ -22,6 +25,7 @@ use std::{
 #[macro_use]
 mod get;
 pub mod audit;
+mod changelog;
 mod paginate;
 mod patch;
 mod post;
@@ -75,19 +79,25 @@ pub struct MinimalDemon {
     ///
     /// Note that the name doesn't need to be unique!
     pub name: String,
+
+    /// The ID of the [`List`](crate::list::List) this [`Demon`] is part of
+    ///
+    /// Positions are only unique within a single list
+    pub list: String,
 }
 
 /// Struct modelling the "full" version of a demon.
 ///
 /// In addition to containing publisher/verifier information it also contains a list of the demon's
-/// creators and a list of accepted records
+/// creators, a list of accepted records and the demon's tags
 #[derive(Debug, Serialize, Deserialize, Display, PartialEq, Eq, Hash)]
 #[display("{}", demon)]
 pub struct FullDemon {
     #[serde(flatten)]
     pub demon: Demon,
-    pub creators: Vec<DatabasePlayer>,
+    pub creators: Vec<DemonCreator>,
     pub records: Vec<MinimalRecordP>,
+    pub tags: Vec<Tag>,
 }
 
 impl Taggable for FullDemon {
@@ -136,10 +146,10 @@ impl Demon {
         Ok(level_id as u64)
     }
 
-    pub async fn validate_position(position: i16, connection: &mut PgConnection) -> Result<()> {
+    pub async fn validate_position(list_id: &str, position: i16, connection: &mut PgConnection) -> Result<()> {
         // To prevent holes from being created in the list, the new position must lie between 1 and (current
         // last position + 1), inclusive
-        let maximal_position = Demon::max_position(connection).await? + 1;
+        let maximal_position = Demon::max_position(list_id, connection).await? + 1;
 
         if position > maximal_position || position < 1 {
             return Err(DemonlistError::InvalidPosition { maximal: maximal_position });
@@ -148,26 +158,32 @@ impl Demon {
         Ok(())
     }
 
-    /// Increments the position of all demons with positions equal to or greater than the given one,
-    /// by one.
-    async fn shift_down(starting_at: i16, connection: &mut PgConnection) -> Result<()> {
-        info!("Shifting down all demons, starting at {}", starting_at);
+    /// Increments the position of all demons on the given list with positions equal to or greater
+    /// than the given one, by one.
+    async fn shift_down(list_id: &str, starting_at: i16, connection: &mut PgConnection) -> Result<()> {
+        info!("Shifting down all demons on list {}, starting at {}", list_id, starting_at);
 
-        sqlx::query!("UPDATE demons SET position = position + 1 WHERE position >= $1", starting_at)
-            .execute(connection)
-            .await?;
+        sqlx::query!(
+            "UPDATE demons SET position = position + 1 WHERE list = $1 AND position >= $2",
+            list_id,
+            starting_at
+        )
+        .execute(connection)
+        .await?;
 
         Ok(())
     }
 
-    /// Gets the current max position a demon has, or `0` if there are no demons
-    /// in the database
-    pub async fn max_position(connection: &mut PgConnection) -> Result<i16> {
-        Ok(sqlx::query!("SELECT MAX(position) as max_position FROM demons")
-            .fetch_one(connection)
-            .await?
-            .max_position
-            .unwrap_or(0))
+    /// Gets the current max position a demon on the given list has, or `0` if there are no demons
+    /// on that list
+    pub async fn max_position(list_id: &str, connection: &mut PgConnection) -> Result<i16> {
+        Ok(
+            sqlx::query!("SELECT MAX(position) as max_position FROM demons WHERE list = $1", list_id)
+                .fetch_one(connection)
+                .await?
+                .max_position
+                .unwrap_or(0),
+        )
     }
 
     pub fn score(&self, progress: i16) -> f64 {
@@ -1,11 +1,12 @@
 use crate::{
     demon::{Demon, MinimalDemon},
+    list::DEFAULT_LIST,
     player::DatabasePlayer,
 };
 use futures::stream::StreamExt;
 use pointercrate_core::{
     first_and_last,
-    pagination::{PageContext, Paginatable, PaginationParameters, PaginationQuery, __pagination_compat},
+    pagination::{__pagination_compat, PageContext, Paginatable, PaginationParameters, PaginationQuery},
     util::non_nullable,
 };
 use serde::{Deserialize, Serialize};
@@ -45,6 +46,21 @@ pub struct DemonIdPagination {
     #[serde(default, deserialize_with = "non_nullable")]
     #[serde(rename = "requirement__lt")]
     requirement_lt: Option<i16>,
+
+    /// Only return demons that have the tag with the given ID
+    #[serde(default, deserialize_with = "non_nullable")]
+    tag: Option<String>,
+
+    /// The list whose demons should be paginated. Not part of the query string, but instead set
+    /// from the request path. Defaults to the [default list](DEFAULT_LIST).
+    #[serde(skip)]
+    pub list: Option<String>,
+}
+
+impl DemonIdPagination {
+    fn list(&self) -> &str {
+        self.list.as_deref().unwrap_or(DEFAULT_LIST)
+    }
 }
 
 impl PaginationQuery for DemonIdPagination {
@@ -63,6 +79,14 @@ impl PaginationQuery for DemonIdPagination {
 impl Paginatable<DemonIdPagination> for Demon {
     first_and_last!("demons");
 
+    async fn first_and_last_matching(query: &DemonIdPagination, connection: &mut PgConnection) -> Result<Option<(i32, i32)>, sqlx::Error> {
+        let row = sqlx::query!("SELECT MIN(id), MAX(id) FROM demons WHERE list = $1", query.list())
+            .fetch_one(connection)
+            .await?;
+
+        Ok(row.min.zip(row.max))
+    }
+
     async fn page(query: &DemonIdPagination, connection: &mut PgConnection) -> Result<(Vec<Demon>, PageContext), sqlx::Error> {
         let order = query.params.order();
 
@@ -83,6 +107,8 @@ impl Paginatable<DemonIdPagination> for Demon {
             .bind(query.name_contains.as_deref())
             .bind(query.level_id)
             .bind(query.params.limit + 1)
+            .bind(query.list())
+            .bind(query.tag.as_deref())
             .fetch(connection);
 
         let mut demons = Vec::new();
@@ -97,6 +123,7 @@ impl Paginatable<DemonIdPagination> for Demon {
                     id: row.get("demon_id"),
                     name: row.get("demon_name"),
                     position: row.get("position"),
+                    list: row.get("list"),
                 },
                 requirement: row.get("requirement"),
                 video,
@@ -157,6 +184,21 @@ pub struct DemonPositionPagination {
     #[serde(default, deserialize_with = "non_nullable")]
     #[serde(rename = "requirement__lt")]
     pub requirement_lt: Option<i16>,
+
+    /// Only return demons that have the tag with the given ID
+    #[serde(default, deserialize_with = "non_nullable")]
+    pub tag: Option<String>,
+
+    /// The list whose demons should be paginated. Not part of the query string, but instead set
+    /// from the request path. Defaults to the [default list](DEFAULT_LIST).
+    #[serde(skip)]
+    pub list: Option<String>,
+}
+
+impl DemonPositionPagination {
+    fn list(&self) -> &str {
+        self.list.as_deref().unwrap_or(DEFAULT_LIST)
+    }
 }
 
 impl PaginationQuery for DemonPositionPagination {
@@ -175,6 +217,19 @@ impl PaginationQuery for DemonPositionPagination {
 impl Paginatable<DemonPositionPagination> for Demon {
     first_and_last!("demons", "position");
 
+    async fn first_and_last_matching(
+        query: &DemonPositionPagination, connection: &mut PgConnection,
+    ) -> Result<Option<(i32, i32)>, sqlx::Error> {
+        let row = sqlx::query!(
+            "SELECT CAST(MIN(position) AS INTEGER), CAST(MAX(position) AS INTEGER) FROM demons WHERE list = $1",
+            query.list()
+        )
+        .fetch_one(connection)
+        .await?;
+
+        Ok(row.min.zip(row.max))
+    }
+
     async fn page(query: &DemonPositionPagination, connection: &mut PgConnection) -> Result<(Vec<Demon>, PageContext), sqlx::Error> {
         let order = query.params.order();
 
@@ -195,6 +250,8 @@ impl Paginatable<DemonPositionPagination> for Demon {
             .bind(query.name_contains.as_deref())
             .bind(query.level_id)
             .bind(query.params.limit + 1)
+            .bind(query.list())
+            .bind(query.tag.as_deref())
             .fetch(connection);
 
         let mut demons = Vec::new();
@@ -209,6 +266,7 @@ impl Paginatable<DemonPositionPagination> for Demon {
                     id: row.get("demon_id"),
                     name: row.get("demon_name"),
                     position: row.get("position"),
+                    list: row.get("list"),
                 },
                 requirement: row.get("requirement"),
                 video,
@@ -184,13 +184,13 @@ impl MinimalDemon {
         Ok(())
     }
 
-    /// Moves this demon to the specified position
+    /// Moves this demon to the specified position on its list
     ///
     /// Validates that `to` is `> 0` and less than or equal to the currently highest position on the
     /// list (to preven "holes")
     pub async fn mv(&mut self, to: i16, connection: &mut PgConnection) -> Result<()> {
         // This returns 0 if the list is empty, but if the list is empty then there is no demon for us to do a move with, so we will never get here anyway.
-        let maximal_position = Demon::max_position(connection).await?;
+        let maximal_position = Demon::max_position(&self.list, connection).await?;
 
         if to > maximal_position || to < 1 {
             return Err(DemonlistError::InvalidPosition { maximal: maximal_position });
@@ -216,9 +216,10 @@ impl MinimalDemon {
             );
 
             sqlx::query!(
-                "UPDATE demons SET position = position - 1 WHERE position > $1 AND position <= $2",
+                "UPDATE demons SET position = position - 1 WHERE list = $3 AND position > $1 AND position <= $2",
                 self.position,
-                to
+                to,
+                self.list
             )
             .execute(&mut *connection)
             .await?;
@@ -229,9 +230,10 @@ impl MinimalDemon {
             );
 
             sqlx::query!(
-                "UPDATE demons SET position = position + 1 WHERE position >= $1 AND position < $2",
+                "UPDATE demons SET position = position + 1 WHERE list = $3 AND position >= $1 AND position < $2",
                 to,
-                self.position
+                self.position,
+                self.list
             )
             .execute(&mut *connection)
             .await?;
@@ -247,7 +249,7 @@ impl MinimalDemon {
 
         self.position = to;
 
-        recompute_scores(connection).await?;
+        recompute_scores(&self.list, connection).await?;
 
         Ok(())
     }
@@ -1,7 +1,8 @@
 use crate::{
-    creator::Creator,
+    creator::{Creator, DemonCreator},
     demon::{Demon, FullDemon, MinimalDemon},
     error::Result,
+    list::{List, DEFAULT_LIST},
     player::{recompute_scores, DatabasePlayer},
 };
 use log::info;
@@ -18,6 +19,11 @@ pub struct PostDemon {
     creators: Vec<String>,
     video: Option<String>,
     level_id: Option<i64>,
+
+    /// The list the demon should be added to. Not part of the request body, but instead set from
+    /// the request path. Defaults to the [default list](DEFAULT_LIST).
+    #[serde(skip)]
+    pub list: Option<String>,
 }
 
 impl FullDemon {
@@ -33,23 +39,26 @@ impl FullDemon {
             None => None,
         };
 
-        Demon::validate_position(data.position, connection).await?;
+        let list = List::by_id(data.list.as_deref().unwrap_or(DEFAULT_LIST), connection).await?;
+
+        Demon::validate_position(&list.id, data.position, connection).await?;
 
         let publisher = DatabasePlayer::by_name_or_create(data.publisher.as_ref(), connection).await?;
         let verifier = DatabasePlayer::by_name_or_create(data.verifier.as_ref(), connection).await?;
 
-        Demon::shift_down(data.position, connection).await?;
+        Demon::shift_down(&list.id, data.position, connection).await?;
 
         let created = sqlx::query!(
-            "INSERT INTO demons (name, position, requirement, video, verifier, publisher, level_id) VALUES ($1::text,$2,$3,$4::text,$5,$6, $7) \
-             RETURNING id, thumbnail",
+            "INSERT INTO demons (name, position, requirement, video, verifier, publisher, level_id, list) VALUES ($1::text,$2,$3,$4::text,$5,$6, \
+             $7, $8) RETURNING id, thumbnail",
             data.name.to_string(),
             data.position,
             data.requirement,
             video.as_ref(),
             verifier.id,
             publisher.id,
-            data.level_id
+            data.level_id,
+            list.id
         )
         .fetch_one(&mut *connection)
         .await?;
@@ -59,6 +68,7 @@ impl FullDemon {
                 id: created.id,
                 position: data.position,
                 name: data.name,
+                list: list.id,
             },
             requirement: data.requirement,
             video,
@@ -72,17 +82,22 @@ impl FullDemon {
 
         for creator in data.creators {
             let player = DatabasePlayer::by_name_or_create(creator.as_ref(), &mut *connection).await?;
-            Creator::insert(&demon.base, &player, connection).await?;
+            Creator::insert(&demon.base, &player, Vec::new(), None, connection).await?;
 
-            creators.push(player);
+            creators.push(DemonCreator {
+                player,
+                roles: Vec::new(),
+                part: None,
+            });
         }
 
-        recompute_scores(connection).await?;
+        recompute_scores(&demon.base.list, connection).await?;
 
         Ok(FullDemon {
             demon,
             creators,
             records: Vec::new(),
+            tags: Vec::new(),
         })
     }
 }
@@ -110,6 +125,7 @@ mod tests {
                 creators: Vec::new(),
                 video: None,
                 level_id: None,
+                list: None,
             },
             &mut conn,
         )
@@ -136,6 +152,7 @@ mod tests {
                 creators: Vec::new(),
                 video: Some("https://www.youtube.com/watch?v=dQw4w9WgXcQ".to_owned()),
                 level_id: None,
+                list: None,
             },
             &mut conn,
         )
@@ -157,6 +174,7 @@ mod tests {
                 creators: Vec::new(),
                 video: Some("https://www.youtube.com/watch?v=dQw4w9WgXcQ".to_owned()),
                 level_id: None,
+                list: None,
             },
             &mut conn,
         )
@@ -178,6 +196,7 @@ mod tests {
                 creators: Vec::new(),
                 video: None,
                 level_id: Some(-1),
+                list: None,
             },
             &mut conn,
         )
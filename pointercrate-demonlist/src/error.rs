@@ -32,6 +32,10 @@ pub enum DemonlistError {
 
     ClaimUnverified,
 
+    /// `403 FORBIDDEN` variant returned when an anonymous request, such as a record submission,
+    /// originates from a VPN, proxy or datacenter
+    ///
+    /// Error Code `40307`
     VpsDetected,
 
     /// `403 FORBIDDEN` variant returned when someone tries to submit a records for a player who
@@ -52,6 +56,21 @@ pub enum DemonlistError {
         record_id: i32,
     },
 
+    /// `404 NOT FOUND` variant
+    ///
+    /// Error Code `40401`
+    EvidenceNotFound {
+        evidence_id: i32,
+        record_id: i32,
+    },
+
+    /// `404 NOT FOUND` variant
+    ///
+    /// Error Code `40401`
+    SubmissionBanNotFound {
+        id: i32,
+    },
+
     CreatorNotFound {
         demon_id: i32,
         player_id: i32,
@@ -95,6 +114,17 @@ pub enum DemonlistError {
         player_id: i32,
     },
 
+    ListNotFound {
+        list_id: String,
+    },
+
+    /// `404 NOT FOUND` variant
+    ///
+    /// Error Code `40401`
+    MergeNotFound {
+        id: i32,
+    },
+
     CreatorExists,
 
     /// `409 CONFLICT` variant
@@ -114,6 +144,38 @@ pub enum DemonlistError {
         player2: String,
     },
 
+    /// `409 CONFLICT` variant returned if attempted to create a list with an ID that is already
+    /// in use
+    ///
+    /// Error Code `40909`
+    ListExists,
+
+    /// `409 CONFLICT` variant returned if attempted to modify or lock a record that another list
+    /// team member currently holds a review lock on
+    ///
+    /// Error Code `40910`
+    RecordLocked {
+        reviewer: String,
+    },
+
+    /// `409 CONFLICT` variant returned if attempted to undo a player merge that was already undone
+    ///
+    /// Error Code `40913`
+    MergeUndone,
+
+    /// `409 CONFLICT` variant returned if attempted to undo a player merge that is older than the
+    /// retention window for merge snapshots
+    ///
+    /// Error Code `40914`
+    MergeExpired,
+
+    /// `409 CONFLICT` variant returned if attempted to undo a player merge while a later merge
+    /// involving the same players has not been undone, or while the merged player's name is in
+    /// use by a different player
+    ///
+    /// Error Code `40915`
+    MergeSuperseded,
+
     /// `422 UNPROCESSABLE ENTITY` variant returned if attempted to create a demon with a record
     /// requirements outside of [0, 100]
     ///
@@ -198,6 +260,158 @@ pub enum DemonlistError {
     ///
     /// Error Code `42235`
     InvalidLevelId,
+
+    /// `422 UNPROCESSABLE ENTITY` variant returned if a list's ID does not consist of lowercase
+    /// alphanumeric characters and dashes, or is longer than 32 characters
+    ///
+    /// Error Code `42236`
+    InvalidListId,
+
+    /// `422 UNPROCESSABLE ENTITY` variant returned if a list's main list would be empty, or
+    /// larger than its extended list
+    ///
+    /// Error Code `42237`
+    InvalidListSize,
+
+    /// `422 UNPROCESSABLE ENTITY` variant returned if a list's tiers do not cover every position
+    /// exactly once, if a tier spans multiple parts of the list (as determined by its sizes), or if
+    /// a tier has an invalid ID, label or edit permission
+    ///
+    /// Error Code `42238`
+    InvalidTiers,
+
+    /// `422 UNPROCESSABLE ENTITY` variant returned if a link to click audio is not a valid URL
+    ///
+    /// Error Code `42239`
+    MalformedEvidenceUrl,
+
+    /// `422 UNPROCESSABLE ENTITY` variant returned if a submission ban does not target exactly one
+    /// of a user account or an IP address/network, or if the given network is malformed
+    ///
+    /// Error Code `42240`
+    InvalidBanTarget,
+
+    /// `404 NOT FOUND` variant
+    ///
+    /// Error Code `40401`
+    PendingSubmissionNotFound {
+        id: i32,
+    },
+
+    /// `409 CONFLICT` variant returned if a claim is to be verified through Geometry Dash, but no
+    /// (unexpired) verification code was issued for it
+    ///
+    /// Error Code `40911`
+    NoVerificationCode,
+
+    /// `409 CONFLICT` variant returned if the Geometry Dash account a claim was verified through is
+    /// already linked to a different player
+    ///
+    /// Error Code `40912`
+    GdAccountInUse,
+
+    /// `422 UNPROCESSABLE ENTITY` variant returned if no Geometry Dash account could be found for
+    /// the player a claim is to be verified on
+    ///
+    /// Error Code `42241`
+    GdAccountNotFound {
+        player_name: String,
+    },
+
+    /// `422 UNPROCESSABLE ENTITY` variant returned if a claim's verification code was neither found
+    /// in the comments nor in the profile of the player's Geometry Dash account
+    ///
+    /// Error Code `42242`
+    ClaimVerificationFailed,
+
+    /// `404 NOT FOUND` variant returned if a player has no profile, or no profile changes waiting
+    /// for approval
+    ///
+    /// Error Code `40401`
+    ProfileNotFound {
+        player_id: i32,
+    },
+
+    /// `422 UNPROCESSABLE ENTITY` variant returned if a field of a player profile is too long, or
+    /// is not a valid link or username for the respective platform
+    ///
+    /// Error Code `42243`
+    InvalidProfileField {
+        field: &'static str,
+    },
+
+    /// `409 CONFLICT` variant returned if attempted to create a nationality whose country code or
+    /// name is already in use
+    ///
+    /// Error Code `40916`
+    NationalityExists,
+
+    /// `409 CONFLICT` variant returned if attempted to create a subdivision whose code is already in
+    /// use within its nation, or whose name is already in use
+    ///
+    /// Error Code `40917`
+    SubdivisionExists,
+
+    /// `422 UNPROCESSABLE ENTITY` variant returned if a country code is not an ISO 3166-1 alpha-2
+    /// code
+    ///
+    /// Error Code `42244`
+    InvalidCountryCode,
+
+    /// `422 UNPROCESSABLE ENTITY` variant returned if a subdivision code is not the part of an ISO
+    /// 3166-2 code following the country code
+    ///
+    /// Error Code `42245`
+    InvalidSubdivisionCode,
+
+    /// `422 UNPROCESSABLE ENTITY` variant returned if the part of a level a creator worked on is
+    /// not a valid percentage range
+    ///
+    /// Error Code `42246`
+    InvalidCreatorPart,
+
+    /// `404 NOT FOUND` variant
+    ///
+    /// Error Code `40401`
+    TagNotFound {
+        tag_id: String,
+    },
+
+    /// `404 NOT FOUND` variant returned if a tag exists, but is not assigned to the given demon
+    ///
+    /// Error Code `40401`
+    DemonTagNotFound {
+        demon_id: i32,
+        tag_id: String,
+    },
+
+    /// `409 CONFLICT` variant returned if attempted to create a tag with an ID that is already in
+    /// use
+    ///
+    /// Error Code `40918`
+    TagExists,
+
+    /// `409 CONFLICT` variant returned if attempted to assign a tag to a demon that already has it
+    ///
+    /// Error Code `40919`
+    DemonTagExists,
+
+    /// `422 UNPROCESSABLE ENTITY` variant returned if a tag's ID does not consist of lowercase
+    /// alphanumeric characters and dashes, or is longer than 32 characters
+    ///
+    /// Error Code `42247`
+    InvalidTagId,
+
+    /// `422 UNPROCESSABLE ENTITY` variant returned if a tag has no names, a name is empty or longer
+    /// than 32 characters, or a name is not keyed by a language code
+    ///
+    /// Error Code `42248`
+    InvalidTagName,
+
+    /// `503 SERVICE UNAVAILABLE` variant returned if a request to the Geometry Dash servers failed
+    ///
+    /// Error Code `50302`
+    GeometryDashUnavailable,
 }
 
 impl std::error::Error for DemonlistError {}
@@ -211,6 +425,8 @@ impl PointercrateError for DemonlistError {
             GeolocationFailed => 40003,
             SubmitterNotFound { .. } => 40401,
             NoteNotFound { .. } => 40401,
+            EvidenceNotFound { .. } => 40401,
+            SubmissionBanNotFound { .. } => 40401,
             CreatorNotFound { .. } => 40401,
             CreatorExists => 40905,
             InvalidRequirement => 42212,
@@ -230,9 +446,16 @@ impl PointercrateError for DemonlistError {
             DemonNotFoundPosition { .. } => 40401,
             RecordNotFound { .. } => 40401,
             ClaimNotFound { .. } => 40401,
+            ListNotFound { .. } => 40401,
+            MergeNotFound { .. } => 40401,
             DuplicateVideo { .. } => 40906,
             NoNationSet => 40907,
             ConflictingClaims { .. } => 40908,
+            ListExists => 40909,
+            RecordLocked { .. } => 40910,
+            MergeUndone => 40913,
+            MergeExpired => 40914,
+            MergeSuperseded => 40915,
             InvalidProgress { .. } => 42215,
             SubmissionExists { .. } => 42217,
             PlayerBanned => 42218,
@@ -244,6 +467,30 @@ impl PointercrateError for DemonlistError {
             RawRequired => 42232,
             MalformedRawUrl => 42233,
             InvalidLevelId => 42235,
+            InvalidListId => 42236,
+            InvalidListSize => 42237,
+            InvalidTiers => 42238,
+            MalformedEvidenceUrl => 42239,
+            InvalidBanTarget => 42240,
+            PendingSubmissionNotFound { .. } => 40401,
+            NoVerificationCode => 40911,
+            GdAccountInUse => 40912,
+            GdAccountNotFound { .. } => 42241,
+            ClaimVerificationFailed => 42242,
+            ProfileNotFound { .. } => 40401,
+            InvalidProfileField { .. } => 42243,
+            NationalityExists => 40916,
+            SubdivisionExists => 40917,
+            InvalidCountryCode => 42244,
+            InvalidSubdivisionCode => 42245,
+            InvalidCreatorPart => 42246,
+            TagNotFound { .. } => 40401,
+            DemonTagNotFound { .. } => 40401,
+            TagExists => 40918,
+            DemonTagExists => 40919,
+            InvalidTagId => 42247,
+            InvalidTagName => 42248,
+            GeometryDashUnavailable => 50302,
         }
     }
 }
@@ -267,6 +514,13 @@ impl Display for DemonlistError {
                 DemonlistError::NoteNotFound { note_id, record_id } => {
                     trp!("error-demonlist-notenotfound", "note-id" = note_id, "record-id" = record_id)
                 },
+                DemonlistError::EvidenceNotFound { evidence_id, record_id } => {
+                    trp!(
+                        "error-demonlist-evidencenotfound",
+                        "evidence-id" = evidence_id,
+                        "record-id" = record_id
+                    )
+                },
                 DemonlistError::CreatorNotFound { demon_id, player_id } => {
                     trp!("error-demonlist-creatornotfound", "player-id" = player_id, "demon-id" = demon_id)
                 },
@@ -289,11 +543,18 @@ impl Display for DemonlistError {
                 DemonlistError::RecordNotFound { record_id } => trp!("error-demonlist-recordnotfound", "record-id" = record_id),
                 DemonlistError::ClaimNotFound { member_id, player_id } =>
                     trp!("error-demonlist-claimnotfound", "member-id" = member_id, "player-id" = player_id),
+                DemonlistError::ListNotFound { list_id } => trp!("error-demonlist-listnotfound", "list-id" = list_id),
+                DemonlistError::MergeNotFound { id } => trp!("error-demonlist-mergenotfound", "id" = id),
                 DemonlistError::CreatorExists => tr("error-demonlist-creatorexists"),
                 DemonlistError::DuplicateVideo { id } => trp!("error-demonlist-duplicatevideo", "record-id" = id),
                 DemonlistError::NoNationSet => tr("error-demonlist-nonationset"),
                 DemonlistError::ConflictingClaims { player1, player2 } =>
                     trp!("error-demonlist-conflictingclaims", "player-1" = player1, "player-2" = player2),
+                DemonlistError::ListExists => tr("error-demonlist-listexists"),
+                DemonlistError::RecordLocked { reviewer } => trp!("error-demonlist-recordlocked", "reviewer" = reviewer),
+                DemonlistError::MergeUndone => tr("error-demonlist-mergeundone"),
+                DemonlistError::MergeExpired => tr("error-demonlist-mergeexpired"),
+                DemonlistError::MergeSuperseded => tr("error-demonlist-mergesuperseded"),
                 DemonlistError::InvalidRequirement => tr("error-demonlist-invalidrequirement"),
                 DemonlistError::InvalidPosition { maximal } => trp!("error-demonlist-invalidposition", "maximal" = maximal),
                 DemonlistError::InvalidProgress { requirement } => trp!("error-demonlist-invalidprogress", "requirement" = requirement),
@@ -312,6 +573,32 @@ impl Display for DemonlistError {
                 DemonlistError::RawRequired => tr("error-demonlist-rawrequired"),
                 DemonlistError::MalformedRawUrl => tr("error-demonlist-malformedrawurl"),
                 DemonlistError::InvalidLevelId => tr("error-demonlist-invalidlevelid"),
+                DemonlistError::InvalidListId => tr("error-demonlist-invalidlistid"),
+                DemonlistError::InvalidListSize => tr("error-demonlist-invalidlistsize"),
+                DemonlistError::InvalidTiers => tr("error-demonlist-invalidtiers"),
+                DemonlistError::MalformedEvidenceUrl => tr("error-demonlist-malformedevidenceurl"),
+                DemonlistError::SubmissionBanNotFound { id } => trp!("error-demonlist-submissionbannotfound", "id" = id),
+                DemonlistError::InvalidBanTarget => tr("error-demonlist-invalidbantarget"),
+                DemonlistError::PendingSubmissionNotFound { id } => trp!("error-demonlist-pendingsubmissionnotfound", "id" = id),
+                DemonlistError::NoVerificationCode => tr("error-demonlist-noverificationcode"),
+                DemonlistError::GdAccountInUse => tr("error-demonlist-gdaccountinuse"),
+                DemonlistError::GdAccountNotFound { player_name } => trp!("error-demonlist-gdaccountnotfound", "player-name" = player_name),
+                DemonlistError::ClaimVerificationFailed => tr("error-demonlist-claimverificationfailed"),
+                DemonlistError::ProfileNotFound { player_id } => trp!("error-demonlist-profilenotfound", "player-id" = player_id),
+                DemonlistError::InvalidProfileField { field } => trp!("error-demonlist-invalidprofilefield", "field" = field),
+                DemonlistError::NationalityExists => tr("error-demonlist-nationalityexists"),
+                DemonlistError::SubdivisionExists => tr("error-demonlist-subdivisionexists"),
+                DemonlistError::InvalidCountryCode => tr("error-demonlist-invalidcountrycode"),
+                DemonlistError::InvalidSubdivisionCode => tr("error-demonlist-invalidsubdivisioncode"),
+                DemonlistError::InvalidCreatorPart => tr("error-demonlist-invalidcreatorpart"),
+                DemonlistError::TagNotFound { tag_id } => trp!("error-demonlist-tagnotfound", "tag-id" = tag_id),
+                DemonlistError::DemonTagNotFound { demon_id, tag_id } =>
+                    trp!("error-demonlist-demontagnotfound", "demon-id" = demon_id, "tag-id" = tag_id),
+                DemonlistError::TagExists => tr("error-demonlist-tagexists"),
+                DemonlistError::DemonTagExists => tr("error-demonlist-demontagexists"),
+                DemonlistError::InvalidTagId => tr("error-demonlist-invalidtagid"),
+                DemonlistError::InvalidTagName => tr("error-demonlist-invalidtagname"),
+                DemonlistError::GeometryDashUnavailable => tr("error-demonlist-geometrydashunavailable"),
             }
         )
     }
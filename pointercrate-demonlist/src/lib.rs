@@ -3,14 +3,15 @@ use pointercrate_user::ADMINISTRATOR;
 
 #[macro_use]
 pub mod demon;
-pub mod config;
 pub mod creator;
 pub mod error;
+pub mod list;
 pub mod nationality;
 pub mod player;
 pub mod record;
 pub mod submitter;
-mod video;
+pub mod tag;
+pub mod video;
 
 pub const LIST_HELPER: Permission = Permission::new("user-permissions.list-helper", 0x2);
 pub const LIST_MODERATOR: Permission = Permission::new("user-permissions.list-moderator", 0x4);
@@ -0,0 +1,32 @@
+use crate::{
+    error::{DemonlistError, Result},
+    list::{List, DEFAULT_LIST},
+};
+use sqlx::{Error, PgConnection};
+
+impl List {
+    pub async fn by_id(id: &str, connection: &mut PgConnection) -> Result<List> {
+        let result = sqlx::query_as!(List, "SELECT id, name, list_size, extended_list_size FROM lists WHERE id = $1", id)
+            .fetch_one(connection)
+            .await;
+
+        match result {
+            Ok(list) => Ok(list),
+            Err(Error::RowNotFound) => Err(DemonlistError::ListNotFound { list_id: id.to_string() }),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Retrieves the list that all endpoints predating support for multiple lists operate on
+    pub async fn default_list(connection: &mut PgConnection) -> Result<List> {
+        List::by_id(DEFAULT_LIST, connection).await
+    }
+
+    pub async fn all(connection: &mut PgConnection) -> Result<Vec<List>> {
+        Ok(
+            sqlx::query_as!(List, "SELECT id, name, list_size, extended_list_size FROM lists ORDER BY id")
+                .fetch_all(connection)
+                .await?,
+        )
+    }
+}
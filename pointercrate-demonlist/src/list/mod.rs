@@ -0,0 +1,48 @@
+//! Module for the lists hosted by a pointercrate instance
+//!
+//! Each list has its own demons, positions and sizes, and players are scored and ranked per list.
+//! Players, nationalities, users and claims are shared between all lists.
+
+use derive_more::Display;
+use pointercrate_core::etag::Taggable;
+use serde::{Deserialize, Serialize};
+
+pub use self::{
+    patch::PatchList,
+    post::PostList,
+    tier::{default_tiers, PostTier, Tier, TierSubmissions, Tiers},
+};
+
+mod get;
+mod patch;
+mod post;
+mod tier;
+
+/// The ID of the list that all endpoints predating support for multiple lists operate on
+pub const DEFAULT_LIST: &str = "main";
+
+#[derive(Debug, Serialize, Deserialize, Hash, Display, PartialEq, Eq, Clone)]
+#[display("{} ({})", name, id)]
+pub struct List {
+    /// The list's unique identifier, as used in URLs
+    pub id: String,
+
+    /// The list's human-readable name
+    pub name: String,
+
+    /// The number of demons on the main list. Records on demons beyond this position only give
+    /// points if they have 100% progress.
+    pub list_size: i16,
+
+    /// The number of demons on the main and extended list combined. Demons beyond this position
+    /// are considered legacy and do not accept records.
+    pub extended_list_size: i16,
+}
+
+impl Taggable for List {}
+
+impl List {
+    pub fn is_default(&self) -> bool {
+        self.id == DEFAULT_LIST
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{
+    error::Result,
+    list::{tier::extended_tier, List, PostTier},
+    player::recompute_scores,
+};
+use log::info;
+use pointercrate_core::util::non_nullable;
+use serde::Deserialize;
+use sqlx::PgConnection;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct PatchList {
+    #[serde(default, deserialize_with = "non_nullable")]
+    pub name: Option<String>,
+
+    #[serde(default, deserialize_with = "non_nullable")]
+    pub list_size: Option<i16>,
+
+    #[serde(default, deserialize_with = "non_nullable")]
+    pub extended_list_size: Option<i16>,
+}
+
+impl List {
+    /// Must be run within a transaction!
+    pub async fn apply_patch(mut self, patch: PatchList, connection: &mut PgConnection) -> Result<Self> {
+        info!("Patching list {} with {:?}", self, patch);
+
+        if let Some(name) = patch.name {
+            sqlx::query!("UPDATE lists SET name = $1 WHERE id = $2", name, self.id)
+                .execute(&mut *connection)
+                .await?;
+
+            self.name = name;
+        }
+
+        let list_size = patch.list_size.unwrap_or(self.list_size);
+        let extended_list_size = patch.extended_list_size.unwrap_or(self.extended_list_size);
+
+        if list_size != self.list_size || extended_list_size != self.extended_list_size {
+            List::validate_sizes(list_size, extended_list_size)?;
+
+            // Move the tier boundaries at the old sizes along, so that the tiers keep agreeing with the sizes
+            let mut tiers: Vec<_> = self
+                .tiers(&mut *connection)
+                .await?
+                .0
+                .into_iter()
+                .map(|tier| PostTier {
+                    id: tier.id,
+                    label: tier.label,
+                    last_position: tier.last_position.map(|last| {
+                        if last == self.list_size {
+                            list_size
+                        } else if last == self.extended_list_size {
+                            extended_list_size
+                        } else {
+                            last
+                        }
+                    }),
+                    submissions: tier.submissions,
+                    edit_permission: tier.edit_permission.text_id().to_string(),
+                })
+                .collect();
+
+            // Lists without an extended part have no tier boundary that could be moved to the new
+            // extended list size, so give them the default extended tier
+            if self.list_size == self.extended_list_size && extended_list_size > list_size {
+                let main_tier = tiers.iter().position(|tier| tier.last_position == Some(list_size));
+
+                if let Some(index) = main_tier {
+                    tiers.insert(index + 1, extended_tier(extended_list_size));
+                }
+            }
+
+            sqlx::query!(
+                "UPDATE lists SET list_size = $1, extended_list_size = $2 WHERE id = $3",
+                list_size,
+                extended_list_size,
+                self.id
+            )
+            .execute(&mut *connection)
+            .await?;
+
+            self.list_size = list_size;
+            self.extended_list_size = extended_list_size;
+
+            self.set_tiers(tiers, connection).await?;
+
+            // The list sizes determine which records give points
+            recompute_scores(&self.id, connection).await?;
+        }
+
+        Ok(self)
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{
+    error::{DemonlistError, Result},
+    list::{default_tiers, List},
+};
+use log::info;
+use serde::Deserialize;
+use sqlx::PgConnection;
+
+#[derive(Deserialize, Debug)]
+pub struct PostList {
+    pub id: String,
+    pub name: String,
+    pub list_size: i16,
+    pub extended_list_size: i16,
+}
+
+impl List {
+    /// Must be run within a transaction!
+    pub async fn create_from(data: PostList, connection: &mut PgConnection) -> Result<List> {
+        info!("Creating new list from {:?}", data);
+
+        List::validate_id(&data.id)?;
+        List::validate_sizes(data.list_size, data.extended_list_size)?;
+
+        let exists = sqlx::query!("SELECT EXISTS (SELECT 1 FROM lists WHERE id = $1) AS \"exists!\"", data.id)
+            .fetch_one(&mut *connection)
+            .await?
+            .exists;
+
+        if exists {
+            return Err(DemonlistError::ListExists);
+        }
+
+        sqlx::query!(
+            "INSERT INTO lists (id, name, list_size, extended_list_size) VALUES ($1, $2, $3, $4)",
+            data.id,
+            data.name,
+            data.list_size,
+            data.extended_list_size
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        let list = List {
+            id: data.id,
+            name: data.name,
+            list_size: data.list_size,
+            extended_list_size: data.extended_list_size,
+        };
+
+        list.set_tiers(default_tiers(list.list_size, list.extended_list_size), connection)
+            .await?;
+
+        Ok(list)
+    }
+
+    /// List IDs are used in URLs, so they are restricted to groups of lowercase alphanumeric
+    /// characters separated by single dashes
+    pub fn validate_id(id: &str) -> Result<()> {
+        let valid = !id.is_empty()
+            && id.len() <= 32
+            && id
+                .split('-')
+                .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()));
+
+        if !valid {
+            return Err(DemonlistError::InvalidListId);
+        }
+
+        Ok(())
+    }
+
+    pub fn validate_sizes(list_size: i16, extended_list_size: i16) -> Result<()> {
+        if list_size < 1 || extended_list_size < list_size {
+            return Err(DemonlistError::InvalidListSize);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::DemonlistError, list::List};
+
+    #[test]
+    fn test_validate_id() {
+        assert_eq!(List::validate_id("main"), Ok(()));
+        assert_eq!(List::validate_id("platformer-2"), Ok(()));
+
+        assert_eq!(List::validate_id(""), Err(DemonlistError::InvalidListId));
+        assert_eq!(List::validate_id("Main"), Err(DemonlistError::InvalidListId));
+        assert_eq!(List::validate_id("-main"), Err(DemonlistError::InvalidListId));
+        assert_eq!(List::validate_id("challenge--list"), Err(DemonlistError::InvalidListId));
+        assert_eq!(List::validate_id("challenge/list"), Err(DemonlistError::InvalidListId));
+        assert_eq!(List::validate_id(&"a".repeat(33)), Err(DemonlistError::InvalidListId));
+    }
+}
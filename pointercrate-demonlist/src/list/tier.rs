@@ -0,0 +1,339 @@
+//! Module for list tiers
+//!
+//! Each list is split into consecutive tiers (e.g. the "main", "extended" and "legacy" sections on
+//! pointercrate). Tiers decide which records can be submitted for a demon and who can edit records
+//! on it. Scoring is governed by the list's sizes, which always need to coincide with tier boundaries,
+//! so that each tier lies entirely within the main, extended or legacy part of the list.
+
+use crate::{
+    error::{DemonlistError, Result},
+    list::List,
+    LIST_ADMINISTRATOR, LIST_HELPER, LIST_MODERATOR,
+};
+use log::info;
+use pointercrate_core::{etag::Taggable, permission::Permission};
+use serde::{Deserialize, Serialize};
+use sqlx::PgConnection;
+use std::collections::HashSet;
+
+/// The permissions that can be required for editing the records in a tier
+const TIER_PERMISSIONS: [Permission; 3] = [LIST_HELPER, LIST_MODERATOR, LIST_ADMINISTRATOR];
+
+#[derive(Debug, Serialize, Deserialize, Hash, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum TierSubmissions {
+    /// Records with any progress above the demon's requirement can be submitted
+    Open,
+
+    /// Only 100% records can be submitted
+    CompletionsOnly,
+
+    /// No records can be submitted. List moderators can still add them directly.
+    Closed,
+}
+
+impl TierSubmissions {
+    fn to_sql(self) -> &'static str {
+        match self {
+            TierSubmissions::Open => "OPEN",
+            TierSubmissions::CompletionsOnly => "COMPLETIONS_ONLY",
+            TierSubmissions::Closed => "CLOSED",
+        }
+    }
+
+    fn from_sql(sql: &str) -> Option<Self> {
+        match sql {
+            "OPEN" => Some(TierSubmissions::Open),
+            "COMPLETIONS_ONLY" => Some(TierSubmissions::CompletionsOnly),
+            "CLOSED" => Some(TierSubmissions::Closed),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Hash, PartialEq, Eq, Clone)]
+pub struct Tier {
+    /// The tier's identifier, unique within its list
+    pub id: String,
+
+    /// Fluent text id of the tier's name. Its `info` attribute is used as the tier's description.
+    /// If no such text id exists, the label itself is displayed as the tier's name.
+    pub label: String,
+
+    /// The first position that is part of this tier
+    pub first_position: i16,
+
+    /// The last position that is part of this tier. `None` if this tier contains all demons after
+    /// `first_position`
+    pub last_position: Option<i16>,
+
+    pub submissions: TierSubmissions,
+
+    /// The permission required to modify records on demons in this tier
+    pub edit_permission: Permission,
+}
+
+/// Description of a single tier when (re-)defining all tiers of a list.
+///
+/// The start of a tier's position range is implied by the previous tier's `last_position`.
+#[derive(Debug, Deserialize, Clone)]
+pub struct PostTier {
+    pub id: String,
+    pub label: String,
+    #[serde(default)]
+    pub last_position: Option<i16>,
+    pub submissions: TierSubmissions,
+    pub edit_permission: String,
+}
+
+/// Wrapper around the tiers of a list, so that they can be tagged as a whole
+#[derive(Debug, Serialize, Hash)]
+#[serde(transparent)]
+pub struct Tiers(pub Vec<Tier>);
+
+impl Taggable for Tiers {}
+
+impl Tier {
+    pub fn contains(&self, position: i16) -> bool {
+        position >= self.first_position && self.last_position.is_none_or(|last| position <= last)
+    }
+}
+
+impl Tiers {
+    pub async fn of_list(list_id: &str, connection: &mut PgConnection) -> Result<Tiers> {
+        let rows = sqlx::query!(
+            r#"SELECT id, label, last_position, submissions::TEXT AS "submissions!", edit_permission FROM list_tiers WHERE list = $1
+             ORDER BY last_position NULLS LAST"#,
+            list_id
+        )
+        .fetch_all(connection)
+        .await?;
+
+        let mut first_position = 1;
+        let mut tiers = Vec::new();
+
+        for row in rows {
+            tiers.push(Tier {
+                id: row.id,
+                label: row.label,
+                first_position,
+                last_position: row.last_position,
+                // Like for positions not covered by any tier, be conservative about unknown values
+                submissions: TierSubmissions::from_sql(&row.submissions).unwrap_or(TierSubmissions::Closed),
+                edit_permission: TIER_PERMISSIONS
+                    .into_iter()
+                    .find(|perm| perm.bit() as i16 == row.edit_permission)
+                    .unwrap_or(LIST_ADMINISTRATOR),
+            });
+
+            first_position = row.last_position.map_or(i16::MAX, |last| last + 1);
+        }
+
+        Ok(Tiers(tiers))
+    }
+
+    /// Gets the tier the given position is part of.
+    ///
+    /// Tiers are validated to always cover all positions, so this only returns `None` if they were
+    /// modified directly in the database.
+    pub fn tier_of(&self, position: i16) -> Option<&Tier> {
+        self.0.iter().find(|tier| tier.contains(position))
+    }
+
+    /// Gets the permission required to modify records on a demon at the given position
+    ///
+    /// Falls back to [`LIST_MODERATOR`] for positions not covered by any tier.
+    pub fn edit_permission(&self, position: i16) -> Permission {
+        self.tier_of(position).map_or(LIST_MODERATOR, |tier| tier.edit_permission)
+    }
+
+    /// Gets the submission rule for a demon at the given position
+    ///
+    /// Submissions are closed for positions not covered by any tier.
+    pub fn submissions(&self, position: i16) -> TierSubmissions {
+        self.tier_of(position).map_or(TierSubmissions::Closed, |tier| tier.submissions)
+    }
+
+    /// The last position for which records can be submitted, or `None` if records can be
+    /// submitted for all positions
+    pub fn last_open_position(&self) -> Option<i16> {
+        let mut last_open = Some(0);
+
+        for tier in &self.0 {
+            if tier.submissions != TierSubmissions::Closed {
+                last_open = tier.last_position;
+            }
+        }
+
+        last_open
+    }
+}
+
+impl List {
+    pub async fn tiers(&self, connection: &mut PgConnection) -> Result<Tiers> {
+        Tiers::of_list(&self.id, connection).await
+    }
+
+    /// Replaces all tiers of this list with the given ones
+    ///
+    /// Must be run within a transaction!
+    pub async fn set_tiers(&self, tiers: Vec<PostTier>, connection: &mut PgConnection) -> Result<Tiers> {
+        info!("Replacing tiers of list {} with {:?}", self, tiers);
+
+        let permissions = validate_tiers(&tiers, self.list_size, self.extended_list_size)?;
+
+        sqlx::query!("DELETE FROM list_tiers WHERE list = $1", self.id)
+            .execute(&mut *connection)
+            .await?;
+
+        for (tier, permission) in tiers.iter().zip(permissions) {
+            sqlx::query!(
+                "INSERT INTO list_tiers (list, id, label, last_position, submissions, edit_permission) VALUES ($1, $2, $3, $4, \
+                 $5::TEXT::TIER_SUBMISSIONS, $6)",
+                self.id,
+                tier.id,
+                tier.label,
+                tier.last_position,
+                tier.submissions.to_sql(),
+                permission.bit() as i16
+            )
+            .execute(&mut *connection)
+            .await?;
+        }
+
+        self.tiers(connection).await
+    }
+}
+
+/// The tiers newly created lists start out with, mirroring the main/extended/legacy split of
+/// pointercrate.com
+pub fn default_tiers(list_size: i16, extended_list_size: i16) -> Vec<PostTier> {
+    let mut tiers = vec![PostTier {
+        id: "main".to_string(),
+        label: "main-list".to_string(),
+        last_position: Some(list_size),
+        submissions: TierSubmissions::Open,
+        edit_permission: LIST_HELPER.text_id().to_string(),
+    }];
+
+    if extended_list_size > list_size {
+        tiers.push(extended_tier(extended_list_size));
+    }
+
+    tiers.push(PostTier {
+        id: "legacy".to_string(),
+        label: "legacy-list".to_string(),
+        last_position: None,
+        submissions: TierSubmissions::Closed,
+        edit_permission: LIST_MODERATOR.text_id().to_string(),
+    });
+
+    tiers
+}
+
+/// The tier covering the extended part of a list in the [default tiers](default_tiers)
+pub(crate) fn extended_tier(extended_list_size: i16) -> PostTier {
+    PostTier {
+        id: "extended".to_string(),
+        label: "extended-list".to_string(),
+        last_position: Some(extended_list_size),
+        submissions: TierSubmissions::CompletionsOnly,
+        edit_permission: LIST_HELPER.text_id().to_string(),
+    }
+}
+
+/// Validates that the given tiers cover all positions exactly once without crossing any of the
+/// given list sizes, and resolves their edit permissions
+///
+/// This means their last positions need to be strictly increasing, with only the final tier being
+/// unbounded, and both list sizes need to be the last position of some tier.
+fn validate_tiers(tiers: &[PostTier], list_size: i16, extended_list_size: i16) -> Result<Vec<Permission>> {
+    let mut ids = HashSet::new();
+    let mut previous_last = 0;
+    let mut permissions = Vec::new();
+
+    for (index, tier) in tiers.iter().enumerate() {
+        List::validate_id(&tier.id).map_err(|_| DemonlistError::InvalidTiers)?;
+
+        if !ids.insert(&tier.id) || tier.label.trim().is_empty() {
+            return Err(DemonlistError::InvalidTiers);
+        }
+
+        match tier.last_position {
+            Some(last) if last > previous_last => previous_last = last,
+            None if index == tiers.len() - 1 => (),
+            _ => return Err(DemonlistError::InvalidTiers),
+        }
+
+        let permission = TIER_PERMISSIONS
+            .into_iter()
+            .find(|perm| perm.text_id() == tier.edit_permission)
+            .ok_or(DemonlistError::InvalidTiers)?;
+
+        permissions.push(permission);
+    }
+
+    // The final tier needs to cover all remaining positions (this also rejects an empty list of tiers)
+    if tiers.last().is_none_or(|tier| tier.last_position.is_some()) {
+        return Err(DemonlistError::InvalidTiers);
+    }
+
+    // Otherwise, records on some demons of a tier would give points differently than records on others
+    for size in [list_size, extended_list_size] {
+        if !tiers.iter().any(|tier| tier.last_position == Some(size)) {
+            return Err(DemonlistError::InvalidTiers);
+        }
+    }
+
+    Ok(permissions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{default_tiers, validate_tiers, PostTier, TierSubmissions};
+    use crate::error::DemonlistError;
+
+    fn tier(id: &str, last_position: Option<i16>) -> PostTier {
+        PostTier {
+            id: id.to_string(),
+            label: "main-list".to_string(),
+            last_position,
+            submissions: TierSubmissions::Open,
+            edit_permission: "user-permissions.list-helper".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_validate_tiers() {
+        assert!(validate_tiers(&default_tiers(75, 150), 75, 150).is_ok());
+        assert!(validate_tiers(&default_tiers(50, 50), 50, 50).is_ok());
+        assert!(validate_tiers(&[tier("main", Some(75)), tier("all", None)], 75, 75).is_ok());
+
+        assert_eq!(validate_tiers(&[], 75, 75), Err(DemonlistError::InvalidTiers));
+        assert_eq!(validate_tiers(&[tier("main", Some(75))], 75, 75), Err(DemonlistError::InvalidTiers));
+        assert_eq!(
+            validate_tiers(&[tier("main", Some(75)), tier("extended", Some(75)), tier("legacy", None)], 75, 75),
+            Err(DemonlistError::InvalidTiers)
+        );
+        assert_eq!(
+            validate_tiers(&[tier("main", None), tier("legacy", None)], 75, 75),
+            Err(DemonlistError::InvalidTiers)
+        );
+        assert_eq!(
+            validate_tiers(&[tier("main", Some(75)), tier("main", None)], 75, 75),
+            Err(DemonlistError::InvalidTiers)
+        );
+
+        let mut unknown_permission = tier("legacy", None);
+        unknown_permission.edit_permission = "user-permissions.administrator".to_string();
+
+        assert_eq!(
+            validate_tiers(&[tier("main", Some(75)), unknown_permission], 75, 75),
+            Err(DemonlistError::InvalidTiers)
+        );
+
+        // Tiers may not span multiple parts of the list
+        assert_eq!(validate_tiers(&[tier("all", None)], 75, 150), Err(DemonlistError::InvalidTiers));
+        assert_eq!(validate_tiers(&default_tiers(75, 150), 50, 150), Err(DemonlistError::InvalidTiers));
+    }
+}
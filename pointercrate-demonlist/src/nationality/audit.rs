@@ -0,0 +1,67 @@
+use crate::{
+    error::Result,
+    nationality::{Continent, Nationality},
+};
+use chrono::NaiveDateTime;
+use futures::StreamExt;
+use pointercrate_core::audit::{AuditLogEntryType, NamedId};
+use serde::Serialize;
+use sqlx::PgConnection;
+
+/// The old values of all fields changed by some modification
+///
+/// For entries about subdivisions, `name` is the subdivision's old name and `continent` is never
+/// set.
+#[derive(Serialize, Debug)]
+pub struct NationalityModificationData {
+    pub name: Option<String>,
+    pub continent: Option<Continent>,
+}
+
+/// An entry in the audit log of a nation
+///
+/// Unlike [`AuditLogEntry`](pointercrate_core::audit::AuditLogEntry), entries are not about a
+/// single object, but about either the nation itself or one of its subdivisions.
+#[derive(Serialize, Debug)]
+pub struct NationalityAuditLogEntry {
+    pub time: NaiveDateTime,
+    pub entry_id: i32,
+    pub user: NamedId,
+
+    /// The code of the subdivision this entry is about, or `None` if it is about the nation itself
+    pub subdivision: Option<String>,
+    pub r#type: AuditLogEntryType<NationalityModificationData>,
+}
+
+impl Nationality {
+    pub async fn audit_log(&self, connection: &mut PgConnection) -> Result<Vec<NationalityAuditLogEntry>> {
+        let mut stream = sqlx::query_file!("sql/nationality_audit_log.sql", self.iso_country_code).fetch(connection);
+        let mut entries = Vec::new();
+
+        while let Some(row) = stream.next().await {
+            let row = row?;
+
+            let r#type = match &row.kind[..] {
+                "addition" => AuditLogEntryType::Addition,
+                "deletion" => AuditLogEntryType::Deletion,
+                _ => AuditLogEntryType::Modification(NationalityModificationData {
+                    name: row.name,
+                    continent: row.continent.as_deref().and_then(Continent::from_sql),
+                }),
+            };
+
+            entries.push(NationalityAuditLogEntry {
+                time: row.time,
+                entry_id: row.audit_id,
+                user: NamedId {
+                    id: row.userid,
+                    name: row.username,
+                },
+                subdivision: row.subdivision,
+                r#type,
+            })
+        }
+
+        Ok(entries)
+    }
+}
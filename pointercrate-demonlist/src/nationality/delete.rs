@@ -0,0 +1,43 @@
+use crate::{
+    error::Result,
+    nationality::{Nationality, Subdivision},
+};
+use log::info;
+use sqlx::PgConnection;
+
+impl Nationality {
+    /// Deletes the given subdivision of this nation
+    ///
+    /// Players from the subdivision keep their nationality, but no longer have a subdivision set.
+    /// The subdivision's scores are dropped, and the nation's scores are recomputed.
+    ///
+    /// Must be run within a transaction!
+    pub async fn delete_subdivision(&self, subdivision: Subdivision, connection: &mut PgConnection) -> Result<()> {
+        info!("Deleting subdivision {} of {}", subdivision.iso_code, self.iso_country_code);
+
+        sqlx::query!(
+            "UPDATE players SET subdivision = NULL WHERE nationality = $1 AND subdivision = $2",
+            self.iso_country_code,
+            subdivision.iso_code
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        sqlx::query!(
+            "DELETE FROM subdivisions WHERE nation = $1 AND iso_code = $2",
+            self.iso_country_code,
+            subdivision.iso_code
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        Nationality {
+            subdivision: None,
+            ..self.clone()
+        }
+        .update_nation_score(connection)
+        .await?;
+
+        Ok(())
+    }
+}
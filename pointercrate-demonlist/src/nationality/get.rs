@@ -1,9 +1,10 @@
 use crate::{
     demon::MinimalDemon,
     error::{DemonlistError, Result},
-    nationality::{BestRecord, MiniDemonWithPlayers, Nationality, NationalityRecord, Subdivision},
+    nationality::{BestRecord, Continent, MiniDemonWithPlayers, Nationality, NationalityRecord, NationalityWithContinent, Subdivision},
 };
 use futures::stream::StreamExt;
+use pointercrate_core::error::CoreError;
 use sqlx::{Error, PgConnection};
 
 impl Nationality {
@@ -52,6 +53,24 @@ impl Nationality {
         })
     }
 
+    pub async fn with_continent(self, connection: &mut PgConnection) -> Result<NationalityWithContinent> {
+        let continent = sqlx::query!(
+            r#"SELECT continent::TEXT as "continent!" FROM nationalities WHERE iso_country_code = $1"#,
+            self.iso_country_code
+        )
+        .fetch_one(connection)
+        .await?
+        .continent;
+
+        let continent =
+            Continent::from_sql(&continent).ok_or_else(|| CoreError::internal_server_error(format!("invalid continent: {}", continent)))?;
+
+        Ok(NationalityWithContinent {
+            continent,
+            nationality: self,
+        })
+    }
+
     pub async fn subdivision_by_code(&self, code: &str, connection: &mut PgConnection) -> Result<Subdivision> {
         let result = sqlx::query!(
             "SELECT name FROM subdivisions WHERE iso_code = $1 AND nation = $2",
@@ -125,12 +144,17 @@ impl Nationality {
     }
 }
 
+/// The subdivision the statistics in this module should be restricted to, if any
+fn subdivision_code(nation: &Nationality) -> Option<&str> {
+    nation.subdivision.as_ref().map(|subdivision| subdivision.iso_code.as_str())
+}
+
 pub async fn unbeaten_in(nation: &Nationality, connection: &mut PgConnection) -> Result<Vec<MinimalDemon>> {
     let mut stream = sqlx::query!(
-        r#"select name::text as "name!", id as "id!", position as "position!" from demons where position <= $1 except (select demons.name, demons.id, position from records inner join players on 
-         players.id=records.player inner join demons on demons.id=records.demon where status_='APPROVED' and nationality=$2 and progress=100 union select demons.name, demons.id, demons.position from demons inner join players on players.id=verifier where players.nationality=$2)"#,
-        crate::config::extended_list_size(),
-        nation.iso_country_code
+        r#"select demons.name::text as "name!", demons.id as "id!", position as "position!", list as "list!" from demons inner join lists on lists.id = demons.list where position <= lists.extended_list_size except (select demons.name, demons.id, position, list from records inner join players on 
+         players.id=records.player inner join demons on demons.id=records.demon where status_='APPROVED' and nationality=$1 and (players.subdivision=$2 or $2 is null) and progress=100 union select demons.name, demons.id, demons.position, demons.list from demons inner join players on players.id=verifier where players.nationality=$1 and (players.subdivision=$2 or $2 is null))"#,
+        nation.iso_country_code,
+        subdivision_code(nation)
     )
     .fetch(connection);
 
@@ -143,6 +167,7 @@ pub async fn unbeaten_in(nation: &Nationality, connection: &mut PgConnection) ->
             id: row.id,
             position: row.position,
             name: row.name,
+            list: row.list,
         });
     }
 
@@ -150,7 +175,7 @@ pub async fn unbeaten_in(nation: &Nationality, connection: &mut PgConnection) ->
 }
 
 pub async fn created_in(nation: &Nationality, connection: &mut PgConnection) -> Result<Vec<MiniDemonWithPlayers>> {
-    let mut stream = sqlx::query!( r#"select demon, demons.name::text as "demon_name!", demons.position, players.name::text as "player_name!" from creators inner join demons on demons.id=demon inner join players on players.id=creator where nationality=$1 order by demon"#, nation.iso_country_code).fetch(connection);
+    let mut stream = sqlx::query!( r#"select demon, demons.name::text as "demon_name!", demons.position, demons.list, players.name::text as "player_name!" from creators inner join demons on demons.id=demon inner join players on players.id=creator where nationality=$1 and (subdivision=$2 or $2 is null) order by demon"#, nation.iso_country_code, subdivision_code(nation)).fetch(connection);
 
     let mut creations = Vec::<MiniDemonWithPlayers>::new();
 
@@ -164,6 +189,7 @@ pub async fn created_in(nation: &Nationality, connection: &mut PgConnection) ->
                     id: row.demon,
                     name: row.demon_name,
                     position: row.position,
+                    list: row.list,
                 },
                 players: vec![row.player_name],
             }),
@@ -175,7 +201,7 @@ pub async fn created_in(nation: &Nationality, connection: &mut PgConnection) ->
 
 pub async fn verified_in(nation: &Nationality, connection: &mut PgConnection) -> Result<Vec<MiniDemonWithPlayers>> {
     let mut stream = sqlx::query!(
-        r#"select demons.id as demon, demons.name::text as "demon_name!", demons.position, players.name::text as "player_name!" from demons inner join players on players.id=verifier where nationality=$1"#, nation.iso_country_code).fetch(connection);
+        r#"select demons.id as demon, demons.name::text as "demon_name!", demons.position, demons.list, players.name::text as "player_name!" from demons inner join players on players.id=verifier where nationality=$1 and (subdivision=$2 or $2 is null)"#, nation.iso_country_code, subdivision_code(nation)).fetch(connection);
 
     let mut demons = Vec::new();
 
@@ -187,6 +213,7 @@ pub async fn verified_in(nation: &Nationality, connection: &mut PgConnection) ->
                 id: row.demon,
                 name: row.demon_name,
                 position: row.position,
+                list: row.list,
             },
             players: vec![row.player_name],
         });
@@ -197,7 +224,7 @@ pub async fn verified_in(nation: &Nationality, connection: &mut PgConnection) ->
 
 pub async fn published_in(nation: &Nationality, connection: &mut PgConnection) -> Result<Vec<MiniDemonWithPlayers>> {
     let mut stream = sqlx::query!(
-        r#"select demons.id as demon, demons.name::text as "demon_name!", demons.position, players.name::text as "player_name!" from demons inner join players on players.id=publisher where nationality=$1"#, nation.iso_country_code).fetch(connection);
+        r#"select demons.id as demon, demons.name::text as "demon_name!", demons.position, demons.list, players.name::text as "player_name!" from demons inner join players on players.id=publisher where nationality=$1 and (subdivision=$2 or $2 is null)"#, nation.iso_country_code, subdivision_code(nation)).fetch(connection);
 
     let mut demons = Vec::new();
 
@@ -209,6 +236,7 @@ pub async fn published_in(nation: &Nationality, connection: &mut PgConnection) -
                 id: row.demon,
                 name: row.demon_name,
                 position: row.position,
+                list: row.list,
             },
             players: vec![row.player_name],
         });
@@ -219,8 +247,9 @@ pub async fn published_in(nation: &Nationality, connection: &mut PgConnection) -
 
 pub async fn best_records_in(nation: &Nationality, connection: &mut PgConnection) -> Result<Vec<BestRecord>> {
     let mut stream = sqlx::query!(
-        r#"SELECT progress as "progress!", demons.id AS "demon_id!", demons.name as "demon_name!: String", demons.position as "position!", players.name as "player_name!: String" FROM best_records_in($1) as records INNER JOIN demons ON records.demon = demons.id INNER JOIN players ON players.id = records.player"#,
-        nation.iso_country_code
+        r#"SELECT progress as "progress!", demons.id AS "demon_id!", demons.name as "demon_name!: String", demons.position as "position!", demons.list as "list!", players.name as "player_name!: String" FROM best_records_in($1, $2) as records INNER JOIN demons ON records.demon = demons.id INNER JOIN players ON players.id = records.player"#,
+        nation.iso_country_code,
+        subdivision_code(nation)
     )
         .fetch(connection);
 
@@ -236,6 +265,7 @@ pub async fn best_records_in(nation: &Nationality, connection: &mut PgConnection
                     id: row.demon_id,
                     name: row.demon_name,
                     position: row.position,
+                    list: row.list,
                 },
                 progress: row.progress,
                 players: vec![row.player_name],
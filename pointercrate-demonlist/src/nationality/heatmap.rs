@@ -0,0 +1,103 @@
+use crate::error::Result;
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use sqlx::PgConnection;
+use std::collections::HashMap;
+
+/// The statistic a [`Heatmap`] is based on
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum HeatmapMetric {
+    /// The nation's/subdivision's score on the list
+    #[default]
+    Score,
+
+    /// The number of players from the nation/subdivision that have a non-zero score on the list
+    Players,
+
+    /// The number of approved records on the list held by players from the nation/subdivision
+    Records,
+}
+
+impl HeatmapMetric {
+    fn to_sql(self) -> &'static str {
+        match self {
+            HeatmapMetric::Score => "score",
+            HeatmapMetric::Players => "players",
+            HeatmapMetric::Records => "records",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct HeatmapQuery {
+    #[serde(default)]
+    pub metric: HeatmapMetric,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct HeatmapEntry {
+    pub country_code: String,
+
+    /// The code of the subdivision this entry is about, or `None` if it is about a whole nation
+    pub subdivision_code: Option<String>,
+    pub value: f64,
+
+    /// The value relative to the highest value of any nation, in `[0, 1]`. For subdivisions, the
+    /// value relative to the value of the nation they belong to.
+    pub normalized: f64,
+}
+
+/// The distribution of some [`HeatmapMetric`] across all nations and subdivisions
+///
+/// Nations and subdivisions for which the metric is zero are not included.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct Heatmap {
+    pub metric: HeatmapMetric,
+    pub nations: Vec<HeatmapEntry>,
+    pub subdivisions: Vec<HeatmapEntry>,
+}
+
+impl Heatmap {
+    pub async fn load(list: &str, metric: HeatmapMetric, connection: &mut PgConnection) -> Result<Heatmap> {
+        let mut stream = sqlx::query_file!("sql/heatmap.sql", list, metric.to_sql()).fetch(connection);
+
+        let mut nations = Vec::new();
+        let mut subdivisions = Vec::new();
+
+        while let Some(row) = stream.next().await {
+            let row = row?;
+
+            let entry = HeatmapEntry {
+                country_code: row.nation,
+                subdivision_code: row.subdivision,
+                value: row.value,
+                normalized: 0.0,
+            };
+
+            match entry.subdivision_code {
+                None => nations.push(entry),
+                Some(_) => subdivisions.push(entry),
+            }
+        }
+
+        // Rows are ordered by value, so the first nation has the highest value
+        let highest_value = nations.first().map(|nation| nation.value).unwrap_or(f64::INFINITY);
+        let mut nation_values = HashMap::new();
+
+        for nation in &mut nations {
+            nation.normalized = nation.value / highest_value;
+            nation_values.insert(nation.country_code.clone(), nation.value);
+        }
+
+        for subdivision in &mut subdivisions {
+            subdivision.normalized = subdivision.value / nation_values.get(&subdivision.country_code).unwrap_or(&f64::INFINITY);
+        }
+
+        Ok(Heatmap {
+            metric,
+            nations,
+            subdivisions,
+        })
+    }
+}
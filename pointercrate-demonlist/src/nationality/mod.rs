@@ -1,11 +1,20 @@
 use crate::demon::MinimalDemon;
-pub use paginate::{NationalityRankingPagination, RankedNation};
+pub use audit::{NationalityAuditLogEntry, NationalityModificationData};
+pub use heatmap::{Heatmap, HeatmapEntry, HeatmapMetric, HeatmapQuery};
+pub use paginate::{NationalityRankingPagination, RankedContinent, RankedNation, RankedSubdivision, SubdivisionRankingPagination};
+pub use patch::{PatchNationality, PatchSubdivision};
 use pointercrate_core::etag::Taggable;
+pub use post::{PostNationality, PostSubdivision};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use sqlx::PgConnection;
 
+mod audit;
+mod delete;
 mod get;
+mod heatmap;
 mod paginate;
+mod patch;
+mod post;
 
 #[derive(Debug, PartialEq, Eq, Serialize, Hash, Deserialize, Clone)]
 pub struct Nationality {
@@ -15,6 +24,14 @@ pub struct Nationality {
     pub subdivision: Option<Subdivision>,
 }
 
+/// A [`Nationality`] together with the continent it is assigned to
+#[derive(Debug, PartialEq, Eq, Serialize, Hash, Deserialize, Clone)]
+pub struct NationalityWithContinent {
+    #[serde(flatten)]
+    pub nationality: Nationality,
+    pub continent: Continent,
+}
+
 #[derive(Debug, Serialize, Hash)]
 pub struct BestRecord {
     progress: i16,
@@ -29,6 +46,9 @@ pub struct MiniDemonWithPlayers {
 }
 
 /// The [`Nationality`] equivalent of [`FullPlayer`], very roughly
+///
+/// If the nation's subdivision is set, all statistics only take into account players from that
+/// subdivision.
 #[derive(Debug, Hash, Serialize)]
 pub struct NationalityRecord {
     #[serde(flatten)]
@@ -74,6 +94,20 @@ impl Continent {
         }
         .to_owned()
     }
+
+    /// The inverse of [`Continent::to_sql`]
+    pub fn from_sql(continent: &str) -> Option<Continent> {
+        match continent {
+            "Asia" => Some(Continent::Asia),
+            "Europe" => Some(Continent::Europe),
+            "Australia and Oceania" => Some(Continent::AustraliaAndOceania),
+            "Africa" => Some(Continent::Africa),
+            "North America" => Some(Continent::NorthAmerica),
+            "South America" => Some(Continent::SouthAmerica),
+            "Central America" => Some(Continent::MiddleAmerica),
+            _ => None,
+        }
+    }
 }
 
 impl<'de> Deserialize<'de> for Continent {
@@ -122,17 +156,14 @@ impl Nationality {
         self.iso_country_code == other.iso_country_code
     }
 
-    /// Updates the score for this [`Nationality`] and contained [`Subdivision`] (if set).
+    /// Updates the scores in all lists for this [`Nationality`] and contained [`Subdivision`] (if set).
     pub async fn update_nation_score(&self, connection: &mut PgConnection) -> Result<(), sqlx::Error> {
-        sqlx::query!(
-            "UPDATE nationalities SET score = coalesce(score_of_nation($1), 0) WHERE iso_country_code = $1",
-            self.iso_country_code
-        )
-        .execute(&mut *connection)
-        .await?;
+        sqlx::query!("SELECT update_nation_scores($1)", self.iso_country_code)
+            .execute(&mut *connection)
+            .await?;
         if let Some(ref subdivision) = self.subdivision {
             sqlx::query!(
-                "UPDATE subdivisions SET score = coalesce(score_of_subdivision($1, $2), 0) WHERE nation = $1 AND iso_code = $2",
+                "SELECT update_subdivision_scores($1, $2)",
                 self.iso_country_code,
                 subdivision.iso_code
             )
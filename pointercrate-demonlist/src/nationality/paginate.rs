@@ -1,11 +1,15 @@
 use crate::{
     error::Result,
-    nationality::{Continent, Nationality},
+    list::DEFAULT_LIST,
+    nationality::{Continent, Nationality, Subdivision},
 };
 use futures::StreamExt;
-use pointercrate_core::util::non_nullable;
+use pointercrate_core::{
+    pagination::{__pagination_compat, PageContext, Paginatable, PaginationParameters, PaginationQuery},
+    util::non_nullable,
+};
 use serde::{Deserialize, Serialize};
-use sqlx::PgConnection;
+use sqlx::{PgConnection, Row};
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct NationalityRankingPagination {
@@ -14,6 +18,11 @@ pub struct NationalityRankingPagination {
 
     #[serde(default, deserialize_with = "non_nullable")]
     name_contains: Option<String>,
+
+    /// The list whose ranking should be retrieved. Not part of the query string, but instead set
+    /// from the request path. Defaults to the [default list](DEFAULT_LIST).
+    #[serde(skip)]
+    pub list: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -28,9 +37,10 @@ impl NationalityRankingPagination {
     pub async fn page(&self, connection: &mut PgConnection) -> Result<Vec<RankedNation>> {
         let mut stream = sqlx::query!(
             r#"SELECT rank as "rank!", score as "score!", nation as "nation!", iso_country_code as "iso_country_code!" FROM ranked_nations WHERE (STRPOS(nation, $1::CITEXT) > 
-             0 OR $1 is NULL) AND (continent::text = $2 OR $2 IS NULL)"#,
+             0 OR $1 is NULL) AND (continent::text = $2 OR $2 IS NULL) AND list = $3"#,
             self.name_contains,
-            self.continent.map(|c| c.to_sql())
+            self.continent.map(|c| c.to_sql()),
+            self.list.as_deref().unwrap_or(DEFAULT_LIST)
         )
         .fetch(connection);
 
@@ -53,3 +63,151 @@ impl NationalityRankingPagination {
         Ok(nations)
     }
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct SubdivisionRankingPagination {
+    #[serde(flatten)]
+    pub params: PaginationParameters,
+
+    #[serde(default, deserialize_with = "non_nullable")]
+    name_contains: Option<String>,
+
+    /// The country code of the nation whose subdivisions should be ranked. Not part of the query
+    /// string, but instead set from the request path.
+    #[serde(skip)]
+    pub nation: String,
+
+    /// The list whose ranking should be paginated. Not part of the query string, but instead set
+    /// from the request path. Defaults to the [default list](DEFAULT_LIST).
+    #[serde(skip)]
+    pub list: Option<String>,
+}
+
+impl SubdivisionRankingPagination {
+    fn list(&self) -> &str {
+        self.list.as_deref().unwrap_or(DEFAULT_LIST)
+    }
+}
+
+impl PaginationQuery for SubdivisionRankingPagination {
+    fn parameters(&self) -> PaginationParameters {
+        self.params
+    }
+
+    fn with_parameters(&self, parameters: PaginationParameters) -> Self {
+        Self {
+            params: parameters,
+            ..self.clone()
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RankedSubdivision {
+    #[serde(skip)]
+    index: i64,
+    pub rank: i64,
+    pub score: f64,
+    #[serde(flatten)]
+    pub subdivision: Subdivision,
+}
+
+impl Paginatable<SubdivisionRankingPagination> for RankedSubdivision {
+    async fn first_and_last(connection: &mut PgConnection) -> std::result::Result<Option<(i32, i32)>, sqlx::Error> {
+        Self::first_and_last_matching(&SubdivisionRankingPagination::default(), connection).await
+    }
+
+    async fn first_and_last_matching(
+        query: &SubdivisionRankingPagination, connection: &mut PgConnection,
+    ) -> std::result::Result<Option<(i32, i32)>, sqlx::Error> {
+        Ok(sqlx::query!(
+            "SELECT COUNT(*) FROM ranked_subdivisions WHERE list = $1 AND nation = $2",
+            query.list(),
+            query.nation
+        )
+        .fetch_one(connection)
+        .await?
+        .count
+        .filter(|&count| count > 0)
+        .map(|max| (1, max as i32)))
+    }
+
+    async fn page(
+        query: &SubdivisionRankingPagination, connection: &mut PgConnection,
+    ) -> std::result::Result<(Vec<RankedSubdivision>, PageContext), sqlx::Error> {
+        let order = query.params.order();
+
+        let sql_query = format!(include_str!("../../sql/paginate_subdivision_ranking.sql"), order);
+
+        let mut stream = sqlx::query(&sql_query)
+            .bind(query.params.before)
+            .bind(query.params.after)
+            .bind(query.name_contains.as_deref())
+            .bind(&query.nation)
+            .bind(query.params.limit + 1)
+            .bind(query.list())
+            .fetch(connection);
+
+        let mut subdivisions = Vec::new();
+
+        while let Some(row) = stream.next().await {
+            let row = row?;
+
+            subdivisions.push(RankedSubdivision {
+                index: row.get("index"),
+                rank: row.get("rank"),
+                score: row.get("score"),
+                subdivision: Subdivision {
+                    iso_code: row.get("iso_code"),
+                    name: row.get("name"),
+                },
+            })
+        }
+
+        Ok(__pagination_compat(&query.params, subdivisions))
+    }
+
+    fn pagination_id(&self) -> i32 {
+        self.index as i32
+    }
+}
+
+/// A continent together with its score, which is computed the same way as the score of a nation,
+/// just with all players from any of the continent's nations
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct RankedContinent {
+    pub rank: i64,
+    pub score: f64,
+    pub continent: Continent,
+
+    /// The number of nations on this continent that have a score on the list
+    pub nations: i64,
+}
+
+impl RankedContinent {
+    /// Ranks all continents with a non-zero score on the given list
+    pub async fn all(list: &str, connection: &mut PgConnection) -> Result<Vec<RankedContinent>> {
+        let mut stream = sqlx::query!(
+            r#"SELECT rank as "rank!", score as "score!", continent::text as "continent!", nations as "nations!" FROM ranked_continents WHERE list = $1 ORDER BY rank"#,
+            list
+        )
+        .fetch(connection);
+
+        let mut continents = Vec::new();
+
+        while let Some(row) = stream.next().await {
+            let row = row?;
+
+            if let Some(continent) = Continent::from_sql(&row.continent) {
+                continents.push(RankedContinent {
+                    rank: row.rank,
+                    score: row.score,
+                    continent,
+                    nations: row.nations,
+                })
+            }
+        }
+
+        Ok(continents)
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{
+    error::{DemonlistError, Result},
+    nationality::{Continent, Nationality, NationalityWithContinent, Subdivision},
+};
+use log::info;
+use pointercrate_core::util::non_nullable;
+use serde::Deserialize;
+use sqlx::PgConnection;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct PatchNationality {
+    #[serde(default, deserialize_with = "non_nullable")]
+    pub nation: Option<String>,
+
+    #[serde(default, deserialize_with = "non_nullable")]
+    pub continent: Option<Continent>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct PatchSubdivision {
+    #[serde(default, deserialize_with = "non_nullable")]
+    pub name: Option<String>,
+}
+
+impl NationalityWithContinent {
+    /// Must be run within a transaction!
+    ///
+    /// Scores of nations are independent of their continent (continent scores are computed on the
+    /// fly), so no scores need to be updated here
+    pub async fn apply_patch(mut self, patch: PatchNationality, connection: &mut PgConnection) -> Result<Self> {
+        info!("Patching nationality {} with {:?}", self.nationality.iso_country_code, patch);
+
+        if let Some(nation) = patch.nation {
+            let exists = sqlx::query!(
+                "SELECT EXISTS (SELECT 1 FROM nationalities WHERE nation = $1::TEXT::CITEXT AND iso_country_code <> $2) AS \"exists!\"",
+                nation,
+                self.nationality.iso_country_code
+            )
+            .fetch_one(&mut *connection)
+            .await?
+            .exists;
+
+            if exists {
+                return Err(DemonlistError::NationalityExists);
+            }
+
+            sqlx::query!(
+                "UPDATE nationalities SET nation = $1::TEXT::CITEXT WHERE iso_country_code = $2",
+                nation,
+                self.nationality.iso_country_code
+            )
+            .execute(&mut *connection)
+            .await?;
+
+            self.nationality.nation = nation;
+        }
+
+        if let Some(continent) = patch.continent {
+            sqlx::query!(
+                "UPDATE nationalities SET continent = CAST($1::TEXT AS continent) WHERE iso_country_code = $2",
+                continent.to_sql(),
+                self.nationality.iso_country_code
+            )
+            .execute(&mut *connection)
+            .await?;
+
+            self.continent = continent;
+        }
+
+        Ok(self)
+    }
+}
+
+impl Nationality {
+    /// Must be run within a transaction!
+    pub async fn patch_subdivision(
+        &self, mut subdivision: Subdivision, patch: PatchSubdivision, connection: &mut PgConnection,
+    ) -> Result<Subdivision> {
+        info!(
+            "Patching subdivision {} of {} with {:?}",
+            subdivision.iso_code, self.iso_country_code, patch
+        );
+
+        if let Some(name) = patch.name {
+            let exists = sqlx::query!(
+                "SELECT EXISTS (SELECT 1 FROM subdivisions WHERE name = $1::TEXT::CITEXT AND NOT (nation = $2 AND iso_code = $3)) AS \"exists!\"",
+                name,
+                self.iso_country_code,
+                subdivision.iso_code
+            )
+            .fetch_one(&mut *connection)
+            .await?
+            .exists;
+
+            if exists {
+                return Err(DemonlistError::SubdivisionExists);
+            }
+
+            sqlx::query!(
+                "UPDATE subdivisions SET name = $1::TEXT::CITEXT WHERE nation = $2 AND iso_code = $3",
+                name,
+                self.iso_country_code,
+                subdivision.iso_code
+            )
+            .execute(&mut *connection)
+            .await?;
+
+            subdivision.name = name;
+        }
+
+        Ok(subdivision)
+    }
+}
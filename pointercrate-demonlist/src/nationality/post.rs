@@ -0,0 +1,185 @@
+use crate::{
+    error::{DemonlistError, Result},
+    nationality::{Continent, Nationality, NationalityWithContinent, Subdivision},
+};
+use log::info;
+use serde::Deserialize;
+use sqlx::PgConnection;
+
+/// All officially assigned ISO 3166-1 alpha-2 country codes, sorted alphabetically
+const ISO_COUNTRY_CODES: [&str; 249] = [
+    "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX", "AZ", "BA", "BB", "BD", "BE", "BF", "BG",
+    "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR", "BS", "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI",
+    "CK", "CL", "CM", "CN", "CO", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DJ", "DK", "DM", "DO", "DZ", "EC", "EE", "EG", "EH",
+    "ER", "ES", "ET", "FI", "FJ", "FK", "FM", "FO", "FR", "GA", "GB", "GD", "GE", "GF", "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ",
+    "GR", "GS", "GT", "GU", "GW", "GY", "HK", "HM", "HN", "HR", "HT", "HU", "ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR", "IS", "IT",
+    "JE", "JM", "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC", "LI", "LK", "LR", "LS",
+    "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MF", "MG", "MH", "MK", "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU",
+    "MV", "MW", "MX", "MY", "MZ", "NA", "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA", "PE", "PF", "PG",
+    "PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW", "PY", "QA", "RE", "RO", "RS", "RU", "RW", "SA", "SB", "SC", "SD", "SE", "SG",
+    "SH", "SI", "SJ", "SK", "SL", "SM", "SN", "SO", "SR", "SS", "ST", "SV", "SX", "SY", "SZ", "TC", "TD", "TF", "TG", "TH", "TJ", "TK",
+    "TL", "TM", "TN", "TO", "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI", "VN", "VU",
+    "WF", "WS", "YE", "YT", "ZA", "ZM", "ZW",
+];
+
+/// User-assigned ISO 3166-1 alpha-2 codes that are nonetheless in common use for a country
+///
+/// `XK` is used for Kosovo, which has no officially assigned code.
+const USER_ASSIGNED_COUNTRY_CODES: [&str; 1] = ["XK"];
+
+#[derive(Deserialize, Debug)]
+pub struct PostNationality {
+    pub country_code: String,
+    pub nation: String,
+    pub continent: Continent,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PostSubdivision {
+    pub iso_code: String,
+    pub name: String,
+}
+
+impl Nationality {
+    /// Must be run within a transaction!
+    pub async fn create_from(data: PostNationality, connection: &mut PgConnection) -> Result<NationalityWithContinent> {
+        info!("Creating new nationality from {:?}", data);
+
+        let country_code = data.country_code.to_uppercase();
+
+        Nationality::validate_country_code(&country_code)?;
+
+        let exists = sqlx::query!(
+            "SELECT EXISTS (SELECT 1 FROM nationalities WHERE iso_country_code = $1 OR nation = $2::TEXT::CITEXT) AS \"exists!\"",
+            country_code,
+            data.nation
+        )
+        .fetch_one(&mut *connection)
+        .await?
+        .exists;
+
+        if exists {
+            return Err(DemonlistError::NationalityExists);
+        }
+
+        sqlx::query!(
+            "INSERT INTO nationalities (iso_country_code, nation, continent) VALUES ($1, $2::TEXT::CITEXT, CAST($3::TEXT AS continent))",
+            country_code,
+            data.nation,
+            data.continent.to_sql()
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        Ok(NationalityWithContinent {
+            nationality: Nationality {
+                iso_country_code: country_code,
+                nation: data.nation,
+                subdivision: None,
+            },
+            continent: data.continent,
+        })
+    }
+
+    /// Must be run within a transaction!
+    pub async fn create_subdivision(&self, data: PostSubdivision, connection: &mut PgConnection) -> Result<Subdivision> {
+        info!("Creating new subdivision of {} from {:?}", self.iso_country_code, data);
+
+        let iso_code = data.iso_code.to_uppercase();
+
+        Nationality::validate_subdivision_code(&iso_code)?;
+
+        let exists = sqlx::query!(
+            "SELECT EXISTS (SELECT 1 FROM subdivisions WHERE (nation = $1 AND iso_code = $2) OR name = $3::TEXT::CITEXT) AS \"exists!\"",
+            self.iso_country_code,
+            iso_code,
+            data.name
+        )
+        .fetch_one(&mut *connection)
+        .await?
+        .exists;
+
+        if exists {
+            return Err(DemonlistError::SubdivisionExists);
+        }
+
+        sqlx::query!(
+            "INSERT INTO subdivisions (iso_code, name, nation) VALUES ($1, $2::TEXT::CITEXT, $3)",
+            iso_code,
+            data.name,
+            self.iso_country_code
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        Ok(Subdivision { iso_code, name: data.name })
+    }
+
+    /// Country codes need to be officially assigned ISO 3166-1 alpha-2 codes (in uppercase), or one of
+    /// the few user-assigned codes commonly used for countries (such as `XK` for Kosovo)
+    pub fn validate_country_code(code: &str) -> Result<()> {
+        if ISO_COUNTRY_CODES.binary_search(&code).is_err() && !USER_ASSIGNED_COUNTRY_CODES.contains(&code) {
+            return Err(DemonlistError::InvalidCountryCode);
+        }
+
+        Ok(())
+    }
+
+    /// Subdivision codes are the part of an ISO 3166-2 code following the country code and the
+    /// dash, which consists of one to three (uppercase) letters and digits
+    pub fn validate_subdivision_code(code: &str) -> Result<()> {
+        let valid = !code.is_empty() && code.len() <= 3 && code.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+
+        if !valid {
+            return Err(DemonlistError::InvalidSubdivisionCode);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::DemonlistError, nationality::Nationality};
+
+    #[test]
+    fn test_validate_country_code() {
+        assert_eq!(Nationality::validate_country_code("DE"), Ok(()));
+        assert_eq!(Nationality::validate_country_code("AQ"), Ok(()));
+        assert_eq!(Nationality::validate_country_code("XK"), Ok(()));
+
+        assert_eq!(Nationality::validate_country_code(""), Err(DemonlistError::InvalidCountryCode));
+        assert_eq!(Nationality::validate_country_code("D"), Err(DemonlistError::InvalidCountryCode));
+        assert_eq!(Nationality::validate_country_code("de"), Err(DemonlistError::InvalidCountryCode));
+        assert_eq!(Nationality::validate_country_code("DEU"), Err(DemonlistError::InvalidCountryCode));
+        assert_eq!(Nationality::validate_country_code("D1"), Err(DemonlistError::InvalidCountryCode));
+        assert_eq!(Nationality::validate_country_code("ÄÖ"), Err(DemonlistError::InvalidCountryCode));
+        assert_eq!(Nationality::validate_country_code("XQ"), Err(DemonlistError::InvalidCountryCode));
+        assert_eq!(Nationality::validate_country_code("ZZ"), Err(DemonlistError::InvalidCountryCode));
+    }
+
+    #[test]
+    fn test_validate_subdivision_code() {
+        assert_eq!(Nationality::validate_subdivision_code("V"), Ok(()));
+        assert_eq!(Nationality::validate_subdivision_code("BY"), Ok(()));
+        assert_eq!(Nationality::validate_subdivision_code("ENG"), Ok(()));
+        assert_eq!(Nationality::validate_subdivision_code("01"), Ok(()));
+
+        assert_eq!(
+            Nationality::validate_subdivision_code(""),
+            Err(DemonlistError::InvalidSubdivisionCode)
+        );
+        assert_eq!(
+            Nationality::validate_subdivision_code("by"),
+            Err(DemonlistError::InvalidSubdivisionCode)
+        );
+        assert_eq!(
+            Nationality::validate_subdivision_code("DE-BY"),
+            Err(DemonlistError::InvalidSubdivisionCode)
+        );
+        assert_eq!(
+            Nationality::validate_subdivision_code("ABCD"),
+            Err(DemonlistError::InvalidSubdivisionCode)
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! Module for the previous names of players, and the ids of players that were merged into them
+//!
+//! Whenever a player is renamed, its old name is kept as an alias, and whenever a player is merged
+//! into another one, the merged-away player's name and id are kept as an alias of the remaining
+//! player. Lookups by name fall back to aliases, so that submissions under an old name still end
+//! up with the right player.
+
+use crate::{error::Result, player::DatabasePlayer};
+use log::info;
+use sqlx::PgConnection;
+
+impl DatabasePlayer {
+    /// Gets the player currently known under the given alias. If multiple players were previously
+    /// known under this name, the one that most recently gave it up is returned.
+    pub async fn by_alias(name: &str, connection: &mut PgConnection) -> Result<Option<DatabasePlayer>> {
+        Ok(sqlx::query_as!(
+            DatabasePlayer,
+            "SELECT players.id, players.name, players.banned FROM player_aliases INNER JOIN players ON players.id = player_aliases.player \
+             WHERE player_aliases.name = $1::CITEXT ORDER BY player_aliases.id DESC LIMIT 1",
+            name.trim()
+        )
+        .fetch_optional(connection)
+        .await?)
+    }
+
+    /// Gets the player the player with the given id was merged into, if any
+    pub async fn merged_into(former_id: i32, connection: &mut PgConnection) -> Result<Option<DatabasePlayer>> {
+        Ok(sqlx::query_as!(
+            DatabasePlayer,
+            "SELECT players.id, players.name, players.banned FROM player_aliases INNER JOIN players ON players.id = player_aliases.player \
+             WHERE player_aliases.former_id = $1",
+            former_id
+        )
+        .fetch_optional(connection)
+        .await?)
+    }
+
+    /// Gets the names this player was previously known under, oldest first. Never contains the
+    /// player's current name.
+    pub async fn previous_names(&self, connection: &mut PgConnection) -> Result<Vec<String>> {
+        Ok(sqlx::query!(
+            r#"SELECT name::TEXT AS "name!" FROM player_aliases WHERE player = $1 AND name <> $2::CITEXT GROUP BY name ORDER BY MIN(id)"#,
+            self.id,
+            self.name
+        )
+        .fetch_all(connection)
+        .await?
+        .into_iter()
+        .map(|row| row.name)
+        .collect())
+    }
+
+    pub(super) async fn add_alias(&self, name: &str, former_id: Option<i32>, connection: &mut PgConnection) -> Result<()> {
+        info!("Adding alias '{}' (former id: {:?}) to player {}", name, former_id, self);
+
+        sqlx::query!(
+            "INSERT INTO player_aliases (player, name, former_id) VALUES ($1, $2::TEXT, $3)",
+            self.id,
+            name,
+            former_id
+        )
+        .execute(connection)
+        .await?;
+
+        Ok(())
+    }
+}
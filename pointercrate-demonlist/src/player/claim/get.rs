@@ -8,12 +8,13 @@ pub struct ClaimBy {
     pub player: DatabasePlayer,
     pub verified: bool,
     pub lock_submissions: bool,
+    pub third_party_approval: bool,
 }
 
 impl PlayerClaim {
     pub async fn verified_claim_on(player_id: i32, connection: &mut PgConnection) -> Result<Option<PlayerClaim>> {
         match sqlx::query!(
-            "SELECT member_id, lock_submissions FROM player_claims WHERE player_id = $1 AND verified",
+            "SELECT member_id, lock_submissions, third_party_approval FROM player_claims WHERE player_id = $1 AND verified",
             player_id
         )
         .fetch_one(connection)
@@ -24,6 +25,7 @@ impl PlayerClaim {
                 player_id,
                 verified: true,
                 lock_submissions: row.lock_submissions,
+                third_party_approval: row.third_party_approval,
             })),
             Err(sqlx::Error::RowNotFound) => Ok(None),
             Err(err) => Err(err.into()),
@@ -32,7 +34,7 @@ impl PlayerClaim {
 
     pub async fn by_user(user_id: i32, connection: &mut PgConnection) -> Result<Option<ClaimBy>> {
         match sqlx::query!(
-            r#"SELECT verified, lock_submissions, player_id, players.name::text as "name!", players.banned FROM player_claims INNER JOIN players ON player_id=players.id
+            r#"SELECT verified, lock_submissions, third_party_approval, player_id, players.name::text as "name!", players.banned FROM player_claims INNER JOIN players ON player_id=players.id
              WHERE member_id = $1"#,
             user_id
         )
@@ -47,7 +49,8 @@ impl PlayerClaim {
                         banned: row.banned,
                     },
                     verified: row.verified,
-                    lock_submissions: row.lock_submissions
+                    lock_submissions: row.lock_submissions,
+                    third_party_approval: row.third_party_approval
                 })),
             Err(sqlx::Error::RowNotFound) => Ok(None),
             Err(err) => Err(err.into()),
@@ -61,6 +64,7 @@ impl PlayerClaim {
                 player_id,
                 verified: claim.verified,
                 lock_submissions: claim.lock_submissions,
+                third_party_approval: claim.third_party_approval,
             }),
             _ => Err(DemonlistError::ClaimNotFound { member_id, player_id }),
         }
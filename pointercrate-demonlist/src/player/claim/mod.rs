@@ -7,6 +7,7 @@ mod get;
 mod paginate;
 mod patch;
 mod put;
+mod verification;
 
 pub use get::ClaimBy;
 
@@ -19,4 +20,8 @@ pub struct PlayerClaim {
     /// Whether the pointercrate user claiming this player has requested submissions to be locked,
     /// meaning records for this player can only be submitted while the claimer is logged in.
     pub lock_submissions: bool,
+
+    /// Whether third-party submissions for this player should be held for the claimer's approval
+    /// instead of being rejected. Only has an effect if submissions are locked.
+    pub third_party_approval: bool,
 }
@@ -6,6 +6,7 @@ use sqlx::PgConnection;
 pub struct PatchPlayerClaim {
     pub verified: Option<bool>,
     pub lock_submissions: Option<bool>,
+    pub third_party_approval: Option<bool>,
 }
 
 impl PlayerClaim {
@@ -15,7 +16,11 @@ impl PlayerClaim {
         }
 
         if let Some(lock_submissions) = patch.lock_submissions {
-            self.set_lock_submissions(lock_submissions, connection).await?;
+            self.set_lock_submissions(lock_submissions, &mut *connection).await?;
+        }
+
+        if let Some(third_party_approval) = patch.third_party_approval {
+            self.set_third_party_approval(third_party_approval, connection).await?;
         }
 
         Ok(self)
@@ -36,29 +41,73 @@ impl PlayerClaim {
         Ok(())
     }
 
-    pub async fn set_verified(&mut self, verified: bool, connection: &mut PgConnection) -> Result<()> {
+    pub async fn set_third_party_approval(&mut self, third_party_approval: bool, connection: &mut PgConnection) -> Result<()> {
         sqlx::query!(
-            "UPDATE player_claims SET verified = $3 WHERE member_id = $1 AND player_id = $2",
+            "UPDATE player_claims SET third_party_approval = $3 WHERE member_id = $1 AND player_id = $2",
             self.user_id,
             self.player_id,
-            verified
+            third_party_approval
         )
-        .execute(&mut *connection)
+        .execute(connection)
         .await?;
 
-        self.verified = verified;
+        self.third_party_approval = third_party_approval;
 
+        Ok(())
+    }
+
+    pub async fn set_verified(&mut self, verified: bool, connection: &mut PgConnection) -> Result<()> {
         if verified {
-            // remove all other claims (verified or not) on that player
-            sqlx::query!(
-                "DELETE FROM player_claims WHERE player_id = $1 AND member_id <> $2",
-                self.player_id,
-                self.user_id
-            )
-            .execute(connection)
-            .await?;
+            return self.mark_verified(None, connection).await;
         }
 
+        sqlx::query!(
+            "UPDATE player_claims SET verified = FALSE WHERE member_id = $1 AND player_id = $2",
+            self.user_id,
+            self.player_id
+        )
+        .execute(connection)
+        .await?;
+
+        self.verified = false;
+
+        Ok(())
+    }
+
+    /// Marks this claim as verified and records the verification in the audit log
+    ///
+    /// `gd_account_id` is the Geometry Dash account through which the claim was verified, or `None`
+    /// if it was verified manually
+    pub(super) async fn mark_verified(&mut self, gd_account_id: Option<i64>, connection: &mut PgConnection) -> Result<()> {
+        sqlx::query!(
+            "UPDATE player_claims SET verified = TRUE WHERE member_id = $1 AND player_id = $2",
+            self.user_id,
+            self.player_id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        self.verified = true;
+
+        // remove all other claims (verified or not) on that player
+        sqlx::query!(
+            "DELETE FROM player_claims WHERE player_id = $1 AND member_id <> $2",
+            self.player_id,
+            self.user_id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        sqlx::query!(
+            "INSERT INTO player_claim_verifications (userid, member_id, player_id, gd_account_id) (SELECT id, $1, $2, $3 FROM active_user \
+             LIMIT 1)",
+            self.user_id,
+            self.player_id,
+            gd_account_id
+        )
+        .execute(connection)
+        .await?;
+
         Ok(())
     }
 }
@@ -41,6 +41,7 @@ impl DatabasePlayer {
             player_id: self.id,
             verified: false,
             lock_submissions: false,
+            third_party_approval: false,
         })
     }
 }
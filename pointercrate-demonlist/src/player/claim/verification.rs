@@ -0,0 +1,111 @@
+//! Module for verifying claims through the Geometry Dash account of the claimed player
+//!
+//! The claimant is issued a one-time code, which they have to post as a comment on, or put into
+//! the profile of, the player's Geometry Dash account. Looking up the account is not the concern
+//! of this module, it only keeps track of the codes and records successful verifications.
+
+use crate::{
+    error::{DemonlistError, Result},
+    player::{claim::PlayerClaim, DatabasePlayer},
+};
+use log::info;
+use rand::{distr::Alphanumeric, Rng};
+use sqlx::PgConnection;
+
+/// The number of hours after which an issued verification code can no longer be used
+const CODE_VALIDITY_HOURS: i32 = 24;
+
+impl PlayerClaim {
+    /// Issues a new verification code for this claim, invalidating any previous one
+    pub async fn issue_verification_code(&self, connection: &mut PgConnection) -> Result<String> {
+        if self.verified {
+            return Err(DemonlistError::AlreadyClaimed);
+        }
+
+        let code = format!(
+            "pc-{}",
+            rand::rng()
+                .sample_iter(Alphanumeric)
+                .take(10)
+                .map(char::from)
+                .collect::<String>()
+                .to_lowercase()
+        );
+
+        sqlx::query!(
+            "UPDATE player_claims SET verification_code = $3, verification_code_issued_at = (NOW() AT TIME ZONE 'utc') WHERE member_id = \
+             $1 AND player_id = $2",
+            self.user_id,
+            self.player_id,
+            code
+        )
+        .execute(connection)
+        .await?;
+
+        Ok(code)
+    }
+
+    /// Gets the verification code issued for this claim, if one was issued and has not yet expired
+    pub async fn verification_code(&self, connection: &mut PgConnection) -> Result<Option<String>> {
+        Ok(sqlx::query!(
+            "SELECT verification_code FROM player_claims WHERE member_id = $1 AND player_id = $2 AND verification_code_issued_at > (NOW() \
+             AT TIME ZONE 'utc') - make_interval(hours => $3)",
+            self.user_id,
+            self.player_id,
+            CODE_VALIDITY_HOURS
+        )
+        .fetch_optional(connection)
+        .await?
+        .and_then(|row| row.verification_code))
+    }
+
+    /// Verifies this claim after its verification code was found on the given Geometry Dash
+    /// account, linking that account to the claimed player
+    pub async fn verify_through_gd_account(&mut self, gd_account_id: i64, connection: &mut PgConnection) -> Result<()> {
+        info!(
+            "Verifying claim of user {} on player {} through Geometry Dash account {}",
+            self.user_id, self.player_id, gd_account_id
+        );
+
+        let linked_elsewhere = sqlx::query!(
+            r#"SELECT EXISTS(SELECT 1 FROM players WHERE gd_account_id = $1 AND id <> $2) AS "linked!""#,
+            gd_account_id,
+            self.player_id
+        )
+        .fetch_one(&mut *connection)
+        .await?
+        .linked;
+
+        if linked_elsewhere {
+            return Err(DemonlistError::GdAccountInUse);
+        }
+
+        sqlx::query!(
+            "UPDATE players SET gd_account_id = $1 WHERE id = $2 AND gd_account_id IS NULL",
+            gd_account_id,
+            self.player_id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        sqlx::query!(
+            "UPDATE player_claims SET verification_code = NULL, verification_code_issued_at = NULL WHERE member_id = $1 AND player_id = $2",
+            self.user_id,
+            self.player_id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        self.mark_verified(Some(gd_account_id), connection).await
+    }
+}
+
+impl DatabasePlayer {
+    /// Gets the id of the Geometry Dash account linked to this player, if any
+    pub async fn gd_account_id(&self, connection: &mut PgConnection) -> Result<Option<i64>> {
+        Ok(sqlx::query!("SELECT gd_account_id FROM players WHERE id = $1", self.id)
+            .fetch_one(connection)
+            .await?
+            .gd_account_id)
+    }
+}
@@ -2,8 +2,9 @@ use crate::{
     creator::created_by,
     demon::{published_by, verified_by},
     error::{DemonlistError, Result},
+    list::DEFAULT_LIST,
     nationality::{Nationality, Subdivision},
-    player::{DatabasePlayer, FullPlayer, Player},
+    player::{DatabasePlayer, FullPlayer, Player, PlayerProfile},
     record::approved_records_by,
 };
 use sqlx::{Error, PgConnection};
@@ -14,6 +15,8 @@ impl Player {
         let published = published_by(&self.base, connection).await?;
         let verified = verified_by(&self.base, connection).await?;
         let created = created_by(self.base.id, connection).await?;
+        let previous_names = self.base.previous_names(connection).await?;
+        let profile = PlayerProfile::of(self.base.id, connection).await?;
 
         Ok(FullPlayer {
             player: self,
@@ -21,14 +24,17 @@ impl Player {
             created,
             verified,
             published,
+            previous_names,
+            profile,
         })
     }
 
     pub async fn by_id(id: i32, connection: &mut PgConnection) -> Result<Player> {
         let result = sqlx::query!(
-            r#"SELECT players.id, players.name, banned, players.score, nationalities.nation::text, iso_country_code::text, iso_code::text as subdivision_code, subdivisions.name::text as subdivision_name, player_ranks.rank FROM players LEFT OUTER JOIN nationalities ON 
-             players.nationality = nationalities.iso_country_code LEFT OUTER JOIN subdivisions ON players.subdivision = subdivisions.iso_code LEFT OUTER JOIN player_ranks ON player_ranks.id = players.id WHERE players.id = $1 AND (subdivisions.nation=nationalities.iso_country_code or players.subdivision is null)"#,
-            id
+            r#"SELECT players.id, players.name, banned, COALESCE(player_scores.score, 0) AS "score!", nationalities.nation::text, iso_country_code::text, iso_code::text as subdivision_code, subdivisions.name::text as subdivision_name, player_ranks.rank AS "rank?" FROM players LEFT OUTER JOIN nationalities ON 
+             players.nationality = nationalities.iso_country_code LEFT OUTER JOIN subdivisions ON players.subdivision = subdivisions.iso_code LEFT OUTER JOIN player_scores ON player_scores.player = players.id AND player_scores.list = $2 LEFT OUTER JOIN player_ranks ON player_ranks.id = players.id AND player_ranks.list = $2 WHERE players.id = $1 AND (subdivisions.nation=nationalities.iso_country_code or players.subdivision is null)"#,
+            id,
+            DEFAULT_LIST
         )
         .fetch_one(connection)
         .await;
@@ -69,7 +75,20 @@ impl Player {
 }
 
 impl DatabasePlayer {
+    /// Gets the player with the given name, falling back to the player that was previously known
+    /// under that name if no player currently has it
     pub async fn by_name(name: &str, connection: &mut PgConnection) -> Result<DatabasePlayer> {
+        match Self::by_current_name(name, &mut *connection).await {
+            Err(DemonlistError::PlayerNotFoundName { player_name }) => match Self::by_alias(&player_name, connection).await? {
+                Some(player) => Ok(player),
+                None => Err(DemonlistError::PlayerNotFoundName { player_name }),
+            },
+            result => result,
+        }
+    }
+
+    /// Gets the player with the given name, ignoring aliases
+    pub(super) async fn by_current_name(name: &str, connection: &mut PgConnection) -> Result<DatabasePlayer> {
         let name = name.trim();
 
         let result = sqlx::query_as!(DatabasePlayer, "SELECT id, name, banned FROM players WHERE name = $1::CITEXT", name)
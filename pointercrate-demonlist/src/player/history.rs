@@ -0,0 +1,70 @@
+//! Module for the score and rank history of players and nations
+//!
+//! History is tracked separately for each list. Whenever player ranks get refreshed, a snapshot of
+//! the score and rank of every player and nation whose score in some list changed since their last
+//! snapshot is taken (see the `snapshot_scores` database
+//! function). Changes in rank alone are captured periodically. This means consecutive snapshots
+//! always differ, and a player's score/rank at some point in time is given by the most recent
+//! snapshot before that point.
+
+use crate::{error::Result, nationality::Nationality, player::DatabasePlayer};
+use chrono::NaiveDateTime;
+use pointercrate_core::error::CoreError;
+use serde::Serialize;
+use sqlx::PgConnection;
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct ScoreSnapshot {
+    pub time: NaiveDateTime,
+    pub score: f64,
+
+    /// `None` if the player/nation was unranked at this point in time, e.g. because they had no
+    /// score (or were banned)
+    pub rank: Option<i64>,
+}
+
+/// Snapshots the current scores and ranks of all players and nations whose score changed since
+/// their last snapshot.
+///
+/// Since a single change in score can shift the rank of every player below the affected one, rank
+/// changes that were not caused by a change in score are only snapshotted if
+/// `include_rank_changes` is set. This is meant to be done periodically, instead of after every
+/// score change.
+///
+/// Needs to be called after the `player_ranks` view was refreshed, which [`refresh_player_ranks`](super::refresh_player_ranks)
+/// takes care of.
+pub async fn snapshot_scores(include_rank_changes: bool, connection: &mut PgConnection) -> std::result::Result<(), CoreError> {
+    sqlx::query!("SELECT snapshot_scores($1)", include_rank_changes)
+        .execute(connection)
+        .await?;
+
+    Ok(())
+}
+
+impl DatabasePlayer {
+    /// Retrieves this player's score and rank history in the given list in chronological order
+    pub async fn score_history(&self, list_id: &str, connection: &mut PgConnection) -> Result<Vec<ScoreSnapshot>> {
+        Ok(sqlx::query_as!(
+            ScoreSnapshot,
+            "SELECT time, score, rank FROM player_score_history WHERE player = $1 AND list = $2 ORDER BY time",
+            self.id,
+            list_id
+        )
+        .fetch_all(connection)
+        .await?)
+    }
+}
+
+impl Nationality {
+    /// Retrieves this nation's score and rank history in the given list in chronological order
+    pub async fn score_history(&self, list_id: &str, connection: &mut PgConnection) -> Result<Vec<ScoreSnapshot>> {
+        Ok(sqlx::query_as!(
+            ScoreSnapshot,
+            "SELECT time, score, rank FROM nation_score_history WHERE nation = $1 AND list = $2 ORDER BY time",
+            self.iso_country_code,
+            list_id
+        )
+        .fetch_all(connection)
+        .await?)
+    }
+}
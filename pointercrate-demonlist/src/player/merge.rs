@@ -0,0 +1,611 @@
+//! Module for previewing and undoing merges of players
+//!
+//! Every merge performed through [`FullPlayer::merge`] is recorded in the `player_merges` table,
+//! together with a snapshot of every row the merge touched, as it was before the merge. This
+//! snapshot is what allows undoing a merge later on. Records are additionally snapshotted as they
+//! were right after the merge, so that undoing a merge does not silently revert changes made to
+//! them since. Merges can also be previewed, by performing them inside a transaction that is
+//! rolled back afterwards.
+
+use crate::{
+    creator::created_by,
+    demon::{published_by, verified_by, MinimalDemon},
+    error::{DemonlistError, Result},
+    player::{DatabasePlayer, FullPlayer, Player},
+    record::{MinimalRecordPD, RecordStatus},
+};
+use chrono::{Duration, NaiveDateTime, Utc};
+use futures::stream::StreamExt;
+use log::info;
+use serde::{Deserialize, Serialize};
+use sqlx::{Connection, PgConnection};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct PlayerMerge {
+    pub id: i32,
+
+    /// The id of the player that remained after the merge
+    pub player_id: i32,
+
+    /// The id of the player that was merged into [`PlayerMerge::player_id`] and deleted
+    pub merged_id: i32,
+
+    pub merged_name: String,
+
+    /// The name of the user who performed the merge, if they still exist
+    pub merged_by: Option<String>,
+
+    pub merged_at: NaiveDateTime,
+
+    /// The name of the user who undid the merge, if it was undone and they still exist
+    pub undone_by: Option<String>,
+
+    pub undone_at: Option<NaiveDateTime>,
+
+    /// The ids of records that had been modified since the merge when it was undone, and which
+    /// were thus left as they were. `None` if the merge was not undone.
+    pub conflicting_records: Option<Vec<i32>>,
+}
+
+/// Everything that would happen if some player was merged into another one
+#[derive(Debug, Serialize)]
+pub struct MergePreview {
+    /// The player that would remain after the merge
+    pub player: DatabasePlayer,
+
+    /// The player that would be merged into [`MergePreview::player`] and deleted
+    pub merged: DatabasePlayer,
+
+    /// Records of either player that would be deleted as duplicates of records of the other one
+    pub deleted_records: Vec<MinimalRecordPD>,
+
+    /// Records of the merged player that would be moved over to the remaining player
+    pub moved_records: Vec<MinimalRecordPD>,
+
+    /// Demons (co)created by the merged player, which would be credited to the remaining player
+    /// instead
+    pub created: Vec<MinimalDemon>,
+
+    /// Demons verified by the merged player, which would be credited to the remaining player
+    /// instead
+    pub verified: Vec<MinimalDemon>,
+
+    /// Demons published by the merged player, which would be credited to the remaining player
+    /// instead
+    pub published: Vec<MinimalDemon>,
+
+    /// The number of claims on the merged player that would be moved to the remaining player
+    pub moved_claims: usize,
+
+    /// The number of claims on either player that would be deleted, because the other player has
+    /// a verified claim
+    pub deleted_claims: usize,
+
+    /// The score of the remaining player in the [default list](crate::list::DEFAULT_LIST) before
+    /// the merge
+    pub score_before: f64,
+
+    /// The score of the merged player in the [default list](crate::list::DEFAULT_LIST) before the
+    /// merge
+    pub merged_score_before: f64,
+
+    /// The score of the remaining player in the [default list](crate::list::DEFAULT_LIST) after
+    /// the merge
+    pub score_after: f64,
+}
+
+impl PlayerMerge {
+    pub async fn by_id(id: i32, connection: &mut PgConnection) -> Result<PlayerMerge> {
+        sqlx::query_as!(
+            PlayerMerge,
+            r#"SELECT id, player AS player_id, merged_id, merged_name::TEXT AS "merged_name!", merger.name AS "merged_by?", merged_at,
+             undoer.name AS "undone_by?", undone_at, conflicting_records FROM player_merges LEFT OUTER JOIN members AS merger ON
+             merger.member_id = merged_by LEFT OUTER JOIN members AS undoer ON undoer.member_id = undone_by WHERE id = $1"#,
+            id
+        )
+        .fetch_optional(connection)
+        .await?
+        .ok_or(DemonlistError::MergeNotFound { id })
+    }
+
+    /// Gets all merges of other players into the player with the given id, most recent first
+    pub async fn into_player(player_id: i32, connection: &mut PgConnection) -> Result<Vec<PlayerMerge>> {
+        Ok(sqlx::query_as!(
+            PlayerMerge,
+            r#"SELECT id, player AS player_id, merged_id, merged_name::TEXT AS "merged_name!", merger.name AS "merged_by?", merged_at,
+             undoer.name AS "undone_by?", undone_at, conflicting_records FROM player_merges LEFT OUTER JOIN members AS merger ON
+             merger.member_id = merged_by LEFT OUTER JOIN members AS undoer ON undoer.member_id = undone_by WHERE player = $1 ORDER BY id
+             DESC"#,
+            player_id
+        )
+        .fetch_all(connection)
+        .await?)
+    }
+
+    /// Records that the player with id `merged_id` is about to be merged into the player with id
+    /// `player_id`, snapshotting everything the merge is going to touch.
+    ///
+    /// Needs to be called before any modifications are made.
+    pub(super) async fn record(player_id: i32, merged_id: i32, connection: &mut PgConnection) -> Result<i32> {
+        let id = sqlx::query_file!("sql/snapshot_player_merge.sql", player_id, merged_id)
+            .fetch_one(connection)
+            .await?
+            .id;
+
+        info!("Recorded merge of player {} into player {} as merge {}", merged_id, player_id, id);
+
+        Ok(id)
+    }
+
+    /// Adds the state of the records touched by the merge with the given id, as they are after
+    /// the merge, to its snapshot.
+    ///
+    /// Needs to be called once all modifications are made.
+    pub(super) async fn record_outcome(id: i32, connection: &mut PgConnection) -> Result<()> {
+        sqlx::query!(
+            "UPDATE player_merges SET snapshot = snapshot || jsonb_build_object('merged_records', (SELECT COALESCE(jsonb_agg(records), \
+             '[]') FROM records WHERE id IN (SELECT (record->>'id')::INTEGER FROM jsonb_array_elements(player_merges.snapshot->'records') \
+             AS record))) WHERE id = $1",
+            id
+        )
+        .execute(connection)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Discards the snapshots of all merges older than `retention_days` days, as these merges
+    /// cannot be undone anymore anyway
+    pub async fn expire_snapshots(retention_days: i64, connection: &mut PgConnection) -> Result<()> {
+        let expired = sqlx::query!(
+            "UPDATE player_merges SET snapshot = NULL WHERE snapshot IS NOT NULL AND merged_at < (NOW() AT TIME ZONE 'utc') - \
+             make_interval(days => $1)",
+            retention_days as i32
+        )
+        .execute(connection)
+        .await?;
+
+        if expired.rows_affected() > 0 {
+            info!("Discarded the snapshots of {} expired merges", expired.rows_affected());
+        }
+
+        Ok(())
+    }
+
+    /// Undoes this merge, restoring the merged player with all its records, creator credits,
+    /// claims, profiles and aliases
+    ///
+    /// Merges older than `retention_days` days cannot be undone anymore. If the remaining player
+    /// was involved in a later merge which has not been undone, that merge has to be undone
+    /// first. Records modified since the merge are left alone and reported in
+    /// [`PlayerMerge::conflicting_records`].
+    pub async fn undo(mut self, retention_days: i64, undone_by: i32, connection: &mut PgConnection) -> Result<PlayerMerge> {
+        if self.undone_at.is_some() {
+            return Err(DemonlistError::MergeUndone);
+        }
+
+        let expired = sqlx::query!(r#"SELECT snapshot IS NULL AS "expired!" FROM player_merges WHERE id = $1"#, self.id)
+            .fetch_one(&mut *connection)
+            .await?
+            .expired;
+
+        if expired || self.merged_at + Duration::days(retention_days) < Utc::now().naive_utc() {
+            return Err(DemonlistError::MergeExpired);
+        }
+
+        // Either another player was merged into the remaining one since, or the remaining player
+        // was merged into yet another one, or the merged player's name was given to someone else
+        let superseded = sqlx::query!(
+            r#"SELECT EXISTS (SELECT 1 FROM player_merges WHERE id > $1 AND undone_at IS NULL AND (player = $2 OR merged_id = $2))
+             OR EXISTS (SELECT 1 FROM players WHERE name = $3::CITEXT) AS "superseded!""#,
+            self.id,
+            self.player_id,
+            self.merged_name
+        )
+        .fetch_one(&mut *connection)
+        .await?
+        .superseded;
+
+        if superseded {
+            return Err(DemonlistError::MergeSuperseded);
+        }
+
+        let player = DatabasePlayer::by_id(self.player_id, &mut *connection).await?;
+
+        info!("Undoing merge {} of player {} into {}", self.id, self.merged_name, player);
+
+        sqlx::query!(
+            "INSERT INTO players (id, name, banned, nationality, link_banned, subdivision, gd_account_id) SELECT id, name, banned, \
+             nationality, link_banned, subdivision, gd_account_id FROM jsonb_populate_record(NULL::players, (SELECT snapshot->'player' \
+             FROM player_merges WHERE id = $1))",
+            self.id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        // Records the merge modified are reset to their state from before the merge, and records
+        // that were deleted as duplicates are recreated, but only if they (or, for deleted
+        // records, their videos) were left alone since the merge. This needs to happen in this
+        // order, as a deleted record's video might have been moved to a record that still exists.
+        let reset = sqlx::query!(
+            "UPDATE records SET progress = before.progress, video = before.video, status_ = before.status_, player = before.player, \
+             submitter = before.submitter, demon = before.demon FROM jsonb_populate_recordset(NULL::records, (SELECT snapshot->'records' \
+             FROM player_merges WHERE id = $1)) AS before, jsonb_populate_recordset(NULL::records, (SELECT snapshot->'merged_records' \
+             FROM player_merges WHERE id = $1)) AS after WHERE records.id = before.id AND after.id = before.id AND (before.progress, \
+             before.video, before.status_, before.player, before.submitter, before.demon) IS DISTINCT FROM (after.progress, after.video, \
+             after.status_, after.player, after.submitter, after.demon) AND (records.progress, records.video, records.status_, \
+             records.player, records.submitter, records.demon) IS NOT DISTINCT FROM (after.progress, after.video, after.status_, \
+             after.player, after.submitter, after.demon) RETURNING records.id",
+            self.id
+        )
+        .fetch_all(&mut *connection)
+        .await?
+        .into_iter()
+        .map(|row| row.id)
+        .collect::<Vec<_>>();
+
+        let recreated = sqlx::query!(
+            "INSERT INTO records (id, progress, video, status_, player, submitter, demon) SELECT id, progress, video, status_, player, \
+             submitter, demon FROM jsonb_populate_recordset(NULL::records, (SELECT snapshot->'records' FROM player_merges WHERE id = $1)) \
+             AS before WHERE NOT EXISTS (SELECT 1 FROM jsonb_populate_recordset(NULL::records, (SELECT snapshot->'merged_records' FROM \
+             player_merges WHERE id = $1)) AS after WHERE after.id = before.id) AND NOT EXISTS (SELECT 1 FROM records WHERE records.id = \
+             before.id OR records.video = before.video) RETURNING id",
+            self.id
+        )
+        .fetch_all(&mut *connection)
+        .await?
+        .into_iter()
+        .map(|row| row.id)
+        .collect::<Vec<_>>();
+
+        info!(
+            "Reset {} records modified and recreated {} records deleted during the merge",
+            reset.len(),
+            recreated.len()
+        );
+
+        let restored = reset.iter().chain(&recreated).copied().collect::<Vec<_>>();
+
+        // Whatever the merge changed, but could not be restored, was modified since
+        let conflicting_records = sqlx::query!(
+            r#"SELECT before.id AS "id!" FROM jsonb_populate_recordset(NULL::records, (SELECT snapshot->'records' FROM player_merges WHERE
+             id = $1)) AS before LEFT OUTER JOIN jsonb_populate_recordset(NULL::records, (SELECT snapshot->'merged_records' FROM
+             player_merges WHERE id = $1)) AS after ON after.id = before.id WHERE NOT before.id = ANY($2) AND (after.id IS NULL OR
+             (before.progress, before.video, before.status_, before.player, before.submitter, before.demon) IS DISTINCT FROM
+             (after.progress, after.video, after.status_, after.player, after.submitter, after.demon)) ORDER BY before.id"#,
+            self.id,
+            &restored
+        )
+        .fetch_all(&mut *connection)
+        .await?
+        .into_iter()
+        .map(|row| row.id)
+        .collect::<Vec<_>>();
+
+        if !conflicting_records.is_empty() {
+            info!(
+                "Not restoring records {:?}, as they were modified since merge {}",
+                conflicting_records, self.id
+            );
+        }
+
+        // Notes and evidence of deleted records were transferred to the records that remained
+        sqlx::query!(
+            "INSERT INTO record_notes (id, record, content, is_public, automatic) SELECT id, record, content, is_public, automatic FROM \
+             jsonb_populate_recordset(NULL::record_notes, (SELECT snapshot->'record_notes' FROM player_merges WHERE id = $1)) WHERE \
+             record = ANY($2) ON CONFLICT (id) DO UPDATE SET record = EXCLUDED.record",
+            self.id,
+            &recreated
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        sqlx::query!(
+            "INSERT INTO record_evidence (id, record, kind, url, public, transferred) SELECT id, record, kind, url, public, transferred \
+             FROM jsonb_populate_recordset(NULL::record_evidence, (SELECT snapshot->'record_evidence' FROM player_merges WHERE id = $1)) \
+             WHERE record = ANY($2) ON CONFLICT (id) DO UPDATE SET record = EXCLUDED.record, transferred = EXCLUDED.transferred",
+            self.id,
+            &recreated
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        sqlx::query!(
+            "INSERT INTO record_video_checks (record, video, result, checked_at, video_host, video_id, title, channel, uploaded_at, \
+             duration) SELECT record, video, result, checked_at, video_host, video_id, title, channel, uploaded_at, duration FROM \
+             jsonb_populate_recordset(NULL::record_video_checks, (SELECT snapshot->'record_video_checks' FROM player_merges WHERE id = \
+             $1)) WHERE record = ANY($2) ON CONFLICT (record) DO NOTHING",
+            self.id,
+            &recreated
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        // Creator entries of the merged player were either transferred, or folded into the entry of
+        // the remaining player and deleted if the remaining player was a creator of the same demon.
+        // Snapshots of merges performed before creators had roles do not contain any.
+        sqlx::query!(
+            "UPDATE creators SET roles = COALESCE(snapshot.roles, '{}'), part_start = snapshot.part_start, part_end = \
+             snapshot.part_end FROM jsonb_populate_recordset(NULL::creators, (SELECT snapshot->'creators' FROM player_merges WHERE id = \
+             $1)) AS snapshot WHERE creators.demon = snapshot.demon AND creators.creator = $2 AND snapshot.creator = $2",
+            self.id,
+            self.player_id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        sqlx::query!(
+            "DELETE FROM creators USING jsonb_populate_recordset(NULL::creators, (SELECT snapshot->'creators' FROM player_merges WHERE id \
+             = $1)) AS snapshot WHERE creators.demon = snapshot.demon AND creators.creator = $2 AND snapshot.creator = $3 AND NOT EXISTS \
+             (SELECT 1 FROM jsonb_populate_recordset(NULL::creators, (SELECT snapshot->'creators' FROM player_merges WHERE id = $1)) AS \
+             original WHERE original.demon = creators.demon AND original.creator = $2)",
+            self.id,
+            self.player_id,
+            self.merged_id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        sqlx::query!(
+            "INSERT INTO creators (creator, demon, roles, part_start, part_end) SELECT creator, demon, COALESCE(roles, '{}'), \
+             part_start, part_end FROM jsonb_populate_recordset(NULL::creators, (SELECT snapshot->'creators' FROM player_merges WHERE id \
+             = $1)) WHERE creator = $2 ON CONFLICT DO NOTHING",
+            self.id,
+            self.merged_id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        sqlx::query!(
+            "UPDATE demons SET verifier = $3 WHERE verifier = $2 AND id IN (SELECT jsonb_array_elements(snapshot->'verified')::INTEGER \
+             FROM player_merges WHERE id = $1)",
+            self.id,
+            self.player_id,
+            self.merged_id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        sqlx::query!(
+            "UPDATE demons SET publisher = $3 WHERE publisher = $2 AND id IN (SELECT jsonb_array_elements(snapshot->'published')::INTEGER \
+             FROM player_merges WHERE id = $1)",
+            self.id,
+            self.player_id,
+            self.merged_id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        // Claims were either moved or deleted, depending on which player had a verified claim
+        sqlx::query!(
+            "DELETE FROM player_claims WHERE id IN (SELECT (jsonb_array_elements(snapshot->'claims')->>'id')::INTEGER FROM player_merges \
+             WHERE id = $1)",
+            self.id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        sqlx::query!(
+            "INSERT INTO player_claims (id, member_id, player_id, verified, lock_submissions, verification_code, \
+             verification_code_issued_at, third_party_approval) SELECT id, member_id, player_id, verified, lock_submissions, \
+             verification_code, verification_code_issued_at, third_party_approval FROM jsonb_populate_recordset(NULL::player_claims, \
+             (SELECT snapshot->'claims' FROM player_merges WHERE id = $1))",
+            self.id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        // Profiles move along with verified claims, so restore them the same way. Merges recorded before profiles existed
+        // have no profiles in their snapshot, in which case the remaining player's profile is left alone.
+        sqlx::query!(
+            "DELETE FROM player_profiles WHERE player = $2 AND (SELECT snapshot ? 'profiles' FROM player_merges WHERE id = $1)",
+            self.id,
+            self.player_id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        sqlx::query!(
+            "INSERT INTO player_profiles (player, display_name, bio, youtube, twitch, discord, gd_account) SELECT player, display_name, \
+             bio, youtube, twitch, discord, gd_account FROM jsonb_populate_recordset(NULL::player_profiles, (SELECT snapshot->'profiles' \
+             FROM player_merges WHERE id = $1))",
+            self.id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        sqlx::query!(
+            "DELETE FROM pending_player_profiles WHERE player = $2 AND (SELECT snapshot ? 'pending_profiles' FROM player_merges WHERE id \
+             = $1)",
+            self.id,
+            self.player_id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        sqlx::query!(
+            "INSERT INTO pending_player_profiles (player, display_name, bio, youtube, twitch, discord, gd_account, submitted_by, \
+             submitted_at) SELECT player, display_name, bio, youtube, twitch, discord, gd_account, submitted_by, submitted_at FROM \
+             jsonb_populate_recordset(NULL::pending_player_profiles, (SELECT snapshot->'pending_profiles' FROM player_merges WHERE id = \
+             $1))",
+            self.id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        sqlx::query!(
+            "UPDATE pending_submissions SET player = $3 WHERE player = $2 AND id IN (SELECT \
+             jsonb_array_elements(snapshot->'pending_submissions')::INTEGER FROM player_merges WHERE id = $1)",
+            self.id,
+            self.player_id,
+            self.merged_id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        sqlx::query!("DELETE FROM player_aliases WHERE former_id = $1", self.merged_id)
+            .execute(&mut *connection)
+            .await?;
+
+        sqlx::query!(
+            "UPDATE player_aliases SET player = $3 WHERE player = $2 AND id IN (SELECT jsonb_array_elements(snapshot->'aliases')::INTEGER \
+             FROM player_merges WHERE id = $1)",
+            self.id,
+            self.player_id,
+            self.merged_id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        sqlx::query!(
+            "INSERT INTO player_score_history (player, time, score, rank, list) SELECT player, time, score, rank, list FROM \
+             jsonb_populate_recordset(NULL::player_score_history, (SELECT snapshot->'score_history' FROM player_merges WHERE id = $1)) \
+             ON CONFLICT DO NOTHING",
+            self.id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        sqlx::query!(
+            "UPDATE player_merges SET undone_by = $2, undone_at = (NOW() AT TIME ZONE 'utc'), conflicting_records = $3 WHERE id = $1",
+            self.id,
+            undone_by,
+            &conflicting_records
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        player.update_score(&mut *connection).await?;
+        DatabasePlayer::by_id(self.merged_id, &mut *connection)
+            .await?
+            .update_score(&mut *connection)
+            .await?;
+
+        let undone = PlayerMerge::by_id(self.id, connection).await?;
+
+        self.undone_by = undone.undone_by;
+        self.undone_at = undone.undone_at;
+        self.conflicting_records = undone.conflicting_records;
+
+        Ok(self)
+    }
+}
+
+impl FullPlayer {
+    /// Computes what would happen if `with` was merged into this player, without actually
+    /// modifying anything.
+    ///
+    /// This performs the merge inside a transaction which is rolled back afterwards, so the
+    /// preview is exactly what [`FullPlayer::merge`] would do.
+    pub async fn preview_merge(mut self, with: DatabasePlayer, connection: &mut PgConnection) -> Result<MergePreview> {
+        let mut transaction = connection.begin().await?;
+
+        let score_before = self.player.score;
+        let merged_score_before = Player::by_id(with.id, &mut *transaction).await?.score;
+
+        let records = affected_records(self.player.base.id, with.id, &mut *transaction).await?;
+        let created = created_by(with.id, &mut *transaction)
+            .await?
+            .into_iter()
+            .map(|created| created.demon)
+            .collect();
+        let verified = verified_by(&with, &mut *transaction).await?;
+        let published = published_by(&with, &mut *transaction).await?;
+
+        let claims = sqlx::query!(
+            "SELECT id, player_id FROM player_claims WHERE player_id = $1 OR player_id = $2",
+            self.player.base.id,
+            with.id
+        )
+        .fetch_all(&mut *transaction)
+        .await?;
+
+        self.merge(with.clone(), &mut *transaction).await?;
+
+        let score_after = self.player.base.update_score(&mut *transaction).await?;
+
+        let remaining_records = sqlx::query!(
+            "SELECT id FROM records WHERE id = ANY($1)",
+            &records.iter().map(|record| record.id).collect::<Vec<_>>()
+        )
+        .fetch_all(&mut *transaction)
+        .await?
+        .into_iter()
+        .map(|row| row.id)
+        .collect::<Vec<_>>();
+
+        let remaining_claims = sqlx::query!(
+            "SELECT id FROM player_claims WHERE id = ANY($1)",
+            &claims.iter().map(|claim| claim.id).collect::<Vec<_>>()
+        )
+        .fetch_all(&mut *transaction)
+        .await?
+        .into_iter()
+        .map(|row| row.id)
+        .collect::<Vec<_>>();
+
+        transaction.rollback().await?;
+
+        let (moved_records, deleted_records): (Vec<_>, Vec<_>) =
+            records.into_iter().partition(|record| remaining_records.contains(&record.id));
+
+        Ok(MergePreview {
+            moved_records: moved_records.into_iter().filter(|record| record.player.id == with.id).collect(),
+            deleted_records,
+            created,
+            verified,
+            published,
+            moved_claims: claims
+                .iter()
+                .filter(|claim| claim.player_id == with.id && remaining_claims.contains(&claim.id))
+                .count(),
+            deleted_claims: claims.iter().filter(|claim| !remaining_claims.contains(&claim.id)).count(),
+            score_before,
+            merged_score_before,
+            score_after,
+            player: self.player.base,
+            merged: with,
+        })
+    }
+}
+
+/// Gets all records a merge of `merged_id` into `player_id` can touch: All records of the merged
+/// player, and the records of the remaining player on the same demons
+async fn affected_records(player_id: i32, merged_id: i32, connection: &mut PgConnection) -> Result<Vec<MinimalRecordPD>> {
+    let mut stream = sqlx::query!(
+        r#"SELECT records.id, records.progress, records.video::TEXT, records.status_::TEXT AS "status_!: String", demons.id AS demon_id,
+         demons.name AS "demon_name: String", demons.position, demons.list, players.id AS player_id, players.name AS "player_name: String",
+         players.banned AS player_banned FROM records INNER JOIN demons ON records.demon = demons.id INNER JOIN players ON records.player =
+         players.id WHERE records.player = $2 OR (records.player = $1 AND records.demon IN (SELECT demon FROM records WHERE player = $2))
+         ORDER BY records.id"#,
+        player_id,
+        merged_id
+    )
+    .fetch(connection);
+
+    let mut records = Vec::new();
+
+    while let Some(row) = stream.next().await {
+        let row = row?;
+
+        records.push(MinimalRecordPD {
+            id: row.id,
+            progress: row.progress,
+            video: row.video,
+            status: RecordStatus::from_sql(&row.status_),
+            demon: MinimalDemon {
+                id: row.demon_id,
+                position: row.position,
+                name: row.demon_name,
+                list: row.list,
+            },
+            player: DatabasePlayer {
+                id: row.player_id,
+                name: row.player_name,
+                banned: row.player_banned,
+            },
+        })
+    }
+
+    Ok(records)
+}
@@ -1,8 +1,11 @@
 pub use self::{
+    history::{snapshot_scores, ScoreSnapshot},
+    merge::{MergePreview, PlayerMerge},
     paginate::{PlayerPagination, RankedPlayer, RankingPagination},
     patch::PatchPlayer,
+    profile::{PatchPlayerProfile, PendingProfile, PlayerProfile},
 };
-use crate::{demon::MinimalDemon, nationality::Nationality, record::MinimalRecordD};
+use crate::{creator::CreatedDemon, demon::MinimalDemon, list::DEFAULT_LIST, nationality::Nationality, record::MinimalRecordD};
 use derive_more::Display;
 use pointercrate_core::{error::CoreError, etag::Taggable};
 use serde::{Deserialize, Serialize};
@@ -12,10 +15,14 @@ use std::{
     hash::{Hash, Hasher},
 };
 
+mod alias;
 pub mod claim;
 mod get;
+mod history;
+mod merge;
 mod paginate;
 mod patch;
+mod profile;
 
 #[derive(Debug, Hash, Eq, PartialEq, Serialize, Display, Clone, Deserialize)]
 #[display("{} (ID: {})", name, id)]
@@ -31,9 +38,16 @@ pub struct FullPlayer {
     #[serde(flatten)]
     pub player: Player,
     pub records: Vec<MinimalRecordD>,
-    pub created: Vec<MinimalDemon>,
+    pub created: Vec<CreatedDemon>,
     pub verified: Vec<MinimalDemon>,
     pub published: Vec<MinimalDemon>,
+
+    /// The names this player was previously known under
+    pub previous_names: Vec<String>,
+
+    /// The profile set up by the player's claimant, if any. Only contains changes approved by a
+    /// moderator.
+    pub profile: Option<PlayerProfile>,
 }
 
 #[derive(Debug, PartialEq, Serialize, Display, Deserialize)]
@@ -42,9 +56,9 @@ pub struct Player {
     #[serde(flatten)]
     pub base: DatabasePlayer,
 
-    /// This [`Player`]'s score on the stats viewer
+    /// This [`Player`]'s score in the [default list](DEFAULT_LIST) on the stats viewer
     ///
-    /// This value is cached in the `score` column of the `players` table, and not computed on-demand!
+    /// Scores are cached per list in the `player_scores` table, and not computed on-demand!
     /// Thus it needs to be updated on any event that can affect a player's score. These are
     /// - Record updates
     ///   * Record status updated (to approved, or from approved)
@@ -52,13 +66,18 @@ pub struct Player {
     ///   * Record holder updated
     ///   * Record Added
     /// - Demon updates
-    ///   * Demon movement/addition (recompute all scores)
-    ///   * Demon requirement updated (recompute all scores)
+    ///   * Demon movement/addition (recompute all scores in the demon's list)
+    ///   * Demon requirement updated (recompute all scores in the demon's list)
     ///   * Demon verifier updated
     /// - Player updates
     ///   * Player banned
     ///   * Player objects merged
+    /// - List updates
+    ///   * List sizes changed (recompute all scores in the list)
     pub score: f64,
+
+    /// This [`Player`]'s rank in the [default list](DEFAULT_LIST), or `None` if they do not have
+    /// any score in it (or are banned)
     pub rank: Option<i64>,
     pub nationality: Option<Nationality>,
 }
@@ -82,34 +101,59 @@ impl Taggable for FullPlayer {
 }
 
 impl DatabasePlayer {
-    /// Recomputes this player's score and updates it in the database.
+    /// Recomputes this player's scores in all lists and updates them in the database.
+    ///
+    /// Returns the player's new score in the [default list](DEFAULT_LIST)
     pub async fn update_score(&self, connection: &mut PgConnection) -> Result<f64, CoreError> {
-        // No need to specially handle banned players - they have no approved records, so `score_of_player` will return 0
-        let new_score = sqlx::query!(
-            "UPDATE players SET score = coalesce(score_of_player($1), 0) WHERE id = $1 RETURNING score",
+        // No need to specially handle banned players - they have no approved records, so `update_player_scores` will remove all their scores
+        sqlx::query!("SELECT update_player_scores($1)", self.id)
+            .execute(&mut *connection)
+            .await?;
+        sqlx::query!(
+            "SELECT update_nation_scores(nationality) FROM players WHERE id = $1 AND nationality IS NOT NULL",
             self.id
         )
-        .fetch_one(&mut *connection)
+        .execute(&mut *connection)
         .await?;
+        sqlx::query!(
+            "SELECT update_subdivision_scores(nationality, subdivision) FROM players WHERE id = $1 AND subdivision IS NOT NULL",
+            self.id
+        )
+        .execute(&mut *connection)
+        .await?;
+        refresh_player_ranks(&mut *connection).await?;
 
-        sqlx::query!("UPDATE nationalities SET score = coalesce(score_of_nation(nationalities.iso_country_code), 0) FROM players WHERE players.id = $1 AND players.nationality = nationalities.iso_country_code", self.id).execute(&mut *connection).await?;
-        sqlx::query!("UPDATE subdivisions SET score = coalesce(score_of_subdivision(subdivisions.nation, subdivisions.iso_code), 0) FROM players WHERE players.id = $1 AND players.nationality = subdivisions.nation AND players.subdivision = subdivisions.iso_code", self.id).execute(&mut *connection).await?;
-        sqlx::query!("REFRESH MATERIALIZED VIEW CONCURRENTLY player_ranks;")
-            .execute(&mut *connection)
-            .await?;
-
-        Ok(new_score.score)
+        Ok(sqlx::query!(
+            "SELECT score FROM player_scores WHERE player = $1 AND list = $2",
+            self.id,
+            DEFAULT_LIST
+        )
+        .fetch_optional(connection)
+        .await?
+        .map(|row| row.score)
+        .unwrap_or(0.0))
     }
 }
 
-pub async fn recompute_scores(connection: &mut PgConnection) -> Result<(), CoreError> {
-    sqlx::query!("SELECT recompute_player_scores();").execute(&mut *connection).await?;
-    sqlx::query!("SELECT recompute_nation_scores();").execute(&mut *connection).await?;
-    sqlx::query!("SELECT recompute_subdivision_scores();")
+/// Recomputes the scores of all players, nations and subdivisions in the given list, and refreshes
+/// the player rankings
+pub async fn recompute_scores(list_id: &str, connection: &mut PgConnection) -> Result<(), CoreError> {
+    sqlx::query!("SELECT recompute_player_scores($1);", list_id)
+        .execute(&mut *connection)
+        .await?;
+    sqlx::query!("SELECT recompute_nation_scores($1);", list_id)
+        .execute(&mut *connection)
+        .await?;
+    sqlx::query!("SELECT recompute_subdivision_scores($1);", list_id)
         .execute(&mut *connection)
         .await?;
+    refresh_player_ranks(connection).await
+}
+
+/// Refreshes the `player_ranks` materialized view (for all lists) and snapshots all score changes
+pub async fn refresh_player_ranks(connection: &mut PgConnection) -> Result<(), CoreError> {
     sqlx::query!("REFRESH MATERIALIZED VIEW CONCURRENTLY player_ranks;")
         .execute(&mut *connection)
         .await?;
-    Ok(())
+    snapshot_scores(false, connection).await
 }
@@ -1,11 +1,13 @@
 use crate::{
+    creator::CreatorRole,
+    list::DEFAULT_LIST,
     nationality::{Continent, Nationality, Subdivision},
     player::{DatabasePlayer, Player},
 };
 use futures::StreamExt;
 use pointercrate_core::{
     first_and_last,
-    pagination::{PageContext, Paginatable, PaginationParameters, PaginationQuery, __pagination_compat},
+    pagination::{__pagination_compat, PageContext, Paginatable, PaginationParameters, PaginationQuery},
     util::{non_nullable, nullable},
 };
 use serde::{Deserialize, Serialize};
@@ -30,6 +32,10 @@ pub struct PlayerPagination {
 
     #[serde(default, deserialize_with = "nullable")]
     subdivision: Option<Option<String>>,
+
+    /// Only return players that are credited with the given role as a creator of some demon
+    #[serde(default, deserialize_with = "non_nullable")]
+    creator_role: Option<CreatorRole>,
 }
 
 impl PaginationQuery for PlayerPagination {
@@ -64,6 +70,8 @@ impl Paginatable<PlayerPagination> for Player {
             .bind(query.nation == Some(None))
             .bind(&query.subdivision)
             .bind(query.params.limit + 1)
+            .bind(DEFAULT_LIST)
+            .bind(query.creator_role.map(|role| role.to_sql()))
             .fetch(connection);
 
         let mut players = Vec::new();
@@ -113,7 +121,7 @@ impl Paginatable<PlayerPagination> for Player {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct RankingPagination {
     #[serde(flatten)]
     pub params: PaginationParameters,
@@ -129,6 +137,17 @@ pub struct RankingPagination {
 
     #[serde(default, deserialize_with = "non_nullable")]
     name_contains: Option<String>,
+
+    /// The list whose ranking should be paginated. Not part of the query string, but instead set
+    /// from the request path. Defaults to the [default list](DEFAULT_LIST).
+    #[serde(skip)]
+    pub list: Option<String>,
+}
+
+impl RankingPagination {
+    fn list(&self) -> &str {
+        self.list.as_deref().unwrap_or(DEFAULT_LIST)
+    }
 }
 
 impl PaginationQuery for RankingPagination {
@@ -154,7 +173,11 @@ pub struct RankedPlayer {
 
 impl Paginatable<RankingPagination> for RankedPlayer {
     async fn first_and_last(connection: &mut PgConnection) -> Result<Option<(i32, i32)>, sqlx::Error> {
-        Ok(sqlx::query!("SELECT COUNT(*) FROM players WHERE NOT banned AND score > 0.0")
+        Self::first_and_last_matching(&RankingPagination::default(), connection).await
+    }
+
+    async fn first_and_last_matching(query: &RankingPagination, connection: &mut PgConnection) -> Result<Option<(i32, i32)>, sqlx::Error> {
+        Ok(sqlx::query!("SELECT COUNT(*) FROM player_ranks WHERE list = $1", query.list())
             .fetch_one(connection)
             .await?
             .count
@@ -175,6 +198,7 @@ impl Paginatable<RankingPagination> for RankedPlayer {
             .bind(query.continent.as_ref().map(|c| c.to_sql()))
             .bind(&query.subdivision)
             .bind(query.params.limit + 1)
+            .bind(query.list())
             .fetch(connection);
 
         let mut players = Vec::new();
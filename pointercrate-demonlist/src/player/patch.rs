@@ -1,7 +1,7 @@
 use crate::{
     error::{DemonlistError, Result},
     nationality::Nationality,
-    player::{claim::PlayerClaim, DatabasePlayer, FullPlayer, Player},
+    player::{claim::PlayerClaim, DatabasePlayer, FullPlayer, Player, PlayerMerge, PlayerProfile},
     record::{approved_records_by, FullRecord},
 };
 use log::info;
@@ -87,12 +87,19 @@ impl FullPlayer {
             // If they are equal case insensitively, we're only doing a cosmetic rename, which won't
             // even require a merge
 
-            // try to see if a player with new name already exists
-            match DatabasePlayer::by_name(name.as_ref(), &mut *connection).await {
+            // try to see if a player with new name already exists. Players merely previously known
+            // under the new name are not merged, the name simply changes hands.
+            match DatabasePlayer::by_current_name(name.as_ref(), &mut *connection).await {
                 Ok(existing) => self.merge(existing, &mut *connection).await?,
                 Err(DemonlistError::PlayerNotFoundName { .. }) => (),
                 Err(err) => return Err(err),
             }
+
+            self.player.base.add_alias(&self.player.base.name, None, &mut *connection).await?;
+
+            if !self.previous_names.contains(&self.player.base.name) {
+                self.previous_names.push(self.player.base.name.clone());
+            }
         }
 
         sqlx::query!(
@@ -103,6 +110,8 @@ impl FullPlayer {
         .execute(connection)
         .await?;
 
+        self.previous_names
+            .retain(|previous_name| previous_name.to_lowercase() != name.to_lowercase());
         self.player.base.name = name;
 
         Ok(())
@@ -110,10 +119,14 @@ impl FullPlayer {
 
     /// Merges the given player into `Self`, deleting `with`.
     ///
+    /// The name and id of `with` are kept as an alias of `Self`, and so are all of `with`'s own
+    /// aliases. The merge is recorded as a [`PlayerMerge`], which allows undoing it later on.
     /// Note that this method **does not** rename `Self`
     pub async fn merge(&mut self, with: DatabasePlayer, connection: &mut PgConnection) -> Result<()> {
         info!("Merging player {} with player {}", self, with);
 
+        let merge_id = PlayerMerge::record(self.player.base.id, with.id, &mut *connection).await?;
+
         let claim_on_self = PlayerClaim::verified_claim_on(self.player.base.id, &mut *connection).await?;
         let claim_on_with = PlayerClaim::verified_claim_on(with.id, &mut *connection).await?;
 
@@ -140,6 +153,10 @@ impl FullPlayer {
                 )
                 .execute(&mut *connection)
                 .await?;
+
+                // The profile was set up by the claimant, so it moves along with the claim
+                PlayerProfile::transfer(with.id, self.player.base.id, &mut *connection).await?;
+                self.profile = PlayerProfile::of(self.player.base.id, &mut *connection).await?;
             },
             (None, None) => {
                 sqlx::query!(
@@ -152,7 +169,18 @@ impl FullPlayer {
             },
         }
 
-        // First, delete duplicate creator entries
+        // First, fold the roles and part of the merged player into the remaining player's entry for
+        // demons both of them (co)created, then delete the now duplicate creator entries
+        sqlx::query!(
+            "UPDATE creators AS c1 SET roles = ARRAY(SELECT DISTINCT role FROM unnest(c1.roles || c2.roles) AS role ORDER BY role), \
+             part_start = COALESCE(c1.part_start, c2.part_start), part_end = COALESCE(c1.part_end, c2.part_end) FROM creators AS c2 \
+             WHERE c2.demon = c1.demon AND c1.creator = $1 AND c2.creator = $2",
+            self.player.base.id,
+            with.id
+        )
+        .execute(&mut *connection)
+        .await?;
+
         let deleted = sqlx::query!(
             "DELETE FROM creators AS c1 WHERE c1.creator = $2 AND EXISTS (SELECT 1 FROM creators AS c2 WHERE c2.demon = c1.demon AND \
              c2.creator = $1)",
@@ -219,12 +247,33 @@ impl FullPlayer {
 
         info!("Moved {} records from {} to {}", updated.rows_affected(), with, self);
 
+        // Submissions waiting for the approval of a claimant. If both players had a verified claim, we bailed out above
+        sqlx::query!(
+            "UPDATE pending_submissions SET player = $1 WHERE player = $2",
+            self.player.base.id,
+            with.id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        // Keep the merged player's name and id (and its previous names) around
+        sqlx::query!(
+            "UPDATE player_aliases SET player = $1 WHERE player = $2",
+            self.player.base.id,
+            with.id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        self.player.base.add_alias(&with.name, Some(with.id), &mut *connection).await?;
+        self.previous_names = self.player.base.previous_names(&mut *connection).await?;
+
         // Delete the second player
         sqlx::query!("DELETE FROM players WHERE id = $1", with.id)
-            .execute(connection)
+            .execute(&mut *connection)
             .await?;
 
-        Ok(())
+        PlayerMerge::record_outcome(merge_id, connection).await
     }
 }
 
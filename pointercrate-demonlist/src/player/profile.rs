@@ -0,0 +1,428 @@
+//! Module for the profiles claimants can set up for the player they claimed
+//!
+//! A profile consists of a bio, a preferred display name and a handful of social links. Changes
+//! made by the claimant are not visible right away. Instead, they are held as a
+//! [`PendingProfile`] until a moderator approves them, at which point they replace the player's
+//! live profile. Moderators can also remove a live profile at any time, e.g. if it is abusive.
+
+use crate::{
+    error::{DemonlistError, Result},
+    player::DatabasePlayer,
+};
+use chrono::NaiveDateTime;
+use log::info;
+use pointercrate_core::util::nullable;
+use serde::{Deserialize, Serialize};
+use sqlx::PgConnection;
+use url::Url;
+
+const MAX_DISPLAY_NAME_LENGTH: usize = 50;
+const MAX_BIO_LENGTH: usize = 1000;
+
+#[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, Clone, Default)]
+pub struct PlayerProfile {
+    /// The name the player prefers to be displayed under, if different from their name on the list
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+
+    /// Link to the player's YouTube channel
+    pub youtube: Option<String>,
+
+    /// The player's Twitch username
+    pub twitch: Option<String>,
+
+    /// The player's Discord handle
+    pub discord: Option<String>,
+
+    /// The name of the player's Geometry Dash account
+    pub gd_account: Option<String>,
+}
+
+/// Changes to a player's profile made by its claimant, waiting for a moderator's approval
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct PendingProfile {
+    pub player: DatabasePlayer,
+
+    /// The complete profile the player would have once these changes are approved
+    pub profile: PlayerProfile,
+
+    /// The id of the user who submitted these changes
+    pub submitted_by: i32,
+    pub submitted_at: NaiveDateTime,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct PatchPlayerProfile {
+    #[serde(default, deserialize_with = "nullable")]
+    pub display_name: Option<Option<String>>,
+
+    #[serde(default, deserialize_with = "nullable")]
+    pub bio: Option<Option<String>>,
+
+    #[serde(default, deserialize_with = "nullable")]
+    pub youtube: Option<Option<String>>,
+
+    #[serde(default, deserialize_with = "nullable")]
+    pub twitch: Option<Option<String>>,
+
+    #[serde(default, deserialize_with = "nullable")]
+    pub discord: Option<Option<String>>,
+
+    #[serde(default, deserialize_with = "nullable")]
+    pub gd_account: Option<Option<String>>,
+}
+
+impl PlayerProfile {
+    /// Gets the live (that is, approved) profile of the player with the given id, if they have one
+    pub async fn of(player_id: i32, connection: &mut PgConnection) -> Result<Option<PlayerProfile>> {
+        Ok(sqlx::query_as!(
+            PlayerProfile,
+            "SELECT display_name, bio, youtube, twitch, discord, gd_account FROM player_profiles WHERE player = $1",
+            player_id
+        )
+        .fetch_optional(connection)
+        .await?)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == PlayerProfile::default()
+    }
+
+    /// Applies the given patch to this profile, validating every changed field
+    ///
+    /// Empty values are treated the same as `null`, meaning they remove the respective field from
+    /// the profile. Usernames are normalized to the form used by the respective platform.
+    pub fn apply_patch(mut self, patch: PatchPlayerProfile) -> Result<Self> {
+        if let Some(display_name) = patch.display_name {
+            self.display_name = validate(display_name, "display_name", |name| {
+                (name.chars().count() <= MAX_DISPLAY_NAME_LENGTH && !name.chars().any(char::is_control)).then(|| name.to_string())
+            })?;
+        }
+
+        if let Some(bio) = patch.bio {
+            self.bio = validate(bio, "bio", |bio| {
+                (bio.chars().count() <= MAX_BIO_LENGTH && !bio.chars().any(|c| c.is_control() && c != '\n' && c != '\r'))
+                    .then(|| bio.to_string())
+            })?;
+        }
+
+        if let Some(youtube) = patch.youtube {
+            self.youtube = validate(youtube, "youtube", validate_youtube_channel)?;
+        }
+
+        if let Some(twitch) = patch.twitch {
+            self.twitch = validate(twitch, "twitch", |name| {
+                let valid =
+                    (4..=25).contains(&name.len()) && !name.starts_with('_') && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+                valid.then(|| name.to_ascii_lowercase())
+            })?;
+        }
+
+        if let Some(discord) = patch.discord {
+            self.discord = validate(discord, "discord", |handle| {
+                let handle = handle.trim_start_matches('@').to_ascii_lowercase();
+                let valid = (2..=32).contains(&handle.len())
+                    && !handle.contains("..")
+                    && handle.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.');
+
+                valid.then_some(handle)
+            })?;
+        }
+
+        if let Some(gd_account) = patch.gd_account {
+            self.gd_account = validate(gd_account, "gd_account", |name| {
+                ((3..=15).contains(&name.len()) && name.chars().all(|c| c.is_ascii_alphanumeric() || c == ' ')).then(|| name.to_string())
+            })?;
+        }
+
+        Ok(self)
+    }
+
+    /// Submits this profile for moderator approval, replacing any changes to the given player's
+    /// profile that are still waiting for approval
+    pub async fn submit(self, player: &DatabasePlayer, submitted_by: i32, connection: &mut PgConnection) -> Result<PendingProfile> {
+        info!("User {} submitted profile {:?} for player {}", submitted_by, self, player);
+
+        let submitted_at = sqlx::query!(
+            "INSERT INTO pending_player_profiles (player, display_name, bio, youtube, twitch, discord, gd_account, submitted_by) VALUES \
+             ($1, $2, $3, $4, $5, $6, $7, $8) ON CONFLICT (player) DO UPDATE SET display_name = EXCLUDED.display_name, bio = \
+             EXCLUDED.bio, youtube = EXCLUDED.youtube, twitch = EXCLUDED.twitch, discord = EXCLUDED.discord, gd_account = \
+             EXCLUDED.gd_account, submitted_by = EXCLUDED.submitted_by, submitted_at = EXCLUDED.submitted_at RETURNING submitted_at",
+            player.id,
+            self.display_name,
+            self.bio,
+            self.youtube,
+            self.twitch,
+            self.discord,
+            self.gd_account,
+            submitted_by
+        )
+        .fetch_one(connection)
+        .await?
+        .submitted_at;
+
+        Ok(PendingProfile {
+            player: player.clone(),
+            profile: self,
+            submitted_by,
+            submitted_at,
+        })
+    }
+
+    /// Removes the live profile of the given player
+    pub async fn remove(player: &DatabasePlayer, connection: &mut PgConnection) -> Result<()> {
+        info!("Removing profile of player {}", player);
+
+        let deleted = sqlx::query!("DELETE FROM player_profiles WHERE player = $1", player.id)
+            .execute(connection)
+            .await?;
+
+        if deleted.rows_affected() == 0 {
+            return Err(DemonlistError::ProfileNotFound { player_id: player.id });
+        }
+
+        Ok(())
+    }
+
+    /// Moves the live and pending profiles of player `from` over to player `to`, replacing the
+    /// profiles `to` had before
+    pub(super) async fn transfer(from: i32, to: i32, connection: &mut PgConnection) -> Result<()> {
+        sqlx::query!("DELETE FROM player_profiles WHERE player = $1", to)
+            .execute(&mut *connection)
+            .await?;
+        sqlx::query!("UPDATE player_profiles SET player = $1 WHERE player = $2", to, from)
+            .execute(&mut *connection)
+            .await?;
+        sqlx::query!("DELETE FROM pending_player_profiles WHERE player = $1", to)
+            .execute(&mut *connection)
+            .await?;
+        sqlx::query!("UPDATE pending_player_profiles SET player = $1 WHERE player = $2", to, from)
+            .execute(connection)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl PendingProfile {
+    /// Gets the profile changes for the given player that are waiting for approval
+    pub async fn of(player: &DatabasePlayer, connection: &mut PgConnection) -> Result<Option<PendingProfile>> {
+        let row = sqlx::query!(
+            "SELECT display_name, bio, youtube, twitch, discord, gd_account, submitted_by, submitted_at FROM pending_player_profiles \
+             WHERE player = $1",
+            player.id
+        )
+        .fetch_optional(connection)
+        .await?;
+
+        Ok(row.map(|row| PendingProfile {
+            player: player.clone(),
+            profile: PlayerProfile {
+                display_name: row.display_name,
+                bio: row.bio,
+                youtube: row.youtube,
+                twitch: row.twitch,
+                discord: row.discord,
+                gd_account: row.gd_account,
+            },
+            submitted_by: row.submitted_by,
+            submitted_at: row.submitted_at,
+        }))
+    }
+
+    /// Gets all profile changes waiting for approval, oldest first
+    pub async fn all(connection: &mut PgConnection) -> Result<Vec<PendingProfile>> {
+        Ok(sqlx::query!(
+            "SELECT players.id, players.name, players.banned, display_name, bio, youtube, twitch, discord, gd_account, submitted_by, \
+             submitted_at FROM pending_player_profiles INNER JOIN players ON players.id = pending_player_profiles.player ORDER BY \
+             submitted_at"
+        )
+        .fetch_all(connection)
+        .await?
+        .into_iter()
+        .map(|row| PendingProfile {
+            player: DatabasePlayer {
+                id: row.id,
+                name: row.name,
+                banned: row.banned,
+            },
+            profile: PlayerProfile {
+                display_name: row.display_name,
+                bio: row.bio,
+                youtube: row.youtube,
+                twitch: row.twitch,
+                discord: row.discord,
+                gd_account: row.gd_account,
+            },
+            submitted_by: row.submitted_by,
+            submitted_at: row.submitted_at,
+        })
+        .collect())
+    }
+
+    /// Makes these changes the player's live profile. If the resulting profile is empty, the
+    /// player's live profile is removed instead.
+    pub async fn approve(self, connection: &mut PgConnection) -> Result<Option<PlayerProfile>> {
+        info!("Approving profile changes for player {}", self.player);
+
+        sqlx::query!("DELETE FROM pending_player_profiles WHERE player = $1", self.player.id)
+            .execute(&mut *connection)
+            .await?;
+
+        if self.profile.is_empty() {
+            sqlx::query!("DELETE FROM player_profiles WHERE player = $1", self.player.id)
+                .execute(connection)
+                .await?;
+
+            return Ok(None);
+        }
+
+        sqlx::query!(
+            "INSERT INTO player_profiles (player, display_name, bio, youtube, twitch, discord, gd_account) VALUES ($1, $2, $3, $4, $5, \
+             $6, $7) ON CONFLICT (player) DO UPDATE SET display_name = EXCLUDED.display_name, bio = EXCLUDED.bio, youtube = \
+             EXCLUDED.youtube, twitch = EXCLUDED.twitch, discord = EXCLUDED.discord, gd_account = EXCLUDED.gd_account",
+            self.player.id,
+            self.profile.display_name,
+            self.profile.bio,
+            self.profile.youtube,
+            self.profile.twitch,
+            self.profile.discord,
+            self.profile.gd_account
+        )
+        .execute(connection)
+        .await?;
+
+        Ok(Some(self.profile))
+    }
+
+    pub async fn reject(self, connection: &mut PgConnection) -> Result<()> {
+        info!("Rejecting profile changes for player {}", self.player);
+
+        sqlx::query!("DELETE FROM pending_player_profiles WHERE player = $1", self.player.id)
+            .execute(connection)
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn validate(value: Option<String>, field: &'static str, validator: impl FnOnce(&str) -> Option<String>) -> Result<Option<String>> {
+    match value.as_deref().map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(value) => validator(value).map(Some).ok_or(DemonlistError::InvalidProfileField { field }),
+    }
+}
+
+/// Validates that the given URL points to a YouTube channel, returning a normalized version of it
+fn validate_youtube_channel(url: &str) -> Option<String> {
+    let url = Url::parse(url).ok()?;
+
+    if !["http", "https"].contains(&url.scheme()) || !url.username().is_empty() || url.password().is_some() {
+        return None;
+    }
+
+    if !matches!(url.domain(), Some("youtube.com" | "www.youtube.com" | "m.youtube.com")) {
+        return None;
+    }
+
+    let segments = url.path_segments()?.filter(|segment| !segment.is_empty()).collect::<Vec<_>>();
+
+    match segments[..] {
+        [kind @ ("channel" | "c" | "user"), name] => Some(format!("https://www.youtube.com/{}/{}", kind, name)),
+        [handle] if handle.starts_with('@') && handle.len() > 1 => Some(format!("https://www.youtube.com/{}", handle)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PatchPlayerProfile, PlayerProfile};
+    use crate::error::DemonlistError;
+
+    #[test]
+    fn test_youtube_links() {
+        let patch = |youtube: &str| PatchPlayerProfile {
+            youtube: Some(Some(youtube.to_string())),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            PlayerProfile::default()
+                .apply_patch(patch("youtube.com/@stadust"))
+                .map(|profile| profile.youtube),
+            Err(DemonlistError::InvalidProfileField { field: "youtube" })
+        );
+        assert_eq!(
+            PlayerProfile::default()
+                .apply_patch(patch("https://www.youtube.com/watch?v=dQw4w9WgXcQ"))
+                .map(|profile| profile.youtube),
+            Err(DemonlistError::InvalidProfileField { field: "youtube" })
+        );
+        assert_eq!(
+            PlayerProfile::default()
+                .apply_patch(patch(" http://m.youtube.com/channel/UC12345/?foo=bar "))
+                .map(|profile| profile.youtube),
+            Ok(Some("https://www.youtube.com/channel/UC12345".to_string()))
+        );
+        assert_eq!(
+            PlayerProfile::default()
+                .apply_patch(patch("https://youtube.com/@stadust"))
+                .map(|profile| profile.youtube),
+            Ok(Some("https://www.youtube.com/@stadust".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_usernames() {
+        let profile = PlayerProfile::default()
+            .apply_patch(PatchPlayerProfile {
+                twitch: Some(Some("Stadust_".to_string())),
+                discord: Some(Some("@Stadust.1".to_string())),
+                gd_account: Some(Some("stadust 1972".to_string())),
+                ..Default::default()
+            })
+            .unwrap();
+
+        assert_eq!(profile.twitch.as_deref(), Some("stadust_"));
+        assert_eq!(profile.discord.as_deref(), Some("stadust.1"));
+        assert_eq!(profile.gd_account.as_deref(), Some("stadust 1972"));
+
+        let invalid = |patch: PatchPlayerProfile| profile.clone().apply_patch(patch).unwrap_err();
+
+        assert_eq!(
+            invalid(PatchPlayerProfile {
+                twitch: Some(Some("_abc".to_string())),
+                ..Default::default()
+            }),
+            DemonlistError::InvalidProfileField { field: "twitch" }
+        );
+        assert_eq!(
+            invalid(PatchPlayerProfile {
+                discord: Some(Some("sta..dust".to_string())),
+                ..Default::default()
+            }),
+            DemonlistError::InvalidProfileField { field: "discord" }
+        );
+        assert_eq!(
+            invalid(PatchPlayerProfile {
+                gd_account: Some(Some("averyverylongname".to_string())),
+                ..Default::default()
+            }),
+            DemonlistError::InvalidProfileField { field: "gd_account" }
+        );
+
+        // Empty values remove fields
+        let profile = profile
+            .apply_patch(PatchPlayerProfile {
+                twitch: Some(None),
+                discord: Some(Some("  ".to_string())),
+                ..Default::default()
+            })
+            .unwrap();
+
+        assert_eq!(profile.twitch, None);
+        assert_eq!(profile.discord, None);
+        assert_eq!(profile.gd_account.as_deref(), Some("stadust 1972"));
+    }
+}
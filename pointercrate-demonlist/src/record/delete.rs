@@ -16,7 +16,8 @@ impl FullRecord {
     /// `FullRecord::delete` should be preferred. Only exists to delete invalid submissions
     /// in the asychronous validation (which is why no score adjustment needs to take place here)
     pub async fn delete_by_id(record_id: i32, connection: &mut PgConnection) -> Result<()> {
-        // Associated notes get deleted due to the ON DELETE CASCADE on record_notes.record
+        // Associated notes and evidence get deleted due to the ON DELETE CASCADE on record_notes.record and
+        // record_evidence.record
 
         sqlx::query!("DELETE FROM records WHERE id = $1", record_id)
             .execute(connection)
@@ -24,4 +25,19 @@ impl FullRecord {
 
         Ok(())
     }
+
+    /// Deletes the given submission because the host of its video reported the video as missing
+    ///
+    /// Unlike [`FullRecord::delete_by_id`], this deletion is remembered for the statistics of the
+    /// record's submitter.
+    pub async fn delete_missing_video(record_id: i32, connection: &mut PgConnection) -> Result<()> {
+        sqlx::query!(
+            "INSERT INTO video_check_deletions (submitter, video) SELECT submitter, video FROM records WHERE id = $1 AND video IS NOT NULL",
+            record_id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        FullRecord::delete_by_id(record_id, connection).await
+    }
 }
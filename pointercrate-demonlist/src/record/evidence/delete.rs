@@ -0,0 +1,12 @@
+use crate::{error::Result, record::evidence::Evidence};
+use sqlx::PgConnection;
+
+impl Evidence {
+    pub async fn delete(self, connection: &mut PgConnection) -> Result<()> {
+        sqlx::query!("DELETE FROM record_evidence WHERE id = $1", self.id)
+            .execute(connection)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{
+    error::{DemonlistError, Result},
+    record::evidence::{Evidence, EvidenceKind},
+};
+use sqlx::{Error, PgConnection};
+
+impl Evidence {
+    pub async fn by_id(record_id: i32, evidence_id: i32, connection: &mut PgConnection) -> Result<Evidence> {
+        let row = sqlx::query!(
+            r#"SELECT id, record, kind::TEXT AS "kind!", url, public, transferred FROM record_evidence WHERE id = $1 AND record = $2"#,
+            evidence_id,
+            record_id
+        )
+        .fetch_one(connection)
+        .await;
+
+        match row {
+            Err(Error::RowNotFound) => Err(DemonlistError::EvidenceNotFound { evidence_id, record_id }),
+            Err(err) => Err(err.into()),
+            Ok(row) => Ok(Evidence {
+                id: row.id,
+                record: row.record,
+                kind: EvidenceKind::from_sql(&row.kind)?,
+                url: row.url,
+                is_public: row.public,
+                transferred: row.transferred,
+            }),
+        }
+    }
+}
+
+/// Gets the evidence links of the given record, in the order in which they were added
+///
+/// Links on records of players whose links are banned are never returned.
+pub async fn evidence_on(record_id: i32, public_only: bool, connection: &mut PgConnection) -> Result<Vec<Evidence>> {
+    sqlx::query!(
+        r#"SELECT record_evidence.id, record, kind::TEXT AS "kind!", url, public, transferred FROM record_evidence INNER JOIN records ON
+         record = records.id INNER JOIN players ON records.player = players.id WHERE record = $1 AND (NOT $2 OR public) AND NOT
+         players.link_banned ORDER BY record_evidence.id"#,
+        record_id,
+        public_only
+    )
+    .fetch_all(connection)
+    .await?
+    .into_iter()
+    .map(|row| {
+        Ok(Evidence {
+            id: row.id,
+            record: row.record,
+            kind: EvidenceKind::from_sql(&row.kind)?,
+            url: row.url,
+            is_public: row.public,
+            transferred: row.transferred,
+        })
+    })
+    .collect()
+}
@@ -0,0 +1,103 @@
+//! Module for the evidence links attached to records
+//!
+//! Besides its main video, a record can carry any number of additional evidence links, such as raw
+//! footage, click audio or a second camera angle. Each link is either public, or only visible to
+//! the list team.
+
+mod delete;
+mod get;
+mod patch;
+mod post;
+
+pub use self::{get::evidence_on, patch::PatchEvidence, post::NewEvidence};
+use crate::error::{DemonlistError, Result};
+use pointercrate_core::{error::CoreError, etag::Taggable};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+use url::Url;
+
+#[derive(Debug, Serialize, Deserialize, Hash, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum EvidenceKind {
+    /// Unedited footage of the completion, including the end screen
+    RawFootage,
+
+    /// A recording of the player's clicks, for levels where a clicks-less video is not enough to
+    /// verify legitimacy
+    ClickAudio,
+
+    /// A recording of the completion from a different angle, e.g. a handcam
+    SecondAngle,
+}
+
+impl EvidenceKind {
+    pub(crate) fn to_sql(self) -> &'static str {
+        match self {
+            EvidenceKind::RawFootage => "RAW_FOOTAGE",
+            EvidenceKind::ClickAudio => "CLICK_AUDIO",
+            EvidenceKind::SecondAngle => "SECOND_ANGLE",
+        }
+    }
+
+    pub(crate) fn from_sql(sql: &str) -> Result<Self> {
+        match sql {
+            "RAW_FOOTAGE" => Ok(EvidenceKind::RawFootage),
+            "CLICK_AUDIO" => Ok(EvidenceKind::ClickAudio),
+            "SECOND_ANGLE" => Ok(EvidenceKind::SecondAngle),
+            _ => Err(CoreError::internal_server_error(format!("invalid evidence kind: {}", sql)).into()),
+        }
+    }
+
+    /// Validates (and potentially normalizes) a link to evidence of this kind
+    ///
+    /// Second angles are videos, and thus need to be hosted on one of the supported video
+    /// platforms. Raw footage and click audio are usually too large for those and can be hosted
+    /// anywhere.
+    pub fn validate_url(self, url: &str) -> Result<String> {
+        match self {
+            EvidenceKind::SecondAngle => crate::video::validate(url),
+            EvidenceKind::RawFootage | EvidenceKind::ClickAudio => {
+                let parsed = Url::parse(url).map_err(|_| match self {
+                    EvidenceKind::RawFootage => DemonlistError::MalformedRawUrl,
+                    _ => DemonlistError::MalformedEvidenceUrl,
+                })?;
+
+                if !["http", "https"].contains(&parsed.scheme()) {
+                    return Err(CoreError::InvalidUrlScheme.into());
+                }
+
+                Ok(url.to_string())
+            },
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Hash, Clone)]
+pub struct Evidence {
+    pub id: i32,
+
+    #[serde(skip)]
+    pub record: i32,
+
+    pub kind: EvidenceKind,
+
+    pub url: String,
+
+    pub is_public: bool,
+
+    /// Whether this link was originally provided for a different record and later transferred to
+    /// this one due to deletion.
+    pub transferred: bool,
+}
+
+impl Taggable for Evidence {
+    fn patch_part(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.url.hash(&mut hasher);
+        self.is_public.hash(&mut hasher);
+        hasher.finish()
+    }
+}
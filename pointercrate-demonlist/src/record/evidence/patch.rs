@@ -0,0 +1,37 @@
+use crate::{error::Result, record::evidence::Evidence};
+use pointercrate_core::util::non_nullable;
+use serde::Deserialize;
+use sqlx::PgConnection;
+
+#[derive(Debug, Deserialize)]
+pub struct PatchEvidence {
+    #[serde(default, deserialize_with = "non_nullable")]
+    pub url: Option<String>,
+
+    #[serde(default, deserialize_with = "non_nullable")]
+    pub is_public: Option<bool>,
+}
+
+impl Evidence {
+    pub async fn apply_patch(mut self, patch: PatchEvidence, connection: &mut PgConnection) -> Result<Evidence> {
+        if let Some(url) = patch.url {
+            let url = self.kind.validate_url(&url)?;
+
+            sqlx::query!("UPDATE record_evidence SET url = $1 WHERE id = $2", url, self.id)
+                .execute(&mut *connection)
+                .await?;
+
+            self.url = url;
+        }
+
+        if let Some(is_public) = patch.is_public {
+            sqlx::query!("UPDATE record_evidence SET public = $1 WHERE id = $2", is_public, self.id)
+                .execute(connection)
+                .await?;
+
+            self.is_public = is_public;
+        }
+
+        Ok(self)
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{
+    error::Result,
+    record::{
+        evidence::{Evidence, EvidenceKind},
+        FullRecord,
+    },
+};
+use serde::{Deserialize, Serialize};
+use sqlx::PgConnection;
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct NewEvidence {
+    pub kind: EvidenceKind,
+
+    pub url: String,
+
+    #[serde(default)]
+    pub is_public: bool,
+}
+
+impl NewEvidence {
+    /// Validates the URL of this evidence link according to its kind
+    pub fn validate(self) -> Result<NewEvidence> {
+        Ok(NewEvidence {
+            url: self.kind.validate_url(&self.url)?,
+            ..self
+        })
+    }
+}
+
+impl Evidence {
+    /// Attaches a new evidence link to the given record
+    ///
+    /// This does **not** insert the link into the record's `evidence` vector!
+    pub async fn create_on(record: &FullRecord, new_evidence: NewEvidence, connection: &mut PgConnection) -> Result<Evidence> {
+        let new_evidence = new_evidence.validate()?;
+
+        let evidence_id = sqlx::query!(
+            "INSERT INTO record_evidence (record, kind, url, public) VALUES ($1, $2::TEXT::EVIDENCE_KIND, $3, $4) RETURNING id",
+            record.id,
+            new_evidence.kind.to_sql(),
+            new_evidence.url,
+            new_evidence.is_public
+        )
+        .fetch_one(connection)
+        .await?
+        .id;
+
+        Ok(Evidence {
+            id: evidence_id,
+            record: record.id,
+            kind: new_evidence.kind,
+            url: new_evidence.url,
+            is_public: new_evidence.is_public,
+            transferred: false,
+        })
+    }
+}
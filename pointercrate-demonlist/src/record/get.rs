@@ -3,8 +3,11 @@ use crate::{
     error::{DemonlistError, Result},
     nationality::Nationality,
     player::DatabasePlayer,
-    record::{FullRecord, MinimalRecordD, MinimalRecordP, RecordStatus},
-    submitter::Submitter,
+    record::{
+        evidence::evidence_on, ApprovedRecord, FullRecord, MinimalRecordD, MinimalRecordP, MinimalRecordPD, RecordStatus, ReviewLock,
+        VideoCheck,
+    },
+    submitter::{Submitter, SubmitterStatistics},
 };
 use futures::stream::StreamExt;
 use sqlx::{Error, PgConnection};
@@ -13,7 +16,6 @@ use sqlx::{Error, PgConnection};
 struct FetchedRecord {
     progress: i16,
     video: Option<String>,
-    raw_footage: Option<String>,
     status: String,
     player_id: i32,
     player_name: String,
@@ -21,8 +23,14 @@ struct FetchedRecord {
     demon_id: i32,
     demon_name: String,
     position: i16,
+    list: String,
     submitter_id: i32,
     submitter_banned: bool,
+    submitter_member: Option<i32>,
+    submitter_submitted: i64,
+    submitter_approved: i64,
+    submitter_rejected: i64,
+    submitter_deleted_by_video_check: i64,
 }
 
 impl FullRecord {
@@ -32,27 +40,44 @@ impl FullRecord {
             .await;
 
         match result {
-            Ok(row) => Ok(FullRecord {
-                id,
-                progress: row.progress,
-                video: row.video,
-                raw_footage: row.raw_footage,
-                status: RecordStatus::from_sql(&row.status),
-                player: DatabasePlayer {
-                    id: row.player_id,
-                    name: row.player_name,
-                    banned: row.player_banned,
-                },
-                demon: MinimalDemon {
-                    id: row.demon_id,
-                    position: row.position,
-                    name: row.demon_name,
-                },
-                submitter: Some(Submitter {
-                    id: row.submitter_id,
-                    banned: row.submitter_banned,
-                }),
-            }),
+            Ok(row) => {
+                let mut record = FullRecord {
+                    id,
+                    progress: row.progress,
+                    video: row.video,
+                    status: RecordStatus::from_sql(&row.status),
+                    player: DatabasePlayer {
+                        id: row.player_id,
+                        name: row.player_name,
+                        banned: row.player_banned,
+                    },
+                    demon: MinimalDemon {
+                        id: row.demon_id,
+                        position: row.position,
+                        name: row.demon_name,
+                        list: row.list,
+                    },
+                    submitter: Some(Submitter {
+                        id: row.submitter_id,
+                        banned: row.submitter_banned,
+                        member_id: row.submitter_member,
+                        statistics: SubmitterStatistics {
+                            submitted: row.submitter_submitted,
+                            approved: row.submitter_approved,
+                            rejected: row.submitter_rejected,
+                            deleted_by_video_check: row.submitter_deleted_by_video_check,
+                        },
+                    }),
+                    evidence: evidence_on(id, false, &mut *connection).await?,
+                    raw_footage: None,
+                    lock: ReviewLock::on_record(id, &mut *connection).await?,
+                    video_check: VideoCheck::of_record(id, connection).await?,
+                };
+
+                record.update_raw_footage();
+
+                Ok(record)
+            },
 
             Err(Error::RowNotFound) => Err(DemonlistError::RecordNotFound { record_id: id }),
             Err(err) => Err(err.into()),
@@ -63,7 +88,7 @@ impl FullRecord {
 pub async fn approved_records_by(player: &DatabasePlayer, connection: &mut PgConnection) -> Result<Vec<MinimalRecordD>> {
     let mut stream = sqlx::query!(
         r#"SELECT records.id, progress, CASE WHEN players.link_banned THEN NULL ELSE records.video::text END, demons.id AS demon_id, 
-         demons.name, demons.position FROM records INNER JOIN demons ON records.demon = demons.id INNER JOIN players ON players.id 
+         demons.name, demons.position, demons.list FROM records INNER JOIN demons ON records.demon = demons.id INNER JOIN players ON players.id 
          = $1 WHERE status_ = 'APPROVED' AND records.player = $1"#,
         player.id
     )
@@ -83,6 +108,46 @@ pub async fn approved_records_by(player: &DatabasePlayer, connection: &mut PgCon
                 id: row.demon_id,
                 position: row.position,
                 name: row.name,
+                list: row.list,
+            },
+        })
+    }
+
+    Ok(records)
+}
+
+/// Retrieves the `limit` most recently approved records, optionally restricted to records on a
+/// specific demon and/or by a specific player
+///
+/// Records for which no audit log data exists are not considered, as we cannot tell when they got
+/// approved. Records of banned players are never returned.
+pub async fn recently_approved_records(
+    demon_id: Option<i32>, player_id: Option<i32>, limit: i64, connection: &mut PgConnection,
+) -> Result<Vec<ApprovedRecord>> {
+    let mut stream = sqlx::query_file!("sql/recently_approved_records.sql", demon_id, player_id, limit).fetch(connection);
+    let mut records = Vec::new();
+
+    while let Some(row) = stream.next().await {
+        let row = row?;
+
+        records.push(ApprovedRecord {
+            approved_at: row.approved_at,
+            record: MinimalRecordPD {
+                id: row.id,
+                progress: row.progress,
+                video: row.video,
+                status: RecordStatus::Approved,
+                demon: MinimalDemon {
+                    id: row.demon_id,
+                    position: row.position,
+                    name: row.demon_name,
+                    list: row.list,
+                },
+                player: DatabasePlayer {
+                    id: row.player_id,
+                    name: row.player_name,
+                    banned: row.player_banned,
+                },
             },
         })
     }
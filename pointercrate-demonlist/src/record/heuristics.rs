@@ -0,0 +1,162 @@
+//! Module for heuristics that flag suspicious submissions
+//!
+//! Once the video of a submission has been checked, each configured [`SubmissionHeuristic`] gets to
+//! look at the record and the metadata of its video. For every heuristic that considers the
+//! submission suspicious, an automatic, team-only note explaining why is left on the record.
+//! Heuristics never reject submissions on their own, the final decision stays with the list team.
+
+use crate::{
+    error::Result,
+    record::{note::Note, FullRecord, VideoCheck},
+};
+use async_trait::async_trait;
+use log::info;
+use sqlx::PgConnection;
+
+#[async_trait]
+pub trait SubmissionHeuristic: Send + Sync {
+    /// Checks the given record, whose video has just been checked
+    ///
+    /// Returns a description of what is suspicious about the record, or `None` if nothing is.
+    async fn check(&self, record: &FullRecord, video_check: &VideoCheck, connection: &mut PgConnection) -> Result<Option<String>>;
+}
+
+/// The set of heuristics run on every submission
+///
+/// The [`Default`] implementation contains all heuristics built into pointercrate.
+pub struct SubmissionHeuristics {
+    heuristics: Vec<Box<dyn SubmissionHeuristic>>,
+}
+
+impl Default for SubmissionHeuristics {
+    fn default() -> Self {
+        SubmissionHeuristics::none()
+            .with_heuristic(UploadedBeforeAddition)
+            .with_heuristic(ChannelWithRejections)
+            .with_heuristic(DuplicateVideoId)
+    }
+}
+
+impl SubmissionHeuristics {
+    /// A set of heuristics that does not flag anything
+    pub fn none() -> Self {
+        SubmissionHeuristics { heuristics: Vec::new() }
+    }
+
+    pub fn with_heuristic(mut self, heuristic: impl SubmissionHeuristic + 'static) -> Self {
+        self.heuristics.push(Box::new(heuristic));
+        self
+    }
+
+    /// Runs all heuristics on the given record, leaving an automatic note for each one that flags it
+    pub async fn flag(&self, record: &FullRecord, video_check: &VideoCheck, connection: &mut PgConnection) -> Result<Vec<Note>> {
+        let mut notes = Vec::new();
+
+        for heuristic in &self.heuristics {
+            if let Some(reason) = heuristic.check(record, video_check, &mut *connection).await? {
+                info!("Flagging submission {} as suspicious: {}", record, reason);
+
+                notes.push(Note::create_automatic(record.id, reason, &mut *connection).await?);
+            }
+        }
+
+        Ok(notes)
+    }
+}
+
+/// Flags records whose video was uploaded before the demon was added to the list
+///
+/// Only applies to hosts that report upload dates.
+pub struct UploadedBeforeAddition;
+
+#[async_trait]
+impl SubmissionHeuristic for UploadedBeforeAddition {
+    async fn check(&self, record: &FullRecord, video_check: &VideoCheck, connection: &mut PgConnection) -> Result<Option<String>> {
+        let Some(uploaded_at) = video_check.metadata.uploaded_at else {
+            return Ok(None);
+        };
+
+        let added_at = sqlx::query!("SELECT time FROM demon_additions WHERE id = $1", record.demon.id)
+            .fetch_optional(connection)
+            .await?;
+
+        Ok(match added_at {
+            Some(row) if uploaded_at < row.time => Some(format!(
+                "The video was uploaded on {}, before {} was added to the list on {}.",
+                uploaded_at.format("%Y-%m-%d %H:%M"),
+                record.demon.name,
+                row.time.format("%Y-%m-%d %H:%M")
+            )),
+            _ => None,
+        })
+    }
+}
+
+/// Flags records whose video was uploaded by a channel that also uploaded the videos of previously
+/// rejected records
+pub struct ChannelWithRejections;
+
+#[async_trait]
+impl SubmissionHeuristic for ChannelWithRejections {
+    async fn check(&self, record: &FullRecord, video_check: &VideoCheck, connection: &mut PgConnection) -> Result<Option<String>> {
+        let Some(ref channel) = video_check.metadata.channel else {
+            return Ok(None);
+        };
+
+        let rejected = sqlx::query!(
+            "SELECT records.id FROM record_video_checks INNER JOIN records ON record = records.id AND record_video_checks.video = \
+             records.video WHERE channel = $1 AND status_ = 'REJECTED' AND records.id <> $2 ORDER BY records.id",
+            channel,
+            record.id
+        )
+        .fetch_all(connection)
+        .await?;
+
+        if rejected.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(format!(
+            "The video's channel ({}) also uploaded the videos of the following rejected records: {}.",
+            channel,
+            rejected.iter().map(|row| row.id.to_string()).collect::<Vec<_>>().join(", ")
+        )))
+    }
+}
+
+/// Flags records whose video is also the video of another record, but linked differently (e.g.
+/// via a shortened link, or with a timestamp)
+///
+/// Videos are identified by their host and their id on that host, as ids of different hosts are
+/// unrelated.
+pub struct DuplicateVideoId;
+
+#[async_trait]
+impl SubmissionHeuristic for DuplicateVideoId {
+    async fn check(&self, record: &FullRecord, video_check: &VideoCheck, connection: &mut PgConnection) -> Result<Option<String>> {
+        let (Some(video_host), Some(video_id)) = (&video_check.video_host, &video_check.video_id) else {
+            return Ok(None);
+        };
+
+        let duplicates = sqlx::query!(
+            "SELECT records.id FROM record_video_checks INNER JOIN records ON record = records.id AND record_video_checks.video = \
+             records.video WHERE video_host = $1 AND video_id = $2 AND records.id <> $3 ORDER BY records.id",
+            video_host,
+            video_id,
+            record.id
+        )
+        .fetch_all(connection)
+        .await?;
+
+        if duplicates.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(format!(
+            "The video ({} id '{}') is also the video of the following records: {}.",
+            video_host,
+            video_id,
+            duplicates.iter().map(|row| row.id.to_string()).collect::<Vec<_>>().join(", ")
+        )))
+    }
+}
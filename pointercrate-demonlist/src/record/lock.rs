@@ -0,0 +1,106 @@
+//! Module for review locks on records
+//!
+//! A list team member can lock a record while reviewing it, to prevent other team members from
+//! concurrently working on the same record. Locks are time-limited and expire automatically.
+
+use crate::{
+    error::{DemonlistError, Result},
+    record::FullRecord,
+};
+use chrono::NaiveDateTime;
+use log::info;
+use serde::{Deserialize, Serialize};
+use sqlx::PgConnection;
+
+#[derive(Debug, Serialize, Deserialize, Hash, PartialEq, Eq, Clone)]
+pub struct ReviewLock {
+    /// The member id of the user holding the lock
+    pub reviewer_id: i32,
+
+    /// The name of the user holding the lock
+    pub reviewer: String,
+
+    /// The (UTC) time at which this lock expires
+    pub locked_until: NaiveDateTime,
+}
+
+impl ReviewLock {
+    /// Gets the lock currently held on the given record, if any. Expired locks are ignored.
+    pub async fn on_record(record_id: i32, connection: &mut PgConnection) -> Result<Option<ReviewLock>> {
+        Ok(sqlx::query_as!(
+            ReviewLock,
+            "SELECT reviewer AS reviewer_id, members.name AS reviewer, locked_until FROM record_locks INNER JOIN members ON \
+             reviewer = member_id WHERE record = $1 AND locked_until > (NOW() AT TIME ZONE 'utc')",
+            record_id
+        )
+        .fetch_optional(connection)
+        .await?)
+    }
+}
+
+impl FullRecord {
+    /// Locks this record for review by the given user for the given number of minutes
+    ///
+    /// If the user already holds the lock on this record, it is renewed. If someone else holds an
+    /// active lock, [`DemonlistError::RecordLocked`] is returned.
+    pub async fn lock_for_review(&mut self, reviewer_id: i32, minutes: i32, connection: &mut PgConnection) -> Result<ReviewLock> {
+        info!("User {} is locking record {} for {} minutes", reviewer_id, self, minutes);
+
+        // Only take over the existing lock if it has expired or belongs to us. This needs to happen
+        // in a single statement, as two reviewers might try to lock the record at the same time.
+        let acquired = sqlx::query_as!(
+            ReviewLock,
+            r#"WITH lock AS (
+                INSERT INTO record_locks (record, reviewer, locked_until) VALUES ($1, $2, (NOW() AT TIME ZONE 'utc') + make_interval(mins => $3))
+                ON CONFLICT (record) DO UPDATE SET reviewer = EXCLUDED.reviewer, locked_until = EXCLUDED.locked_until
+                WHERE record_locks.reviewer = EXCLUDED.reviewer OR record_locks.locked_until <= (NOW() AT TIME ZONE 'utc')
+                RETURNING reviewer, locked_until
+             )
+             SELECT lock.reviewer AS "reviewer_id!", members.name AS "reviewer!", lock.locked_until AS "locked_until!" FROM lock
+             INNER JOIN members ON lock.reviewer = member_id"#,
+            self.id,
+            reviewer_id,
+            minutes
+        )
+        .fetch_optional(&mut *connection)
+        .await?;
+
+        match acquired {
+            Some(lock) => {
+                self.lock = Some(lock.clone());
+
+                Ok(lock)
+            },
+            None => {
+                let existing = ReviewLock::on_record(self.id, connection).await?;
+
+                Err(DemonlistError::RecordLocked {
+                    reviewer: existing.map(|lock| lock.reviewer).unwrap_or_default(),
+                })
+            },
+        }
+    }
+
+    /// Removes the lock on this record, regardless of who holds it
+    pub async fn release_lock(&mut self, connection: &mut PgConnection) -> Result<()> {
+        info!("Releasing review lock on record {}", self);
+
+        sqlx::query!("DELETE FROM record_locks WHERE record = $1", self.id)
+            .execute(connection)
+            .await?;
+
+        self.lock = None;
+
+        Ok(())
+    }
+
+    /// Checks that no one other than the given user holds an active lock on this record
+    pub fn require_unlocked_for(&self, user_id: i32) -> Result<()> {
+        match self.lock {
+            Some(ref lock) if lock.reviewer_id != user_id => Err(DemonlistError::RecordLocked {
+                reviewer: lock.reviewer.clone(),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
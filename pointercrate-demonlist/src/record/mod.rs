@@ -22,12 +22,22 @@
 //!   the 'under consideration' status makes. A record under consideration IS NOT UNIQUE!
 
 pub use self::{
-    get::{approved_records_by, approved_records_on, submission_count},
-    paginate::RecordPagination,
+    get::{approved_records_by, approved_records_on, recently_approved_records, submission_count},
+    lock::ReviewLock,
+    paginate::{RecordPagination, ReviewAssignment},
     patch::PatchRecord,
     post::Submission,
+    video_check::{VideoCheck, VideoCheckResult, VideoMetadata},
 };
-use crate::{demon::MinimalDemon, error::Result, nationality::Nationality, player::DatabasePlayer, submitter::Submitter};
+use crate::{
+    demon::MinimalDemon,
+    error::Result,
+    nationality::Nationality,
+    player::DatabasePlayer,
+    record::evidence::{Evidence, EvidenceKind},
+    submitter::Submitter,
+};
+use chrono::NaiveDateTime;
 use derive_more::Display;
 use pointercrate_core::etag::Taggable;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -40,11 +50,17 @@ use std::{
 
 pub mod audit;
 mod delete;
+pub mod evidence;
 mod get;
+pub mod heuristics;
+mod lock;
 pub mod note;
 mod paginate;
 mod patch;
+pub mod pending;
 mod post;
+pub mod stats;
+mod video_check;
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
 pub enum RecordStatus {
@@ -127,7 +143,26 @@ pub struct FullRecord {
     pub player: DatabasePlayer,
     pub demon: MinimalDemon,
     pub submitter: Option<Submitter>,
+
+    /// Additional evidence links, such as raw footage or click audio
+    #[serde(default)]
+    pub evidence: Vec<Evidence>,
+
+    /// The first raw footage link in `evidence`, if any
+    ///
+    /// Only kept for compatibility with API consumers predating typed evidence links, use
+    /// `evidence` instead. Will be removed in a future version.
+    #[serde(default)]
     pub raw_footage: Option<String>,
+
+    /// The review lock currently held on this record, if any
+    #[serde(default)]
+    pub lock: Option<ReviewLock>,
+
+    /// The result of automatically checking whether this record's video exists, if it has been
+    /// checked
+    #[serde(default)]
+    pub video_check: Option<VideoCheck>,
 }
 
 impl Taggable for FullRecord {
@@ -141,7 +176,9 @@ impl Taggable for FullRecord {
         self.demon.id.hash(&mut hasher);
         // notes have sub-endpoint -> no hash
         // submitter cannot be patched -> no hash
-        // raw footage cannot be patched -> no hash
+        // evidence has sub-endpoint -> no hash
+        // lock has sub-endpoint -> no hash
+        // video check cannot be patched -> no hash
         hasher.finish()
     }
 }
@@ -157,6 +194,15 @@ pub struct MinimalRecordPD {
     pub player: DatabasePlayer,
 }
 
+/// An approved record together with the time at which it was approved
+#[derive(Debug, Serialize)]
+pub struct ApprovedRecord {
+    pub approved_at: NaiveDateTime,
+
+    #[serde(flatten)]
+    pub record: MinimalRecordPD,
+}
+
 #[derive(Debug, Hash, Serialize, Deserialize, Display, PartialEq, Eq)]
 #[display("{}% on {} (ID: {})", progress, demon, id)]
 pub struct MinimalRecordD {
@@ -179,6 +225,15 @@ pub struct MinimalRecordP {
 }
 
 impl FullRecord {
+    /// Updates the deprecated `raw_footage` field to match this record's evidence
+    pub fn update_raw_footage(&mut self) {
+        self.raw_footage = self
+            .evidence
+            .iter()
+            .find(|evidence| evidence.kind == EvidenceKind::RawFootage)
+            .map(|evidence| evidence.url.clone());
+    }
+
     pub async fn was_modified(&self, connection: &mut PgConnection) -> Result<bool> {
         Ok(sqlx::query!(
             r#"SELECT EXISTS (SELECT 1 FROM record_modifications WHERE id = $1 AND status_ IS NOT NULL) AS "was_modified!: bool""#,
@@ -12,6 +12,7 @@ struct PartialNote {
     is_public: bool,
     author: Option<String>,
     transferred: bool,
+    is_automatic: bool,
 }
 
 impl PartialNote {
@@ -36,6 +37,7 @@ impl PartialNote {
             is_public: self.is_public,
             author: self.author,
             transferred: self.transferred,
+            is_automatic: self.is_automatic,
             editors,
         })
     }
@@ -47,7 +49,7 @@ impl Note {
         let row = sqlx::query_as!(
             PartialNote,
             r#"SELECT id, record, content, is_public, members.name AS "author?: String", EXISTS(SELECT 1 FROM record_notes_modifications WHERE record IS NOT NULL 
-             AND id = $1) AS "transferred!: bool" FROM record_notes NATURAL JOIN record_notes_additions LEFT OUTER JOIN members on 
+             AND id = $1) AS "transferred!: bool", automatic AS is_automatic FROM record_notes NATURAL JOIN record_notes_additions LEFT OUTER JOIN members on 
              members.member_id = record_notes_additions.userid WHERE id = $1 and record = $2"#,
             note_id, record_id
         )
@@ -66,7 +68,7 @@ pub async fn notes_on(record_id: i32, public_only: bool, connection: &mut PgConn
     let partials = sqlx::query_as!(
         PartialNote,
         r#"SELECT id, record, content, is_public, members.name AS "author?: String", EXISTS(SELECT 1 FROM record_notes_modifications WHERE record IS NOT NULL AND 
-         id = $1) AS "transferred!: bool"  FROM record_notes NATURAL JOIN record_notes_additions LEFT OUTER JOIN members on members.member_id = 
+         id = $1) AS "transferred!: bool", automatic AS is_automatic FROM record_notes NATURAL JOIN record_notes_additions LEFT OUTER JOIN members on members.member_id = 
          record_notes_additions.userid WHERE record = $1 AND (NOT $2 OR is_public)"#,
         record_id, public_only
     )
@@ -27,6 +27,10 @@ pub struct Note {
     /// one due to deletion.
     pub transferred: bool,
 
+    /// Whether this note was left automatically by pointercrate itself, e.g. because the record was
+    /// flagged as suspicious
+    pub is_automatic: bool,
+
     /// The name of the user that created this note. None if it is a submitter provided note
     ///
     /// If the user had a display name set, this is the display name
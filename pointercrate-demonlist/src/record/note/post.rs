@@ -39,6 +39,31 @@ impl Note {
             content: new_note.content,
             is_public: new_note.is_public,
             transferred: false,
+            is_automatic: false,
+            author: None,
+            editors: vec![],
+        })
+    }
+
+    /// Creates a new team-only note on the given record that is marked as having been left by
+    /// pointercrate itself
+    pub async fn create_automatic(record_id: i32, content: String, connection: &mut PgConnection) -> Result<Note> {
+        let note_id = sqlx::query!(
+            "INSERT INTO record_notes (record, content, is_public, automatic) VALUES ($1, $2, FALSE, TRUE) RETURNING id",
+            record_id,
+            content
+        )
+        .fetch_one(connection)
+        .await?
+        .id;
+
+        Ok(Note {
+            id: note_id,
+            record: record_id,
+            content,
+            is_public: false,
+            transferred: false,
+            is_automatic: true,
             author: None,
             editors: vec![],
         })
@@ -1,17 +1,29 @@
 use crate::{
     demon::MinimalDemon,
+    list::DEFAULT_LIST,
     player::DatabasePlayer,
-    record::{MinimalRecordPD, RecordStatus},
+    record::{MinimalRecordPD, RecordStatus, VideoCheckResult},
 };
 use futures::StreamExt;
 use pointercrate_core::{
     first_and_last,
-    pagination::{PageContext, Paginatable, PaginationParameters, PaginationQuery, __pagination_compat},
+    pagination::{__pagination_compat, PageContext, Paginatable, PaginationParameters, PaginationQuery},
     util::{non_nullable, nullable},
 };
 use serde::{Deserialize, Serialize};
 use sqlx::{postgres::PgRow, PgConnection, Row};
 
+/// Filter for records based on who is currently reviewing them
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewAssignment {
+    /// Only records locked for review by the user making the request
+    Me,
+
+    /// Only records that no one currently holds a review lock on
+    Unassigned,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
 pub struct RecordPagination {
     #[serde(flatten)]
@@ -46,6 +58,11 @@ pub struct RecordPagination {
     #[serde(default, deserialize_with = "non_nullable")]
     demon: Option<String>,
 
+    /// Only records on demons in the given list. Since demon positions are only unique within a
+    /// list, defaults to the [default list](DEFAULT_LIST) if any `demon_position` filter is set.
+    #[serde(default, deserialize_with = "non_nullable")]
+    pub list: Option<String>,
+
     #[serde(default, deserialize_with = "non_nullable")]
     demon_id: Option<i32>,
 
@@ -54,6 +71,30 @@ pub struct RecordPagination {
 
     #[serde(default, deserialize_with = "non_nullable")]
     pub submitter: Option<i32>,
+
+    #[serde(default, deserialize_with = "non_nullable")]
+    pub assigned: Option<ReviewAssignment>,
+
+    /// Only records whose current video was checked with the given result
+    #[serde(default, deserialize_with = "non_nullable")]
+    pub video_check: Option<VideoCheckResult>,
+
+    /// The member id of the user making the request. Needs to be set by the endpoint if `assigned`
+    /// is [`ReviewAssignment::Me`].
+    #[serde(skip)]
+    pub reviewer: Option<i32>,
+}
+
+impl RecordPagination {
+    fn list(&self) -> Option<&str> {
+        let filters_position = self.demon_position.is_some() || self.demon_position_lt.is_some() || self.demon_position_gt.is_some();
+
+        match self.list {
+            Some(ref list) => Some(list),
+            None if filters_position => Some(DEFAULT_LIST),
+            None => None,
+        }
+    }
 }
 
 impl PaginationQuery for RecordPagination {
@@ -93,6 +134,13 @@ impl Paginatable<RecordPagination> for MinimalRecordPD {
             .bind(query.video == Some(None))
             .bind(query.player)
             .bind(query.submitter)
+            .bind(match query.assigned {
+                Some(ReviewAssignment::Me) => Some(query.reviewer.unwrap_or_default()),
+                _ => None,
+            })
+            .bind(query.assigned == Some(ReviewAssignment::Unassigned))
+            .bind(query.video_check.map(|result| result.to_sql()))
+            .bind(query.list())
             .bind(query.params.limit + 1)
             .fetch(&mut *connection);
 
@@ -115,6 +163,7 @@ impl Paginatable<RecordPagination> for MinimalRecordPD {
                     id: row.try_get("demon_id")?,
                     position: row.try_get("position")?,
                     name: row.try_get("demon_name")?,
+                    list: row.try_get("list")?,
                 },
             })
         }
@@ -2,7 +2,7 @@ use crate::{
     demon::MinimalDemon,
     error::{DemonlistError, Result},
     player::DatabasePlayer,
-    record::{FullRecord, RecordStatus},
+    record::{evidence::evidence_on, FullRecord, RecordStatus, VideoCheck},
 };
 use log::{info, warn};
 use pointercrate_core::{
@@ -69,6 +69,14 @@ impl FullRecord {
             _ => (),
         }
 
+        // Status, progress, player or demon changes might have caused evidence from deleted duplicates to be
+        // transferred to this record
+        self.evidence = evidence_on(self.id, false, &mut *connection).await?;
+        self.update_raw_footage();
+
+        // Video checks only apply to the video that was checked
+        self.video_check = VideoCheck::of_record(self.id, &mut *connection).await?;
+
         // Not all record update require recomputing scores (for example, changing status from "submitted" to "under consideration")
         // but the logic for correctly determining this is hard, and updating scores of individual players cheap, so we do not bother.
         self.player.update_score(connection).await?;
@@ -99,6 +107,16 @@ impl FullRecord {
                 .execute(&mut *connection)
                 .await?;
 
+                sqlx::query!(
+                    "UPDATE record_evidence SET record = $1, transferred = TRUE FROM records WHERE record_evidence.record = records.id AND \
+                     records.demon = $2 AND records.player = $3",
+                    self.id,
+                    demon,
+                    player
+                )
+                .execute(&mut *connection)
+                .await?;
+
                 let records_deleted = sqlx::query!("DELETE FROM records WHERE player = $1 AND demon = $2", player, demon)
                     .execute(connection)
                     .await?;
@@ -163,6 +181,17 @@ impl FullRecord {
                 .execute(&mut *connection)
                 .await?;
 
+                sqlx::query!(
+                    "UPDATE record_evidence SET record = $1, transferred = TRUE FROM records WHERE record_evidence.record = records.id AND \
+                     records.demon = $2 AND records.player = $3 AND (records.status_ = 'REJECTED' OR records.progress <= $4)",
+                    self.id,
+                    demon,
+                    player,
+                    self.progress
+                )
+                .execute(&mut *connection)
+                .await?;
+
                 let records_deleted = sqlx::query!(
                     "DELETE FROM records WHERE demon = $1 AND player = $2 AND (status_ = 'REJECTED' OR progress <= $3)",
                     demon,
@@ -283,6 +312,16 @@ impl FullRecord {
                 .execute(&mut *connection)
                 .await?;
 
+                sqlx::query!(
+                    "UPDATE record_evidence SET record = $1, transferred = TRUE FROM records WHERE record_evidence.record = records.id AND \
+                     records.id <> $1 AND records.player = $2 AND records.demon = $3",
+                    self.id,
+                    self.player.id,
+                    self.demon.id
+                )
+                .execute(&mut *connection)
+                .await?;
+
                 sqlx::query!(
                     "DELETE FROM records WHERE id <> $1 AND player = $2 AND demon = $3",
                     self.id,
@@ -316,6 +355,17 @@ impl FullRecord {
                 .execute(&mut *connection)
                 .await?;
 
+                sqlx::query!(
+                    "UPDATE record_evidence SET record = $1, transferred = TRUE FROM records WHERE record_evidence.record = records.id AND \
+                     records.id <> $1 AND records.player = $2 AND records.demon = $3 AND progress <= $4",
+                    self.id,
+                    self.player.id,
+                    self.demon.id,
+                    self.progress
+                )
+                .execute(&mut *connection)
+                .await?;
+
                 sqlx::query!(
                     "DELETE FROM records WHERE id <> $1 AND records.player = $2 AND records.demon = $3 AND progress <= $4",
                     self.id,
@@ -349,7 +399,7 @@ impl FullRecord {
     /// Updates this record's progress
     ///
     /// If this record is approved, all submissions with lower progress of the same (player,
-    /// demon)-tuple are deleted and have their notes transferred to this record.
+    /// demon)-tuple are deleted and have their notes and evidence transferred to this record.
     pub async fn set_progress(&mut self, progress: i16, connection: &mut PgConnection) -> Result<()> {
         let requirement = self.demon.requirement(&mut *connection).await?;
 
@@ -358,7 +408,7 @@ impl FullRecord {
         }
 
         if self.status == RecordStatus::Approved {
-            // Transfer over all notes from the records deleted below
+            // Transfer over all notes and evidence from the records deleted below
             sqlx::query!(
                 "UPDATE record_notes SET record = $1 FROM records WHERE record_notes.record = records.id AND player = $2 AND demon = $3 \
                  AND progress < $4 AND status_='SUBMITTED'",
@@ -370,6 +420,17 @@ impl FullRecord {
             .execute(&mut *connection)
             .await?;
 
+            sqlx::query!(
+                "UPDATE record_evidence SET record = $1, transferred = TRUE FROM records WHERE record_evidence.record = records.id AND \
+                 player = $2 AND demon = $3 AND progress < $4 AND status_='SUBMITTED'",
+                self.id,
+                self.player.id,
+                self.demon.id,
+                progress
+            )
+            .execute(&mut *connection)
+            .await?;
+
             let deleted = sqlx::query!(
                 "DELETE FROM records WHERE player = $1 AND demon = $2 AND status_='SUBMITTED'",
                 self.player.id,
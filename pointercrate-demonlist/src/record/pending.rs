@@ -0,0 +1,147 @@
+//! Module for third-party submissions held for the approval of the player's claimant
+//!
+//! If a verified claim on a player has both `lock_submissions` and `third_party_approval` set,
+//! submissions for that player made by anyone but the claimant are not turned into records right
+//! away. Instead, they are stored here until the claimant either accepts them (at which point they
+//! become regular submissions) or declines them. Until then, they are not visible to the list team.
+//! Should the claim change in a way that leaves no one to approve them (e.g. because it is deleted,
+//! or `third_party_approval` is unset), held submissions become regular submissions as well.
+
+use crate::{
+    demon::MinimalDemon,
+    error::{DemonlistError, Result},
+    player::DatabasePlayer,
+    record::evidence::{EvidenceKind, NewEvidence},
+};
+use chrono::NaiveDateTime;
+use log::info;
+use serde::Serialize;
+use sqlx::PgConnection;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PendingSubmission {
+    pub id: i32,
+    pub progress: i16,
+    pub video: Option<String>,
+    pub player: DatabasePlayer,
+    pub demon: MinimalDemon,
+    pub evidence: Vec<NewEvidence>,
+    pub note: Option<String>,
+
+    /// The (UTC) time at which this submission was made
+    pub submitted_at: NaiveDateTime,
+
+    /// The submitter who made this submission. Never shown to the claimant.
+    #[serde(skip)]
+    pub submitter_id: i32,
+}
+
+impl PendingSubmission {
+    /// Gets all submissions for the given player that are waiting for the claimant's approval,
+    /// oldest first
+    pub async fn for_player(player_id: i32, connection: &mut PgConnection) -> Result<Vec<PendingSubmission>> {
+        let ids = sqlx::query!("SELECT id FROM pending_submissions WHERE player = $1 ORDER BY id", player_id)
+            .fetch_all(&mut *connection)
+            .await?;
+
+        let mut submissions = Vec::new();
+
+        for row in ids {
+            submissions.push(PendingSubmission::by_id(row.id, &mut *connection).await?);
+        }
+
+        Ok(submissions)
+    }
+
+    pub async fn by_id(id: i32, connection: &mut PgConnection) -> Result<PendingSubmission> {
+        let row = sqlx::query!(
+            "SELECT progress, video, player, demon, submitter, note, submitted_at FROM pending_submissions WHERE id = $1",
+            id
+        )
+        .fetch_optional(&mut *connection)
+        .await?
+        .ok_or(DemonlistError::PendingSubmissionNotFound { id })?;
+
+        let evidence = sqlx::query!(
+            r#"SELECT kind::TEXT AS "kind!", url, public FROM pending_submission_evidence WHERE submission = $1 ORDER BY id"#,
+            id
+        )
+        .fetch_all(&mut *connection)
+        .await?
+        .into_iter()
+        .map(|row| {
+            Ok(NewEvidence {
+                kind: EvidenceKind::from_sql(&row.kind)?,
+                url: row.url,
+                is_public: row.public,
+            })
+        })
+        .collect::<Result<_>>()?;
+
+        Ok(PendingSubmission {
+            id,
+            progress: row.progress,
+            video: row.video,
+            player: DatabasePlayer::by_id(row.player, &mut *connection).await?,
+            demon: MinimalDemon::by_id(row.demon, &mut *connection).await?,
+            evidence,
+            note: row.note,
+            submitted_at: row.submitted_at,
+            submitter_id: row.submitter,
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(super) async fn create(
+        progress: i16, video: Option<String>, player: DatabasePlayer, demon: MinimalDemon, evidence: Vec<NewEvidence>,
+        note: Option<String>, submitter_id: i32, connection: &mut PgConnection,
+    ) -> Result<PendingSubmission> {
+        let row = sqlx::query!(
+            "INSERT INTO pending_submissions (progress, video, player, demon, submitter, note) VALUES ($1, $2, $3, $4, $5, $6) RETURNING id, \
+             submitted_at",
+            progress,
+            video,
+            player.id,
+            demon.id,
+            submitter_id,
+            note
+        )
+        .fetch_one(&mut *connection)
+        .await?;
+
+        for evidence in &evidence {
+            sqlx::query!(
+                "INSERT INTO pending_submission_evidence (submission, kind, url, public) VALUES ($1, $2::TEXT::EVIDENCE_KIND, $3, $4)",
+                row.id,
+                evidence.kind.to_sql(),
+                evidence.url,
+                evidence.is_public
+            )
+            .execute(&mut *connection)
+            .await?;
+        }
+
+        info!("Holding submission {} for player {} for the claimant's approval", row.id, player);
+
+        Ok(PendingSubmission {
+            id: row.id,
+            progress,
+            video,
+            player,
+            demon,
+            evidence,
+            note,
+            submitted_at: row.submitted_at,
+            submitter_id,
+        })
+    }
+
+    /// Discards this submission. Also used once it was accepted and turned into a record.
+    pub async fn delete(&self, connection: &mut PgConnection) -> Result<()> {
+        sqlx::query!("DELETE FROM pending_submissions WHERE id = $1", self.id)
+            .execute(connection)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -1,15 +1,19 @@
 use crate::{
     demon::MinimalDemon,
     error::{DemonlistError, Result},
+    list::{TierSubmissions, Tiers},
     player::{claim::PlayerClaim, DatabasePlayer},
-    record::{FullRecord, RecordStatus},
+    record::{
+        evidence::{Evidence, EvidenceKind, NewEvidence},
+        pending::PendingSubmission,
+        FullRecord, RecordStatus,
+    },
     submitter::Submitter,
 };
 use derive_more::Display;
 use log::debug;
 use serde::Deserialize;
 use sqlx::PgConnection;
-use url::Url;
 
 #[derive(Deserialize, Debug, Display)]
 #[display("{}% on {} by {} [status: {}]", progress, demon, player, status)]
@@ -19,9 +23,12 @@ pub struct Submission {
     demon: i32,
     #[serde(default)]
     video: Option<String>,
+    /// Shorthand for a team-only [`EvidenceKind::RawFootage`] entry in `evidence`
     #[serde(default)]
     raw_footage: Option<String>,
     #[serde(default)]
+    evidence: Vec<NewEvidence>,
+    #[serde(default)]
     status: RecordStatus,
 
     /// An initial, submitter provided note for the demon.
@@ -37,7 +44,7 @@ pub struct NormalizedSubmission {
     status: RecordStatus,
 
     video: Option<String>,
-    raw_footage: Option<String>,
+    evidence: Vec<NewEvidence>,
     note: Option<String>,
 }
 
@@ -45,7 +52,7 @@ pub struct NormalizedSubmission {
 pub struct ValidatedSubmission {
     progress: i16,
     video: Option<String>,
-    raw_footage: Option<String>,
+    evidence: Vec<NewEvidence>,
     status: RecordStatus,
     player: DatabasePlayer,
     demon: MinimalDemon,
@@ -72,13 +79,26 @@ impl Submission {
         let player = DatabasePlayer::by_name_or_create(self.player.as_ref(), connection).await?;
         let demon = MinimalDemon::by_id(self.demon, connection).await?;
 
+        let mut evidence = self.evidence;
+
+        if let Some(raw_footage) = self.raw_footage {
+            evidence.insert(
+                0,
+                NewEvidence {
+                    kind: EvidenceKind::RawFootage,
+                    url: raw_footage,
+                    is_public: false,
+                },
+            );
+        }
+
         Ok(NormalizedSubmission {
             progress: self.progress,
             player,
             demon,
             status: self.status,
             video,
-            raw_footage: self.raw_footage,
+            evidence,
             note: self.note,
         })
     }
@@ -89,21 +109,25 @@ impl NormalizedSubmission {
         PlayerClaim::verified_claim_on(self.player.id, connection).await
     }
 
-    pub async fn validate(self, connection: &mut PgConnection) -> Result<ValidatedSubmission> {
+    /// Validates this submission against the list's rules
+    ///
+    /// If `raw_footage_required` is set, submissions (but not records added directly by list
+    /// mods) need to include raw footage.
+    pub async fn validate(self, raw_footage_required: bool, connection: &mut PgConnection) -> Result<ValidatedSubmission> {
         // Banned player can't have records on the list
         if self.player.banned {
             return Err(DemonlistError::PlayerBanned);
         }
 
-        // Cannot submit records for the legacy list (it is possible to directly add them for list mods)
-        if self.demon.position > crate::config::extended_list_size() && self.status == RecordStatus::Submitted {
-            return Err(DemonlistError::SubmitLegacy);
-        }
+        // The tier's submission rules only apply to submissions (it is possible to directly add records for list mods)
+        if self.status == RecordStatus::Submitted {
+            let tiers = Tiers::of_list(&self.demon.list, &mut *connection).await?;
 
-        // Can only submit 100% records for the extended list (it is possible to directly add them for list
-        // mods)
-        if self.demon.position > crate::config::list_size() && self.progress != 100 && self.status == RecordStatus::Submitted {
-            return Err(DemonlistError::Non100Extended);
+            match tiers.submissions(self.demon.position) {
+                TierSubmissions::Closed => return Err(DemonlistError::SubmitLegacy),
+                TierSubmissions::CompletionsOnly if self.progress != 100 => return Err(DemonlistError::Non100Extended),
+                _ => (),
+            }
         }
 
         let requirement = self.demon.requirement(&mut *connection).await?;
@@ -147,21 +171,20 @@ impl NormalizedSubmission {
             });
         }
 
-        match self.raw_footage {
-            Some(ref raw) => {
-                let _ = Url::parse(raw).map_err(|_| DemonlistError::MalformedRawUrl)?;
-            },
-            None if self.status == RecordStatus::Submitted => {
-                // list mods can submit without raw
-                return Err(DemonlistError::RawRequired);
-            },
-            _ => (),
+        let evidence = self.evidence.into_iter().map(NewEvidence::validate).collect::<Result<Vec<_>>>()?;
+
+        // list mods can submit without raw
+        if raw_footage_required
+            && self.status == RecordStatus::Submitted
+            && !evidence.iter().any(|evidence| evidence.kind == EvidenceKind::RawFootage)
+        {
+            return Err(DemonlistError::RawRequired);
         }
 
         Ok(ValidatedSubmission {
             progress: self.progress,
             video: self.video,
-            raw_footage: self.raw_footage,
+            evidence,
             status: self.status,
             player: self.player,
             demon: self.demon,
@@ -171,15 +194,38 @@ impl NormalizedSubmission {
 }
 
 impl ValidatedSubmission {
+    /// Makes this submission be put under consideration right away, instead of just being
+    /// submitted. Has no effect on records that are directly added with some other status.
+    pub fn put_under_consideration(&mut self) {
+        if self.status == RecordStatus::Submitted {
+            self.status = RecordStatus::UnderConsideration;
+        }
+    }
+
+    /// Holds this submission for the approval of the player's claimant, instead of turning it into
+    /// a record right away
+    pub async fn hold_for_claimant(self, submitter: &Submitter, connection: &mut PgConnection) -> Result<PendingSubmission> {
+        PendingSubmission::create(
+            self.progress,
+            self.video,
+            self.player,
+            self.demon,
+            self.evidence,
+            self.note,
+            submitter.id,
+            connection,
+        )
+        .await
+    }
+
     pub async fn create(self, submitter: Submitter, connection: &mut PgConnection) -> Result<FullRecord> {
         let id = sqlx::query!(
-            "INSERT INTO records (progress, video, status_, player, submitter, demon, raw_footage) VALUES ($1, $2::TEXT, 'SUBMITTED', $3, $4, $5, $6) RETURNING id",
+            "INSERT INTO records (progress, video, status_, player, submitter, demon) VALUES ($1, $2::TEXT, 'SUBMITTED', $3, $4, $5) RETURNING id",
             self.progress,
             self.video,
             self.player.id,
             submitter.id,
-            self.demon.id,
-            self.raw_footage
+            self.demon.id
         )
         .fetch_one(&mut *connection)
         .await?
@@ -189,11 +235,14 @@ impl ValidatedSubmission {
             id,
             progress: self.progress,
             video: self.video,
-            raw_footage: self.raw_footage,
+            evidence: Vec::new(),
+            raw_footage: None,
             status: RecordStatus::Submitted,
             player: self.player,
             demon: self.demon,
             submitter: Some(submitter),
+            lock: None,
+            video_check: None,
         };
 
         // Dealing with different status and upholding their invariant is complicated, we should not
@@ -202,6 +251,14 @@ impl ValidatedSubmission {
             record.set_status(self.status, &mut *connection).await?;
         }
 
+        for new_evidence in self.evidence {
+            let evidence = Evidence::create_on(&record, new_evidence, &mut *connection).await?;
+
+            record.evidence.push(evidence);
+        }
+
+        record.update_raw_footage();
+
         if let Some(note) = self.note {
             if !note.trim().is_empty() {
                 sqlx::query!("INSERT INTO record_notes (record, content) VALUES ($1, $2)", record.id, note)
@@ -218,11 +275,28 @@ impl ValidatedSubmission {
     }
 }
 
+impl PendingSubmission {
+    /// Turns this submission into a regular submission after the claimant accepted it. It has to be
+    /// validated again, as other records might have been submitted in the meantime.
+    pub fn into_submission(self) -> NormalizedSubmission {
+        NormalizedSubmission {
+            progress: self.progress,
+            player: self.player,
+            demon: self.demon,
+            status: RecordStatus::Submitted,
+            video: self.video,
+            evidence: self.evidence,
+            note: self.note,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
         demon::MinimalDemon,
         error::DemonlistError,
+        list::DEFAULT_LIST,
         player::DatabasePlayer,
         record::{post::NormalizedSubmission, RecordStatus},
     };
@@ -241,13 +315,14 @@ mod tests {
                 id: 1,
                 position: 1,
                 name: "Bloodbath".to_string(),
+                list: DEFAULT_LIST.to_string(),
             },
             status: RecordStatus::Submitted,
             video: None,
-            raw_footage: None,
+            evidence: Vec::new(),
             note: None,
         }
-        .validate(&mut conn)
+        .validate(true, &mut conn)
         .await;
 
         assert!(result.is_err());
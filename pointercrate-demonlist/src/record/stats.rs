@@ -0,0 +1,97 @@
+//! Module for statistics about the list team's handling of record submissions
+//!
+//! All statistics are derived from the record audit log.
+
+use crate::error::Result;
+use chrono::NaiveDateTime;
+use pointercrate_core::{audit::NamedId, error::CoreError};
+use serde::{Deserialize, Serialize};
+use sqlx::PgConnection;
+
+#[derive(Debug, Deserialize)]
+pub struct ModerationStatsQuery {
+    /// The point in time from which on status changes should be considered
+    pub after: NaiveDateTime,
+
+    /// The point in time until which status changes should be considered. Defaults to the current
+    /// time.
+    #[serde(default)]
+    pub before: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ModerationStats {
+    pub after: NaiveDateTime,
+    pub before: NaiveDateTime,
+
+    /// Per-user statistics for everyone who changed the status of a record in the given time frame,
+    /// most active first
+    pub reviewers: Vec<ReviewerStats>,
+
+    /// The current number of records waiting for review in each tier of each list. Unlike the
+    /// other statistics, this does not depend on the time frame.
+    pub queue: Vec<TierQueue>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReviewerStats {
+    pub user: NamedId,
+
+    /// The number of records this user changed to 'approved'
+    pub approvals: i64,
+
+    /// The number of records this user changed to 'rejected'
+    pub rejections: i64,
+
+    /// The number of records this user changed to 'under consideration'
+    pub under_consideration: i64,
+
+    /// The median time, in seconds, between a record being submitted and this user approving or
+    /// rejecting it. `None` if this user did not decide on any submissions.
+    pub median_decision_seconds: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TierQueue {
+    pub list: String,
+    pub tier: String,
+    pub submitted: i64,
+    pub under_consideration: i64,
+}
+
+impl ModerationStatsQuery {
+    pub async fn stats(&self, connection: &mut PgConnection) -> Result<ModerationStats> {
+        let before = self.before.unwrap_or_else(|| chrono::Utc::now().naive_utc());
+
+        if before <= self.after {
+            return Err(CoreError::AfterSmallerBefore.into());
+        }
+
+        let reviewers = sqlx::query_file!("sql/reviewer_stats.sql", self.after, before)
+            .fetch_all(&mut *connection)
+            .await?
+            .into_iter()
+            .map(|row| ReviewerStats {
+                user: NamedId {
+                    id: row.id,
+                    name: row.name,
+                },
+                approvals: row.approvals,
+                rejections: row.rejections,
+                under_consideration: row.under_consideration,
+                median_decision_seconds: row.median_decision_seconds,
+            })
+            .collect();
+
+        let queue = sqlx::query_file_as!(TierQueue, "sql/review_queue.sql")
+            .fetch_all(connection)
+            .await?;
+
+        Ok(ModerationStats {
+            after: self.after,
+            before,
+            reviewers,
+            queue,
+        })
+    }
+}
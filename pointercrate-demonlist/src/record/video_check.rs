@@ -0,0 +1,146 @@
+//! Module for the results of automatically checking whether a record's video exists
+//!
+//! After a record is submitted, pointercrate asks the video's host whether the video actually
+//! exists. Submissions whose video definitely does not exist are deleted, but if the host could not
+//! give a definitive answer, the record is kept and the result stored, so that the list team can
+//! check the video manually.
+//!
+//! Whatever metadata the host told us about the video is stored alongside the result, so that
+//! suspicious submissions can be flagged (see [`crate::record::heuristics`]).
+
+use crate::{error::Result, video::Video};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::PgConnection;
+
+#[derive(Debug, Serialize, Deserialize, Hash, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum VideoCheckResult {
+    /// The host confirmed that the video exists
+    Available,
+
+    /// The host reported that the video does not exist (anymore)
+    Missing,
+
+    /// The host could not be reached, or gave an answer from which nothing could be concluded
+    /// (e.g. because the video is private). The video needs to be checked manually.
+    Inconclusive,
+}
+
+impl VideoCheckResult {
+    pub fn to_sql(self) -> &'static str {
+        match self {
+            VideoCheckResult::Available => "AVAILABLE",
+            VideoCheckResult::Missing => "MISSING",
+            VideoCheckResult::Inconclusive => "INCONCLUSIVE",
+        }
+    }
+
+    fn from_sql(sql: &str) -> Self {
+        match sql {
+            "AVAILABLE" => VideoCheckResult::Available,
+            "MISSING" => VideoCheckResult::Missing,
+            "INCONCLUSIVE" => VideoCheckResult::Inconclusive,
+            _ => panic!("invalid video check result: {}", sql),
+        }
+    }
+}
+
+/// Metadata about a video, as reported by its host
+///
+/// Which of these are available depends on the host, so all of them are optional.
+#[derive(Debug, Serialize, Deserialize, Hash, PartialEq, Eq, Clone, Default)]
+pub struct VideoMetadata {
+    pub title: Option<String>,
+
+    /// Some host specific identifier of the channel that uploaded the video, usually a link to it
+    pub channel: Option<String>,
+
+    /// The (UTC) time at which the video was uploaded
+    pub uploaded_at: Option<NaiveDateTime>,
+
+    /// The length of the video, in seconds
+    pub duration: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Hash, PartialEq, Eq, Clone)]
+pub struct VideoCheck {
+    pub result: VideoCheckResult,
+
+    /// The (UTC) time at which the video was checked
+    pub checked_at: NaiveDateTime,
+
+    /// The name of the host the video is uploaded to. `None` if the video's host is not supported
+    /// anymore.
+    pub video_host: Option<String>,
+
+    /// The id of the video on its host. `None` if the video's host is not supported anymore.
+    pub video_id: Option<String>,
+
+    #[serde(flatten)]
+    pub metadata: VideoMetadata,
+}
+
+impl VideoCheck {
+    /// Gets the result of checking the given record's current video, if it has been checked
+    pub async fn of_record(record_id: i32, connection: &mut PgConnection) -> Result<Option<VideoCheck>> {
+        Ok(sqlx::query!(
+            r#"SELECT result::TEXT AS "result!", checked_at, video_host, video_id, title, channel, uploaded_at, duration FROM record_video_checks 
+             INNER JOIN records ON record = records.id WHERE record = $1 AND record_video_checks.video = records.video"#,
+            record_id
+        )
+        .fetch_optional(connection)
+        .await?
+        .map(|row| VideoCheck {
+            result: VideoCheckResult::from_sql(&row.result),
+            checked_at: row.checked_at,
+            video_host: row.video_host,
+            video_id: row.video_id,
+            metadata: VideoMetadata {
+                title: row.title,
+                channel: row.channel,
+                uploaded_at: row.uploaded_at,
+                duration: row.duration,
+            },
+        }))
+    }
+
+    /// Stores the result of checking the given video of the given record, together with the
+    /// metadata retrieved while doing so, replacing any previous result
+    pub async fn store(
+        record_id: i32, video: &str, result: VideoCheckResult, metadata: VideoMetadata, connection: &mut PgConnection,
+    ) -> Result<VideoCheck> {
+        let (video_host, video_id) = match Video::parse(video) {
+            Ok(video) => (Some(video.host().to_string()), Some(video.id().to_string())),
+            Err(_) => (None, None),
+        };
+
+        let checked_at = sqlx::query!(
+            "INSERT INTO record_video_checks (record, video, result, video_host, video_id, title, channel, uploaded_at, duration) VALUES \
+             ($1, $2, $3::TEXT::VIDEO_CHECK_RESULT, $4, $5, $6, $7, $8, $9) ON CONFLICT (record) DO UPDATE SET video = EXCLUDED.video, \
+             result = EXCLUDED.result, checked_at = EXCLUDED.checked_at, video_host = EXCLUDED.video_host, video_id = EXCLUDED.video_id, \
+             title = EXCLUDED.title, channel = EXCLUDED.channel, uploaded_at = EXCLUDED.uploaded_at, duration = EXCLUDED.duration \
+             RETURNING checked_at",
+            record_id,
+            video,
+            result.to_sql(),
+            video_host,
+            video_id,
+            metadata.title,
+            metadata.channel,
+            metadata.uploaded_at,
+            metadata.duration
+        )
+        .fetch_one(connection)
+        .await?
+        .checked_at;
+
+        Ok(VideoCheck {
+            result,
+            checked_at,
+            video_host,
+            video_id,
+            metadata,
+        })
+    }
+}
@@ -0,0 +1,174 @@
+//! Module for bans from submitting records
+//!
+//! Besides banning individual submitters, the list team can ban user accounts and IP addresses or
+//! whole networks, regardless of which submitters exist for them. This prevents banned submitters
+//! from simply evading their ban by logging in, logging out or rotating their IPv6 address.
+
+use crate::error::{DemonlistError, Result};
+use chrono::NaiveDateTime;
+use log::info;
+use pointercrate_core::etag::Taggable;
+use serde::{Deserialize, Serialize};
+use sqlx::{Error, PgConnection};
+use std::net::IpAddr;
+
+#[derive(Debug, Serialize, Deserialize, Hash, PartialEq, Eq, Clone)]
+pub struct SubmissionBan {
+    pub id: i32,
+
+    /// The id of the banned user account, if this ban targets an account
+    pub member_id: Option<i32>,
+
+    /// The banned IP address or network in CIDR notation (e.g. `2001:db8::/64`), if this ban
+    /// targets IP addresses
+    pub network: Option<String>,
+
+    /// The (UTC) time at which the ban was issued
+    pub banned_at: NaiveDateTime,
+}
+
+impl Taggable for SubmissionBan {}
+
+#[derive(Debug, Deserialize)]
+pub struct NewSubmissionBan {
+    #[serde(default)]
+    member_id: Option<i32>,
+
+    /// Either a single IP address, or a network in CIDR notation
+    #[serde(default)]
+    network: Option<String>,
+}
+
+impl NewSubmissionBan {
+    fn validate(&self) -> Result<()> {
+        match (self.member_id, &self.network) {
+            (Some(_), None) => Ok(()),
+            (None, Some(network)) => {
+                let (address, prefix_length) = match network.split_once('/') {
+                    Some((address, prefix_length)) => (address, Some(prefix_length)),
+                    None => (network.as_str(), None),
+                };
+
+                let max_prefix_length = match address.parse::<IpAddr>().map_err(|_| DemonlistError::InvalidBanTarget)? {
+                    IpAddr::V4(_) => 32,
+                    IpAddr::V6(_) => 128,
+                };
+
+                match prefix_length.map(str::parse::<u8>) {
+                    None => Ok(()),
+                    Some(Ok(prefix_length)) if prefix_length <= max_prefix_length => Ok(()),
+                    Some(_) => Err(DemonlistError::InvalidBanTarget),
+                }
+            },
+            _ => Err(DemonlistError::InvalidBanTarget),
+        }
+    }
+}
+
+impl SubmissionBan {
+    pub async fn all(connection: &mut PgConnection) -> Result<Vec<SubmissionBan>> {
+        Ok(sqlx::query_as!(
+            SubmissionBan,
+            "SELECT id, member AS member_id, network::TEXT, banned_at FROM submission_bans ORDER BY id"
+        )
+        .fetch_all(connection)
+        .await?)
+    }
+
+    pub async fn by_id(id: i32, connection: &mut PgConnection) -> Result<SubmissionBan> {
+        let result = sqlx::query_as!(
+            SubmissionBan,
+            "SELECT id, member AS member_id, network::TEXT, banned_at FROM submission_bans WHERE id = $1",
+            id
+        )
+        .fetch_one(connection)
+        .await;
+
+        match result {
+            Ok(ban) => Ok(ban),
+            Err(Error::RowNotFound) => Err(DemonlistError::SubmissionBanNotFound { id }),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Issues the given ban, deleting all submissions covered by it that are still in the
+    /// 'submitted' state
+    pub async fn create(new_ban: NewSubmissionBan, connection: &mut PgConnection) -> Result<SubmissionBan> {
+        new_ban.validate()?;
+
+        let ban = sqlx::query_as!(
+            SubmissionBan,
+            "INSERT INTO submission_bans (member, network) VALUES ($1, network(cast($2::text as inet))::inet) RETURNING id, member AS \
+             member_id, network::TEXT, banned_at",
+            new_ban.member_id,
+            new_ban.network
+        )
+        .fetch_one(&mut *connection)
+        .await?;
+
+        let deleted = sqlx::query!(
+            "DELETE FROM records USING submitters WHERE records.submitter = submitters.submitter_id AND records.status_ = 'SUBMITTED' AND \
+             (submitters.member = $1 OR (submitters.member IS NULL AND submitters.ip_address <<= cast($2::text as inet)))",
+            ban.member_id,
+            ban.network
+        )
+        .execute(connection)
+        .await?;
+
+        info!(
+            "Submission ban {:?} caused deletion of {} submissions",
+            ban,
+            deleted.rows_affected()
+        );
+
+        Ok(ban)
+    }
+
+    pub async fn delete(self, connection: &mut PgConnection) -> Result<()> {
+        info!("Lifting submission ban {:?}", self);
+
+        sqlx::query!("DELETE FROM submission_bans WHERE id = $1", self.id)
+            .execute(connection)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Checks whether a submission from the given IP address, made while logged in to the given
+    /// user account (if any), is covered by some ban
+    pub async fn applies_to(ip: IpAddr, member_id: Option<i32>, connection: &mut PgConnection) -> Result<bool> {
+        Ok(sqlx::query!(
+            r#"SELECT EXISTS(SELECT 1 FROM submission_bans WHERE network >>= cast($1::text as inet) OR member = $2) AS "banned!""#,
+            ip.to_string(),
+            member_id
+        )
+        .fetch_one(connection)
+        .await?
+        .banned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NewSubmissionBan;
+
+    fn ban(member_id: Option<i32>, network: Option<&str>) -> NewSubmissionBan {
+        NewSubmissionBan {
+            member_id,
+            network: network.map(ToString::to_string),
+        }
+    }
+
+    #[test]
+    fn test_validate_ban_target() {
+        assert!(ban(Some(1), None).validate().is_ok());
+        assert!(ban(None, Some("192.0.2.1")).validate().is_ok());
+        assert!(ban(None, Some("192.0.2.0/24")).validate().is_ok());
+        assert!(ban(None, Some("2001:db8::/64")).validate().is_ok());
+
+        assert!(ban(None, None).validate().is_err());
+        assert!(ban(Some(1), Some("192.0.2.1")).validate().is_err());
+        assert!(ban(None, Some("192.0.2.0/33")).validate().is_err());
+        assert!(ban(None, Some("not an ip")).validate().is_err());
+    }
+}
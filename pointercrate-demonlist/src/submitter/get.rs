@@ -1,33 +1,87 @@
 use crate::{
     error::{DemonlistError, Result},
-    submitter::Submitter,
+    submitter::{Submitter, SubmitterStatistics},
 };
 use sqlx::{Error, PgConnection};
 use std::net::IpAddr;
 
+struct FetchedSubmitter {
+    submitter_id: i32,
+    banned: bool,
+    member: Option<i32>,
+    submitted: i64,
+    approved: i64,
+    rejected: i64,
+    deleted_by_video_check: i64,
+}
+
+impl From<FetchedSubmitter> for Submitter {
+    fn from(row: FetchedSubmitter) -> Self {
+        Submitter {
+            id: row.submitter_id,
+            banned: row.banned,
+            member_id: row.member,
+            statistics: SubmitterStatistics {
+                submitted: row.submitted,
+                approved: row.approved,
+                rejected: row.rejected,
+                deleted_by_video_check: row.deleted_by_video_check,
+            },
+        }
+    }
+}
+
 impl Submitter {
     pub async fn by_id(id: i32, connection: &mut PgConnection) -> Result<Submitter> {
-        let result = sqlx::query!("SELECT submitter_id, banned FROM submitters WHERE submitter_id = $1", id)
-            .fetch_one(connection)
-            .await;
+        let result = sqlx::query_as!(
+            FetchedSubmitter,
+            r#"SELECT submitter_id, banned, member, submitted AS "submitted!", approved AS "approved!", rejected AS "rejected!", 
+             deleted_by_video_check AS "deleted_by_video_check!" FROM submitters INNER JOIN submitter_statistics ON submitter = submitter_id 
+             WHERE submitter_id = $1"#,
+            id
+        )
+        .fetch_one(connection)
+        .await;
 
         match result {
-            Ok(row) => Ok(Submitter { id, banned: row.banned }),
+            Ok(row) => Ok(row.into()),
             Err(Error::RowNotFound) => Err(DemonlistError::SubmitterNotFound { id }),
             Err(err) => Err(err.into()),
         }
     }
 
+    /// Gets the anonymous submitter the given IP address belongs to, if it exists
+    ///
+    /// Anonymous submitters are identified by either a single IP address, or by a whole network (see
+    /// [`Submitter::create_submitter`]). If the address belongs to multiple submitters, the one
+    /// identified by the largest network is returned. It is reported as banned if any of the
+    /// submitters the address belongs to is banned, as otherwise a banned submitter identified by a
+    /// single address could evade their ban once a submitter for their whole network is created.
     pub async fn by_ip(ip: IpAddr, connection: &mut PgConnection) -> Result<Option<Submitter>> {
-        Ok(sqlx::query!(
-            "SELECT submitter_id, banned FROM submitters WHERE ip_address = cast($1::text as inet)",
+        Ok(sqlx::query_as!(
+            FetchedSubmitter,
+            r#"SELECT submitter_id, banned OR EXISTS (SELECT 1 FROM submitters AS others WHERE others.member IS NULL AND others.banned 
+             AND others.ip_address >>= cast($1::text as inet)) AS "banned!", member, submitted AS "submitted!", approved AS "approved!", rejected AS "rejected!", 
+             deleted_by_video_check AS "deleted_by_video_check!" FROM submitters INNER JOIN submitter_statistics ON submitter = submitter_id 
+             WHERE member IS NULL AND ip_address >>= cast($1::text as inet) ORDER BY masklen(ip_address), submitter_id LIMIT 1"#,
             ip.to_string()
         )
         .fetch_optional(&mut *connection)
         .await?
-        .map(|row| Submitter {
-            id: row.submitter_id,
-            banned: row.banned,
-        }))
+        .map(Into::into))
+    }
+
+    /// Gets the submitter linked to the given user account, if it exists
+    pub async fn by_member(member_id: i32, connection: &mut PgConnection) -> Result<Option<Submitter>> {
+        Ok(sqlx::query_as!(
+            FetchedSubmitter,
+            r#"SELECT submitter_id, banned, member, submitted AS "submitted!", approved AS "approved!", rejected AS "rejected!", 
+             deleted_by_video_check AS "deleted_by_video_check!" FROM submitters INNER JOIN submitter_statistics ON submitter = submitter_id 
+             WHERE member = $1"#,
+            member_id
+        )
+        .fetch_optional(&mut *connection)
+        .await?
+        .map(Into::into))
     }
 }
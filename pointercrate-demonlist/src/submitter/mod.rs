@@ -2,20 +2,31 @@ use derive_more::Display;
 use serde::Deserialize;
 use serde::Serialize;
 
+pub use ban::{NewSubmissionBan, SubmissionBan};
 pub use paginate::SubmitterPagination;
 pub use patch::PatchSubmitter;
 use pointercrate_core::etag::Taggable;
+pub use statistics::{ReputationThresholds, SubmitterStatistics};
 
+mod ban;
 mod get;
 mod paginate;
 mod patch;
 mod post;
+mod statistics;
 
 #[derive(Debug, Deserialize, Serialize, Hash, Display, Copy, Clone, PartialEq, Eq)]
 #[display("{} (Banned: {})", id, banned)]
 pub struct Submitter {
     pub id: i32,
     pub banned: bool,
+
+    /// The id of the user account this submitter is linked to. `None` for anonymous submitters,
+    /// which are identified by their IP address.
+    pub member_id: Option<i32>,
+
+    /// Statistics about the records this submitter has submitted
+    pub statistics: SubmitterStatistics,
 }
 
 impl Taggable for Submitter {}
@@ -1,4 +1,4 @@
-use crate::submitter::Submitter;
+use crate::submitter::{Submitter, SubmitterStatistics};
 use futures::StreamExt;
 use pointercrate_core::{
     first_and_last,
@@ -15,6 +15,18 @@ pub struct SubmitterPagination {
 
     #[serde(default, deserialize_with = "non_nullable")]
     banned: Option<bool>,
+
+    #[serde(rename = "submitted__gt", default, deserialize_with = "non_nullable")]
+    submitted_gt: Option<i64>,
+
+    #[serde(rename = "approved__lt", default, deserialize_with = "non_nullable")]
+    approved_lt: Option<i64>,
+
+    #[serde(rename = "rejected__gt", default, deserialize_with = "non_nullable")]
+    rejected_gt: Option<i64>,
+
+    #[serde(rename = "deleted_by_video_check__gt", default, deserialize_with = "non_nullable")]
+    deleted_by_video_check_gt: Option<i64>,
 }
 
 impl PaginationQuery for SubmitterPagination {
@@ -36,12 +48,22 @@ impl Paginatable<SubmitterPagination> for Submitter {
     async fn page(query: &SubmitterPagination, connection: &mut PgConnection) -> Result<(Vec<Submitter>, PageContext), sqlx::Error> {
         let order = query.params.order();
 
-        let sql_query = format!("SELECT submitter_id, banned FROM submitters WHERE (submitter_id < $1 OR $1 IS NULL) AND (submitter_id > $2 OR $2 IS NULL) AND (banned = $3 OR $3 IS NULL) ORDER BY submitter_id {} LIMIT $4", order);
+        let sql_query = format!(
+            "SELECT submitter_id, banned, member, submitted, approved, rejected, deleted_by_video_check FROM submitters INNER JOIN \
+             submitter_statistics ON submitter = submitter_id WHERE (submitter_id < $1 OR $1 IS NULL) AND (submitter_id > $2 OR $2 IS \
+             NULL) AND (banned = $3 OR $3 IS NULL) AND (submitted > $4 OR $4 IS NULL) AND (approved < $5 OR $5 IS NULL) AND (rejected > \
+             $6 OR $6 IS NULL) AND (deleted_by_video_check > $7 OR $7 IS NULL) ORDER BY submitter_id {} LIMIT $8",
+            order
+        );
 
         let mut stream = sqlx::query(&sql_query)
             .bind(query.params.before)
             .bind(query.params.after)
             .bind(query.banned)
+            .bind(query.submitted_gt)
+            .bind(query.approved_lt)
+            .bind(query.rejected_gt)
+            .bind(query.deleted_by_video_check_gt)
             .bind(query.params.limit + 1)
             .fetch(connection);
 
@@ -53,6 +75,13 @@ impl Paginatable<SubmitterPagination> for Submitter {
             submitters.push(Submitter {
                 id: row.get("submitter_id"),
                 banned: row.get("banned"),
+                member_id: row.get("member"),
+                statistics: SubmitterStatistics {
+                    submitted: row.get("submitted"),
+                    approved: row.get("approved"),
+                    rejected: row.get("rejected"),
+                    deleted_by_video_check: row.get("deleted_by_video_check"),
+                },
             })
         }
 
@@ -1,17 +1,54 @@
-use crate::{error::Result, submitter::Submitter};
+use crate::{
+    error::Result,
+    submitter::{Submitter, SubmitterStatistics},
+};
 use sqlx::PgConnection;
 use std::net::IpAddr;
 
 impl Submitter {
-    pub async fn create_submitter(ip: IpAddr, connection: &mut PgConnection) -> Result<Submitter> {
+    /// Creates a new anonymous submitter for the given IP address
+    ///
+    /// IPv6 addresses are grouped into networks with the given prefix length, as a single client
+    /// usually has a whole network (e.g. a /64) available to it and can freely rotate addresses
+    /// inside of it. IPv4 submitters are always identified by a single address.
+    pub async fn create_submitter(ip: IpAddr, ipv6_prefix_length: i32, connection: &mut PgConnection) -> Result<Submitter> {
         let id = sqlx::query!(
-            "INSERT INTO submitters (ip_address) VALUES (cast($1::text as inet)) RETURNING submitter_id",
-            ip.to_string()
+            "INSERT INTO submitters (ip_address) VALUES (CASE WHEN family(cast($1::text as inet)) = 6 THEN \
+             network(set_masklen(cast($1::text as inet), $2))::inet ELSE cast($1::text as inet) END) RETURNING submitter_id",
+            ip.to_string(),
+            ipv6_prefix_length
         )
         .fetch_one(connection)
         .await?
         .submitter_id;
 
-        Ok(Submitter { id, banned: false })
+        Ok(Submitter {
+            id,
+            banned: false,
+            member_id: None,
+            statistics: SubmitterStatistics::default(),
+        })
+    }
+
+    /// Creates a new submitter for submissions made while logged in to the given user account
+    ///
+    /// The given IP address is only stored for reference, the submitter is identified by the account
+    /// alone.
+    pub async fn create_for_member(member_id: i32, ip: IpAddr, connection: &mut PgConnection) -> Result<Submitter> {
+        let id = sqlx::query!(
+            "INSERT INTO submitters (ip_address, member) VALUES (cast($1::text as inet), $2) RETURNING submitter_id",
+            ip.to_string(),
+            member_id
+        )
+        .fetch_one(connection)
+        .await?
+        .submitter_id;
+
+        Ok(Submitter {
+            id,
+            banned: false,
+            member_id: Some(member_id),
+            statistics: SubmitterStatistics::default(),
+        })
     }
 }
@@ -0,0 +1,56 @@
+//! Module for statistics about the submissions of a submitter
+//!
+//! These give the list team a quick impression of a submitter's track record, and allow new
+//! submissions of submitters with a bad one to automatically receive extra scrutiny (see
+//! [`ReputationThresholds`]).
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Hash, Copy, Clone, PartialEq, Eq, Default)]
+pub struct SubmitterStatistics {
+    /// The total number of records submitted by this submitter, regardless of what happened to them
+    pub submitted: i64,
+
+    /// The number of this submitter's records that are currently approved
+    pub approved: i64,
+
+    /// The number of this submitter's records that are currently rejected
+    pub rejected: i64,
+
+    /// The number of this submitter's submissions that were deleted because the host of their
+    /// video reported it as missing
+    pub deleted_by_video_check: i64,
+}
+
+impl SubmitterStatistics {
+    /// The number of this submitter's submissions that were either rejected or deleted because of
+    /// a missing video
+    pub fn unsuccessful(&self) -> i64 {
+        self.rejected + self.deleted_by_video_check
+    }
+}
+
+/// Thresholds on the number of unsuccessful submissions (see
+/// [`SubmitterStatistics::unsuccessful`]) a submitter can have before their new submissions get
+/// extra scrutiny. `None` disables the respective measure.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReputationThresholds {
+    /// From this number on, new submissions are put under consideration instead of just being
+    /// submitted
+    pub under_consideration: Option<i64>,
+
+    /// From this number on, new submissions need to include raw footage, even if raw footage is
+    /// not required in general
+    pub raw_footage: Option<i64>,
+}
+
+impl ReputationThresholds {
+    pub fn puts_under_consideration(&self, statistics: &SubmitterStatistics) -> bool {
+        self.under_consideration
+            .is_some_and(|threshold| statistics.unsuccessful() >= threshold)
+    }
+
+    pub fn requires_raw_footage(&self, statistics: &SubmitterStatistics) -> bool {
+        self.raw_footage.is_some_and(|threshold| statistics.unsuccessful() >= threshold)
+    }
+}
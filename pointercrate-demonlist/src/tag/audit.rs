@@ -0,0 +1,64 @@
+use crate::error::Result;
+use chrono::NaiveDateTime;
+use futures::StreamExt;
+use pointercrate_core::audit::{AuditLogEntryType, NamedId};
+use serde::Serialize;
+use sqlx::PgConnection;
+
+/// The name a tag had in some language before a modification, or `None` if the tag had no name in
+/// that language before
+#[derive(Serialize, Debug)]
+pub struct TagModificationData {
+    pub language: String,
+    pub name: Option<String>,
+}
+
+/// An entry in the audit log of a tag
+///
+/// Entries are either about the tag itself, or about the tag being assigned to or removed from
+/// some demon.
+#[derive(Serialize, Debug)]
+pub struct TagAuditLogEntry {
+    pub time: NaiveDateTime,
+    pub entry_id: i32,
+    pub user: NamedId,
+
+    /// The demon this tag was assigned to (for additions) or removed from (for deletions), or
+    /// `None` if this entry is about the tag itself
+    pub demon: Option<NamedId>,
+    pub r#type: AuditLogEntryType<TagModificationData>,
+}
+
+/// Gets the audit log of the tag with the given ID
+///
+/// Since the log outlives the tag, this also works for tags that have since been deleted.
+pub async fn audit_log_for_tag(tag_id: &str, connection: &mut PgConnection) -> Result<Vec<TagAuditLogEntry>> {
+    let mut stream = sqlx::query_file!("sql/tag_audit_log.sql", tag_id).fetch(connection);
+    let mut entries = Vec::new();
+
+    while let Some(row) = stream.next().await {
+        let row = row?;
+
+        let r#type = match &row.kind[..] {
+            "addition" => AuditLogEntryType::Addition,
+            "deletion" => AuditLogEntryType::Deletion,
+            _ => AuditLogEntryType::Modification(TagModificationData {
+                language: row.language.unwrap_or_default(),
+                name: row.name,
+            }),
+        };
+
+        entries.push(TagAuditLogEntry {
+            time: row.time,
+            entry_id: row.audit_id,
+            user: NamedId {
+                id: row.userid,
+                name: row.username,
+            },
+            demon: row.demon.map(|id| NamedId { id, name: row.demon_name }),
+            r#type,
+        })
+    }
+
+    Ok(entries)
+}
@@ -0,0 +1,39 @@
+use crate::{
+    demon::MinimalDemon,
+    error::{DemonlistError, Result},
+    tag::Tag,
+};
+use log::info;
+use sqlx::PgConnection;
+
+impl Tag {
+    /// Deletes this tag, removing it from all demons it was assigned to
+    pub async fn delete(self, connection: &mut PgConnection) -> Result<()> {
+        info!("Deleting tag {}", self);
+
+        sqlx::query!("DELETE FROM tags WHERE id = $1", self.id)
+            .execute(connection)
+            .await
+            .map(|result| info!("Deletion of tag effected {} rows", result.rows_affected()))?;
+
+        Ok(())
+    }
+
+    /// Removes this tag from the given demon
+    pub async fn unassign(&self, demon: &MinimalDemon, connection: &mut PgConnection) -> Result<()> {
+        info!("Removing tag {} from demon {}", self, demon);
+
+        let result = sqlx::query!("DELETE FROM demon_tags WHERE demon = $1 AND tag = $2", demon.id, self.id)
+            .execute(connection)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DemonlistError::DemonTagNotFound {
+                demon_id: demon.id,
+                tag_id: self.id.clone(),
+            });
+        }
+
+        Ok(())
+    }
+}
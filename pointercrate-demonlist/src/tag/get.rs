@@ -0,0 +1,67 @@
+use crate::{
+    demon::MinimalDemon,
+    error::{DemonlistError, Result},
+    tag::Tag,
+};
+use sqlx::PgConnection;
+use std::collections::BTreeMap;
+
+impl Tag {
+    pub async fn by_id(id: &str, connection: &mut PgConnection) -> Result<Tag> {
+        let rows = sqlx::query!(
+            r#"SELECT tags.id, tag_names.language AS "language?", tag_names.name AS "name?" FROM tags LEFT OUTER JOIN tag_names ON 
+             tag_names.tag = tags.id WHERE tags.id = $1"#,
+            id
+        )
+        .fetch_all(connection)
+        .await?;
+
+        collect_tags(rows.into_iter().map(|row| (row.id, row.language, row.name)))
+            .pop()
+            .ok_or_else(|| DemonlistError::TagNotFound { tag_id: id.to_string() })
+    }
+
+    pub async fn all(connection: &mut PgConnection) -> Result<Vec<Tag>> {
+        let rows = sqlx::query!(
+            r#"SELECT tags.id, tag_names.language AS "language?", tag_names.name AS "name?" FROM tags LEFT OUTER JOIN tag_names ON 
+             tag_names.tag = tags.id ORDER BY tags.id"#
+        )
+        .fetch_all(connection)
+        .await?;
+
+        Ok(collect_tags(rows.into_iter().map(|row| (row.id, row.language, row.name))))
+    }
+}
+
+pub async fn tags_of(demon: &MinimalDemon, connection: &mut PgConnection) -> Result<Vec<Tag>> {
+    let rows = sqlx::query!(
+        r#"SELECT tags.id, tag_names.language AS "language?", tag_names.name AS "name?" FROM tags INNER JOIN demon_tags ON 
+         demon_tags.tag = tags.id LEFT OUTER JOIN tag_names ON tag_names.tag = tags.id WHERE demon_tags.demon = $1 ORDER BY tags.id"#,
+        demon.id
+    )
+    .fetch_all(connection)
+    .await?;
+
+    Ok(collect_tags(rows.into_iter().map(|row| (row.id, row.language, row.name))))
+}
+
+/// Groups `(id, language, name)` rows, as returned by a left join of `tags` and `tag_names`, into
+/// tags. Rows belonging to the same tag need to be adjacent.
+fn collect_tags(rows: impl Iterator<Item = (String, Option<String>, Option<String>)>) -> Vec<Tag> {
+    let mut tags: Vec<Tag> = Vec::new();
+
+    for (id, language, name) in rows {
+        if !matches!(tags.last(), Some(tag) if tag.id == id) {
+            tags.push(Tag {
+                id,
+                names: BTreeMap::new(),
+            });
+        }
+
+        if let (Some(tag), Some(language), Some(name)) = (tags.last_mut(), language, name) {
+            tag.names.insert(language, name);
+        }
+    }
+
+    tags
+}
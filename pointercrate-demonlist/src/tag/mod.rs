@@ -0,0 +1,52 @@
+//! Module for the tags describing the gameplay style of demons (e.g. "wave" or "memory")
+//!
+//! Tag definitions are managed by the list team and shared between all lists. Each tag can have a
+//! name in each of the languages the site is available in.
+
+use derive_more::Display;
+use pointercrate_core::{
+    etag::Taggable,
+    localization::{LocaleConfiguration, LANGUAGE},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+pub use self::{
+    audit::{audit_log_for_tag, TagAuditLogEntry, TagModificationData},
+    get::tags_of,
+    patch::PatchTag,
+    post::{PostDemonTag, PostTag},
+};
+
+mod audit;
+mod delete;
+mod get;
+mod patch;
+mod post;
+
+#[derive(Debug, Serialize, Deserialize, Hash, Display, PartialEq, Eq, Clone)]
+#[display("{}", id)]
+pub struct Tag {
+    /// The tag's unique identifier, as used in URLs
+    pub id: String,
+
+    /// The tag's names, keyed by language code (e.g. `en`)
+    pub names: BTreeMap<String, String>,
+}
+
+impl Taggable for Tag {}
+
+impl Tag {
+    /// The tag's name in the language of the current request
+    ///
+    /// Falls back to the name in the site's default language if the tag has no name in the
+    /// requested language, and to the tag's ID if it has no name in that language either.
+    pub fn localized_name(&self) -> &str {
+        let fallback = LocaleConfiguration::get().fallback.to_string();
+        let language = LANGUAGE
+            .try_with(|language| language.to_string())
+            .unwrap_or_else(|_| fallback.clone());
+
+        self.names.get(&language).or_else(|| self.names.get(&fallback)).unwrap_or(&self.id)
+    }
+}
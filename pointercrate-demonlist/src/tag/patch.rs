@@ -0,0 +1,57 @@
+use crate::{
+    error::{DemonlistError, Result},
+    tag::Tag,
+};
+use log::info;
+use serde::Deserialize;
+use sqlx::PgConnection;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct PatchTag {
+    /// The names to change, keyed by language code. Setting a name to `null` removes the tag's
+    /// name in that language.
+    #[serde(default)]
+    pub names: BTreeMap<String, Option<String>>,
+}
+
+impl Tag {
+    /// Must be run within a transaction!
+    pub async fn apply_patch(mut self, patch: PatchTag, connection: &mut PgConnection) -> Result<Self> {
+        info!("Patching tag {} with {:?}", self, patch);
+
+        for (language, name) in patch.names {
+            match name {
+                Some(name) => {
+                    Tag::validate_name(&language, &name)?;
+
+                    sqlx::query!(
+                        "INSERT INTO tag_names (tag, language, name) VALUES ($1, $2, $3) ON CONFLICT (tag, language) DO UPDATE SET name = \
+                         EXCLUDED.name",
+                        self.id,
+                        language,
+                        name
+                    )
+                    .execute(&mut *connection)
+                    .await?;
+
+                    self.names.insert(language, name);
+                },
+                None => {
+                    sqlx::query!("DELETE FROM tag_names WHERE tag = $1 AND language = $2", self.id, language)
+                        .execute(&mut *connection)
+                        .await?;
+
+                    self.names.remove(&language);
+                },
+            }
+        }
+
+        // A tag without any names cannot be displayed
+        if self.names.is_empty() {
+            return Err(DemonlistError::InvalidTagName);
+        }
+
+        Ok(self)
+    }
+}
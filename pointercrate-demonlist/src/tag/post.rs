@@ -0,0 +1,148 @@
+use crate::{
+    demon::MinimalDemon,
+    error::{DemonlistError, Result},
+    tag::Tag,
+};
+use log::info;
+use serde::Deserialize;
+use sqlx::PgConnection;
+use std::collections::BTreeMap;
+
+#[derive(Deserialize, Debug)]
+pub struct PostTag {
+    pub id: String,
+    pub names: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PostDemonTag {
+    pub tag: String,
+}
+
+impl Tag {
+    /// Must be run within a transaction!
+    pub async fn create_from(data: PostTag, connection: &mut PgConnection) -> Result<Tag> {
+        info!("Creating new tag from {:?}", data);
+
+        Tag::validate_id(&data.id)?;
+
+        if data.names.is_empty() {
+            return Err(DemonlistError::InvalidTagName);
+        }
+
+        for (language, name) in &data.names {
+            Tag::validate_name(language, name)?;
+        }
+
+        let exists = sqlx::query!("SELECT EXISTS (SELECT 1 FROM tags WHERE id = $1) AS \"exists!\"", data.id)
+            .fetch_one(&mut *connection)
+            .await?
+            .exists;
+
+        if exists {
+            return Err(DemonlistError::TagExists);
+        }
+
+        sqlx::query!("INSERT INTO tags (id) VALUES ($1)", data.id)
+            .execute(&mut *connection)
+            .await?;
+
+        for (language, name) in &data.names {
+            sqlx::query!(
+                "INSERT INTO tag_names (tag, language, name) VALUES ($1, $2, $3)",
+                data.id,
+                language,
+                name
+            )
+            .execute(&mut *connection)
+            .await?;
+        }
+
+        Ok(Tag {
+            id: data.id,
+            names: data.names,
+        })
+    }
+
+    /// Tags this demon with this tag
+    pub async fn assign(&self, demon: &MinimalDemon, connection: &mut PgConnection) -> Result<()> {
+        info!("Tagging demon {} with {}", demon, self);
+
+        let result = sqlx::query!(
+            "INSERT INTO demon_tags (demon, tag) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+            demon.id,
+            self.id
+        )
+        .execute(connection)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DemonlistError::DemonTagExists);
+        }
+
+        Ok(())
+    }
+
+    /// Tag IDs are used in URLs, so they are restricted to groups of lowercase alphanumeric
+    /// characters separated by single dashes
+    pub fn validate_id(id: &str) -> Result<()> {
+        let valid = !id.is_empty()
+            && id.len() <= 32
+            && id
+                .split('-')
+                .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()));
+
+        if !valid {
+            return Err(DemonlistError::InvalidTagId);
+        }
+
+        Ok(())
+    }
+
+    /// Names are keyed by ISO 639 language codes, which consist of two or three lowercase letters
+    pub fn validate_name(language: &str, name: &str) -> Result<()> {
+        let valid = (2..=3).contains(&language.len())
+            && language.chars().all(|c| c.is_ascii_lowercase())
+            && !name.trim().is_empty()
+            && name.chars().count() <= 32;
+
+        if !valid {
+            return Err(DemonlistError::InvalidTagName);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::DemonlistError, tag::Tag};
+
+    #[test]
+    fn test_validate_id() {
+        assert_eq!(Tag::validate_id("wave"), Ok(()));
+        assert_eq!(Tag::validate_id("2p"), Ok(()));
+        assert_eq!(Tag::validate_id("high-cps"), Ok(()));
+
+        assert_eq!(Tag::validate_id(""), Err(DemonlistError::InvalidTagId));
+        assert_eq!(Tag::validate_id("Wave"), Err(DemonlistError::InvalidTagId));
+        assert_eq!(Tag::validate_id("high--cps"), Err(DemonlistError::InvalidTagId));
+        assert_eq!(Tag::validate_id("-wave"), Err(DemonlistError::InvalidTagId));
+        assert_eq!(Tag::validate_id("high cps"), Err(DemonlistError::InvalidTagId));
+        assert_eq!(Tag::validate_id(&"a".repeat(33)), Err(DemonlistError::InvalidTagId));
+    }
+
+    #[test]
+    fn test_validate_name() {
+        assert_eq!(Tag::validate_name("en", "Wave"), Ok(()));
+        assert_eq!(Tag::validate_name("ru", "Волна"), Ok(()));
+        assert_eq!(Tag::validate_name("fil", "Alon"), Ok(()));
+
+        assert_eq!(Tag::validate_name("en", ""), Err(DemonlistError::InvalidTagName));
+        assert_eq!(Tag::validate_name("en", "   "), Err(DemonlistError::InvalidTagName));
+        assert_eq!(Tag::validate_name("en", &"a".repeat(33)), Err(DemonlistError::InvalidTagName));
+        assert_eq!(Tag::validate_name("EN", "Wave"), Err(DemonlistError::InvalidTagName));
+        assert_eq!(Tag::validate_name("en-us", "Wave"), Err(DemonlistError::InvalidTagName));
+        assert_eq!(Tag::validate_name("e", "Wave"), Err(DemonlistError::InvalidTagName));
+    }
+}
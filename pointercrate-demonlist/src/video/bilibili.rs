@@ -0,0 +1,60 @@
+use crate::video::VideoProvider;
+use url::Url;
+
+pub struct Bilibili;
+
+impl VideoProvider for Bilibili {
+    fn name(&self) -> &'static str {
+        "Bilibili"
+    }
+
+    fn domains(&self) -> &'static [&'static str] {
+        &["www.bilibili.com", "bilibili.com"]
+    }
+
+    fn expected_format(&self) -> &'static str {
+        "https://www.bilibili.com/video/{video_id}' or 'https://bilibili.com/video/{video_id}"
+    }
+
+    fn canonical_id(&self, url: &Url) -> Option<String> {
+        match url.path_segments()?.collect::<Vec<_>>()[..] {
+            ["video", video_id] if !video_id.is_empty() => Some(video_id.to_string()),
+            _ => None,
+        }
+    }
+
+    fn normalize(&self, video_id: &str) -> String {
+        format!("https://www.bilibili.com/video/{}", video_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::video::validate;
+
+    #[test]
+    fn test_accepted() {
+        for url in [
+            "https://www.bilibili.com/video/BV1xx411c7mD",
+            "https://bilibili.com/video/BV1xx411c7mD",
+        ] {
+            assert_eq!(
+                validate(url).as_deref(),
+                Ok("https://www.bilibili.com/video/BV1xx411c7mD"),
+                "{}",
+                url
+            );
+        }
+    }
+
+    #[test]
+    fn test_rejected() {
+        for url in [
+            "https://www.bilibili.com/",
+            "https://www.bilibili.com/video/",
+            "https://www.bilibili.com/BV1xx411c7mD",
+        ] {
+            assert!(validate(url).is_err(), "{}", url);
+        }
+    }
+}
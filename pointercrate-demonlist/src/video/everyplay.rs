@@ -0,0 +1,52 @@
+use crate::video::VideoProvider;
+use url::Url;
+
+pub struct Everyplay;
+
+impl VideoProvider for Everyplay {
+    fn name(&self) -> &'static str {
+        "Everyplay"
+    }
+
+    fn domains(&self) -> &'static [&'static str] {
+        &["everyplay.com", "www.everyplay.com"]
+    }
+
+    fn expected_format(&self) -> &'static str {
+        "https://everyplay.com/videos/{video_id}' or 'https://www.everyplay.com/videos/{video_id}"
+    }
+
+    fn canonical_id(&self, url: &Url) -> Option<String> {
+        match url.path_segments()?.collect::<Vec<_>>()[..] {
+            ["videos", video_id] if !video_id.is_empty() => Some(video_id.to_string()),
+            _ => None,
+        }
+    }
+
+    fn normalize(&self, video_id: &str) -> String {
+        format!("https://everyplay.com/videos/{}", video_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::video::validate;
+
+    #[test]
+    fn test_accepted() {
+        for url in ["https://everyplay.com/videos/1234", "https://www.everyplay.com/videos/1234"] {
+            assert_eq!(validate(url).as_deref(), Ok("https://everyplay.com/videos/1234"), "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_rejected() {
+        for url in [
+            "https://everyplay.com/",
+            "https://everyplay.com/videos/",
+            "https://everyplay.com/1234",
+        ] {
+            assert!(validate(url).is_err(), "{}", url);
+        }
+    }
+}
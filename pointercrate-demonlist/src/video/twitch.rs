@@ -0,0 +1,65 @@
+use crate::video::VideoProvider;
+use url::Url;
+
+pub struct Twitch;
+
+impl VideoProvider for Twitch {
+    fn name(&self) -> &'static str {
+        "Twitch"
+    }
+
+    fn domains(&self) -> &'static [&'static str] {
+        &["www.twitch.tv", "twitch.tv"]
+    }
+
+    fn expected_format(&self) -> &'static str {
+        "https://www.twitch.tv/videos/{video_id}' or \
+         'https://twitch.tv/videos/{video_id}' or \
+         'https://www.twitch.tv/{channel_name}/v/{video_id}' or \
+         'https://twitch.tv/{channel_name}/v/{video_id}"
+    }
+
+    fn canonical_id(&self, url: &Url) -> Option<String> {
+        match url.path_segments()?.collect::<Vec<_>>()[..] {
+            ["videos", video_id] | [_, "v", video_id] if !video_id.is_empty() => Some(video_id.to_string()),
+            _ => None,
+        }
+    }
+
+    fn normalize(&self, video_id: &str) -> String {
+        format!("https://www.twitch.tv/videos/{}", video_id)
+    }
+
+    fn embed_url(&self, video_id: &str) -> Option<String> {
+        Some(format!("https://player.twitch.tv/?video={}&autoplay=false", video_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::video::validate;
+
+    #[test]
+    fn test_accepted() {
+        for url in [
+            "https://www.twitch.tv/videos/123456789",
+            "https://twitch.tv/videos/123456789",
+            "https://www.twitch.tv/stardust1971/v/123456789",
+            "https://twitch.tv/stardust1971/v/123456789",
+        ] {
+            assert_eq!(validate(url).as_deref(), Ok("https://www.twitch.tv/videos/123456789"), "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_rejected() {
+        for url in [
+            "https://www.twitch.tv/",
+            "https://www.twitch.tv/stardust1971",
+            "https://www.twitch.tv/videos/",
+            "https://www.twitch.tv/videos/123456789/extra",
+        ] {
+            assert!(validate(url).is_err(), "{}", url);
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::video::VideoProvider;
+use url::Url;
+
+pub struct Vimeo;
+
+impl VideoProvider for Vimeo {
+    fn name(&self) -> &'static str {
+        "Vimeo"
+    }
+
+    fn domains(&self) -> &'static [&'static str] {
+        &["vimeo.com", "www.vimeo.com"]
+    }
+
+    fn expected_format(&self) -> &'static str {
+        "https://vimeo.com/{video_id}' or 'https://www.vimeo.com/{video_id}"
+    }
+
+    fn canonical_id(&self, url: &Url) -> Option<String> {
+        match url.path_segments()?.collect::<Vec<_>>()[..] {
+            [video_id] if !video_id.is_empty() => Some(video_id.to_string()),
+            _ => None,
+        }
+    }
+
+    fn normalize(&self, video_id: &str) -> String {
+        format!("https://vimeo.com/{}", video_id)
+    }
+
+    fn oembed_url(&self, video_id: &str) -> Option<String> {
+        Url::parse_with_params(
+            "https://vimeo.com/api/oembed.json",
+            &[("format", "json"), ("url", &self.normalize(video_id))],
+        )
+        .ok()
+        .map(String::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::video::validate;
+
+    #[test]
+    fn test_accepted() {
+        for url in ["https://vimeo.com/76979871", "https://www.vimeo.com/76979871"] {
+            assert_eq!(validate(url).as_deref(), Ok("https://vimeo.com/76979871"), "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_rejected() {
+        for url in ["https://vimeo.com/", "https://vimeo.com/channels/76979871"] {
+            assert!(validate(url).is_err(), "{}", url);
+        }
+    }
+}
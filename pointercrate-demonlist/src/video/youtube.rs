@@ -0,0 +1,117 @@
+use crate::video::VideoProvider;
+use url::Url;
+
+pub struct YouTube;
+
+impl VideoProvider for YouTube {
+    fn name(&self) -> &'static str {
+        "YouTube"
+    }
+
+    fn domains(&self) -> &'static [&'static str] {
+        &["www.youtube.com", "m.youtube.com", "youtube.com", "youtu.be"]
+    }
+
+    fn expected_format(&self) -> &'static str {
+        "https://www.youtube.com/watch?v={video_id}' or \
+         'https://m.youtube.com/watch?v={video_id}' or \
+         'https://youtube.com/watch?v={video_id}' or \
+         'https://www.youtube.com/shorts/{video_id}' or \
+         'https://www.youtube.com/live/{video_id}' or \
+         'https://www.youtube.com/embed/{video_id}' or \
+         'https://youtu.be/{video_id}"
+    }
+
+    fn canonical_id(&self, url: &Url) -> Option<String> {
+        let segments = url.path_segments()?.collect::<Vec<_>>();
+
+        let video_id = if url.domain() == Some("youtu.be") {
+            match segments[..] {
+                [video_id] => video_id.to_string(),
+                _ => return None,
+            }
+        } else {
+            match segments[..] {
+                ["watch"] => url
+                    .query_pairs()
+                    .find_map(|(key, value)| if key == "v" { Some(value.into_owned()) } else { None })?,
+                ["shorts", video_id] | ["live", video_id] | ["embed", video_id] => video_id.to_string(),
+                _ => return None,
+            }
+        };
+
+        if video_id.is_empty() {
+            return None;
+        }
+
+        // Video IDs are always 11 characters long, anything after that is garbage (e.g. trailing
+        // punctuation when someone copies the link from a sentence)
+        Some(video_id.chars().take(11).collect())
+    }
+
+    fn normalize(&self, video_id: &str) -> String {
+        format!("https://www.youtube.com/watch?v={}", video_id)
+    }
+
+    fn embed_url(&self, video_id: &str) -> Option<String> {
+        Some(format!("https://www.youtube.com/embed/{}", video_id))
+    }
+
+    fn thumbnail_url(&self, video_id: &str) -> Option<String> {
+        Some(format!("https://i.ytimg.com/vi/{}/mqdefault.jpg", video_id))
+    }
+
+    fn oembed_url(&self, video_id: &str) -> Option<String> {
+        Url::parse_with_params(
+            "https://www.youtube.com/oembed",
+            &[("format", "json"), ("url", &self.normalize(video_id))],
+        )
+        .ok()
+        .map(String::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::video::validate;
+
+    #[test]
+    fn test_accepted() {
+        for url in [
+            "https://www.youtube.com/watch?v=dQw4w9WgXcQ",
+            "https://m.youtube.com/watch?v=dQw4w9WgXcQ",
+            "https://youtube.com/watch?v=dQw4w9WgXcQ",
+            "http://www.youtube.com/watch?v=dQw4w9WgXcQ",
+            "https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=42s",
+            "https://www.youtube.com/watch?feature=share&v=dQw4w9WgXcQ",
+            "https://www.youtube.com/shorts/dQw4w9WgXcQ",
+            "https://www.youtube.com/live/dQw4w9WgXcQ?si=abcdef",
+            "https://www.youtube.com/embed/dQw4w9WgXcQ",
+            "https://youtu.be/dQw4w9WgXcQ",
+            "https://youtu.be/dQw4w9WgXcQ?t=10",
+        ] {
+            assert_eq!(
+                validate(url).as_deref(),
+                Ok("https://www.youtube.com/watch?v=dQw4w9WgXcQ"),
+                "{}",
+                url
+            );
+        }
+    }
+
+    #[test]
+    fn test_rejected() {
+        for url in [
+            "https://www.youtube.com/",
+            "https://www.youtube.com/watch",
+            "https://www.youtube.com/watch?v=",
+            "https://www.youtube.com/shorts/",
+            "https://www.youtube.com/@stardust1971",
+            "https://www.youtube.com/channel/UCkmH7B9c6lXlRu1KyJgHWzw",
+            "https://youtu.be/",
+            "https://youtu.be/dQw4w9WgXcQ/extra",
+        ] {
+            assert!(validate(url).is_err(), "{}", url);
+        }
+    }
+}
@@ -9,15 +9,15 @@ use pointercrate_core_pages::{
     navigation::{NavigationBar, TopLevelNavigationBarItem},
     PageConfiguration,
 };
-use pointercrate_demonlist::LIST_ADMINISTRATOR;
+use pointercrate_demonlist::{list::List, LIST_ADMINISTRATOR};
 use pointercrate_demonlist_api::GeolocationProvider;
 use pointercrate_demonlist_pages::account::{
-    demons::DemonsTab, list_integration::ListIntegrationTab, players::PlayersPage, records::RecordsPage,
+    demons::DemonsTab, list_integration::ListIntegrationTab, moderation::ModerationStatsTab, players::PlayersPage, records::RecordsPage,
 };
 use pointercrate_user::MODERATOR;
 use pointercrate_user_pages::account::{profile::ProfileTab, users::UsersTab, AccountPageConfig};
 use rocket::{async_trait, fs::FileServer, response::Redirect, serde, uri, Request};
-use std::net::IpAddr;
+use std::{net::IpAddr, sync::OnceLock};
 use unic_langid::lang;
 use unic_langid::subtags::Language;
 
@@ -107,6 +107,14 @@ async fn rocket() -> _ {
     // DATABASE_URL environment variable
     let pool = PointercratePool::init().await;
 
+    // The footer links to the first extended and legacy demons of the default list, so we need
+    // to know its sizes. These only change when a list administrator changes them, so loading
+    // them once at startup is good enough for our purposes.
+    let mut connection = pool.connection().await.expect("Failed to acquire database connection");
+    let default_list = List::default_list(&mut connection).await.expect("Failed to load default list");
+    DEFAULT_LIST.set(default_list).unwrap();
+    drop(connection);
+
     // Set up the HTTP server
     let rocket = rocket::build()
         // Tell it about the connection pool to use (individual handlers can get hold of this pool by declaring an argument of type `&State<PointercratePool>`)
@@ -157,7 +165,9 @@ async fn rocket() -> _ {
         // Tab where list helpers can manage players
         .with_page(PlayersPage)
         // Tab where list helpers can manage records
-        .with_page(RecordsPage);
+        .with_page(RecordsPage)
+        // Tab where list administrators can view statistics about the list team's activity
+        .with_page(ModerationStatsTab);
 
     let rocket = rocket.manage(account_page_config);
 
@@ -184,6 +194,9 @@ async fn rocket() -> _ {
         .mount("/static/example", FileServer::new("pointercrate-example/static"))
 }
 
+/// The default list of this instance, loaded at startup
+static DEFAULT_LIST: OnceLock<List> = OnceLock::new();
+
 /// Constructs a [`PageConfiguration`] for your site.
 ///
 /// A `PageConfiguration` object is a description of your websites general
@@ -191,6 +204,8 @@ async fn rocket() -> _ {
 /// links to include) and various metadata without you needing to worry (much)
 /// about styling and layout.
 fn page_configuration() -> PageConfiguration {
+    let default_list = DEFAULT_LIST.get().expect("default list not loaded");
+
     // Define a navigation bar with only two items, a link to the user account page,
     // and a link to your demonlist.
     let nav_bar = NavigationBar::new("/static/images/path/to/your/logo.png")
@@ -236,11 +251,11 @@ fn page_configuration() -> PageConfiguration {
         links: vec![
             Link::new("/demonlist/1/", tr("footer-demonlist.top-demon")),
             Link::new(
-                format!("/demonlist/{}/", pointercrate_demonlist::config::list_size() + 1),
+                format!("/demonlist/{}/", default_list.list_size + 1),
                 tr("footer-demonlist.extended-list"),
             ),
             Link::new(
-                format!("/demonlist/{}/", pointercrate_demonlist::config::extended_list_size() + 1),
+                format!("/demonlist/{}/", default_list.extended_list_size + 1),
                 tr("footer-demonlist.legacy-list"),
             ),
         ],
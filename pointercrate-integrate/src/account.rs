@@ -0,0 +1,266 @@
+//! Module for looking up Geometry Dash accounts and the content of their profiles
+//!
+//! Used to verify that a pointercrate user actually owns the Geometry Dash account of the player
+//! they claimed. The lookups go through the [`GdBackend`] trait, so that they can be served by
+//! something other than the actual Geometry Dash servers (e.g. in tests).
+
+use base64::{
+    alphabet,
+    engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+    Engine,
+};
+use log::{debug, warn};
+use reqwest::{header::HeaderMap, header::CONTENT_TYPE, Client};
+use std::{collections::HashMap, fmt::Display};
+
+/// The base url used if no endpoint was configured via [`crate::set_gd_connector_endpoint`]
+const DEFAULT_ENDPOINT: &str = "https://www.boomlings.com/database";
+
+/// The secret every request to the Geometry Dash servers needs to include
+const SECRET: &str = "Wmfd2893gb7";
+
+/// Account comments are base64 encoded with the URL-safe alphabet, but the servers are not
+/// consistent about padding
+const COMMENT_ENGINE: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::URL_SAFE,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GdAccount {
+    pub account_id: u64,
+    pub name: String,
+}
+
+#[derive(Debug)]
+pub enum GdBackendError {
+    Request(reqwest::Error),
+    MalformedResponse,
+}
+
+impl Display for GdBackendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GdBackendError::Request(err) => write!(f, "request to Geometry Dash servers failed: {}", err),
+            GdBackendError::MalformedResponse => write!(f, "Geometry Dash servers returned a malformed response"),
+        }
+    }
+}
+
+impl std::error::Error for GdBackendError {}
+
+#[async_trait::async_trait]
+pub trait GdBackend: Send + Sync {
+    /// Looks up the account with exactly the given name (case-insensitively)
+    async fn find_account(&self, name: &str) -> Result<Option<GdAccount>, GdBackendError>;
+
+    /// Retrieves the contents of the most recent comments posted on the given account's profile
+    async fn account_comments(&self, account_id: u64) -> Result<Vec<String>, GdBackendError>;
+
+    /// Retrieves the social media links (YouTube, Twitter, Twitch) set on the given account's
+    /// profile
+    async fn profile_links(&self, account_id: u64) -> Result<Vec<String>, GdBackendError>;
+
+    /// Checks whether the given code was posted as a comment on, or put into the profile of, the
+    /// given account
+    async fn displays_code(&self, account_id: u64, code: &str) -> Result<bool, GdBackendError> {
+        if self
+            .account_comments(account_id)
+            .await?
+            .iter()
+            .any(|comment| comment.contains(code))
+        {
+            return Ok(true);
+        }
+
+        Ok(self.profile_links(account_id).await?.iter().any(|link| link.contains(code)))
+    }
+}
+
+/// [`GdBackend`] making requests to the actual Geometry Dash servers
+#[derive(Default)]
+pub struct BoomlingsBackend {
+    http_client: Client,
+}
+
+impl BoomlingsBackend {
+    async fn make_request(&self, endpoint: &str, body: String) -> Result<String, GdBackendError> {
+        let base_url = dash_rs::request::GD_SERVER_ENDPOINT_BASE_URL
+            .get()
+            .map(String::as_str)
+            .unwrap_or(DEFAULT_ENDPOINT);
+        let url = format!("{}/{}", base_url.trim_end_matches('/'), endpoint);
+
+        debug!("Making request to {} with body {}", url, body);
+
+        let response = self
+            .http_client
+            .post(url)
+            // boomlings.com rejects any request with a User-Agent header set, so make sure reqwest doesn't "helpfully" add one
+            .headers(HeaderMap::new())
+            .body(body)
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .send()
+            .await
+            .map_err(|err| {
+                warn!("Failed to make boomlings request: {:?}", err);
+
+                GdBackendError::Request(err)
+            })?;
+
+        response.text().await.map_err(GdBackendError::Request)
+    }
+}
+
+#[async_trait::async_trait]
+impl GdBackend for BoomlingsBackend {
+    async fn find_account(&self, name: &str) -> Result<Option<GdAccount>, GdBackendError> {
+        let body = format!("str={}&total=0&page=0&secret={}", urlencode(name), SECRET);
+        let response = self.make_request("getGJUsers20.php", body).await?;
+
+        parse_users_response(&response).map(|accounts| accounts.into_iter().find(|account| account.name.eq_ignore_ascii_case(name)))
+    }
+
+    async fn account_comments(&self, account_id: u64) -> Result<Vec<String>, GdBackendError> {
+        let body = format!(
+            "accountID={}&total=0&page=0&secret={}&gameVersion=22&binaryVersion=42",
+            account_id, SECRET
+        );
+        let response = self.make_request("getGJAccountComments20.php", body).await?;
+
+        parse_account_comments_response(&response)
+    }
+
+    async fn profile_links(&self, account_id: u64) -> Result<Vec<String>, GdBackendError> {
+        let body = format!("targetAccountID={}&secret={}", account_id, SECRET);
+        let response = self.make_request("getGJUserInfo20.php", body).await?;
+
+        parse_user_info_response(&response)
+    }
+}
+
+fn urlencode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Splits a robtop-style `key<sep>value<sep>key<sep>value...` string into a map
+fn parse_indexed(data: &str, separator: char) -> HashMap<&str, &str> {
+    let mut parts = data.split(separator);
+    let mut map = HashMap::new();
+
+    while let (Some(key), Some(value)) = (parts.next(), parts.next()) {
+        map.insert(key, value);
+    }
+
+    map
+}
+
+/// Strips the `#total:offset:count` pagination suffix from a response, returning `None` if the
+/// servers indicated an error (responses of `-1`)
+fn response_body(response: &str) -> Option<&str> {
+    let response = response.trim();
+
+    if response == "-1" {
+        return None;
+    }
+
+    Some(response.split('#').next().unwrap_or_default())
+}
+
+fn parse_users_response(response: &str) -> Result<Vec<GdAccount>, GdBackendError> {
+    let Some(body) = response_body(response) else {
+        return Ok(Vec::new());
+    };
+
+    body.split('|')
+        .filter(|user| !user.is_empty())
+        .map(|user| {
+            let user = parse_indexed(user, ':');
+
+            Ok(GdAccount {
+                account_id: user
+                    .get("16")
+                    .and_then(|id| id.parse().ok())
+                    .ok_or(GdBackendError::MalformedResponse)?,
+                name: user.get("1").ok_or(GdBackendError::MalformedResponse)?.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn parse_account_comments_response(response: &str) -> Result<Vec<String>, GdBackendError> {
+    let Some(body) = response_body(response) else {
+        return Err(GdBackendError::MalformedResponse);
+    };
+
+    body.split('|')
+        .filter(|comment| !comment.is_empty())
+        .map(|comment| {
+            let content = parse_indexed(comment, '~').get("2").copied().unwrap_or_default();
+            let decoded = COMMENT_ENGINE.decode(content).map_err(|_| GdBackendError::MalformedResponse)?;
+
+            String::from_utf8(decoded).map_err(|_| GdBackendError::MalformedResponse)
+        })
+        .collect()
+}
+
+fn parse_user_info_response(response: &str) -> Result<Vec<String>, GdBackendError> {
+    let Some(body) = response_body(response) else {
+        return Err(GdBackendError::MalformedResponse);
+    };
+
+    let profile = parse_indexed(body, ':');
+
+    Ok(["20", "44", "45"]
+        .iter()
+        .filter_map(|index| profile.get(index))
+        .filter(|link| !link.is_empty())
+        .map(ToString::to_string)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_users_response() {
+        let response = "1:stadust:2:1234:13:10:17:0:6::9:1:10:1:11:3:14:0:15:0:16:5678:3:100:8:0:4:0#1:0:10";
+
+        assert_eq!(
+            parse_users_response(response).unwrap(),
+            vec![GdAccount {
+                account_id: 5678,
+                name: "stadust".to_string()
+            }]
+        );
+        assert!(parse_users_response("-1").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_account_comments_response() {
+        // "pc-abc123" and "hello", the latter without padding
+        let response = "2~cGMtYWJjMTIz~4~0~9~1 hour~6~1|2~aGVsbG8~4~2~9~2 days~6~2#2:0:10";
+
+        assert_eq!(
+            parse_account_comments_response(response).unwrap(),
+            vec!["pc-abc123".to_string(), "hello".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_parse_user_info_response() {
+        let response = "1:stadust:2:1234:16:5678:20:pc-abc123:44::45:stadust";
+
+        assert_eq!(
+            parse_user_info_response(response).unwrap(),
+            vec!["pc-abc123".to_string(), "stadust".to_string()]
+        );
+    }
+}
@@ -1,5 +1,6 @@
 //! this crate is a burning pile of trash
 
+pub mod account;
 pub mod gd;
 
 pub fn set_gd_connector_endpoint(endpoint: String) {
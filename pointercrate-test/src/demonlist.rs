@@ -6,35 +6,107 @@ use pointercrate_core_api::preferences::PreferenceManager;
 use pointercrate_demonlist::demon::FullDemon;
 use pointercrate_demonlist::{
     player::{claim::PlayerClaim, FullPlayer},
-    record::RecordStatus,
-    submitter::Submitter,
+    record::{RecordStatus, VideoCheckResult, VideoMetadata},
+    submitter::{ReputationThresholds, Submitter},
     LIST_ADMINISTRATOR, LIST_HELPER, LIST_MODERATOR,
 };
+use pointercrate_demonlist_api::VideoVerifier;
+use pointercrate_integrate::account::{GdAccount, GdBackend, GdBackendError};
 use pointercrate_user::auth::{AuthenticatedUser, PasswordOrBrowser};
 use pointercrate_user_pages::account::AccountPageConfig;
-use rocket::{http::Status, local::asynchronous::Client};
+use rocket::{http::Status, local::asynchronous::Client, Build, Rocket};
 use sqlx::{pool::PoolConnection, PgConnection, Pool, Postgres};
-use std::{net::IpAddr, str::FromStr};
+use std::{net::IpAddr, str::FromStr, sync::Arc};
+
+/// A [`VideoVerifier`] that gives the same answer for every video, so that tests do not depend on
+/// network access
+pub struct StaticVerifier(pub VideoCheckResult, pub VideoMetadata);
+
+#[rocket::async_trait]
+impl VideoVerifier for StaticVerifier {
+    async fn verify(&self, _video: &str) -> (VideoCheckResult, VideoMetadata) {
+        (self.0, self.1.clone())
+    }
+}
+
+/// A [`GdBackend`] that knows about a single Geometry Dash account, so that claim verification can
+/// be tested without access to the Geometry Dash servers
+pub struct StaticGdBackend {
+    pub account: GdAccount,
+    pub comments: Vec<String>,
+    pub profile_links: Vec<String>,
+}
+
+#[rocket::async_trait]
+impl GdBackend for StaticGdBackend {
+    async fn find_account(&self, name: &str) -> Result<Option<GdAccount>, GdBackendError> {
+        Ok(Some(self.account.clone()).filter(|account| account.name.eq_ignore_ascii_case(name)))
+    }
+
+    async fn account_comments(&self, account_id: u64) -> Result<Vec<String>, GdBackendError> {
+        match account_id == self.account.account_id {
+            true => Ok(self.comments.clone()),
+            false => Err(GdBackendError::MalformedResponse),
+        }
+    }
+
+    async fn profile_links(&self, account_id: u64) -> Result<Vec<String>, GdBackendError> {
+        match account_id == self.account.account_id {
+            true => Ok(self.profile_links.clone()),
+            false => Err(GdBackendError::MalformedResponse),
+        }
+    }
+}
 
 pub async fn setup_rocket(pool: Pool<Postgres>) -> (TestClient, PoolConnection<Postgres>) {
+    setup_rocket_with_verifier(pool, StaticVerifier(VideoCheckResult::Available, VideoMetadata::default())).await
+}
+
+pub async fn setup_rocket_with_verifier(
+    pool: Pool<Postgres>, verifier: impl VideoVerifier + 'static,
+) -> (TestClient, PoolConnection<Postgres>) {
+    setup_rocket_with(pool, |rocket| rocket.manage(Arc::new(verifier) as Arc<dyn VideoVerifier>)).await
+}
+
+pub async fn setup_rocket_with_thresholds(
+    pool: Pool<Postgres>, thresholds: ReputationThresholds,
+) -> (TestClient, PoolConnection<Postgres>) {
+    setup_rocket_with(pool, |rocket| {
+        rocket
+            .manage(Arc::new(StaticVerifier(VideoCheckResult::Available, VideoMetadata::default())) as Arc<dyn VideoVerifier>)
+            .manage(thresholds)
+    })
+    .await
+}
+
+/// Sets up the demonlist with the given additional state, which takes precedence over the
+/// defaults the demonlist would otherwise use
+pub async fn setup_rocket_with(
+    pool: Pool<Postgres>, configure: impl FnOnce(Rocket<Build>) -> Rocket<Build>,
+) -> (TestClient, PoolConnection<Postgres>) {
     let _ = dotenv::dotenv();
 
     let mut connection = pool.acquire().await.unwrap();
 
-    let permissions = PermissionsManager::new(vec![LIST_HELPER, LIST_MODERATOR, LIST_ADMINISTRATOR])
+    let mut permissions = PermissionsManager::new(vec![LIST_HELPER, LIST_MODERATOR, LIST_ADMINISTRATOR])
         .assigns(LIST_ADMINISTRATOR, LIST_MODERATOR)
         .implies(LIST_ADMINISTRATOR, LIST_MODERATOR)
         .implies(LIST_MODERATOR, LIST_HELPER);
 
+    // Claims (and everything attached to them) are managed by pointercrate moderators
+    permissions.merge_with(pointercrate_user::default_permissions_manager());
+
     LocalesLoader::empty();
 
-    let rocket = pointercrate_demonlist_api::setup(rocket::build().manage(PointercratePool::from(pool)))
+    let rocket = configure(rocket::build().manage(PointercratePool::from(pool)));
+
+    let rocket = pointercrate_demonlist_api::setup(rocket)
         .manage(permissions)
         .manage(AccountPageConfig::default())
         .manage(PreferenceManager::default().preference("locale", "en"));
 
     // generate some data
-    Submitter::create_submitter(IpAddr::from_str("127.0.0.1").unwrap(), &mut connection)
+    Submitter::create_submitter(IpAddr::from_str("127.0.0.1").unwrap(), 64, &mut connection)
         .await
         .unwrap();
 
@@ -75,6 +147,7 @@ pub async fn put_claim(user_id: i32, player_id: i32, verified: bool, lock_submis
         player_id,
         verified,
         lock_submissions,
+        third_party_approval: false,
     }
 }
 
@@ -85,7 +158,7 @@ pub async fn add_simple_record(progress: i16, player: i32, demon: i32, status: R
         .unwrap();
 
     sqlx::query!(
-        "INSERT INTO records (progress, status_, player, submitter, demon, video, raw_footage) VALUES ($1, $2::text::record_status, $3, $4, $5, NULL, NULL) \
+        "INSERT INTO records (progress, status_, player, submitter, demon, video) VALUES ($1, $2::text::record_status, $3, $4, $5, NULL) \
          RETURNING id",
         progress,
         status.to_sql(),
@@ -1,8 +1,9 @@
-use pointercrate_core::pagination::PaginationParameters;
+use pointercrate_core::{etag::Taggable, pagination::PaginationParameters};
 use pointercrate_core_api::pagination::LinksBuilder;
 use pointercrate_demonlist::{
-    demon::{Demon, DemonPositionPagination},
-    player::DatabasePlayer,
+    creator::{CreatorRole, Part},
+    demon::{Demon, DemonPositionPagination, FullDemon},
+    player::{DatabasePlayer, FullPlayer, Player},
     LIST_MODERATOR,
 };
 use rocket::http::Status;
@@ -200,3 +201,207 @@ async fn test_demon_pagination(pool: Pool<Postgres>) {
 
     assert_eq!(links, expected.generate(&base).unwrap());
 }
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_list_changelog(pool: Pool<Postgres>) {
+    let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let user = pointercrate_test::user::system_user_with_perms(LIST_MODERATOR, &mut connection).await;
+    let player = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+
+    let start = sqlx::query!(r#"SELECT LOCALTIMESTAMP AS "now!""#)
+        .fetch_one(&mut *connection)
+        .await
+        .unwrap()
+        .now;
+
+    let id1 = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 100, player.id, player.id, &mut connection).await;
+    let id2 = pointercrate_test::demonlist::add_demon("Bloodbath 2", 2, 100, player.id, player.id, &mut connection).await;
+
+    let before_move = sqlx::query!(r#"SELECT LOCALTIMESTAMP AS "now!""#)
+        .fetch_one(&mut *connection)
+        .await
+        .unwrap()
+        .now;
+
+    let demon: FullDemon = clnt.get(format!("/api/v2/demons/{}/", id2)).get_success_result().await;
+
+    clnt.patch(format!("/api/v2/demons/{}/", id2), &serde_json::json!({"position": 1}))
+        .authorize_as(&user)
+        .header("If-Match", demon.etag_string())
+        .execute()
+        .await;
+
+    // Both demons were added in this time frame, so their movement is not reported separately
+    let changelog: serde_json::Value = clnt
+        .get(format!("/api/v2/demons/changelog/?after={}", start.format("%Y-%m-%dT%H:%M:%S%.f")))
+        .get_result()
+        .await;
+
+    assert_eq!(changelog["additions"].as_array().unwrap().len(), 2, "{:?}", changelog);
+    assert!(changelog["moves"].as_array().unwrap().is_empty(), "{:?}", changelog);
+    assert!(changelog["removals"].as_array().unwrap().is_empty(), "{:?}", changelog);
+
+    // Only the movement happened in this time frame
+    let changelog: serde_json::Value = clnt
+        .get(format!(
+            "/api/v2/demons/changelog/?after={}",
+            before_move.format("%Y-%m-%dT%H:%M:%S%.f")
+        ))
+        .get_result()
+        .await;
+
+    assert!(changelog["additions"].as_array().unwrap().is_empty(), "{:?}", changelog);
+
+    let moves = changelog["moves"].as_array().unwrap();
+    assert_eq!(moves.len(), 2, "{:?}", changelog);
+
+    let moved = moves.iter().find(|entry| entry["demon"]["id"] == id2).unwrap();
+    assert_eq!(moved["old_position"], 2);
+    assert_eq!(moved["new_position"], 1);
+    assert_eq!(moved["reasons"], serde_json::json!(["Moved"]));
+
+    let shifted = moves.iter().find(|entry| entry["demon"]["id"] == id1).unwrap();
+    assert_eq!(shifted["old_position"], 1);
+    assert_eq!(shifted["new_position"], 2);
+    assert_eq!(shifted["reasons"][0]["OtherMoved"]["other"]["id"], id2);
+
+    // Nothing happened before the demons were added
+    let changelog: serde_json::Value = clnt
+        .get(format!(
+            "/api/v2/demons/changelog/?after=2000-01-01T00:00:00&before={}",
+            start.format("%Y-%m-%dT%H:%M:%S%.f")
+        ))
+        .get_result()
+        .await;
+
+    assert!(changelog["additions"].as_array().unwrap().is_empty(), "{:?}", changelog);
+    assert!(changelog["moves"].as_array().unwrap().is_empty(), "{:?}", changelog);
+
+    let result: serde_json::Value = clnt
+        .get(format!(
+            "/api/v2/demons/changelog/?after={}&before={}",
+            before_move.format("%Y-%m-%dT%H:%M:%S%.f"),
+            start.format("%Y-%m-%dT%H:%M:%S%.f")
+        ))
+        .expect_status(Status::UnprocessableEntity)
+        .get_result()
+        .await;
+
+    assert_eq!(result["code"].as_i64(), Some(42227));
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_movements_feed(pool: Pool<Postgres>) {
+    let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let user = pointercrate_test::user::system_user_with_perms(LIST_MODERATOR, &mut connection).await;
+    let player = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+
+    let id1 = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 100, player.id, player.id, &mut connection).await;
+    let id2 = pointercrate_test::demonlist::add_demon("Bloodbath 2", 2, 100, player.id, player.id, &mut connection).await;
+
+    let demon: FullDemon = clnt.get(format!("/api/v2/demons/{}/", id2)).get_success_result().await;
+
+    clnt.patch(format!("/api/v2/demons/{}/", id2), &serde_json::json!({"position": 1}))
+        .authorize_as(&user)
+        .header("If-Match", demon.etag_string())
+        .execute()
+        .await;
+
+    let feed = clnt
+        .get("/demonlist/feeds/movements/")
+        .expect_status(Status::Ok)
+        .expect_header("Content-Type", "application/atom+xml; charset=utf-8")
+        .execute()
+        .await
+        .into_string()
+        .await
+        .unwrap();
+
+    // Two additions and the move of `id2`. The resulting move of `id1` is implied and not reported separately
+    assert_eq!(feed.matches("<entry>").count(), 3);
+    assert!(feed.contains(&format!("urn:pointercrate:movement:{}:", id1)));
+    assert!(feed.contains("<link rel=\"self\" href=\"/demonlist/feeds/movements/\"></link>"));
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_creator_roles(pool: Pool<Postgres>) {
+    let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let user = pointercrate_test::user::system_user_with_perms(LIST_MODERATOR, &mut connection).await;
+    let demon = clnt.add_demon(&user, "Bloodbath", 1, 100, "Riot", "Riot").await;
+    let url = format!("/api/v2/demons/{}/creators/", demon.demon.base.id);
+
+    clnt.post(&url, &serde_json::json!({"creator": "Riot", "roles": ["host", "gameplay", "host"]}))
+        .authorize_as(&user)
+        .expect_status(Status::Created)
+        .execute()
+        .await;
+
+    clnt.post(
+        &url,
+        &serde_json::json!({"creator": "Knobbelboy", "roles": ["decoration", "verifier_of_part"], "part": {"start": 34, "end": 52}}),
+    )
+    .authorize_as(&user)
+    .expect_status(Status::Created)
+    .execute()
+    .await;
+
+    // creators without any roles are still allowed
+    clnt.post(&url, &serde_json::json!({"creator": "Michigun"}))
+        .authorize_as(&user)
+        .expect_status(Status::Created)
+        .execute()
+        .await;
+
+    for part in [
+        serde_json::json!({"start": 52, "end": 34}),
+        serde_json::json!({"start": 90, "end": 101}),
+    ] {
+        let result: serde_json::Value = clnt
+            .post(&url, &serde_json::json!({"creator": "Cyclic", "part": part}))
+            .authorize_as(&user)
+            .expect_status(Status::UnprocessableEntity)
+            .get_result()
+            .await;
+
+        assert_eq!(result["code"].as_i64(), Some(42246));
+    }
+
+    let demon: FullDemon = clnt
+        .get(format!("/api/v2/demons/{}/", demon.demon.base.id))
+        .get_success_result()
+        .await;
+
+    assert_eq!(demon.creators.len(), 3);
+
+    let riot = demon.creators.iter().find(|creator| creator.player.name == "Riot").unwrap();
+    let knobbelboy = demon.creators.iter().find(|creator| creator.player.name == "Knobbelboy").unwrap();
+    let michigun = demon.creators.iter().find(|creator| creator.player.name == "Michigun").unwrap();
+
+    assert_eq!(riot.roles, vec![CreatorRole::Host, CreatorRole::Gameplay]);
+    assert_eq!(riot.part, None);
+    assert_eq!(knobbelboy.roles, vec![CreatorRole::Decoration, CreatorRole::VerifierOfPart]);
+    assert_eq!(knobbelboy.part, Some(Part { start: 34, end: 52 }));
+    assert!(michigun.roles.is_empty());
+
+    let player: FullPlayer = clnt
+        .get(format!("/api/v1/players/{}/", knobbelboy.player.id))
+        .get_success_result()
+        .await;
+
+    assert_eq!(player.created.len(), 1);
+    assert_eq!(player.created[0].demon.id, demon.demon.base.id);
+    assert_eq!(player.created[0].roles, knobbelboy.roles);
+    assert_eq!(player.created[0].part, knobbelboy.part);
+
+    let decorators: Vec<Player> = clnt
+        .get("/api/v1/players/?creator_role=decoration")
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert_eq!(decorators.len(), 1);
+    assert_eq!(decorators[0].base.id, knobbelboy.player.id);
+}
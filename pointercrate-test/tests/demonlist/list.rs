@@ -0,0 +1,342 @@
+use pointercrate_core::etag::Taggable;
+use pointercrate_demonlist::{
+    demon::{Demon, FullDemon},
+    list::List,
+    player::DatabasePlayer,
+    record::RecordStatus,
+    LIST_ADMINISTRATOR,
+};
+use rocket::http::Status;
+use sqlx::{Pool, Postgres};
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_create_and_patch_list(pool: Pool<Postgres>) {
+    let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let admin = pointercrate_test::user::system_user_with_perms(LIST_ADMINISTRATOR, &mut connection).await;
+
+    let list: List = clnt
+        .post(
+            "/api/v2/lists/",
+            &serde_json::json!({"id": "platformer", "name": "Platformer List", "list_size": 25, "extended_list_size": 50}),
+        )
+        .authorize_as(&admin)
+        .expect_status(Status::Created)
+        .expect_header("Location", "/api/v2/lists/platformer/")
+        .get_success_result()
+        .await;
+
+    assert_eq!(list.list_size, 25);
+
+    let result: serde_json::Value = clnt
+        .post(
+            "/api/v2/lists/",
+            &serde_json::json!({"id": "platformer", "name": "Platformer List", "list_size": 25, "extended_list_size": 50}),
+        )
+        .authorize_as(&admin)
+        .expect_status(Status::Conflict)
+        .get_result()
+        .await;
+
+    assert_eq!(result["code"].as_i64(), Some(40909));
+
+    let result: serde_json::Value = clnt
+        .post(
+            "/api/v2/lists/",
+            &serde_json::json!({"id": "Not A Slug", "name": "Invalid", "list_size": 25, "extended_list_size": 50}),
+        )
+        .authorize_as(&admin)
+        .expect_status(Status::UnprocessableEntity)
+        .get_result()
+        .await;
+
+    assert_eq!(result["code"].as_i64(), Some(42236));
+
+    let result: serde_json::Value = clnt
+        .patch("/api/v2/lists/platformer/", &serde_json::json!({"extended_list_size": 10}))
+        .authorize_as(&admin)
+        .header("If-Match", list.etag_string())
+        .expect_status(Status::UnprocessableEntity)
+        .get_result()
+        .await;
+
+    assert_eq!(result["code"].as_i64(), Some(42237));
+
+    let list: List = clnt
+        .patch("/api/v2/lists/platformer/", &serde_json::json!({"list_size": 30}))
+        .authorize_as(&admin)
+        .header("If-Match", list.etag_string())
+        .expect_status(Status::Ok)
+        .get_success_result()
+        .await;
+
+    assert_eq!(list.list_size, 30);
+    assert_eq!(list.extended_list_size, 50);
+
+    // The tiers move along with the list's sizes
+    let tiers = list.tiers(&mut connection).await.unwrap();
+
+    assert_eq!(tiers.0[0].last_position, Some(30));
+    assert_eq!(tiers.0[1].first_position, 31);
+    assert_eq!(tiers.0[1].last_position, Some(50));
+
+    let result: serde_json::Value = clnt
+        .get("/api/v2/lists/nonexistent/")
+        .expect_status(Status::NotFound)
+        .get_result()
+        .await;
+
+    assert_eq!(result["code"].as_i64(), Some(40401));
+
+    clnt.get("/api/v2/lists/nonexistent/demons/listed/")
+        .expect_status(Status::NotFound)
+        .execute()
+        .await;
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_grow_list_without_extended_part(pool: Pool<Postgres>) {
+    let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let admin = pointercrate_test::user::system_user_with_perms(LIST_ADMINISTRATOR, &mut connection).await;
+
+    let list: List = clnt
+        .post(
+            "/api/v2/lists/",
+            &serde_json::json!({"id": "platformer", "name": "Platformer List", "list_size": 25, "extended_list_size": 25}),
+        )
+        .authorize_as(&admin)
+        .expect_status(Status::Created)
+        .get_success_result()
+        .await;
+
+    assert_eq!(list.tiers(&mut connection).await.unwrap().0.len(), 2);
+
+    let list: List = clnt
+        .patch("/api/v2/lists/platformer/", &serde_json::json!({"extended_list_size": 50}))
+        .authorize_as(&admin)
+        .header("If-Match", list.etag_string())
+        .expect_status(Status::Ok)
+        .get_success_result()
+        .await;
+
+    assert_eq!(list.list_size, 25);
+    assert_eq!(list.extended_list_size, 50);
+
+    // The new extended part of the list gets a tier of its own
+    let tiers = list.tiers(&mut connection).await.unwrap();
+
+    assert_eq!(tiers.0.len(), 3);
+    assert_eq!(tiers.0[0].last_position, Some(25));
+    assert_eq!(tiers.0[1].id, "extended");
+    assert_eq!(tiers.0[1].first_position, 26);
+    assert_eq!(tiers.0[1].last_position, Some(50));
+    assert_eq!(tiers.0[2].last_position, None);
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_lists_are_independent(pool: Pool<Postgres>) {
+    let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let admin = pointercrate_test::user::system_user_with_perms(LIST_ADMINISTRATOR, &mut connection).await;
+
+    clnt.post(
+        "/api/v2/lists/",
+        &serde_json::json!({"id": "platformer", "name": "Platformer List", "list_size": 25, "extended_list_size": 50}),
+    )
+    .authorize_as(&admin)
+    .expect_status(Status::Created)
+    .execute()
+    .await;
+
+    let player = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let main_demon = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 100, player.id, player.id, &mut connection).await;
+
+    // Position 1 is already taken on the main list, but not on the platformer list
+    let platformer_demon: FullDemon = clnt
+        .post(
+            "/api/v2/lists/platformer/demons/",
+            &serde_json::json!({"name": "Bloodbath", "requirement": 100, "position": 1, "verifier": "Riot", "publisher": "Riot", "creators": []}),
+        )
+        .authorize_as(&admin)
+        .expect_status(Status::Created)
+        .get_success_result()
+        .await;
+
+    assert_eq!(platformer_demon.demon.base.position, 1);
+    assert_eq!(platformer_demon.demon.base.list, "platformer");
+
+    let (demons, _) = clnt
+        .get("/api/v2/lists/platformer/demons/listed/")
+        .get_pagination_result::<Demon>()
+        .await;
+
+    assert_eq!(demons.len(), 1);
+    assert_eq!(demons[0].base.id, platformer_demon.demon.base.id);
+
+    let (demons, _) = clnt.get("/api/v2/demons/listed/").get_pagination_result::<Demon>().await;
+
+    assert_eq!(demons.len(), 1);
+    assert_eq!(demons[0].base.id, main_demon);
+
+    // Verifiers only get score on the list their verification is part of
+    let (ranking, _) = clnt
+        .get("/api/v2/lists/platformer/players/ranking/")
+        .get_pagination_result::<serde_json::Value>()
+        .await;
+
+    assert_eq!(ranking.len(), 1);
+    assert_eq!(ranking[0]["name"], "Riot");
+    assert_eq!(ranking[0]["rank"], 1);
+
+    let (ranking, _) = clnt
+        .get("/api/v2/lists/main/players/ranking/")
+        .get_pagination_result::<serde_json::Value>()
+        .await;
+
+    assert!(ranking.iter().all(|ranked| ranked["name"] != "Riot"), "{:?}", ranking);
+
+    // Positions are only unique within a list, so filtering records by position only considers the default list, unless
+    // told otherwise
+    let main_record =
+        pointercrate_test::demonlist::add_simple_record(100, player.id, main_demon, RecordStatus::Approved, &mut connection).await;
+    let platformer_record = pointercrate_test::demonlist::add_simple_record(
+        100,
+        player.id,
+        platformer_demon.demon.base.id,
+        RecordStatus::Approved,
+        &mut connection,
+    )
+    .await;
+
+    for (url, record) in [
+        ("/api/v1/records/?demon_position=1", main_record),
+        ("/api/v1/records/?demon_position__lt=2", main_record),
+        ("/api/v1/records/?demon_position=1&list=platformer", platformer_record),
+    ] {
+        let (records, _) = clnt
+            .get(url)
+            .authorize_as(&admin)
+            .get_pagination_result::<serde_json::Value>()
+            .await;
+
+        assert_eq!(records.len(), 1, "{}", url);
+        assert_eq!(records[0]["id"], record, "{}", url);
+    }
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_configure_tiers(pool: Pool<Postgres>) {
+    let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let admin = pointercrate_test::user::system_user_with_perms(LIST_ADMINISTRATOR, &mut connection).await;
+
+    let player = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let top = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 50, player.id, player.id, &mut connection).await;
+    let middle = pointercrate_test::demonlist::add_demon("Sakupen Circles", 2, 50, player.id, player.id, &mut connection).await;
+    let bottom = pointercrate_test::demonlist::add_demon("Cadrega City", 3, 50, player.id, player.id, &mut connection).await;
+
+    let tiers = List::default_list(&mut connection)
+        .await
+        .unwrap()
+        .tiers(&mut connection)
+        .await
+        .unwrap();
+
+    assert_eq!(tiers.0.len(), 3);
+    assert_eq!(tiers.0[0].id, "main");
+
+    let list = List::default_list(&mut connection).await.unwrap();
+    let list: List = clnt
+        .patch("/api/v2/lists/main/", &serde_json::json!({"list_size": 1, "extended_list_size": 2}))
+        .authorize_as(&admin)
+        .header("If-Match", list.etag_string())
+        .expect_status(Status::Ok)
+        .get_success_result()
+        .await;
+    let tiers = list.tiers(&mut connection).await.unwrap();
+
+    let new_tiers = serde_json::json!([
+        {"id": "top", "label": "main-list", "last_position": 1, "submissions": "open", "edit_permission": "user-permissions.list-helper"},
+        {"id": "middle", "label": "extended-list", "last_position": 2, "submissions": "completions_only", "edit_permission": "user-permissions.list-moderator"},
+        {"id": "rest", "label": "legacy-list", "submissions": "closed", "edit_permission": "user-permissions.list-administrator"}
+    ]);
+
+    clnt.put("/api/v2/lists/main/tiers/")
+        .body(&new_tiers)
+        .header("If-Match", tiers.etag_string())
+        .expect_status(Status::Unauthorized)
+        .execute()
+        .await;
+
+    let result: serde_json::Value = clnt
+        .put("/api/v2/lists/main/tiers/")
+        .body(&serde_json::json!([{"id": "top", "label": "main-list", "last_position": 1, "submissions": "open", "edit_permission": "user-permissions.list-helper"}]))
+        .authorize_as(&admin)
+        .header("If-Match", tiers.etag_string())
+        .expect_status(Status::UnprocessableEntity)
+        .get_result()
+        .await;
+
+    assert_eq!(result["code"].as_i64(), Some(42238));
+
+    // Tiers may not span both the main and the extended list
+    let result: serde_json::Value = clnt
+        .put("/api/v2/lists/main/tiers/")
+        .body(&serde_json::json!([
+            {"id": "top", "label": "main-list", "last_position": 2, "submissions": "open", "edit_permission": "user-permissions.list-helper"},
+            {"id": "rest", "label": "legacy-list", "submissions": "closed", "edit_permission": "user-permissions.list-administrator"}
+        ]))
+        .authorize_as(&admin)
+        .header("If-Match", tiers.etag_string())
+        .expect_status(Status::UnprocessableEntity)
+        .get_result()
+        .await;
+
+    assert_eq!(result["code"].as_i64(), Some(42238));
+
+    let result: serde_json::Value = clnt
+        .put("/api/v2/lists/main/tiers/")
+        .body(&new_tiers)
+        .authorize_as(&admin)
+        .header("If-Match", tiers.etag_string())
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert_eq!(result["data"][1]["first_position"], 2);
+    assert_eq!(result["data"][1]["last_position"], 2);
+    assert_eq!(result["data"][2]["first_position"], 3);
+    assert_eq!(result["data"][2]["edit_permission"], "user-permissions.list-administrator");
+
+    // Each submission needs its own video, as otherwise they would count as duplicates of each other
+    let submit = |demon: i32, progress: i16| {
+        serde_json::json! {{"progress": progress, "demon": demon, "player": "stardust1971", "video": format!("https://youtube.com/watch?v={:0>11}", demon), "raw_footage": "https://pointercrate.com"}}
+    };
+
+    clnt.post("/api/v1/records/", &submit(top, 60))
+        .expect_status(Status::Ok)
+        .execute()
+        .await;
+
+    let result: serde_json::Value = clnt
+        .post("/api/v1/records/", &submit(middle, 60))
+        .expect_status(Status::UnprocessableEntity)
+        .get_result()
+        .await;
+
+    assert_eq!(result["code"].as_i64(), Some(42220));
+
+    clnt.post("/api/v1/records/", &submit(middle, 100))
+        .expect_status(Status::Ok)
+        .execute()
+        .await;
+
+    let result: serde_json::Value = clnt
+        .post("/api/v1/records/", &submit(bottom, 100))
+        .expect_status(Status::UnprocessableEntity)
+        .get_result()
+        .await;
+
+    assert_eq!(result["code"].as_i64(), Some(42219));
+}
@@ -1,4 +1,7 @@
 mod demon;
+mod list;
 mod nationality;
 mod player;
 mod record;
+mod submitter;
+mod tag;
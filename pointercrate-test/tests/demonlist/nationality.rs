@@ -1,9 +1,13 @@
 use pointercrate_demonlist::{
-    nationality::{Nationality, RankedNation},
-    player::{DatabasePlayer, Player},
-    LIST_MODERATOR,
+    nationality::{
+        Continent, Heatmap, HeatmapMetric, Nationality, NationalityWithContinent, RankedContinent, RankedNation, RankedSubdivision,
+        Subdivision,
+    },
+    player::{DatabasePlayer, FullPlayer, Player},
+    LIST_ADMINISTRATOR, LIST_MODERATOR,
 };
 use rocket::http::Status;
+use serde_json::{json, Value};
 use sqlx::{Pool, Postgres};
 
 #[sqlx::test(migrations = "../migrations")]
@@ -33,3 +37,387 @@ pub async fn test_search_nation(pool: Pool<Postgres>) {
     assert_eq!(json[0].nationality.iso_country_code, "DE");
     assert_eq!(json[0].nationality.nation, "Germany");
 }
+
+#[sqlx::test(migrations = "../migrations")]
+pub async fn test_subdivision_and_continent_ranking(pool: Pool<Postgres>) {
+    let (client, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let helper = pointercrate_test::user::system_user_with_perms(LIST_MODERATOR, &mut connection).await;
+
+    for (name, subdivision) in [("stardust1971", "BY"), ("stardust1972", "BE")] {
+        let player = DatabasePlayer::by_name_or_create(name, &mut connection).await.unwrap();
+        let mut player = Player::by_id(player.id, &mut connection).await.unwrap();
+        let nationality = Nationality {
+            iso_country_code: "DE".into(),
+            nation: "Germany".into(),
+            subdivision: Some(Subdivision {
+                iso_code: subdivision.into(),
+                name: String::new(),
+            }),
+        };
+        player.set_nationality(Some(nationality), &mut connection).await.unwrap();
+    }
+
+    client.add_demon(&helper, "Bloodbath", 1, 100, "stardust1971", "stardust1971").await;
+    client
+        .add_demon(&helper, "Sakupen Circles", 2, 100, "stardust1972", "stardust1972")
+        .await;
+
+    let ranking: Vec<RankedSubdivision> = client
+        .get("/api/v1/nationalities/de/subdivisions/ranking/")
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert_eq!(ranking.len(), 2);
+    assert_eq!(ranking[0].rank, 1);
+    assert_eq!(ranking[0].subdivision.iso_code, "BY");
+    assert_eq!(ranking[1].rank, 2);
+    assert_eq!(ranking[1].subdivision.iso_code, "BE");
+    assert!(ranking[0].score > ranking[1].score);
+
+    let ranking: Vec<RankedSubdivision> = client
+        .get("/api/v1/nationalities/de/subdivisions/ranking/?name_contains=berlin")
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert_eq!(ranking.len(), 1);
+    assert_eq!(ranking[0].subdivision.iso_code, "BE");
+
+    let bavaria: Value = client
+        .get("/api/v1/nationalities/de/subdivisions/by/")
+        .expect_status(Status::Ok)
+        .get_success_result()
+        .await;
+
+    assert_eq!(bavaria["subdivision"]["iso_code"], "BY");
+    assert_eq!(bavaria["verified"].as_array().unwrap().len(), 1);
+    assert_eq!(bavaria["verified"][0]["demon"]["name"], "Bloodbath");
+
+    client
+        .get("/api/v1/nationalities/de/subdivisions/xx/")
+        .expect_status(Status::NotFound)
+        .execute()
+        .await;
+
+    let continents: Vec<RankedContinent> = client
+        .get("/api/v1/nationalities/continents/ranking/")
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert_eq!(continents.len(), 1);
+    assert_eq!(continents[0].continent, Continent::Europe);
+    assert_eq!(continents[0].rank, 1);
+    assert_eq!(continents[0].nations, 1);
+}
+
+#[sqlx::test(migrations = "../migrations")]
+pub async fn test_nationality_management(pool: Pool<Postgres>) {
+    let (client, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let moderator = pointercrate_test::user::system_user_with_perms(LIST_MODERATOR, &mut connection).await;
+    let admin = pointercrate_test::user::named_system_user_with_perms("Sarah", LIST_ADMINISTRATOR, &mut connection).await;
+
+    client
+        .post(
+            "/api/v1/nationalities/",
+            &json! {{"country_code": "AQ", "nation": "Testland", "continent": "europe"}},
+        )
+        .authorize_as(&moderator)
+        .expect_status(Status::Forbidden)
+        .execute()
+        .await;
+
+    client
+        .post(
+            "/api/v1/nationalities/",
+            &json! {{"country_code": "XQZ", "nation": "Testland", "continent": "europe"}},
+        )
+        .authorize_as(&admin)
+        .expect_status(Status::UnprocessableEntity)
+        .execute()
+        .await;
+
+    // Two letters, but not an assigned ISO 3166-1 code
+    let result: Value = client
+        .post(
+            "/api/v1/nationalities/",
+            &json! {{"country_code": "XQ", "nation": "Testland", "continent": "europe"}},
+        )
+        .authorize_as(&admin)
+        .expect_status(Status::UnprocessableEntity)
+        .get_result()
+        .await;
+
+    assert_eq!(result["code"].as_i64(), Some(42244));
+
+    client
+        .post(
+            "/api/v1/nationalities/",
+            &json! {{"country_code": "AQ", "nation": "germany", "continent": "europe"}},
+        )
+        .authorize_as(&admin)
+        .expect_status(Status::Conflict)
+        .execute()
+        .await;
+
+    let created: NationalityWithContinent = client
+        .post(
+            "/api/v1/nationalities/",
+            &json! {{"country_code": "aq", "nation": "Testland", "continent": "europe"}},
+        )
+        .authorize_as(&admin)
+        .expect_status(Status::Created)
+        .expect_header("Location", "/api/v1/nationalities/AQ/")
+        .get_result()
+        .await;
+
+    assert_eq!(created.nationality.iso_country_code, "AQ");
+    assert_eq!(created.continent, Continent::Europe);
+
+    let patched: NationalityWithContinent = client
+        .patch("/api/v1/nationalities/AQ/", &json! {{"continent": "asia"}})
+        .authorize_as(&admin)
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert_eq!(patched.nationality.nation, "Testland");
+    assert_eq!(patched.continent, Continent::Asia);
+
+    client
+        .post(
+            "/api/v1/nationalities/AQ/subdivisions/",
+            &json! {{"iso_code": "A-B", "name": "Testshire"}},
+        )
+        .authorize_as(&admin)
+        .expect_status(Status::UnprocessableEntity)
+        .execute()
+        .await;
+
+    let subdivision: Subdivision = client
+        .post(
+            "/api/v1/nationalities/AQ/subdivisions/",
+            &json! {{"iso_code": "ts", "name": "Testshire"}},
+        )
+        .authorize_as(&admin)
+        .expect_status(Status::Created)
+        .get_result()
+        .await;
+
+    assert_eq!(subdivision.iso_code, "TS");
+
+    client
+        .post(
+            "/api/v1/nationalities/AQ/subdivisions/",
+            &json! {{"iso_code": "TS", "name": "Other Testshire"}},
+        )
+        .authorize_as(&admin)
+        .expect_status(Status::Conflict)
+        .execute()
+        .await;
+
+    let subdivision: Subdivision = client
+        .patch("/api/v1/nationalities/AQ/subdivisions/TS/", &json! {{"name": "New Testshire"}})
+        .authorize_as(&admin)
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert_eq!(subdivision.name, "New Testshire");
+
+    // Deleting a subdivision keeps its players' nationality, and drops the subdivision's scores
+    let player = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let mut full_player = Player::by_id(player.id, &mut connection).await.unwrap();
+    full_player
+        .set_nationality(
+            Some(Nationality {
+                iso_country_code: "AQ".into(),
+                nation: "Testland".into(),
+                subdivision: Some(subdivision),
+            }),
+            &mut connection,
+        )
+        .await
+        .unwrap();
+
+    client
+        .add_demon(&moderator, "Bloodbath", 1, 100, "stardust1971", "stardust1971")
+        .await;
+
+    let ranking: Vec<RankedSubdivision> = client
+        .get("/api/v1/nationalities/AQ/subdivisions/ranking/")
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert_eq!(ranking.len(), 1);
+
+    client
+        .delete("/api/v1/nationalities/AQ/subdivisions/TS/")
+        .authorize_as(&admin)
+        .expect_status(Status::NoContent)
+        .execute()
+        .await;
+
+    let ranking: Vec<RankedSubdivision> = client
+        .get("/api/v1/nationalities/AQ/subdivisions/ranking/")
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert!(ranking.is_empty());
+
+    let full_player: FullPlayer = client
+        .get(format!("/api/v1/players/{}/", player.id))
+        .expect_status(Status::Ok)
+        .get_success_result()
+        .await;
+
+    let nationality = full_player.player.nationality.unwrap();
+
+    assert_eq!(nationality.iso_country_code, "AQ");
+    assert_eq!(nationality.subdivision, None);
+
+    let continents: Vec<RankedContinent> = client
+        .get("/api/v1/nationalities/continents/ranking/")
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert_eq!(continents.len(), 1);
+    assert_eq!(continents[0].continent, Continent::Asia);
+
+    let log: Vec<Value> = client
+        .get("/api/v1/nationalities/AQ/audit/")
+        .authorize_as(&admin)
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    let types: Vec<_> = log
+        .iter()
+        .map(|entry| (entry["subdivision"].as_str(), entry["type"].clone()))
+        .collect();
+
+    assert_eq!(
+        types,
+        vec![
+            (None, json!("Addition")),
+            (None, json!({"Modification": {"name": null, "continent": "europe"}})),
+            (Some("TS"), json!("Addition")),
+            (Some("TS"), json!({"Modification": {"name": "Testshire", "continent": null}})),
+            (Some("TS"), json!({"Modification": {"name": "New Testshire", "continent": null}})),
+            (Some("TS"), json!("Deletion")),
+        ]
+    );
+}
+
+#[sqlx::test(migrations = "../migrations")]
+pub async fn test_heatmap(pool: Pool<Postgres>) {
+    let (client, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let helper = pointercrate_test::user::system_user_with_perms(LIST_MODERATOR, &mut connection).await;
+
+    for (name, country_code, nation, subdivision) in [
+        ("stardust1971", "DE", "Germany", Some("BY")),
+        ("stardust1972", "DE", "Germany", None),
+        ("stardust1973", "FR", "France", None),
+    ] {
+        let player = DatabasePlayer::by_name_or_create(name, &mut connection).await.unwrap();
+        let mut player = Player::by_id(player.id, &mut connection).await.unwrap();
+        let nationality = Nationality {
+            iso_country_code: country_code.into(),
+            nation: nation.into(),
+            subdivision: subdivision.map(|code| Subdivision {
+                iso_code: code.into(),
+                name: String::new(),
+            }),
+        };
+        player.set_nationality(Some(nationality), &mut connection).await.unwrap();
+    }
+
+    client.add_demon(&helper, "Bloodbath", 1, 100, "stardust1971", "stardust1971").await;
+    client
+        .add_demon(&helper, "Sakupen Circles", 2, 100, "stardust1972", "stardust1972")
+        .await;
+    client.add_demon(&helper, "Acheron", 3, 100, "stardust1973", "stardust1973").await;
+
+    let heatmap: Heatmap = client
+        .get("/api/v1/nationalities/heatmap/?metric=players")
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert_eq!(heatmap.metric, HeatmapMetric::Players);
+    assert_eq!(heatmap.nations.len(), 2);
+    assert_eq!(heatmap.nations[0].country_code, "DE");
+    assert_eq!(heatmap.nations[0].value, 2.0);
+    assert_eq!(heatmap.nations[0].normalized, 1.0);
+    assert_eq!(heatmap.nations[1].country_code, "FR");
+    assert_eq!(heatmap.nations[1].normalized, 0.5);
+    assert_eq!(heatmap.subdivisions.len(), 1);
+    assert_eq!(heatmap.subdivisions[0].subdivision_code.as_deref(), Some("BY"));
+    assert_eq!(heatmap.subdivisions[0].normalized, 0.5);
+
+    let heatmap: Heatmap = client
+        .get("/api/v1/nationalities/heatmap/")
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert_eq!(heatmap.metric, HeatmapMetric::Score);
+    assert_eq!(heatmap.nations[0].country_code, "DE");
+
+    // Verifications are not records
+    let heatmap: Heatmap = client
+        .get("/api/v1/nationalities/heatmap/?metric=records")
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert!(heatmap.nations.is_empty());
+
+    client
+        .get("/api/v1/nationalities/heatmap/?metric=demons")
+        .expect_status(Status::BadRequest)
+        .execute()
+        .await;
+
+    let svg = client
+        .get("/demonlist/statsviewer/heatmap.svg?metric=players")
+        .expect_status(Status::Ok)
+        .expect_header("Content-Type", "image/svg+xml")
+        .execute()
+        .await
+        .into_string()
+        .await
+        .unwrap();
+
+    assert!(svg.contains("#de, #de > path"));
+    assert!(svg.contains("#fr, #fr > path"));
+    assert!(!svg.contains("heatmap.css"));
+
+    let png = client
+        .get("/demonlist/statsviewer/heatmap.png?width=100")
+        .expect_status(Status::Ok)
+        .expect_header("Content-Type", "image/png")
+        .execute()
+        .await
+        .into_bytes()
+        .await
+        .unwrap();
+
+    assert!(png.starts_with(b"\x89PNG"));
+
+    // Requested widths are rounded up to one of the supported widths. The width is the first field of the IHDR chunk.
+    assert_eq!(png[16..20], 640u32.to_be_bytes());
+
+    client
+        .get("/demonlist/statsviewer/heatmap.png?list=nonexistent")
+        .expect_status(Status::NotFound)
+        .execute()
+        .await;
+}
@@ -1,6 +1,18 @@
-use pointercrate_demonlist::player::{claim::PlayerClaim, DatabasePlayer, FullPlayer};
+use pointercrate_demonlist::{
+    player::{claim::PlayerClaim, DatabasePlayer, FullPlayer},
+    record::{VideoCheckResult, VideoMetadata},
+    LIST_HELPER,
+};
+use pointercrate_demonlist_api::VideoVerifier;
+use pointercrate_integrate::account::{GdAccount, GdBackend};
+use pointercrate_test::{
+    demonlist::{setup_rocket_with, StaticGdBackend, StaticVerifier},
+    TestClient,
+};
+use pointercrate_user::auth::{AuthenticatedUser, PasswordOrBrowser};
 use rocket::http::Status;
-use sqlx::{Pool, Postgres};
+use sqlx::{pool::PoolConnection, Pool, Postgres};
+use std::sync::Arc;
 
 #[sqlx::test(migrations = "../migrations")]
 async fn test_put_claim(pool: Pool<Postgres>) {
@@ -26,7 +38,8 @@ async fn test_put_claim(pool: Pool<Postgres>) {
             user_id: user.user().id,
             player_id,
             verified: false,
-            lock_submissions: false
+            lock_submissions: false,
+            third_party_approval: false
         }
     );
 }
@@ -67,3 +80,124 @@ async fn test_players_me(pool: Pool<Postgres>) {
 
     assert_eq!(claimed.player.base.id, player_id);
 }
+
+/// Sets up a claim on a player whose Geometry Dash account displays the given comments. If
+/// `post_code` is set, a verification code is issued and posted as an additional comment.
+async fn setup_gd_verification(
+    pool: Pool<Postgres>, post_code: bool,
+) -> (TestClient, PoolConnection<Postgres>, AuthenticatedUser<PasswordOrBrowser>, i32) {
+    let mut connection = pool.acquire().await.unwrap();
+
+    let user = pointercrate_test::user::add_normal_user(&mut connection).await;
+    let player = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let claim = player.initiate_claim(user.user().id, &mut connection).await.unwrap();
+
+    let mut comments = vec!["gg".to_string()];
+
+    if post_code {
+        let code = claim.issue_verification_code(&mut connection).await.unwrap();
+
+        comments.push(format!("verifying my pointercrate claim: {}", code));
+    }
+
+    let backend = StaticGdBackend {
+        account: GdAccount {
+            account_id: 71,
+            name: "Stardust1971".to_string(),
+        },
+        comments,
+        profile_links: vec![],
+    };
+
+    let (client, connection) = setup_rocket_with(pool, |rocket| {
+        rocket
+            .manage(Arc::new(StaticVerifier(VideoCheckResult::Available, VideoMetadata::default())) as Arc<dyn VideoVerifier>)
+            .manage(Arc::new(backend) as Arc<dyn GdBackend>)
+    })
+    .await;
+
+    (client, connection, user, player.id)
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_verify_claim_through_gd_account(pool: Pool<Postgres>) {
+    let (client, mut connection, user, player_id) = setup_gd_verification(pool, true).await;
+
+    let claim: PlayerClaim = client
+        .post(format!("/api/v1/players/{}/claims/{}/verify/", player_id, user.user().id), &())
+        .authorize_as(&user)
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert!(claim.verified);
+
+    let player = DatabasePlayer::by_id(player_id, &mut connection).await.unwrap();
+
+    assert_eq!(player.gd_account_id(&mut connection).await.unwrap(), Some(71));
+
+    let audit_entries = sqlx::query!(
+        "SELECT userid, gd_account_id FROM player_claim_verifications WHERE player_id = $1",
+        player_id
+    )
+    .fetch_all(&mut *connection)
+    .await
+    .unwrap();
+
+    assert_eq!(audit_entries.len(), 1);
+    assert_eq!(audit_entries[0].userid, user.user().id);
+    assert_eq!(audit_entries[0].gd_account_id, Some(71));
+
+    // The code was used up
+    let claim = PlayerClaim::get(user.user().id, player_id, &mut connection).await.unwrap();
+
+    assert!(claim.verification_code(&mut connection).await.unwrap().is_none());
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_verify_claim_without_code(pool: Pool<Postgres>) {
+    let (client, mut connection, user, player_id) = setup_gd_verification(pool, false).await;
+    let claim_url = format!("/api/v1/players/{}/claims/{}/", player_id, user.user().id);
+
+    let result: serde_json::Value = client
+        .post(format!("{}verify/", claim_url), &())
+        .authorize_as(&user)
+        .expect_status(Status::Conflict)
+        .get_result()
+        .await;
+
+    assert_eq!(result["code"], 40911);
+
+    // Nobody but the claimant can request codes
+    let other_user = pointercrate_test::user::named_system_user_with_perms("Stardust", LIST_HELPER, &mut connection).await;
+
+    client
+        .post(format!("{}verification-code/", claim_url), &())
+        .authorize_as(&other_user)
+        .expect_status(Status::NotFound)
+        .execute()
+        .await;
+
+    let code: serde_json::Value = client
+        .post(format!("{}verification-code/", claim_url), &())
+        .authorize_as(&user)
+        .expect_status(Status::Created)
+        .get_result()
+        .await;
+
+    assert!(code["code"].as_str().unwrap().starts_with("pc-"));
+
+    // The newly issued code was not posted on the account
+    let result: serde_json::Value = client
+        .post(format!("{}verify/", claim_url), &())
+        .authorize_as(&user)
+        .expect_status(Status::UnprocessableEntity)
+        .get_result()
+        .await;
+
+    assert_eq!(result["code"], 42242);
+
+    let claim = PlayerClaim::get(user.user().id, player_id, &mut connection).await.unwrap();
+
+    assert!(!claim.verified);
+}
@@ -0,0 +1,386 @@
+use pointercrate_demonlist::{
+    creator::{CreatorRole, Part},
+    player::{DatabasePlayer, FullPlayer, PlayerMerge},
+    record::RecordStatus,
+    LIST_ADMINISTRATOR, LIST_MODERATOR,
+};
+use rocket::http::Status;
+use serde_json::{json, Value};
+use sqlx::{Pool, Postgres};
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_merge_preview(pool: Pool<Postgres>) {
+    let (client, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+    let moderator = pointercrate_test::user::system_user_with_perms(LIST_MODERATOR, &mut connection).await;
+
+    let player1 = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let player2 = DatabasePlayer::by_name_or_create("stardust1972", &mut connection).await.unwrap();
+
+    let demon1 = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 87, player1.id, player1.id, &mut connection).await;
+    let demon2 = pointercrate_test::demonlist::add_demon("Sonic Wave", 2, 87, player2.id, player2.id, &mut connection).await;
+
+    let record1 = pointercrate_test::demonlist::add_simple_record(90, player1.id, demon1, RecordStatus::Approved, &mut connection).await;
+    let record2 = pointercrate_test::demonlist::add_simple_record(95, player2.id, demon1, RecordStatus::Approved, &mut connection).await;
+    let record3 = pointercrate_test::demonlist::add_simple_record(100, player1.id, demon2, RecordStatus::Approved, &mut connection).await;
+
+    let preview: Value = client
+        .get(format!("/api/v1/players/{}/merge-preview/{}/", player2.id, player1.id))
+        .authorize_as(&moderator)
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    let ids = |key: &str| {
+        preview[key]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|value| value["id"].as_i64().unwrap() as i32)
+            .collect::<Vec<_>>()
+    };
+
+    // The moved record takes over the progress of the record with higher progress, which gets deleted
+    assert_eq!(ids("deleted_records"), vec![record2]);
+    assert_eq!(ids("moved_records"), vec![record1, record3]);
+    assert_eq!(ids("verified"), vec![demon1]);
+    assert_eq!(ids("published"), vec![demon1]);
+    assert!(preview["score_after"].as_f64().unwrap() > preview["score_before"].as_f64().unwrap());
+
+    // Nothing actually happened
+    let player: FullPlayer = client
+        .get(format!("/api/v1/players/{}/", player1.id))
+        .expect_status(Status::Ok)
+        .get_success_result()
+        .await;
+
+    assert_eq!(player.records.len(), 2);
+
+    let player: FullPlayer = client
+        .get(format!("/api/v1/players/{}/", player2.id))
+        .expect_status(Status::Ok)
+        .get_success_result()
+        .await;
+
+    assert_eq!(player.records.len(), 1);
+    assert_eq!(player.records[0].id, record2);
+    assert!(PlayerMerge::into_player(player2.id, &mut connection).await.unwrap().is_empty());
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_merge_preview_requires_moderator(pool: Pool<Postgres>) {
+    let (client, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+    let user = pointercrate_test::user::add_normal_user(&mut connection).await;
+
+    let player1 = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let player2 = DatabasePlayer::by_name_or_create("stardust1972", &mut connection).await.unwrap();
+
+    client
+        .get(format!("/api/v1/players/{}/merge-preview/{}/", player2.id, player1.id))
+        .authorize_as(&user)
+        .expect_status(Status::Forbidden)
+        .execute()
+        .await;
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_undo_merge(pool: Pool<Postgres>) {
+    let (client, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+    let moderator = pointercrate_test::user::system_user_with_perms(LIST_MODERATOR, &mut connection).await;
+    let administrator = pointercrate_test::user::named_system_user_with_perms("Sarah", LIST_ADMINISTRATOR, &mut connection).await;
+
+    let player1 = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let player2 = DatabasePlayer::by_name_or_create("stardust1972", &mut connection).await.unwrap();
+
+    let demon1 = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 87, player1.id, player1.id, &mut connection).await;
+
+    let record1 = pointercrate_test::demonlist::add_simple_record(90, player1.id, demon1, RecordStatus::Approved, &mut connection).await;
+    let record2 = pointercrate_test::demonlist::add_simple_record(95, player2.id, demon1, RecordStatus::Approved, &mut connection).await;
+
+    client
+        .patch_player(player2.id, &moderator, json! {{"name": "stardust1971"}})
+        .await
+        .execute()
+        .await;
+
+    let merges: Vec<PlayerMerge> = client
+        .get(format!("/api/v1/players/{}/merges/", player2.id))
+        .authorize_as(&moderator)
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert_eq!(merges.len(), 1);
+    assert_eq!(merges[0].merged_id, player1.id);
+    assert_eq!(merges[0].merged_name, "stardust1971");
+    assert_eq!(merges[0].undone_at, None);
+
+    // Only administrators can undo merges
+    client
+        .post(format!("/api/v1/players/{}/merges/{}/undo/", player2.id, merges[0].id), &())
+        .authorize_as(&moderator)
+        .expect_status(Status::Forbidden)
+        .execute()
+        .await;
+
+    // The merged player's name is still in use by the remaining player
+    client
+        .post(format!("/api/v1/players/{}/merges/{}/undo/", player2.id, merges[0].id), &())
+        .authorize_as(&administrator)
+        .expect_status(Status::Conflict)
+        .execute()
+        .await;
+
+    client
+        .patch_player(player2.id, &moderator, json! {{"name": "stardust1972"}})
+        .await
+        .execute()
+        .await;
+
+    let undone: PlayerMerge = client
+        .post(format!("/api/v1/players/{}/merges/{}/undo/", player2.id, merges[0].id), &())
+        .authorize_as(&administrator)
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert!(undone.undone_at.is_some());
+
+    // Both players and their records are back
+    let restored: FullPlayer = client
+        .get(format!("/api/v1/players/{}/", player1.id))
+        .expect_status(Status::Ok)
+        .get_success_result()
+        .await;
+
+    assert_eq!(restored.player.base.name, "stardust1971");
+    assert_eq!(restored.records.len(), 1);
+    assert_eq!(restored.records[0].id, record1);
+    assert_eq!(restored.records[0].progress, 90);
+    assert_eq!(restored.verified.len(), 1);
+    assert_eq!(restored.published.len(), 1);
+
+    let remaining: FullPlayer = client
+        .get(format!("/api/v1/players/{}/", player2.id))
+        .expect_status(Status::Ok)
+        .get_success_result()
+        .await;
+
+    assert_eq!(remaining.records.len(), 1);
+    assert_eq!(remaining.records[0].id, record2);
+    assert_eq!(remaining.records[0].progress, 95);
+    assert!(remaining.verified.is_empty());
+
+    // A merge can only be undone once
+    client
+        .post(format!("/api/v1/players/{}/merges/{}/undo/", player2.id, merges[0].id), &())
+        .authorize_as(&administrator)
+        .expect_status(Status::Conflict)
+        .execute()
+        .await;
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_undo_merge_keeps_modified_records(pool: Pool<Postgres>) {
+    let (client, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+    let moderator = pointercrate_test::user::system_user_with_perms(LIST_MODERATOR, &mut connection).await;
+    let administrator = pointercrate_test::user::named_system_user_with_perms("Sarah", LIST_ADMINISTRATOR, &mut connection).await;
+
+    let player1 = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let player2 = DatabasePlayer::by_name_or_create("stardust1972", &mut connection).await.unwrap();
+
+    let demon1 = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 87, player2.id, player2.id, &mut connection).await;
+    let demon2 = pointercrate_test::demonlist::add_demon("Sonic Wave", 2, 87, player2.id, player2.id, &mut connection).await;
+
+    let record1 = pointercrate_test::demonlist::add_simple_record(90, player1.id, demon1, RecordStatus::Approved, &mut connection).await;
+    let record2 = pointercrate_test::demonlist::add_simple_record(95, player2.id, demon1, RecordStatus::Approved, &mut connection).await;
+    let record3 = pointercrate_test::demonlist::add_simple_record(90, player1.id, demon2, RecordStatus::Approved, &mut connection).await;
+
+    client
+        .patch_player(player2.id, &moderator, json! {{"name": "stardust1971"}})
+        .await
+        .execute()
+        .await;
+
+    // Someone updates one of the moved records after the merge
+    sqlx::query!("UPDATE records SET progress = 99 WHERE id = $1", record3)
+        .execute(&mut *connection)
+        .await
+        .unwrap();
+
+    client
+        .patch_player(player2.id, &moderator, json! {{"name": "stardust1972"}})
+        .await
+        .execute()
+        .await;
+
+    let merge = PlayerMerge::into_player(player2.id, &mut connection).await.unwrap().remove(0);
+
+    let undone: PlayerMerge = client
+        .post(format!("/api/v1/players/{}/merges/{}/undo/", player2.id, merge.id), &())
+        .authorize_as(&administrator)
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert_eq!(undone.conflicting_records, Some(vec![record3]));
+
+    // The modified record stays with the remaining player, everything else is restored
+    let restored: FullPlayer = client
+        .get(format!("/api/v1/players/{}/", player1.id))
+        .expect_status(Status::Ok)
+        .get_success_result()
+        .await;
+
+    assert_eq!(restored.records.len(), 1);
+    assert_eq!(restored.records[0].id, record1);
+    assert_eq!(restored.records[0].progress, 90);
+
+    let remaining: FullPlayer = client
+        .get(format!("/api/v1/players/{}/", player2.id))
+        .expect_status(Status::Ok)
+        .get_success_result()
+        .await;
+
+    let mut records = remaining
+        .records
+        .iter()
+        .map(|record| (record.id, record.progress))
+        .collect::<Vec<_>>();
+    records.sort();
+
+    assert_eq!(records, vec![(record2, 95), (record3, 99)]);
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_expired_merge_snapshots_are_discarded(pool: Pool<Postgres>) {
+    let (client, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+    let moderator = pointercrate_test::user::system_user_with_perms(LIST_MODERATOR, &mut connection).await;
+    let administrator = pointercrate_test::user::named_system_user_with_perms("Sarah", LIST_ADMINISTRATOR, &mut connection).await;
+
+    let player1 = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let player2 = DatabasePlayer::by_name_or_create("stardust1972", &mut connection).await.unwrap();
+
+    client
+        .patch_player(player2.id, &moderator, json! {{"name": "stardust1971"}})
+        .await
+        .execute()
+        .await;
+
+    sqlx::query!(
+        "UPDATE player_merges SET merged_at = merged_at - INTERVAL '31 days' WHERE merged_id = $1",
+        player1.id
+    )
+    .execute(&mut *connection)
+    .await
+    .unwrap();
+
+    PlayerMerge::expire_snapshots(30, &mut connection).await.unwrap();
+
+    let expired = sqlx::query!(
+        r#"SELECT snapshot IS NULL AS "expired!" FROM player_merges WHERE merged_id = $1"#,
+        player1.id
+    )
+    .fetch_one(&mut *connection)
+    .await
+    .unwrap()
+    .expired;
+
+    assert!(expired);
+
+    let merge = PlayerMerge::into_player(player2.id, &mut connection).await.unwrap().remove(0);
+
+    client
+        .patch_player(player2.id, &moderator, json! {{"name": "stardust1972"}})
+        .await
+        .execute()
+        .await;
+
+    client
+        .post(format!("/api/v1/players/{}/merges/{}/undo/", player2.id, merge.id), &())
+        .authorize_as(&administrator)
+        .expect_status(Status::Conflict)
+        .execute()
+        .await;
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_merge_folds_creator_entries(pool: Pool<Postgres>) {
+    let (client, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+    let moderator = pointercrate_test::user::system_user_with_perms(LIST_MODERATOR, &mut connection).await;
+    let administrator = pointercrate_test::user::named_system_user_with_perms("Sarah", LIST_ADMINISTRATOR, &mut connection).await;
+
+    let player1 = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let player2 = DatabasePlayer::by_name_or_create("stardust1972", &mut connection).await.unwrap();
+
+    let demon = client.add_demon(&moderator, "Bloodbath", 1, 100, "Riot", "Riot").await;
+    let url = format!("/api/v2/demons/{}/creators/", demon.demon.base.id);
+
+    client
+        .post(
+            &url,
+            &json! {{"creator": "stardust1971", "roles": ["host"], "part": {"start": 34, "end": 52}}},
+        )
+        .authorize_as(&moderator)
+        .expect_status(Status::Created)
+        .execute()
+        .await;
+
+    client
+        .post(&url, &json! {{"creator": "stardust1972", "roles": ["decoration"]}})
+        .authorize_as(&moderator)
+        .expect_status(Status::Created)
+        .execute()
+        .await;
+
+    client
+        .patch_player(player2.id, &moderator, json! {{"name": "stardust1971"}})
+        .await
+        .execute()
+        .await;
+
+    // The remaining player is credited with the roles of both players, and the merged player's part
+    let merged: FullPlayer = client
+        .get(format!("/api/v1/players/{}/", player2.id))
+        .expect_status(Status::Ok)
+        .get_success_result()
+        .await;
+
+    assert_eq!(merged.created.len(), 1);
+    assert_eq!(merged.created[0].roles, vec![CreatorRole::Host, CreatorRole::Decoration]);
+    assert_eq!(merged.created[0].part, Some(Part { start: 34, end: 52 }));
+
+    client
+        .patch_player(player2.id, &moderator, json! {{"name": "stardust1972"}})
+        .await
+        .execute()
+        .await;
+
+    let merge = PlayerMerge::into_player(player2.id, &mut connection).await.unwrap().remove(0);
+
+    client
+        .post(format!("/api/v1/players/{}/merges/{}/undo/", player2.id, merge.id), &())
+        .authorize_as(&administrator)
+        .expect_status(Status::Ok)
+        .execute()
+        .await;
+
+    let restored: FullPlayer = client
+        .get(format!("/api/v1/players/{}/", player1.id))
+        .expect_status(Status::Ok)
+        .get_success_result()
+        .await;
+
+    assert_eq!(restored.created.len(), 1);
+    assert_eq!(restored.created[0].roles, vec![CreatorRole::Host]);
+    assert_eq!(restored.created[0].part, Some(Part { start: 34, end: 52 }));
+
+    let remaining: FullPlayer = client
+        .get(format!("/api/v1/players/{}/", player2.id))
+        .expect_status(Status::Ok)
+        .get_success_result()
+        .await;
+
+    assert_eq!(remaining.created.len(), 1);
+    assert_eq!(remaining.created[0].roles, vec![CreatorRole::Decoration]);
+    assert_eq!(remaining.created[0].part, None);
+}
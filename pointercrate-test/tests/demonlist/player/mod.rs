@@ -9,6 +9,8 @@ use serde_json::json;
 use sqlx::{PgConnection, Pool, Postgres};
 
 mod claim;
+mod merge;
+mod profile;
 mod score;
 
 async fn create_players(connection: &mut PgConnection) -> (DatabasePlayer, DatabasePlayer) {
@@ -365,9 +367,56 @@ async fn test_player_merge(pool: Pool<Postgres>) {
     assert_eq!(patched.records[0].progress, 95);
     assert_eq!(patched.player.base.id, player2.id);
 
+    assert_eq!(patched.previous_names, vec!["stardust1972".to_string()]);
+
+    // The merged-away id now redirects to the player it was merged into
     client
         .get(format!("/api/v1/players/{}/", player1.id))
+        .expect_status(Status::PermanentRedirect)
+        .expect_header("Location", format!("/api/v1/players/{}/", player2.id))
+        .execute()
+        .await;
+
+    // Ids that never existed are still not found
+    client
+        .get(format!("/api/v1/players/{}/", player2.id + 100))
         .expect_status(Status::NotFound)
         .execute()
         .await;
 }
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_player_rename_keeps_alias(pool: Pool<Postgres>) {
+    let (client, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+    let moderator = pointercrate_test::user::system_user_with_perms(LIST_MODERATOR, &mut connection).await;
+
+    let player = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+
+    let patched: FullPlayer = client
+        .patch_player(player.id, &moderator, json! {{"name": "stardust1972"}})
+        .await
+        .get_success_result()
+        .await;
+
+    assert_eq!(patched.player.base.name, "stardust1972");
+    assert_eq!(patched.previous_names, vec!["stardust1971".to_string()]);
+
+    // Looking up the old name resolves to the renamed player, so submissions under the old name end up with them
+    assert_eq!(
+        DatabasePlayer::by_name("stardust1971", &mut connection).await.unwrap().id,
+        player.id
+    );
+    assert_eq!(
+        DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap().id,
+        player.id
+    );
+
+    // Renaming back to a previous name does not list the current name as a previous one
+    let patched: FullPlayer = client
+        .patch_player(player.id, &moderator, json! {{"name": "stardust1971"}})
+        .await
+        .get_success_result()
+        .await;
+
+    assert_eq!(patched.previous_names, vec!["stardust1972".to_string()]);
+}
@@ -0,0 +1,151 @@
+use pointercrate_demonlist::player::{DatabasePlayer, FullPlayer, PendingProfile, PlayerProfile};
+use pointercrate_user::MODERATOR;
+use rocket::http::Status;
+use serde_json::{json, Value};
+use sqlx::{Pool, Postgres};
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_profile_requires_verified_claim(pool: Pool<Postgres>) {
+    let (client, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+    let user = pointercrate_test::user::add_normal_user(&mut connection).await;
+    let player = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+
+    pointercrate_test::demonlist::put_claim(user.user().id, player.id, false, false, &mut connection).await;
+
+    client
+        .patch("/api/v1/players/me/profile/", &json! {{"bio": "Hello"}})
+        .authorize_as(&user)
+        .expect_status(Status::Forbidden)
+        .execute()
+        .await;
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_profile_approval(pool: Pool<Postgres>) {
+    let (client, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+    let user = pointercrate_test::user::add_normal_user(&mut connection).await;
+    let moderator = pointercrate_test::user::system_user_with_perms(MODERATOR, &mut connection).await;
+    let player = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+
+    pointercrate_test::demonlist::put_claim(user.user().id, player.id, true, false, &mut connection).await;
+
+    client
+        .patch(
+            "/api/v1/players/me/profile/",
+            &json! {{"youtube": "https://www.youtube.com/watch?v=dQw4w9WgXcQ"}},
+        )
+        .authorize_as(&user)
+        .expect_status(Status::UnprocessableEntity)
+        .execute()
+        .await;
+
+    let pending: PendingProfile = client
+        .patch(
+            "/api/v1/players/me/profile/",
+            &json! {{"bio": "Hello", "twitch": "Stardust1971", "youtube": "https://youtube.com/@stardust1971"}},
+        )
+        .authorize_as(&user)
+        .expect_status(Status::Accepted)
+        .get_result()
+        .await;
+
+    assert_eq!(pending.player, player);
+    assert_eq!(pending.profile.twitch.as_deref(), Some("stardust1971"));
+    assert_eq!(pending.profile.youtube.as_deref(), Some("https://www.youtube.com/@stardust1971"));
+
+    // Further changes build upon the pending ones
+    let pending: PendingProfile = client
+        .patch("/api/v1/players/me/profile/", &json! {{"discord": "stardust1971"}})
+        .authorize_as(&user)
+        .expect_status(Status::Accepted)
+        .get_result()
+        .await;
+
+    assert_eq!(pending.profile.bio.as_deref(), Some("Hello"));
+    assert_eq!(pending.profile.discord.as_deref(), Some("stardust1971"));
+
+    // Nothing is visible before a moderator approved the changes
+    let full_player: FullPlayer = client
+        .get(format!("/api/v1/players/{}/", player.id))
+        .expect_status(Status::Ok)
+        .get_success_result()
+        .await;
+
+    assert_eq!(full_player.profile, None);
+
+    client
+        .post(format!("/api/v1/players/{}/profile/approve/", player.id), &())
+        .authorize_as(&user)
+        .expect_status(Status::Forbidden)
+        .execute()
+        .await;
+
+    let listed: Vec<PendingProfile> = client
+        .get("/api/v1/players/profiles/pending/")
+        .authorize_as(&moderator)
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert_eq!(listed, vec![pending]);
+
+    let approved: Option<PlayerProfile> = client
+        .post(format!("/api/v1/players/{}/profile/approve/", player.id), &())
+        .authorize_as(&moderator)
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    let full_player: FullPlayer = client
+        .get(format!("/api/v1/players/{}/", player.id))
+        .expect_status(Status::Ok)
+        .get_success_result()
+        .await;
+
+    assert!(approved.is_some());
+    assert_eq!(full_player.profile, approved);
+
+    let own: Value = client
+        .get("/api/v1/players/me/profile/")
+        .authorize_as(&user)
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert_eq!(own["pending"], Value::Null);
+    assert_eq!(own["profile"]["bio"], "Hello");
+
+    // Rejected changes leave the live profile untouched
+    client
+        .patch("/api/v1/players/me/profile/", &json! {{"bio": "Something abusive"}})
+        .authorize_as(&user)
+        .expect_status(Status::Accepted)
+        .execute()
+        .await;
+
+    client
+        .post(format!("/api/v1/players/{}/profile/reject/", player.id), &())
+        .authorize_as(&moderator)
+        .expect_status(Status::NoContent)
+        .execute()
+        .await;
+
+    client
+        .post(format!("/api/v1/players/{}/profile/reject/", player.id), &())
+        .authorize_as(&moderator)
+        .expect_status(Status::NotFound)
+        .execute()
+        .await;
+
+    assert_eq!(PlayerProfile::of(player.id, &mut connection).await.unwrap(), approved);
+
+    // Moderators can remove live profiles
+    client
+        .delete(format!("/api/v1/players/{}/profile/", player.id))
+        .authorize_as(&moderator)
+        .expect_status(Status::NoContent)
+        .execute()
+        .await;
+
+    assert_eq!(PlayerProfile::of(player.id, &mut connection).await.unwrap(), None);
+}
@@ -2,7 +2,9 @@
 
 use pointercrate_core::etag::Taggable;
 use pointercrate_demonlist::{
-    player::{DatabasePlayer, FullPlayer},
+    list::{List, DEFAULT_LIST},
+    nationality::Nationality,
+    player::{DatabasePlayer, FullPlayer, Player},
     record::FullRecord,
     LIST_MODERATOR,
 };
@@ -70,16 +72,21 @@ pub async fn test_verifications_give_score(pool: Pool<Postgres>) {
 }
 
 async fn nationality_score(iso_country_code: &str, connection: &mut PgConnection) -> f64 {
-    sqlx::query!("SELECT score FROM nationalities WHERE iso_country_code = $1", iso_country_code)
-        .fetch_one(&mut *connection)
-        .await
-        .unwrap()
-        .score
+    sqlx::query!(
+        r#"SELECT COALESCE((SELECT score FROM nation_scores WHERE list = $1 AND nation = $2), 0) AS "score!""#,
+        DEFAULT_LIST,
+        iso_country_code
+    )
+    .fetch_one(&mut *connection)
+    .await
+    .unwrap()
+    .score
 }
 
 async fn subdivision_score(nation: &str, iso_code: &str, connection: &mut PgConnection) -> f64 {
     sqlx::query!(
-        "SELECT score FROM subdivisions WHERE nation = $1 AND iso_code = $2",
+        r#"SELECT COALESCE((SELECT score FROM subdivision_scores WHERE list = $1 AND nation = $2 AND subdivision = $3), 0) AS "score!""#,
+        DEFAULT_LIST,
         nation,
         iso_code
     )
@@ -134,7 +141,7 @@ pub async fn test_extended_progress_records_give_no_score(pool: Pool<Postgres>)
     let helper = pointercrate_test::user::system_user_with_perms(LIST_MODERATOR, &mut connection).await;
     let player = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
 
-    let list_size = std::env::var("LIST_SIZE").unwrap().parse::<i16>().unwrap();
+    let list_size = List::default_list(&mut connection).await.unwrap().list_size;
 
     let mut last_demon_id = 0;
 
@@ -174,7 +181,7 @@ pub async fn test_score_resets_if_last_record_removed(pool: Pool<Postgres>) {
     let helper = pointercrate_test::user::system_user_with_perms(LIST_MODERATOR, &mut connection).await;
     let player = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
 
-    let list_size = std::env::var("LIST_SIZE").unwrap().parse::<i16>().unwrap();
+    let list_size = List::default_list(&mut connection).await.unwrap().list_size;
 
     let mut last_demon_id = 0;
 
@@ -233,3 +240,72 @@ pub async fn test_score_resets_if_last_record_removed(pool: Pool<Postgres>) {
         "Removal of player's last record did not reset their score to 0"
     );
 }
+
+#[sqlx::test(migrations = "../migrations")]
+pub async fn test_score_history(pool: Pool<Postgres>) {
+    let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let helper = pointercrate_test::user::system_user_with_perms(LIST_MODERATOR, &mut connection).await;
+    let player = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let mut player = Player::by_id(player.id, &mut connection).await.unwrap();
+    player
+        .set_nationality(
+            Some(Nationality {
+                iso_country_code: "DE".into(),
+                nation: "Germany".into(),
+                subdivision: None,
+            }),
+            &mut connection,
+        )
+        .await
+        .unwrap();
+
+    let demon = clnt.add_demon(&helper, "Bloodbath", 1, 100, "stardust1972", "stardust1972").await;
+
+    let submission = serde_json::json! {{"progress": 100, "demon": demon.demon.base.id, "player": "stardust1971", "video": "https://youtube.com/watch?v=1234567890", "status": "Approved"}};
+
+    let record = clnt
+        .post("/api/v1/records/", &submission)
+        .authorize_as(&helper)
+        .expect_status(Status::Ok)
+        .get_success_result::<FullRecord>()
+        .await;
+
+    clnt.patch(
+        format!("/api/v1/records/{}/", record.id),
+        &serde_json::json!({"status": "Rejected"}),
+    )
+    .authorize_as(&helper)
+    .header("If-Match", record.etag_string())
+    .expect_status(Status::Ok)
+    .execute()
+    .await;
+
+    let history: Vec<serde_json::Value> = clnt
+        .get(format!("/api/v1/players/{}/history/", player.base.id))
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    // One snapshot from the approval, one from the rejection
+    assert_eq!(history.len(), 2, "{:?}", history);
+    assert_ne!(history[0]["score"].as_f64(), Some(0.0));
+    assert!(history[0]["rank"].as_i64().is_some());
+    assert_eq!(history[1]["score"].as_f64(), Some(0.0));
+    assert!(history[1]["rank"].is_null());
+
+    let history: Vec<serde_json::Value> = clnt
+        .get("/api/v1/nationalities/de/history/")
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert_eq!(history.len(), 2, "{:?}", history);
+    assert_eq!(history[0]["rank"].as_i64(), Some(1));
+    assert!(history[1]["rank"].is_null());
+
+    clnt.get("/api/v1/players/0/history/")
+        .expect_status(Status::NotFound)
+        .execute()
+        .await;
+}
@@ -1,14 +1,26 @@
 use pointercrate_core::error::PointercrateError;
 use pointercrate_core::etag::Taggable;
+use pointercrate_core::pool::audit_connection;
 use pointercrate_demonlist::{
     error::DemonlistError,
     player::{DatabasePlayer, FullPlayer},
-    record::{note::Note, FullRecord, RecordStatus},
-    LIST_HELPER, LIST_MODERATOR,
+    record::{
+        evidence::{Evidence, EvidenceKind},
+        note::Note,
+        FullRecord, RecordStatus, VideoCheck, VideoCheckResult, VideoMetadata,
+    },
+    submitter::{ReputationThresholds, Submitter},
+    LIST_ADMINISTRATOR, LIST_HELPER, LIST_MODERATOR,
 };
-use pointercrate_test::{demonlist::add_simple_record, user::system_user_with_perms};
+use pointercrate_demonlist_api::{CidrProxyDetector, ProxyDetector, VideoVerifier};
+use pointercrate_test::{
+    demonlist::{add_simple_record, setup_rocket_with, setup_rocket_with_thresholds, setup_rocket_with_verifier, StaticVerifier},
+    user::{named_system_user_with_perms, system_user_with_perms},
+};
+use pointercrate_user::MODERATOR;
 use rocket::http::Status;
 use sqlx::{PgConnection, Pool, Postgres};
+use std::sync::Arc;
 
 #[sqlx::test(migrations = "../migrations")]
 async fn paginate_records_unauthorized(pool: Pool<Postgres>) {
@@ -118,6 +130,175 @@ async fn unauthed_submit_for_player_with_locked_submission(pool: Pool<Postgres>)
     )
 }
 
+#[sqlx::test(migrations = "../migrations")]
+async fn third_party_submission_held_for_claimant(pool: Pool<Postgres>) {
+    let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let user = pointercrate_test::user::add_normal_user(&mut connection).await;
+    let helper = named_system_user_with_perms("Stardust", LIST_HELPER, &mut connection).await;
+    let player1 = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let demon1 = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 87, player1.id, player1.id, &mut connection).await;
+    let demon2 = pointercrate_test::demonlist::add_demon("Bloodlust", 2, 53, player1.id, player1.id, &mut connection).await;
+
+    pointercrate_test::demonlist::put_claim(user.user().id, player1.id, true, true, &mut connection).await;
+
+    clnt.patch(
+        format!("/api/v1/players/{}/claims/{}/", player1.id, user.user().id),
+        &serde_json::json! {{"third_party_approval": true}},
+    )
+    .authorize_as(&user)
+    .expect_status(Status::Ok)
+    .execute()
+    .await;
+
+    let submission = serde_json::json! {{"progress": 100, "demon": demon1, "player": "stardust1971", "video": "https://youtube.com/watch?v=1234567890", "raw_footage": "https://pointercrate.com"}};
+
+    let pending: serde_json::Value = clnt
+        .post("/api/v1/records/", &submission)
+        .expect_status(Status::Accepted)
+        .get_result()
+        .await;
+
+    // Nothing reaches the list team before the claimant accepted it
+    let records: Vec<serde_json::Value> = clnt
+        .get(format!("/api/v1/records/?player={}", player1.id))
+        .authorize_as(&helper)
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert!(records.is_empty());
+
+    let held: Vec<serde_json::Value> = clnt
+        .get("/api/v1/records/pending/")
+        .authorize_as(&user)
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert_eq!(held.len(), 1);
+    assert_eq!(held[0]["id"], pending["id"]);
+    assert!(held[0].get("submitter").is_none());
+
+    let record: serde_json::Value = clnt
+        .post(format!("/api/v1/records/pending/{}/accept/", pending["id"]), &())
+        .authorize_as(&user)
+        .expect_status(Status::Ok)
+        .get_success_result()
+        .await;
+
+    assert_eq!(record["status"].as_str(), Some("submitted"));
+    assert_eq!(record["progress"].as_i64(), Some(100));
+
+    let records: Vec<serde_json::Value> = clnt
+        .get(format!("/api/v1/records/?player={}", player1.id))
+        .authorize_as(&helper)
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert_eq!(records.len(), 1);
+
+    // Declined submissions are discarded
+    let submission = serde_json::json! {{"progress": 100, "demon": demon2, "player": "stardust1971", "video": "https://youtube.com/watch?v=0987654321", "raw_footage": "https://pointercrate.com"}};
+
+    let pending: serde_json::Value = clnt
+        .post("/api/v1/records/", &submission)
+        .expect_status(Status::Accepted)
+        .get_result()
+        .await;
+
+    clnt.post(format!("/api/v1/records/pending/{}/decline/", pending["id"]), &())
+        .authorize_as(&user)
+        .expect_status(Status::NoContent)
+        .execute()
+        .await;
+
+    let held: Vec<serde_json::Value> = clnt
+        .get("/api/v1/records/pending/")
+        .authorize_as(&user)
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert!(held.is_empty());
+
+    clnt.post(format!("/api/v1/records/pending/{}/accept/", pending["id"]), &())
+        .authorize_as(&user)
+        .expect_status(Status::NotFound)
+        .execute()
+        .await;
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn held_submissions_released_when_claim_changes(pool: Pool<Postgres>) {
+    let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let user = pointercrate_test::user::add_normal_user(&mut connection).await;
+    let helper = named_system_user_with_perms("Stardust", LIST_HELPER, &mut connection).await;
+    let moderator = named_system_user_with_perms("Sarah", MODERATOR, &mut connection).await;
+    let player1 = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let demon1 = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 87, player1.id, player1.id, &mut connection).await;
+    let demon2 = pointercrate_test::demonlist::add_demon("Bloodlust", 2, 53, player1.id, player1.id, &mut connection).await;
+
+    pointercrate_test::demonlist::put_claim(user.user().id, player1.id, true, true, &mut connection).await;
+
+    let claim_url = format!("/api/v1/players/{}/claims/{}/", player1.id, user.user().id);
+
+    for (demon, video, release) in [
+        (demon1, "https://youtube.com/watch?v=1234567890", "disable"),
+        (demon2, "https://youtube.com/watch?v=0987654321", "delete"),
+    ] {
+        clnt.patch(&claim_url, &serde_json::json! {{"third_party_approval": true}})
+            .authorize_as(&user)
+            .expect_status(Status::Ok)
+            .execute()
+            .await;
+
+        let submission = serde_json::json! {{"progress": 100, "demon": demon, "player": "stardust1971", "video": video, "raw_footage": "https://pointercrate.com"}};
+
+        clnt.post("/api/v1/records/", &submission)
+            .expect_status(Status::Accepted)
+            .execute()
+            .await;
+
+        // Once the claimant cannot approve the submission anymore, it enters the regular queue
+        match release {
+            "disable" => {
+                clnt.patch(&claim_url, &serde_json::json! {{"third_party_approval": false}})
+                    .authorize_as(&user)
+                    .expect_status(Status::Ok)
+                    .execute()
+                    .await
+            },
+            _ => {
+                clnt.delete(&claim_url)
+                    .authorize_as(&moderator)
+                    .expect_status(Status::NoContent)
+                    .execute()
+                    .await
+            },
+        }
+
+        let records: Vec<serde_json::Value> = clnt
+            .get(format!("/api/v1/records/?player={}&demon_id={}", player1.id, demon))
+            .authorize_as(&helper)
+            .expect_status(Status::Ok)
+            .get_result()
+            .await;
+
+        assert_eq!(records.len(), 1, "{}", release);
+        assert_eq!(records[0]["status"].as_str(), Some("submitted"), "{}", release);
+    }
+
+    let held = sqlx::query!("SELECT COUNT(*) AS \"count!\" FROM pending_submissions")
+        .fetch_one(&mut *connection)
+        .await
+        .unwrap();
+
+    assert_eq!(held.count, 0);
+}
+
 #[sqlx::test(migrations = "../migrations")]
 async fn submit_existing_record(pool: Pool<Postgres>) {
     let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
@@ -168,15 +349,16 @@ async fn test_no_submitter_info_on_unauthed_get(pool: Pool<Postgres>) {
 }
 
 #[sqlx::test(migrations = "../migrations")]
-async fn test_no_raw_footage_on_unauthed_get(pool: Pool<Postgres>) {
+async fn test_no_private_evidence_on_unauthed_get(pool: Pool<Postgres>) {
     let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
 
     let raw_footage = "https://youtube.com/watch?v=0987654321";
+    let click_audio = "https://pointercrate.com/clicks.mp3";
 
     let user = pointercrate_test::user::system_user_with_perms(LIST_HELPER, &mut connection).await;
     let player1 = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
     let demon1 = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 50, player1.id, player1.id, &mut connection).await;
-    let submission = serde_json::json! {{"progress": 100, "demon": demon1, "player": player1.name, "video": "https://youtube.com/watch?v=1234567890", "raw_footage": raw_footage, "status": "approved"}};
+    let submission = serde_json::json! {{"progress": 100, "demon": demon1, "player": player1.name, "video": "https://youtube.com/watch?v=1234567890", "raw_footage": raw_footage, "evidence": [{"kind": "click_audio", "url": click_audio, "is_public": true}], "status": "approved"}};
 
     let record: FullRecord = clnt
         .post("/api/v1/records/", &submission)
@@ -186,6 +368,9 @@ async fn test_no_raw_footage_on_unauthed_get(pool: Pool<Postgres>) {
         .await;
 
     let record: FullRecord = clnt.get(format!("/api/v1/records/{}/", record.id)).get_success_result().await;
+    assert_eq!(record.evidence.len(), 1);
+    assert_eq!(record.evidence[0].kind, EvidenceKind::ClickAudio);
+    assert_eq!(record.evidence[0].url, click_audio);
     assert_eq!(record.raw_footage, None);
 
     let record: FullRecord = clnt
@@ -193,9 +378,109 @@ async fn test_no_raw_footage_on_unauthed_get(pool: Pool<Postgres>) {
         .authorize_as(&user)
         .get_success_result()
         .await;
+    assert_eq!(record.evidence.len(), 2);
+    assert_eq!(record.evidence[0].kind, EvidenceKind::RawFootage);
+    assert_eq!(record.evidence[0].url, raw_footage);
+    assert!(!record.evidence[0].is_public);
+    // Still provided for API consumers predating typed evidence
     assert_eq!(record.raw_footage.as_deref(), Some(raw_footage));
 }
 
+#[sqlx::test(migrations = "../migrations")]
+async fn test_record_evidence(pool: Pool<Postgres>) {
+    let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let helper = system_user_with_perms(LIST_HELPER, &mut connection).await;
+    let player1 = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let demon1 = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 50, player1.id, player1.id, &mut connection).await;
+    let duplicate = add_simple_record(60, player1.id, demon1, RecordStatus::Submitted, &mut connection).await;
+    let record = add_simple_record(70, player1.id, demon1, RecordStatus::Submitted, &mut connection).await;
+
+    let evidence: Evidence = clnt
+        .post(
+            format!("/api/v1/records/{}/evidence/", duplicate),
+            &serde_json::json! {{"kind": "raw_footage", "url": "https://pointercrate.com/raw.mp4"}},
+        )
+        .authorize_as(&helper)
+        .expect_status(Status::Created)
+        .get_success_result()
+        .await;
+
+    assert!(!evidence.is_public);
+    assert!(!evidence.transferred);
+
+    // Second angles are videos and need to be hosted on a supported platform
+    clnt.post(
+        format!("/api/v1/records/{}/evidence/", duplicate),
+        &serde_json::json! {{"kind": "second_angle", "url": "https://pointercrate.com/handcam.mp4"}},
+    )
+    .authorize_as(&helper)
+    .expect_status(Status::UnprocessableEntity)
+    .execute()
+    .await;
+
+    let evidence: Evidence = clnt
+        .patch(
+            format!("/api/v1/records/{}/evidence/{}/", duplicate, evidence.id),
+            &serde_json::json! {{"is_public": true}},
+        )
+        .authorize_as(&helper)
+        .header("If-Match", evidence.etag_string())
+        .expect_status(Status::Ok)
+        .get_success_result()
+        .await;
+
+    assert!(evidence.is_public);
+
+    // Approving the 70% record deletes the 60% submission, whose evidence should carry over
+    let approved: FullRecord = clnt
+        .get(format!("/api/v1/records/{}/", record))
+        .authorize_as(&helper)
+        .get_success_result()
+        .await;
+
+    let approved: FullRecord = clnt
+        .patch(format!("/api/v1/records/{}/", record), &serde_json::json!({"status": "approved"}))
+        .authorize_as(&helper)
+        .header("If-Match", approved.etag_string())
+        .expect_status(Status::Ok)
+        .get_success_result()
+        .await;
+
+    assert_eq!(approved.evidence.len(), 1);
+    assert_eq!(approved.evidence[0].id, evidence.id);
+    assert!(approved.evidence[0].transferred);
+
+    clnt.delete(format!("/api/v1/records/{}/evidence/{}/", record, evidence.id))
+        .authorize_as(&helper)
+        .expect_status(Status::NoContent)
+        .execute()
+        .await;
+
+    clnt.delete(format!("/api/v1/records/{}/evidence/{}/", record, evidence.id))
+        .authorize_as(&helper)
+        .expect_status(Status::NotFound)
+        .execute()
+        .await;
+
+    // Like all other changes to records, changes to their evidence are audit logged
+    let log = sqlx::query!(
+        r#"SELECT
+            (SELECT COUNT(*) FROM record_evidence_additions WHERE id = $1) AS "additions!",
+            (SELECT COUNT(*) FROM record_evidence_modifications WHERE id = $1) AS "modifications!",
+            (SELECT COUNT(*) FROM record_evidence_deletions WHERE id = $1) AS "deletions!""#,
+        evidence.id
+    )
+    .fetch_one(&mut *connection)
+    .await
+    .unwrap();
+
+    assert_eq!(log.additions, 1);
+    // At least the publicity change, the transfer and the copy made upon deletion
+    assert!(log.modifications >= 3);
+    assert_eq!(log.deletions, 1);
+}
+
 #[sqlx::test(migrations = "../migrations")]
 async fn test_record_note_creation_and_deletion(pool: Pool<Postgres>) {
     let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
@@ -269,3 +554,548 @@ async fn test_record_deletion_updates_player_score(pool: Pool<Postgres>) {
 
     assert_eq!(player.player.score, 0.0f64, "Deleting approved record failed to lower player score");
 }
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_record_feeds(pool: Pool<Postgres>) {
+    let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let player = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let banned = DatabasePlayer::by_name_or_create("stardust1972", &mut connection).await.unwrap();
+    let demon = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 50, player.id, player.id, &mut connection).await;
+
+    let approved = add_simple_record(100, player.id, demon, RecordStatus::Approved, &mut connection).await;
+    let submitted = add_simple_record(100, banned.id, demon, RecordStatus::Submitted, &mut connection).await;
+
+    sqlx::query!("UPDATE players SET banned = TRUE WHERE id = $1", banned.id)
+        .execute(&mut *connection)
+        .await
+        .unwrap();
+    sqlx::query!("UPDATE records SET status_ = 'APPROVED' WHERE id = $1", submitted)
+        .execute(&mut *connection)
+        .await
+        .unwrap();
+
+    let feed = clnt
+        .get(format!("/demonlist/feeds/demons/{}/records/", demon))
+        .header("Accept", "application/atom+xml")
+        .expect_status(Status::Ok)
+        .expect_header("Content-Type", "application/atom+xml; charset=utf-8")
+        .expect_header("Cache-Control", "public, max-age=900")
+        .execute()
+        .await
+        .into_string()
+        .await
+        .unwrap();
+
+    assert!(feed.contains(&format!("urn:pointercrate:record:{}", approved)));
+    // Records of banned players must not show up in feeds
+    assert!(!feed.contains(&format!("urn:pointercrate:record:{}", submitted)));
+
+    let feed = clnt
+        .get(format!("/demonlist/feeds/players/{}/records/", player.id))
+        .expect_status(Status::Ok)
+        .execute()
+        .await
+        .into_string()
+        .await
+        .unwrap();
+
+    assert!(feed.contains(&format!("urn:pointercrate:record:{}", approved)));
+    assert!(feed.contains(&format!("/demonlist/statsviewer/?player={}", player.id)));
+
+    let response = clnt
+        .get(format!("/demonlist/feeds/players/{}/records/", player.id))
+        .expect_status(Status::Ok)
+        .execute()
+        .await;
+    let last_modified = response.headers().get_one("Last-Modified").unwrap().to_string();
+
+    // Feed readers that already have the latest version of a feed should not have to download it again
+    clnt.get(format!("/demonlist/feeds/players/{}/records/", player.id))
+        .header("If-Modified-Since", last_modified.clone())
+        .expect_status(Status::NotModified)
+        .expect_header("Last-Modified", last_modified)
+        .execute()
+        .await;
+
+    clnt.get(format!("/demonlist/feeds/players/{}/records/", player.id))
+        .header("If-Modified-Since", "Thu, 01 Jan 1970 00:00:00 GMT")
+        .expect_status(Status::Ok)
+        .execute()
+        .await;
+
+    clnt.get("/demonlist/feeds/players/0/records/")
+        .expect_status(Status::NotFound)
+        .execute()
+        .await;
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_record_review_locks(pool: Pool<Postgres>) {
+    let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let helper1 = named_system_user_with_perms("Helper1", LIST_HELPER, &mut connection).await;
+    let helper2 = named_system_user_with_perms("Helper2", LIST_HELPER, &mut connection).await;
+    let moderator = named_system_user_with_perms("Moderator", LIST_MODERATOR, &mut connection).await;
+
+    let player = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let demon = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 50, player.id, player.id, &mut connection).await;
+    let locked = add_simple_record(60, player.id, demon, RecordStatus::Submitted, &mut connection).await;
+    let unlocked = add_simple_record(70, player.id, demon, RecordStatus::Submitted, &mut connection).await;
+
+    let lock: serde_json::Value = clnt
+        .put(format!("/api/v1/records/{}/lock/", locked))
+        .authorize_as(&helper1)
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert_eq!(lock["reviewer"], "Helper1");
+
+    // Someone else cannot take over the lock while it is active
+    let result: serde_json::Value = clnt
+        .put(format!("/api/v1/records/{}/lock/", locked))
+        .authorize_as(&helper2)
+        .expect_status(Status::Conflict)
+        .get_result()
+        .await;
+
+    assert_eq!(result["code"].as_i64(), Some(40910));
+
+    let record: FullRecord = clnt
+        .get(format!("/api/v1/records/{}/", locked))
+        .authorize_as(&helper2)
+        .get_success_result()
+        .await;
+
+    assert_eq!(record.lock.as_ref().map(|lock| lock.reviewer.as_str()), Some("Helper1"));
+
+    let result: serde_json::Value = clnt
+        .patch(format!("/api/v1/records/{}/", locked), &serde_json::json!({"progress": 65}))
+        .authorize_as(&helper2)
+        .header("If-Match", record.etag_string())
+        .expect_status(Status::Conflict)
+        .get_result()
+        .await;
+
+    assert_eq!(result["code"].as_i64(), Some(40910));
+
+    let (records, _) = clnt
+        .get("/api/v1/records/?assigned=me")
+        .authorize_as(&helper1)
+        .get_pagination_result::<serde_json::Value>()
+        .await;
+
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0]["id"], locked);
+
+    let (records, _) = clnt
+        .get("/api/v1/records/?assigned=unassigned&status=submitted")
+        .authorize_as(&helper1)
+        .get_pagination_result::<serde_json::Value>()
+        .await;
+
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0]["id"], unlocked);
+
+    clnt.get("/api/v1/records/?assigned=me")
+        .expect_status(Status::Unauthorized)
+        .execute()
+        .await;
+
+    // Only moderators can break someone else's lock
+    clnt.delete(format!("/api/v1/records/{}/lock/", locked))
+        .authorize_as(&helper2)
+        .expect_status(Status::Forbidden)
+        .execute()
+        .await;
+
+    clnt.delete(format!("/api/v1/records/{}/lock/", locked))
+        .authorize_as(&moderator)
+        .expect_status(Status::NoContent)
+        .execute()
+        .await;
+
+    let lock: serde_json::Value = clnt
+        .put(format!("/api/v1/records/{}/lock/", locked))
+        .authorize_as(&helper2)
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert_eq!(lock["reviewer"], "Helper2");
+
+    // Expired locks are ignored
+    sqlx::query!("UPDATE record_locks SET locked_until = locked_until - INTERVAL '1 day'")
+        .execute(&mut *connection)
+        .await
+        .unwrap();
+
+    let record: FullRecord = clnt
+        .get(format!("/api/v1/records/{}/", locked))
+        .authorize_as(&helper1)
+        .get_success_result()
+        .await;
+
+    assert!(record.lock.is_none());
+
+    clnt.put(format!("/api/v1/records/{}/lock/", locked))
+        .authorize_as(&helper1)
+        .expect_status(Status::Ok)
+        .execute()
+        .await;
+
+    // Unlocking requires the same permissions as locking
+    sqlx::query!(
+        "UPDATE list_tiers SET edit_permission = $1 WHERE list = 'main' AND id = 'main'",
+        LIST_MODERATOR.bit() as i16
+    )
+    .execute(&mut *connection)
+    .await
+    .unwrap();
+
+    clnt.delete(format!("/api/v1/records/{}/lock/", locked))
+        .authorize_as(&helper1)
+        .expect_status(Status::Forbidden)
+        .execute()
+        .await;
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_moderation_stats(pool: Pool<Postgres>) {
+    let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let helper = named_system_user_with_perms("Helper", LIST_HELPER, &mut connection).await;
+    let admin = named_system_user_with_perms("Admin", LIST_ADMINISTRATOR, &mut connection).await;
+
+    let verifier = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let demon = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 50, verifier.id, verifier.id, &mut connection).await;
+
+    // Attribute the submissions to the system user, so that their submission times get logged
+    audit_connection(&mut connection, 0).await.unwrap();
+
+    let mut submissions = Vec::new();
+
+    for player in ["Player1", "Player2", "Player3"] {
+        let player = DatabasePlayer::by_name_or_create(player, &mut connection).await.unwrap();
+        let record_id = add_simple_record(100, player.id, demon, RecordStatus::Submitted, &mut connection).await;
+
+        submissions.push(FullRecord::by_id(record_id, &mut connection).await.unwrap());
+    }
+
+    for (record, status) in submissions.iter().zip(["approved", "rejected"]) {
+        clnt.patch(format!("/api/v1/records/{}/", record.id), &serde_json::json!({ "status": status }))
+            .authorize_as(&helper)
+            .header("If-Match", record.etag_string())
+            .expect_status(Status::Ok)
+            .execute()
+            .await;
+    }
+
+    clnt.get("/api/v1/records/stats/?after=2000-01-01T00:00:00")
+        .authorize_as(&helper)
+        .expect_status(Status::Forbidden)
+        .execute()
+        .await;
+
+    let stats: serde_json::Value = clnt
+        .get("/api/v1/records/stats/?after=2000-01-01T00:00:00")
+        .authorize_as(&admin)
+        .get_result()
+        .await;
+
+    let reviewers = stats["reviewers"].as_array().unwrap();
+
+    assert_eq!(reviewers.len(), 1, "{:?}", reviewers);
+    assert_eq!(reviewers[0]["user"]["name"], "Helper");
+    assert_eq!(reviewers[0]["approvals"], 1);
+    assert_eq!(reviewers[0]["rejections"], 1);
+    assert!(reviewers[0]["median_decision_seconds"].is_number());
+
+    assert_eq!(stats["queue"][0]["list"], "main");
+    assert_eq!(stats["queue"][0]["tier"], "main");
+    assert_eq!(stats["queue"][0]["submitted"], 1);
+
+    // Nothing happened in the future
+    let stats: serde_json::Value = clnt
+        .get("/api/v1/records/stats/?after=2100-01-01T00:00:00&before=2100-02-01T00:00:00")
+        .authorize_as(&admin)
+        .get_result()
+        .await;
+
+    assert_eq!(stats["reviewers"].as_array().map(Vec::len), Some(0));
+}
+
+/// Waits for the asynchronous video check of the given submission to finish, returning whether the
+/// submission still exists afterwards
+async fn await_video_check(record_id: i32, connection: &mut PgConnection) -> bool {
+    for _ in 0..100 {
+        let row = sqlx::query!(
+            r#"SELECT EXISTS(SELECT 1 FROM records WHERE id = $1) AS "exists!", EXISTS(SELECT 1 FROM record_video_checks WHERE record = $1) AS "checked!""#,
+            record_id
+        )
+        .fetch_one(&mut *connection)
+        .await
+        .unwrap();
+
+        if !row.exists || row.checked {
+            return row.exists;
+        }
+
+        rocket::tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+
+    panic!("video of record {} was never checked", record_id)
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_missing_video_deletes_submission(pool: Pool<Postgres>) {
+    let (clnt, mut connection) =
+        setup_rocket_with_verifier(pool, StaticVerifier(VideoCheckResult::Missing, VideoMetadata::default())).await;
+
+    let player1 = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let demon1 = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 50, player1.id, player1.id, &mut connection).await;
+
+    let submission = serde_json::json! {{"progress": 60, "demon": demon1, "player": "stardust1971", "video": "https://youtube.com/watch?v=1234567890", "raw_footage": "https://pointercrate.com"}};
+
+    let record: FullRecord = clnt.post("/api/v1/records/", &submission).get_success_result().await;
+
+    assert!(!await_video_check(record.id, &mut connection).await);
+
+    let submitter = Submitter::by_ip("127.0.0.1".parse().unwrap(), &mut connection)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(submitter.statistics.submitted, 1);
+    assert_eq!(submitter.statistics.deleted_by_video_check, 1);
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_inconclusive_video_check_keeps_submission(pool: Pool<Postgres>) {
+    let (clnt, mut connection) =
+        setup_rocket_with_verifier(pool, StaticVerifier(VideoCheckResult::Inconclusive, VideoMetadata::default())).await;
+
+    let helper = system_user_with_perms(LIST_HELPER, &mut connection).await;
+    let player1 = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let demon1 = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 50, player1.id, player1.id, &mut connection).await;
+
+    let submission = serde_json::json! {{"progress": 60, "demon": demon1, "player": "stardust1971", "video": "https://youtube.com/watch?v=1234567890", "raw_footage": "https://pointercrate.com"}};
+
+    let record: FullRecord = clnt.post("/api/v1/records/", &submission).get_success_result().await;
+
+    assert!(await_video_check(record.id, &mut connection).await);
+
+    let record: FullRecord = clnt
+        .get(format!("/api/v1/records/{}/", record.id))
+        .authorize_as(&helper)
+        .get_success_result()
+        .await;
+
+    assert_eq!(record.video_check.map(|check| check.result), Some(VideoCheckResult::Inconclusive));
+
+    let (records, _) = clnt
+        .get("/api/v1/records/?video_check=inconclusive")
+        .authorize_as(&helper)
+        .get_pagination_result::<serde_json::Value>()
+        .await;
+
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0]["id"], record.id);
+
+    clnt.get("/api/v1/records/?video_check=inconclusive")
+        .expect_status(Status::Unauthorized)
+        .execute()
+        .await;
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_suspicious_submission_flagged(pool: Pool<Postgres>) {
+    let metadata = VideoMetadata {
+        channel: Some("https://www.youtube.com/@stardust1971".to_string()),
+        uploaded_at: Some("2000-01-01T00:00:00".parse().unwrap()),
+        ..VideoMetadata::default()
+    };
+
+    let (clnt, mut connection) = setup_rocket_with_verifier(pool, StaticVerifier(VideoCheckResult::Available, metadata.clone())).await;
+
+    // Attribute the demon addition to the system user, so that its addition time gets logged
+    audit_connection(&mut connection, 0).await.unwrap();
+
+    let helper = system_user_with_perms(LIST_HELPER, &mut connection).await;
+    let player1 = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let player2 = DatabasePlayer::by_name_or_create("stardust1972", &mut connection).await.unwrap();
+    let demon1 = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 50, player1.id, player1.id, &mut connection).await;
+
+    // A rejected record whose video was uploaded by the same channel
+    let rejected = add_simple_record(100, player1.id, demon1, RecordStatus::Rejected, &mut connection).await;
+    // An approved record with the same video, but linked differently
+    let approved = add_simple_record(100, player2.id, demon1, RecordStatus::Approved, &mut connection).await;
+    // A record whose video has the same id, but on a different host, making it a different video
+    let player3 = DatabasePlayer::by_name_or_create("stardust1974", &mut connection).await.unwrap();
+    let unrelated = add_simple_record(100, player3.id, demon1, RecordStatus::Approved, &mut connection).await;
+
+    for (record, video, metadata) in [
+        (rejected, "https://www.youtube.com/watch?v=aaaaaaaaaaa", metadata),
+        (approved, "https://youtu.be/12345678901", VideoMetadata::default()),
+        (unrelated, "https://vimeo.com/12345678901", VideoMetadata::default()),
+    ] {
+        sqlx::query!("UPDATE records SET video = $1 WHERE id = $2", video, record)
+            .execute(&mut *connection)
+            .await
+            .unwrap();
+        VideoCheck::store(record, video, VideoCheckResult::Available, metadata, &mut connection)
+            .await
+            .unwrap();
+    }
+
+    let submission = serde_json::json! {{"progress": 60, "demon": demon1, "player": "stardust1973", "video": "https://youtube.com/watch?v=12345678901", "raw_footage": "https://pointercrate.com"}};
+
+    let record: FullRecord = clnt.post("/api/v1/records/", &submission).get_success_result().await;
+
+    assert!(await_video_check(record.id, &mut connection).await);
+
+    // The heuristics run after the video check has been stored
+    let mut notes = Vec::new();
+
+    for _ in 0..100 {
+        notes = clnt
+            .get(format!("/api/v1/records/{}/notes/", record.id))
+            .authorize_as(&helper)
+            .get_result::<Vec<Note>>()
+            .await
+            .into_iter()
+            .filter(|note| note.is_automatic)
+            .collect();
+
+        if notes.len() == 3 {
+            break;
+        }
+
+        rocket::tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+
+    notes.sort_by_key(|note| note.id);
+
+    assert_eq!(notes.len(), 3);
+    assert!(notes.iter().all(|note| !note.is_public));
+    assert!(notes[0].content.contains("uploaded on 2000-01-01"));
+    assert!(notes[1].content.contains(&rejected.to_string()));
+    assert!(notes[2].content.contains(&approved.to_string()));
+    assert!(!notes[2].content.contains(&unrelated.to_string()));
+
+    let video_check = FullRecord::by_id(record.id, &mut connection).await.unwrap().video_check.unwrap();
+
+    assert_eq!(video_check.video_host.as_deref(), Some("YouTube"));
+    assert_eq!(video_check.video_id.as_deref(), Some("12345678901"));
+    assert_eq!(
+        video_check.metadata.channel.as_deref(),
+        Some("https://www.youtube.com/@stardust1971")
+    );
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_submitter_statistics(pool: Pool<Postgres>) {
+    let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let moderator = system_user_with_perms(LIST_MODERATOR, &mut connection).await;
+    let player1 = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let player2 = DatabasePlayer::by_name_or_create("stardust1972", &mut connection).await.unwrap();
+    let demon1 = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 50, player1.id, player1.id, &mut connection).await;
+    let demon2 = pointercrate_test::demonlist::add_demon("Bloodlust", 2, 50, player1.id, player1.id, &mut connection).await;
+
+    add_simple_record(100, player1.id, demon1, RecordStatus::Rejected, &mut connection).await;
+    add_simple_record(100, player2.id, demon1, RecordStatus::Rejected, &mut connection).await;
+    let record = add_simple_record(100, player1.id, demon2, RecordStatus::Approved, &mut connection).await;
+
+    let record: FullRecord = clnt
+        .get(format!("/api/v1/records/{}/", record))
+        .authorize_as(&moderator)
+        .get_success_result()
+        .await;
+
+    let statistics = record.submitter.unwrap().statistics;
+
+    assert_eq!(statistics.submitted, 3);
+    assert_eq!(statistics.approved, 1);
+    assert_eq!(statistics.rejected, 2);
+    assert_eq!(statistics.deleted_by_video_check, 0);
+
+    let (submitters, _) = clnt
+        .get("/api/v1/submitters/?rejected__gt=1")
+        .authorize_as(&moderator)
+        .get_pagination_result::<Submitter>()
+        .await;
+
+    assert_eq!(submitters.len(), 1);
+    assert_eq!(submitters[0].statistics, statistics);
+
+    let (submitters, _) = clnt
+        .get("/api/v1/submitters/?rejected__gt=2")
+        .authorize_as(&moderator)
+        .get_pagination_result::<Submitter>()
+        .await;
+
+    assert!(submitters.is_empty());
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_low_reputation_submission_under_consideration(pool: Pool<Postgres>) {
+    let thresholds = ReputationThresholds {
+        under_consideration: Some(2),
+        raw_footage: None,
+    };
+
+    let (clnt, mut connection) = setup_rocket_with_thresholds(pool, thresholds).await;
+
+    let player1 = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let demon1 = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 50, player1.id, player1.id, &mut connection).await;
+    let demon2 = pointercrate_test::demonlist::add_demon("Bloodlust", 2, 50, player1.id, player1.id, &mut connection).await;
+
+    add_simple_record(100, player1.id, demon1, RecordStatus::Rejected, &mut connection).await;
+
+    let submission = serde_json::json! {{"progress": 60, "demon": demon2, "player": "stardust1972", "video": "https://youtube.com/watch?v=1234567890", "raw_footage": "https://pointercrate.com"}};
+
+    let record: FullRecord = clnt.post("/api/v1/records/", &submission).get_success_result().await;
+
+    assert_eq!(record.status, RecordStatus::Submitted);
+
+    add_simple_record(100, player1.id, demon2, RecordStatus::Rejected, &mut connection).await;
+
+    let submission = serde_json::json! {{"progress": 60, "demon": demon1, "player": "stardust1972", "video": "https://youtube.com/watch?v=0987654321", "raw_footage": "https://pointercrate.com"}};
+
+    let record: FullRecord = clnt.post("/api/v1/records/", &submission).get_success_result().await;
+
+    assert_eq!(record.status, RecordStatus::UnderConsideration);
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_anonymous_submission_from_proxy(pool: Pool<Postgres>) {
+    let (clnt, mut connection) = setup_rocket_with(pool, |rocket| {
+        rocket
+            .manage(Arc::new(StaticVerifier(VideoCheckResult::Available, VideoMetadata::default())) as Arc<dyn VideoVerifier>)
+            .manage(Box::new(CidrProxyDetector::parse("127.0.0.0/8").unwrap()) as Box<dyn ProxyDetector>)
+    })
+    .await;
+
+    let helper = system_user_with_perms(LIST_HELPER, &mut connection).await;
+    let player1 = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let demon1 = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 50, player1.id, player1.id, &mut connection).await;
+
+    let submission = serde_json::json! {{"progress": 60, "demon": demon1, "player": "stardust1971", "video": "https://youtube.com/watch?v=1234567890", "raw_footage": "https://pointercrate.com"}};
+
+    let result: serde_json::Value = clnt
+        .post("/api/v1/records/", &submission)
+        .expect_status(Status::Forbidden)
+        .get_result()
+        .await;
+
+    assert_eq!(result["code"].as_i64(), Some(40307));
+
+    // Authenticated submissions are not affected
+    clnt.post("/api/v1/records/", &submission)
+        .authorize_as(&helper)
+        .expect_status(Status::Ok)
+        .execute()
+        .await;
+}
@@ -0,0 +1,179 @@
+use pointercrate_demonlist::{player::DatabasePlayer, submitter::Submitter, LIST_HELPER, LIST_MODERATOR};
+use pointercrate_test::user::system_user_with_perms;
+use rocket::http::Status;
+use sqlx::{Pool, Postgres};
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_ipv6_submitters_grouped_by_prefix(pool: Pool<Postgres>) {
+    let mut connection = pool.acquire().await.unwrap();
+
+    let submitter = Submitter::create_submitter("2001:db8::1".parse().unwrap(), 64, &mut connection)
+        .await
+        .unwrap();
+
+    let same_prefix = Submitter::by_ip("2001:db8::ffff".parse().unwrap(), &mut connection).await.unwrap();
+    let other_prefix = Submitter::by_ip("2001:db8:0:1::1".parse().unwrap(), &mut connection).await.unwrap();
+
+    assert_eq!(same_prefix.map(|submitter| submitter.id), Some(submitter.id));
+    assert!(other_prefix.is_none());
+
+    // IPv4 addresses are never grouped
+    Submitter::create_submitter("192.0.2.1".parse().unwrap(), 64, &mut connection)
+        .await
+        .unwrap();
+
+    assert!(Submitter::by_ip("192.0.2.2".parse().unwrap(), &mut connection)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_ipv6_prefix_does_not_lift_legacy_ban(pool: Pool<Postgres>) {
+    let mut connection = pool.acquire().await.unwrap();
+
+    // Before submitters were grouped by prefix, IPv6 submitters were identified by single addresses
+    let legacy = Submitter::create_submitter("2001:db8::1".parse().unwrap(), 128, &mut connection)
+        .await
+        .unwrap();
+
+    sqlx::query!("UPDATE submitters SET banned = TRUE WHERE submitter_id = $1", legacy.id)
+        .execute(&mut *connection)
+        .await
+        .unwrap();
+
+    let prefix = Submitter::create_submitter("2001:db8::2".parse().unwrap(), 64, &mut connection)
+        .await
+        .unwrap();
+
+    let submitter = Submitter::by_ip("2001:db8::1".parse().unwrap(), &mut connection)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(submitter.id, prefix.id);
+    assert!(submitter.banned);
+
+    // Other addresses in the network are not affected by the ban of a single address
+    assert!(
+        !Submitter::by_ip("2001:db8::2".parse().unwrap(), &mut connection)
+            .await
+            .unwrap()
+            .unwrap()
+            .banned
+    );
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_logged_in_submission_linked_to_account(pool: Pool<Postgres>) {
+    let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let helper = system_user_with_perms(LIST_HELPER, &mut connection).await;
+    let player = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let demon = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 50, player.id, player.id, &mut connection).await;
+
+    let submission = serde_json::json! {{"progress": 60, "demon": demon, "player": "stardust1971", "video": "https://youtube.com/watch?v=1234567890", "raw_footage": "https://pointercrate.com"}};
+
+    let record: serde_json::Value = clnt
+        .post("/api/v1/records/", &submission)
+        .authorize_as(&helper)
+        .expect_status(Status::Ok)
+        .get_success_result()
+        .await;
+
+    let submitter = Submitter::by_member(helper.user().id, &mut connection).await.unwrap().unwrap();
+    let anonymous = Submitter::by_ip("127.0.0.1".parse().unwrap(), &mut connection)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_ne!(submitter.id, anonymous.id);
+    assert_eq!(record["submitter"]["id"].as_i64(), Some(submitter.id as i64));
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_submission_bans(pool: Pool<Postgres>) {
+    let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let moderator = system_user_with_perms(LIST_MODERATOR, &mut connection).await;
+    let player = DatabasePlayer::by_name_or_create("stardust1971", &mut connection).await.unwrap();
+    let demon = pointercrate_test::demonlist::add_demon("Bloodbath", 1, 50, player.id, player.id, &mut connection).await;
+
+    let submission = serde_json::json! {{"progress": 60, "demon": demon, "player": "stardust1971", "video": "https://youtube.com/watch?v=1234567890", "raw_footage": "https://pointercrate.com"}};
+
+    let result: serde_json::Value = clnt
+        .post("/api/v1/submitters/bans/", &serde_json::json! {{"network": "127.0.0.1/33"}})
+        .authorize_as(&moderator)
+        .expect_status(Status::UnprocessableEntity)
+        .get_result()
+        .await;
+
+    assert_eq!(result["code"].as_i64(), Some(42240));
+
+    let ban: serde_json::Value = clnt
+        .post("/api/v1/submitters/bans/", &serde_json::json! {{"network": "127.0.0.0/8"}})
+        .authorize_as(&moderator)
+        .expect_status(Status::Created)
+        .get_success_result()
+        .await;
+
+    assert_eq!(ban["network"].as_str(), Some("127.0.0.0/8"));
+
+    let result: serde_json::Value = clnt
+        .post("/api/v1/records/", &submission)
+        .expect_status(Status::Forbidden)
+        .get_result()
+        .await;
+
+    assert_eq!(result["code"].as_i64(), Some(40304));
+
+    // Network bans also cover logged in users, so that they cannot be evaded by logging in
+    clnt.post("/api/v1/records/", &submission)
+        .authorize_as(&moderator)
+        .expect_status(Status::Forbidden)
+        .execute()
+        .await;
+
+    let member_ban: serde_json::Value = clnt
+        .post("/api/v1/submitters/bans/", &serde_json::json! {{"member_id": moderator.user().id}})
+        .authorize_as(&moderator)
+        .expect_status(Status::Created)
+        .get_success_result()
+        .await;
+
+    let bans: Vec<serde_json::Value> = clnt
+        .get("/api/v1/submitters/bans/")
+        .authorize_as(&moderator)
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert_eq!(bans.len(), 2);
+
+    clnt.delete(format!("/api/v1/submitters/bans/{}/", ban["id"]))
+        .authorize_as(&moderator)
+        .expect_status(Status::NoContent)
+        .execute()
+        .await;
+
+    // The account is still banned, but anonymous submissions from the same network are allowed again
+    clnt.post("/api/v1/records/", &submission)
+        .authorize_as(&moderator)
+        .expect_status(Status::Forbidden)
+        .execute()
+        .await;
+
+    clnt.post("/api/v1/records/", &submission).expect_status(Status::Ok).execute().await;
+
+    clnt.delete(format!("/api/v1/submitters/bans/{}/", member_ban["id"]))
+        .authorize_as(&moderator)
+        .expect_status(Status::NoContent)
+        .execute()
+        .await;
+
+    clnt.delete(format!("/api/v1/submitters/bans/{}/", member_ban["id"]))
+        .authorize_as(&moderator)
+        .expect_status(Status::NotFound)
+        .execute()
+        .await;
+}
@@ -0,0 +1,233 @@
+use pointercrate_core::etag::Taggable;
+use pointercrate_demonlist::{
+    demon::{Demon, FullDemon},
+    tag::Tag,
+    LIST_ADMINISTRATOR, LIST_MODERATOR,
+};
+use rocket::http::Status;
+use serde_json::{json, Value};
+use sqlx::{Pool, Postgres};
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_create_and_patch_tag(pool: Pool<Postgres>) {
+    let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let moderator = pointercrate_test::user::system_user_with_perms(LIST_MODERATOR, &mut connection).await;
+    let admin = pointercrate_test::user::named_system_user_with_perms("Sarah", LIST_ADMINISTRATOR, &mut connection).await;
+
+    clnt.post("/api/v2/tags/", &json!({"id": "wave", "names": {"en": "Wave"}}))
+        .authorize_as(&moderator)
+        .expect_status(Status::Forbidden)
+        .execute()
+        .await;
+
+    let tag: Tag = clnt
+        .post("/api/v2/tags/", &json!({"id": "wave", "names": {"en": "Wave", "ru": "Волна"}}))
+        .authorize_as(&admin)
+        .expect_status(Status::Created)
+        .expect_header("Location", "/api/v2/tags/wave/")
+        .get_success_result()
+        .await;
+
+    assert_eq!(tag.names.len(), 2);
+    assert_eq!(tag.names["ru"], "Волна");
+
+    for (data, code) in [
+        (json!({"id": "wave", "names": {"en": "Wave"}}), 40918),
+        (json!({"id": "Not A Slug", "names": {"en": "Invalid"}}), 42247),
+        (json!({"id": "ship", "names": {}}), 42248),
+        (json!({"id": "ship", "names": {"english": "Ship"}}), 42248),
+    ] {
+        let result: Value = clnt
+            .post("/api/v2/tags/", &data)
+            .authorize_as(&admin)
+            .expect_status(Status::from_code(code / 100).unwrap())
+            .get_result()
+            .await;
+
+        assert_eq!(result["code"].as_i64(), Some(code as i64));
+    }
+
+    let tag: Tag = clnt
+        .patch(
+            "/api/v2/tags/wave/",
+            &json!({"names": {"en": "Wave gameplay", "ru": null, "de": "Welle"}}),
+        )
+        .authorize_as(&admin)
+        .header("If-Match", tag.etag_string())
+        .expect_status(Status::Ok)
+        .get_success_result()
+        .await;
+
+    assert_eq!(tag.names.len(), 2);
+    assert_eq!(tag.names["en"], "Wave gameplay");
+    assert_eq!(tag.names["de"], "Welle");
+
+    // Removing all names would leave the tag without a name to display
+    let result: Value = clnt
+        .patch("/api/v2/tags/wave/", &json!({"names": {"en": null, "de": null}}))
+        .authorize_as(&admin)
+        .header("If-Match", tag.etag_string())
+        .expect_status(Status::UnprocessableEntity)
+        .get_result()
+        .await;
+
+    assert_eq!(result["code"].as_i64(), Some(42248));
+
+    let tags: Vec<Tag> = clnt.get("/api/v2/tags/").expect_status(Status::Ok).get_result().await;
+
+    assert_eq!(tags, vec![tag]);
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn test_demon_tags(pool: Pool<Postgres>) {
+    let (clnt, mut connection) = pointercrate_test::demonlist::setup_rocket(pool).await;
+
+    let admin = pointercrate_test::user::system_user_with_perms(LIST_ADMINISTRATOR, &mut connection).await;
+
+    let demon = clnt.add_demon(&admin, "Bloodbath", 1, 100, "Riot", "Riot").await;
+    let demon_id = demon.demon.base.id;
+
+    for (id, name) in [("memory", "Memory"), ("wave", "Wave")] {
+        clnt.post("/api/v2/tags/", &json!({"id": id, "names": {"en": name}}))
+            .authorize_as(&admin)
+            .expect_status(Status::Created)
+            .execute()
+            .await;
+    }
+
+    clnt.post(format!("/api/v2/demons/{}/tags/", demon_id), &json!({"tag": "wave"}))
+        .authorize_as(&admin)
+        .expect_status(Status::Created)
+        .expect_header("Location", format!("/api/v2/demons/{}/tags/wave/", demon_id))
+        .execute()
+        .await;
+
+    let result: Value = clnt
+        .post(format!("/api/v2/demons/{}/tags/", demon_id), &json!({"tag": "wave"}))
+        .authorize_as(&admin)
+        .expect_status(Status::Conflict)
+        .get_result()
+        .await;
+
+    assert_eq!(result["code"].as_i64(), Some(40919));
+
+    clnt.post(format!("/api/v2/demons/{}/tags/", demon_id), &json!({"tag": "ship"}))
+        .authorize_as(&admin)
+        .expect_status(Status::NotFound)
+        .execute()
+        .await;
+
+    let demon: FullDemon = clnt.get(format!("/api/v2/demons/{}/", demon_id)).get_success_result().await;
+
+    assert_eq!(demon.tags.len(), 1);
+    assert_eq!(demon.tags[0].id, "wave");
+
+    for url in ["/api/v2/demons/?tag=wave", "/api/v2/demons/listed/?tag=wave"] {
+        let demons: Vec<Demon> = clnt.get(url).expect_status(Status::Ok).get_result().await;
+
+        assert_eq!(demons.len(), 1, "{}", url);
+        assert_eq!(demons[0].base.id, demon_id);
+    }
+
+    let demons: Vec<Demon> = clnt.get("/api/v2/demons/?tag=memory").expect_status(Status::Ok).get_result().await;
+
+    assert!(demons.is_empty());
+
+    clnt.delete(format!("/api/v2/demons/{}/tags/wave/", demon_id))
+        .authorize_as(&admin)
+        .expect_status(Status::NoContent)
+        .execute()
+        .await;
+
+    clnt.delete(format!("/api/v2/demons/{}/tags/wave/", demon_id))
+        .authorize_as(&admin)
+        .expect_status(Status::NotFound)
+        .execute()
+        .await;
+
+    // Deleting a tag removes it from all demons
+    clnt.post(format!("/api/v2/demons/{}/tags/", demon_id), &json!({"tag": "memory"}))
+        .authorize_as(&admin)
+        .expect_status(Status::Created)
+        .execute()
+        .await;
+
+    let memory: Tag = clnt.get("/api/v2/tags/memory/").get_success_result().await;
+
+    clnt.delete("/api/v2/tags/memory/")
+        .authorize_as(&admin)
+        .header("If-Match", memory.etag_string())
+        .expect_status(Status::NoContent)
+        .execute()
+        .await;
+
+    let demon: FullDemon = clnt.get(format!("/api/v2/demons/{}/", demon_id)).get_success_result().await;
+
+    assert!(demon.tags.is_empty());
+
+    let log: Value = clnt
+        .get("/api/v2/tags/wave/audit/")
+        .authorize_as(&admin)
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    let kinds = log
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| (entry["demon"]["id"].as_i64(), entry["type"].clone()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        kinds,
+        vec![
+            (None, json!("Addition")),
+            (None, json!({"Modification": {"language": "en", "name": null}})),
+            (Some(demon_id as i64), json!("Addition")),
+            (Some(demon_id as i64), json!("Deletion")),
+        ]
+    );
+    // The history of deleted tags stays available
+    let log: Vec<Value> = clnt
+        .get("/api/v2/tags/memory/audit/")
+        .authorize_as(&admin)
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    assert!(log
+        .iter()
+        .any(|entry| entry["demon"].is_null() && entry["type"] == json!("Deletion")));
+
+    clnt.get("/api/v2/tags/ship/audit/")
+        .authorize_as(&admin)
+        .expect_status(Status::NotFound)
+        .execute()
+        .await;
+
+    let log: Vec<Value> = clnt
+        .get(format!("/api/v2/demons/{}/audit/", demon_id))
+        .authorize_as(&admin)
+        .expect_status(Status::Ok)
+        .get_result()
+        .await;
+
+    let tag_changes = log
+        .iter()
+        .filter_map(|entry| entry["type"].get("Modification"))
+        .filter(|data| !data["added_tag"].is_null() || !data["removed_tag"].is_null())
+        .map(|data| (data["added_tag"].as_str(), data["removed_tag"].as_str()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        tag_changes,
+        vec![
+            (Some("wave"), None),
+            (None, Some("wave")),
+            (Some("memory"), None),
+            (None, Some("memory")),
+        ]
+    );
+}
@@ -74,6 +74,20 @@ impl AuthenticatedUser<PasswordOrBrowser> {
         self.increment_generation_id(connection).await
     }
 
+    #[cfg(feature = "oauth2")]
+    pub async fn link_google_account(
+        &mut self, creds: &super::oauth::ValidatedGoogleCredentials, connection: &mut PgConnection,
+    ) -> Result<()> {
+        match &mut self.auth_type {
+            AuthenticationType::Legacy(legacy) => legacy.set_linked_google_account(creds, connection).await?,
+            _ => return Err(CoreError::Unauthorized.into()),
+        }
+
+        self.increment_generation_id(connection).await
+    }
+}
+
+impl<Auth> AuthenticatedUser<Auth> {
     pub(super) async fn increment_generation_id(&mut self, connection: &mut PgConnection) -> Result<()> {
         sqlx::query!(
             "UPDATE members SET generation = generation + 1 WHERE member_id = $1",
@@ -86,18 +100,6 @@ impl AuthenticatedUser<PasswordOrBrowser> {
 
         Ok(())
     }
-
-    #[cfg(feature = "oauth2")]
-    pub async fn link_google_account(
-        &mut self, creds: &super::oauth::ValidatedGoogleCredentials, connection: &mut PgConnection,
-    ) -> Result<()> {
-        match &mut self.auth_type {
-            AuthenticationType::Legacy(legacy) => legacy.set_linked_google_account(creds, connection).await?,
-            _ => return Err(CoreError::Unauthorized.into()),
-        }
-
-        self.increment_generation_id(connection).await
-    }
 }
 
 #[cfg(test)]
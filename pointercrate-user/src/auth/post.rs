@@ -3,7 +3,7 @@ use sqlx::PgConnection;
 
 use crate::auth::AuthenticatedUser;
 
-use super::PasswordOrBrowser;
+use super::{NoAuth, PasswordOrBrowser};
 
 impl AuthenticatedUser<PasswordOrBrowser> {
     /// Invalidates all access tokens for the given account
@@ -17,6 +17,20 @@ impl AuthenticatedUser<PasswordOrBrowser> {
     }
 }
 
+impl AuthenticatedUser<NoAuth> {
+    /// Invalidates all access tokens for the given account without the account owner having
+    /// authenticated
+    ///
+    /// Only meant for administrative tooling that has direct access to the database anyway (e.g.
+    /// to lock out a compromised account). Request handlers should use the
+    /// [`PasswordOrBrowser`] version of this function.
+    pub async fn invalidate_all_tokens(mut self, connection: &mut PgConnection) -> Result<()> {
+        log::warn!("Forcefully invalidating all tokens for user {}", self.user());
+
+        self.increment_generation_id(connection).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     // this is fine, as tests are always ran with --all-features
@@ -46,4 +60,31 @@ mod tests {
             .validate_api_access(AccessClaims::decode(&access_token).unwrap())
             .is_err());
     }
+
+    #[cfg(feature = "legacy_accounts")]
+    #[sqlx::test(migrations = "../migrations")]
+    fn test_forcefully_invalidate_all_tokens(mut conn: sqlx::pool::PoolConnection<sqlx::Postgres>) {
+        use crate::auth::{legacy::Registration, AccessClaims, AuthenticatedUser};
+
+        let registration = Registration {
+            name: "Patrick".to_string(),
+            password: "very bad password".to_string(),
+        };
+
+        let patrick = AuthenticatedUser::register(registration, &mut conn).await.unwrap();
+        let patricks_id = patrick.user().id;
+        let access_token = patrick.generate_programmatic_access_token();
+
+        AuthenticatedUser::by_id(patricks_id, &mut conn)
+            .await
+            .unwrap()
+            .invalidate_all_tokens(&mut conn)
+            .await
+            .unwrap();
+
+        let patricks_clone = AuthenticatedUser::by_id(patricks_id, &mut conn).await.unwrap();
+        assert!(patricks_clone
+            .validate_api_access(AccessClaims::decode(&access_token).unwrap())
+            .is_err());
+    }
 }